@@ -1,9 +1,9 @@
 from demoparser2 import DemoParser
-import glob
 
 
 parser = DemoParser("path/to/demo.dem")
-df = parser.parse_event("player_death", other=["game_time", "round_start_time"])
-df["player_died_time"] = df["game_time"] - df["round_start_time"]
+# round_time is seconds since freezetime ended and clock_time is the in-game round timer.
+# Both use the tick interval of the server so they work on 128 tick demos too.
+df = parser.parse_event("player_death", other=["game_time", "round_time", "clock_time"])
 
-print(df.loc[:, ["attacker_name", "player_died_time"]])
\ No newline at end of file
+print(df.loc[:, ["attacker_name", "round_time", "clock_time"]])
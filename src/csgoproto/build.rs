@@ -34,5 +34,6 @@ fn main() -> Result<()> {
         .default_package_filename("protobuf")
         .bytes(["."])
         .enum_attribute(".", "#[derive(::strum::EnumIter)]")
+        .message_attribute(".", "#[derive(::serde::Serialize)]")
         .compile_protos(&protos, &["GameTracking-CS2/Protobufs/"])
 }
@@ -1,4 +1,5 @@
 // This file is @generated by prost-build.
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgProtoBufHeader {
     #[prost(fixed64, optional, tag="1")]
@@ -24,6 +25,7 @@ pub struct CMsgProtoBufHeader {
     #[prost(uint32, optional, tag="201")]
     pub gc_dir_index_source: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CChinaAgreementSessionsStartAgreementSessionInGameRequest {
     #[prost(uint32, optional, tag="1")]
@@ -33,6 +35,7 @@ pub struct CChinaAgreementSessionsStartAgreementSessionInGameRequest {
     #[prost(string, optional, tag="3")]
     pub client_ipaddress: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CChinaAgreementSessionsStartAgreementSessionInGameResponse {
     #[prost(string, optional, tag="1")]
@@ -74,6 +77,7 @@ impl GcProtoBufMsgSrc {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSoidOwner {
     #[prost(uint32, optional, tag="1")]
@@ -81,6 +85,7 @@ pub struct CMsgSoidOwner {
     #[prost(uint64, optional, tag="2")]
     pub id: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSoSingleObject {
     #[prost(int32, optional, tag="2")]
@@ -92,6 +97,7 @@ pub struct CMsgSoSingleObject {
     #[prost(message, optional, tag="5")]
     pub owner_soid: ::core::option::Option<CMsgSoidOwner>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSoMultipleObjects {
     #[prost(message, repeated, tag="2")]
@@ -103,6 +109,7 @@ pub struct CMsgSoMultipleObjects {
 }
 /// Nested message and enum types in `CMsgSOMultipleObjects`.
 pub mod c_msg_so_multiple_objects {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SingleObject {
         #[prost(int32, optional, tag="1")]
@@ -111,6 +118,7 @@ pub mod c_msg_so_multiple_objects {
         pub object_data: ::core::option::Option<::prost::bytes::Bytes>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSoCacheSubscribed {
     #[prost(message, repeated, tag="2")]
@@ -122,6 +130,7 @@ pub struct CMsgSoCacheSubscribed {
 }
 /// Nested message and enum types in `CMsgSOCacheSubscribed`.
 pub mod c_msg_so_cache_subscribed {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SubscribedType {
         #[prost(int32, optional, tag="1")]
@@ -130,11 +139,13 @@ pub mod c_msg_so_cache_subscribed {
         pub object_data: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSoCacheUnsubscribed {
     #[prost(message, optional, tag="2")]
     pub owner_soid: ::core::option::Option<CMsgSoidOwner>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSoCacheSubscriptionCheck {
     #[prost(fixed64, optional, tag="2")]
@@ -142,16 +153,19 @@ pub struct CMsgSoCacheSubscriptionCheck {
     #[prost(message, optional, tag="3")]
     pub owner_soid: ::core::option::Option<CMsgSoidOwner>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSoCacheSubscriptionRefresh {
     #[prost(message, optional, tag="2")]
     pub owner_soid: ::core::option::Option<CMsgSoidOwner>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSoCacheVersion {
     #[prost(fixed64, optional, tag="1")]
     pub version: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgAccountDetails {
     #[prost(bool, optional, tag="1")]
@@ -191,6 +205,7 @@ pub struct CMsgAccountDetails {
     #[prost(bool, optional, tag="19")]
     pub eligible_for_community_market: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGcMultiplexMessage {
     #[prost(uint32, optional, tag="1")]
@@ -202,11 +217,13 @@ pub struct CMsgGcMultiplexMessage {
     #[prost(bool, optional, tag="4")]
     pub replytogc: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGcMultiplexMessageResponse {
     #[prost(uint32, optional, tag="1")]
     pub msgtype: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CgcToGcMsgMasterAck {
     #[prost(uint32, optional, tag="1")]
@@ -214,14 +231,17 @@ pub struct CgcToGcMsgMasterAck {
     #[prost(uint32, optional, tag="2")]
     pub gc_type: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CgcToGcMsgMasterAckResponse {
     #[prost(int32, optional, tag="1", default="2")]
     pub eresult: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CgcToGcMsgMasterStartupComplete {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CgcToGcMsgRouted {
     #[prost(uint32, optional, tag="1")]
@@ -233,6 +253,7 @@ pub struct CgcToGcMsgRouted {
     #[prost(uint32, optional, tag="4")]
     pub ip: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CgcToGcMsgRoutedReply {
     #[prost(uint32, optional, tag="1")]
@@ -240,6 +261,7 @@ pub struct CgcToGcMsgRoutedReply {
     #[prost(bytes="bytes", optional, tag="2")]
     pub net_message: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGcUpdateSessionIp {
     #[prost(fixed64, optional, tag="1")]
@@ -247,16 +269,19 @@ pub struct CMsgGcUpdateSessionIp {
     #[prost(fixed32, optional, tag="2")]
     pub ip: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGcRequestSessionIp {
     #[prost(fixed64, optional, tag="1")]
     pub steamid: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGcRequestSessionIpResponse {
     #[prost(fixed32, optional, tag="1")]
     pub ip: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSoCacheHaveVersion {
     #[prost(message, optional, tag="1")]
@@ -264,6 +289,7 @@ pub struct CMsgSoCacheHaveVersion {
     #[prost(fixed64, optional, tag="2")]
     pub version: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgClientHello {
     #[prost(uint32, optional, tag="1")]
@@ -285,6 +311,7 @@ pub struct CMsgClientHello {
     #[prost(uint32, optional, tag="9")]
     pub steam_launcher: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgServerHello {
     #[prost(uint32, optional, tag="1")]
@@ -304,6 +331,7 @@ pub struct CMsgServerHello {
     #[prost(uint32, optional, tag="9")]
     pub socache_control: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgClientWelcome {
     #[prost(uint32, optional, tag="1")]
@@ -331,6 +359,7 @@ pub struct CMsgClientWelcome {
 }
 /// Nested message and enum types in `CMsgClientWelcome`.
 pub mod c_msg_client_welcome {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Location {
         #[prost(float, optional, tag="1")]
@@ -341,6 +370,7 @@ pub mod c_msg_client_welcome {
         pub country: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgConnectionStatus {
     #[prost(enumeration="GcConnectionStatus", optional, tag="1", default="HaveSession")]
@@ -356,6 +386,7 @@ pub struct CMsgConnectionStatus {
     #[prost(int32, optional, tag="6")]
     pub estimated_wait_seconds_remaining: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CWorkshopPopulateItemDescriptionsRequest {
     #[prost(uint32, optional, tag="1")]
@@ -365,6 +396,7 @@ pub struct CWorkshopPopulateItemDescriptionsRequest {
 }
 /// Nested message and enum types in `CWorkshop_PopulateItemDescriptions_Request`.
 pub mod c_workshop_populate_item_descriptions_request {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SingleItemDescription {
         #[prost(uint32, optional, tag="1")]
@@ -374,6 +406,7 @@ pub mod c_workshop_populate_item_descriptions_request {
         #[prost(bool, optional, tag="3")]
         pub one_per_account: ::core::option::Option<bool>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ItemDescriptionsLanguageBlock {
         #[prost(string, optional, tag="1")]
@@ -382,6 +415,7 @@ pub mod c_workshop_populate_item_descriptions_request {
         pub descriptions: ::prost::alloc::vec::Vec<SingleItemDescription>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CWorkshopGetContributorsRequest {
     #[prost(uint32, optional, tag="1")]
@@ -389,11 +423,13 @@ pub struct CWorkshopGetContributorsRequest {
     #[prost(uint32, optional, tag="2")]
     pub gameitemid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CWorkshopGetContributorsResponse {
     #[prost(fixed64, repeated, packed="false", tag="1")]
     pub contributors: ::prost::alloc::vec::Vec<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CWorkshopSetItemPaymentRulesRequest {
     #[prost(uint32, optional, tag="1")]
@@ -413,6 +449,7 @@ pub struct CWorkshopSetItemPaymentRulesRequest {
 }
 /// Nested message and enum types in `CWorkshop_SetItemPaymentRules_Request`.
 pub mod c_workshop_set_item_payment_rules_request {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct WorkshopItemPaymentRule {
         #[prost(uint64, optional, tag="1")]
@@ -424,6 +461,7 @@ pub mod c_workshop_set_item_payment_rules_request {
         #[prost(uint32, optional, tag="4", default="1")]
         pub rule_type: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct WorkshopDirectPaymentRule {
         #[prost(uint64, optional, tag="1")]
@@ -431,6 +469,7 @@ pub mod c_workshop_set_item_payment_rules_request {
         #[prost(string, optional, tag="2")]
         pub rule_description: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct PartnerItemPaymentRule {
         #[prost(uint32, optional, tag="1")]
@@ -441,9 +480,11 @@ pub mod c_workshop_set_item_payment_rules_request {
         pub rule_description: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CWorkshopSetItemPaymentRulesResponse {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CGameServersAggregationQueryRequest {
     #[prost(string, optional, tag="1")]
@@ -451,6 +492,7 @@ pub struct CGameServersAggregationQueryRequest {
     #[prost(string, repeated, tag="3")]
     pub group_fields: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CGameServersAggregationQueryResponse {
     #[prost(message, repeated, tag="1")]
@@ -458,6 +500,7 @@ pub struct CGameServersAggregationQueryResponse {
 }
 /// Nested message and enum types in `CGameServers_AggregationQuery_Response`.
 pub mod c_game_servers_aggregation_query_response {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Group {
         #[prost(string, repeated, tag="1")]
@@ -476,6 +519,7 @@ pub mod c_game_servers_aggregation_query_response {
         pub player_capacity: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CWorkshopAddSpecialPaymentRequest {
     #[prost(uint32, optional, tag="1")]
@@ -489,9 +533,11 @@ pub struct CWorkshopAddSpecialPaymentRequest {
     #[prost(uint64, optional, tag="5")]
     pub payment_row_usd: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CWorkshopAddSpecialPaymentResponse {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CProductInfoSetRichPresenceLocalizationRequest {
     #[prost(uint32, optional, tag="1")]
@@ -503,6 +549,7 @@ pub struct CProductInfoSetRichPresenceLocalizationRequest {
 }
 /// Nested message and enum types in `CProductInfo_SetRichPresenceLocalization_Request`.
 pub mod c_product_info_set_rich_presence_localization_request {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Token {
         #[prost(string, optional, tag="1")]
@@ -510,6 +557,7 @@ pub mod c_product_info_set_rich_presence_localization_request {
         #[prost(string, optional, tag="2")]
         pub value: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct LanguageSection {
         #[prost(string, optional, tag="1")]
@@ -518,9 +566,11 @@ pub mod c_product_info_set_rich_presence_localization_request {
         pub tokens: ::prost::alloc::vec::Vec<Token>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CProductInfoSetRichPresenceLocalizationResponse {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSerializedSoCache {
     #[prost(uint32, optional, tag="1")]
@@ -532,6 +582,7 @@ pub struct CMsgSerializedSoCache {
 }
 /// Nested message and enum types in `CMsgSerializedSOCache`.
 pub mod c_msg_serialized_so_cache {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct TypeCache {
         #[prost(uint32, optional, tag="1")]
@@ -541,6 +592,7 @@ pub mod c_msg_serialized_so_cache {
         #[prost(uint32, optional, tag="3")]
         pub service_id: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Cache {
         #[prost(uint32, optional, tag="1")]
@@ -554,6 +606,7 @@ pub mod c_msg_serialized_so_cache {
     }
     /// Nested message and enum types in `Cache`.
     pub mod cache {
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct Version {
             #[prost(uint32, optional, tag="1")]
@@ -632,6 +685,7 @@ impl GcConnectionStatus {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoFileHeader {
     #[prost(string, required, tag="1")]
@@ -665,6 +719,7 @@ pub struct CDemoFileHeader {
     #[prost(int32, optional, tag="15")]
     pub server_start_tick: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CGameInfo {
     #[prost(message, optional, tag="4")]
@@ -674,6 +729,7 @@ pub struct CGameInfo {
 }
 /// Nested message and enum types in `CGameInfo`.
 pub mod c_game_info {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct CDotaGameInfo {
         #[prost(uint64, optional, tag="1")]
@@ -701,6 +757,7 @@ pub mod c_game_info {
     }
     /// Nested message and enum types in `CDotaGameInfo`.
     pub mod c_dota_game_info {
+        #[derive(::serde::Serialize)]
         #[derive(Clone, PartialEq, ::prost::Message)]
         pub struct CPlayerInfo {
             #[prost(string, optional, tag="1")]
@@ -714,6 +771,7 @@ pub mod c_game_info {
             #[prost(int32, optional, tag="5")]
             pub game_team: ::core::option::Option<i32>,
         }
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct CHeroSelectEvent {
             #[prost(bool, optional, tag="1")]
@@ -724,12 +782,14 @@ pub mod c_game_info {
             pub hero_id: ::core::option::Option<i32>,
         }
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct CcsGameInfo {
         #[prost(int32, repeated, packed="false", tag="1")]
         pub round_start_ticks: ::prost::alloc::vec::Vec<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoFileInfo {
     #[prost(float, optional, tag="1")]
@@ -741,11 +801,13 @@ pub struct CDemoFileInfo {
     #[prost(message, optional, tag="4")]
     pub game_info: ::core::option::Option<CGameInfo>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoPacket {
     #[prost(bytes="bytes", optional, tag="3")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoFullPacket {
     #[prost(message, optional, tag="1")]
@@ -753,6 +815,7 @@ pub struct CDemoFullPacket {
     #[prost(message, optional, tag="2")]
     pub packet: ::core::option::Option<CDemoPacket>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoSaveGame {
     #[prost(bytes="bytes", optional, tag="1")]
@@ -764,19 +827,23 @@ pub struct CDemoSaveGame {
     #[prost(int32, optional, tag="4")]
     pub version: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CDemoSyncTick {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoConsoleCmd {
     #[prost(string, optional, tag="1")]
     pub cmdstring: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoSendTables {
     #[prost(bytes="bytes", optional, tag="1")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoClassInfo {
     #[prost(message, repeated, tag="1")]
@@ -784,6 +851,7 @@ pub struct CDemoClassInfo {
 }
 /// Nested message and enum types in `CDemoClassInfo`.
 pub mod c_demo_class_info {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ClassT {
         #[prost(int32, optional, tag="1")]
@@ -794,6 +862,7 @@ pub mod c_demo_class_info {
         pub table_name: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoCustomData {
     #[prost(int32, optional, tag="1")]
@@ -801,11 +870,13 @@ pub struct CDemoCustomData {
     #[prost(bytes="bytes", optional, tag="2")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoCustomDataCallbacks {
     #[prost(string, repeated, tag="1")]
     pub save_id: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoAnimationHeader {
     #[prost(sint32, optional, tag="1")]
@@ -815,6 +886,7 @@ pub struct CDemoAnimationHeader {
     #[prost(bytes="bytes", optional, tag="3")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoAnimationData {
     #[prost(sint32, optional, tag="1")]
@@ -828,6 +900,7 @@ pub struct CDemoAnimationData {
     #[prost(int64, optional, tag="5")]
     pub data_checksum: ::core::option::Option<i64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoStringTables {
     #[prost(message, repeated, tag="1")]
@@ -835,6 +908,7 @@ pub struct CDemoStringTables {
 }
 /// Nested message and enum types in `CDemoStringTables`.
 pub mod c_demo_string_tables {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ItemsT {
         #[prost(string, optional, tag="1")]
@@ -842,6 +916,7 @@ pub mod c_demo_string_tables {
         #[prost(bytes="bytes", optional, tag="2")]
         pub data: ::core::option::Option<::prost::bytes::Bytes>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct TableT {
         #[prost(string, optional, tag="1")]
@@ -854,9 +929,11 @@ pub mod c_demo_string_tables {
         pub table_flags: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CDemoStop {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoUserCmd {
     #[prost(int32, optional, tag="1")]
@@ -864,16 +941,19 @@ pub struct CDemoUserCmd {
     #[prost(bytes="bytes", optional, tag="2")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoSpawnGroups {
     #[prost(bytes="bytes", repeated, tag="3")]
     pub msgs: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoSpawnGroupsHltvBroadcast {
     #[prost(bytes="bytes", optional, tag="1")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoRecovery {
     #[prost(message, optional, tag="1")]
@@ -883,6 +963,7 @@ pub struct CDemoRecovery {
 }
 /// Nested message and enum types in `CDemoRecovery`.
 pub mod c_demo_recovery {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DemoInitialSpawnGroupEntry {
         #[prost(uint32, optional, tag="1")]
@@ -978,6 +1059,7 @@ impl EDemoCommands {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CEngineGotvSyncPacket {
     #[prost(uint64, optional, tag="1")]
@@ -1001,6 +1083,7 @@ pub struct CEngineGotvSyncPacket {
     #[prost(uint32, optional, tag="11")]
     pub cdndelay: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GameServerPing {
     #[prost(int32, optional, tag="2")]
@@ -1010,6 +1093,7 @@ pub struct GameServerPing {
     #[prost(uint32, optional, tag="5")]
     pub instances: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct DataCenterPing {
     #[prost(fixed32, optional, tag="1")]
@@ -1017,6 +1101,7 @@ pub struct DataCenterPing {
     #[prost(sint32, optional, tag="2")]
     pub ping: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct DetailedSearchStatistic {
     #[prost(uint32, optional, tag="1")]
@@ -1026,6 +1111,7 @@ pub struct DetailedSearchStatistic {
     #[prost(uint32, optional, tag="4")]
     pub players_searching: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TournamentPlayer {
     #[prost(uint32, optional, tag="1")]
@@ -1043,6 +1129,7 @@ pub struct TournamentPlayer {
     #[prost(string, optional, tag="7")]
     pub player_desc: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TournamentTeam {
     #[prost(int32, optional, tag="1")]
@@ -1056,6 +1143,7 @@ pub struct TournamentTeam {
     #[prost(message, repeated, tag="5")]
     pub players: ::prost::alloc::vec::Vec<TournamentPlayer>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TournamentEvent {
     #[prost(int32, optional, tag="1")]
@@ -1077,6 +1165,7 @@ pub struct TournamentEvent {
     #[prost(uint32, optional, tag="9")]
     pub active_section_id: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GlobalStatistics {
     #[prost(uint32, optional, tag="1")]
@@ -1110,6 +1199,7 @@ pub struct GlobalStatistics {
     #[prost(uint32, optional, tag="16")]
     pub required_appid_version2: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OperationalStatisticDescription {
     #[prost(string, optional, tag="1")]
@@ -1117,6 +1207,7 @@ pub struct OperationalStatisticDescription {
     #[prost(uint32, optional, tag="2")]
     pub idkey: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OperationalStatisticElement {
     #[prost(uint32, optional, tag="1")]
@@ -1124,6 +1215,7 @@ pub struct OperationalStatisticElement {
     #[prost(int32, repeated, packed="false", tag="2")]
     pub values: ::prost::alloc::vec::Vec<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OperationalStatisticsPacket {
     #[prost(int32, optional, tag="1")]
@@ -1133,6 +1225,7 @@ pub struct OperationalStatisticsPacket {
     #[prost(message, repeated, tag="3")]
     pub values: ::prost::alloc::vec::Vec<OperationalStatisticElement>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OperationalVarValue {
     #[prost(string, optional, tag="1")]
@@ -1144,6 +1237,7 @@ pub struct OperationalVarValue {
     #[prost(bytes="bytes", optional, tag="4")]
     pub svalue: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlayerRankingInfo {
     #[prost(uint32, optional, tag="1")]
@@ -1179,6 +1273,7 @@ pub struct PlayerRankingInfo {
 }
 /// Nested message and enum types in `PlayerRankingInfo`.
 pub mod player_ranking_info {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct PerMapRank {
         #[prost(uint32, optional, tag="1")]
@@ -1189,6 +1284,7 @@ pub mod player_ranking_info {
         pub wins: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct PlayerCommendationInfo {
     #[prost(uint32, optional, tag="1")]
@@ -1198,6 +1294,7 @@ pub struct PlayerCommendationInfo {
     #[prost(uint32, optional, tag="4")]
     pub cmd_leader: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlayerMedalsInfo {
     #[prost(uint32, repeated, packed="false", tag="7")]
@@ -1205,6 +1302,7 @@ pub struct PlayerMedalsInfo {
     #[prost(uint32, optional, tag="8")]
     pub featured_display_item_defidx: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct AccountActivity {
     #[prost(uint32, optional, tag="1")]
@@ -1216,6 +1314,7 @@ pub struct AccountActivity {
     #[prost(uint64, optional, tag="4")]
     pub matchid: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct TournamentMatchSetup {
     #[prost(int32, optional, tag="1")]
@@ -1227,6 +1326,7 @@ pub struct TournamentMatchSetup {
     #[prost(int32, optional, tag="4")]
     pub event_stage_id: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ServerHltvInfo {
     #[prost(uint32, optional, tag="1")]
@@ -1270,6 +1370,7 @@ pub struct ServerHltvInfo {
     #[prost(uint32, optional, tag="21")]
     pub flags: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct IpAddressMask {
     #[prost(uint32, optional, tag="1")]
@@ -1285,6 +1386,7 @@ pub struct IpAddressMask {
     #[prost(uint32, optional, tag="6")]
     pub token: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgCsgoSteamUserStatChange {
     #[prost(int32, optional, tag="1")]
@@ -1294,6 +1396,7 @@ pub struct CMsgCsgoSteamUserStatChange {
     #[prost(bool, optional, tag="3")]
     pub absolute: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct XpProgressData {
     #[prost(uint32, optional, tag="1")]
@@ -1301,6 +1404,7 @@ pub struct XpProgressData {
     #[prost(int32, optional, tag="2")]
     pub xp_category: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MatchEndItemUpdates {
     #[prost(uint64, optional, tag="1")]
@@ -1310,6 +1414,7 @@ pub struct MatchEndItemUpdates {
     #[prost(uint32, optional, tag="3")]
     pub item_attr_delta_value: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ScoreLeaderboardData {
     #[prost(uint64, optional, tag="1")]
@@ -1325,6 +1430,7 @@ pub struct ScoreLeaderboardData {
 }
 /// Nested message and enum types in `ScoreLeaderboardData`.
 pub mod score_leaderboard_data {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Entry {
         #[prost(uint32, optional, tag="1")]
@@ -1332,6 +1438,7 @@ pub mod score_leaderboard_data {
         #[prost(uint32, optional, tag="2")]
         pub val: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct AccountEntries {
         #[prost(uint32, optional, tag="1")]
@@ -1340,6 +1447,7 @@ pub mod score_leaderboard_data {
         pub entries: ::prost::alloc::vec::Vec<Entry>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlayerQuestData {
     #[prost(uint32, optional, tag="1")]
@@ -1361,6 +1469,7 @@ pub struct PlayerQuestData {
 }
 /// Nested message and enum types in `PlayerQuestData`.
 pub mod player_quest_data {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct QuestItemData {
         #[prost(uint64, optional, tag="1")]
@@ -1379,6 +1488,7 @@ pub mod player_quest_data {
         pub quest_type: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeepPlayerStatsEntry {
     #[prost(uint32, optional, tag="1")]
@@ -1438,6 +1548,7 @@ pub struct DeepPlayerStatsEntry {
     #[prost(uint32, repeated, packed="false", tag="34")]
     pub mates: ::prost::alloc::vec::Vec<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct DeepPlayerMatchEvent {
     #[prost(uint32, optional, tag="1")]
@@ -1469,6 +1580,7 @@ pub struct DeepPlayerMatchEvent {
     #[prost(int32, optional, tag="14")]
     pub event_data: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGcServerQuestUpdateData {
     #[prost(message, repeated, tag="1")]
@@ -1482,6 +1594,7 @@ pub struct CMsgGcServerQuestUpdateData {
     #[prost(uint32, optional, tag="5")]
     pub flags: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingGc2ServerConfirm {
     #[prost(uint32, optional, tag="1")]
@@ -1493,6 +1606,7 @@ pub struct CMsgGccStrike15V2MatchmakingGc2ServerConfirm {
     #[prost(uint32, optional, tag="4")]
     pub retry: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Gc2ServerReservationUpdate {
     #[prost(uint32, optional, tag="1")]
@@ -1500,6 +1614,7 @@ pub struct CMsgGccStrike15V2Gc2ServerReservationUpdate {
     #[prost(uint32, optional, tag="2")]
     pub viewers_external_steam: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingStart {
     #[prost(uint32, repeated, packed="false", tag="1")]
@@ -1519,11 +1634,13 @@ pub struct CMsgGccStrike15V2MatchmakingStart {
     #[prost(uint64, optional, tag="8")]
     pub lobby_id: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingStop {
     #[prost(int32, optional, tag="1")]
     pub abandon: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingGc2ClientUpdateNote {
     #[prost(int32, optional, tag="1")]
@@ -1535,6 +1652,7 @@ pub struct CMsgGccStrike15V2MatchmakingGc2ClientUpdateNote {
     #[prost(float, optional, tag="4")]
     pub distance: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingClient2ServerPing {
     #[prost(message, repeated, tag="1")]
@@ -1556,6 +1674,7 @@ pub struct CMsgGccStrike15V2MatchmakingClient2ServerPing {
     #[prost(string, optional, tag="9")]
     pub debug_message: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingGc2ClientUpdate {
     #[prost(int32, optional, tag="1")]
@@ -1591,6 +1710,7 @@ pub struct CMsgGccStrike15V2MatchmakingGc2ClientUpdate {
     #[prost(uint32, repeated, packed="false", tag="18")]
     pub insecure_account_id_sessions: ::prost::alloc::vec::Vec<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDataGccStrike15V2TournamentMatchDraft {
     #[prost(int32, optional, tag="1")]
@@ -1636,6 +1756,7 @@ pub struct CDataGccStrike15V2TournamentMatchDraft {
 }
 /// Nested message and enum types in `CDataGCCStrike15_v2_TournamentMatchDraft`.
 pub mod c_data_gcc_strike15_v2_tournament_match_draft {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Entry {
         #[prost(int32, optional, tag="1")]
@@ -1644,6 +1765,7 @@ pub mod c_data_gcc_strike15_v2_tournament_match_draft {
         pub team_id_ct: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CPreMatchInfoData {
     #[prost(int32, optional, tag="1")]
@@ -1657,6 +1779,7 @@ pub struct CPreMatchInfoData {
 }
 /// Nested message and enum types in `CPreMatchInfoData`.
 pub mod c_pre_match_info_data {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct TeamStats {
         #[prost(int32, optional, tag="1")]
@@ -1667,6 +1790,7 @@ pub mod c_pre_match_info_data {
         pub match_info_teams: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingGc2ServerReserve {
     #[prost(uint32, repeated, packed="false", tag="1")]
@@ -1712,6 +1836,7 @@ pub struct CMsgGccStrike15V2MatchmakingGc2ServerReserve {
     #[prost(uint32, optional, tag="22")]
     pub match_id_additional: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingServerReservationResponse {
     #[prost(uint64, optional, tag="1")]
@@ -1753,6 +1878,7 @@ pub struct CMsgGccStrike15V2MatchmakingServerReservationResponse {
     #[prost(uint32, optional, tag="19")]
     pub cpus_online: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingGc2ClientReserve {
     #[prost(uint64, optional, tag="1")]
@@ -1774,6 +1900,7 @@ pub struct CMsgGccStrike15V2MatchmakingGc2ClientReserve {
     #[prost(uint32, optional, tag="9")]
     pub gs_location_id: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingServerRoundStats {
     #[prost(uint64, optional, tag="1")]
@@ -1843,15 +1970,18 @@ pub struct CMsgGccStrike15V2MatchmakingServerRoundStats {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_MatchmakingServerRoundStats`.
 pub mod c_msg_gcc_strike15_v2_matchmaking_server_round_stats {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DropInfo {
         #[prost(uint32, optional, tag="1")]
         pub account_mvp: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingClient2GcHello {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingGc2ClientHello {
     #[prost(uint32, optional, tag="1")]
@@ -1895,6 +2025,7 @@ pub struct CMsgGccStrike15V2MatchmakingGc2ClientHello {
     #[prost(uint64, optional, tag="21")]
     pub owcaseid: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2AccountPrivacySettings {
     #[prost(message, repeated, tag="1")]
@@ -1902,6 +2033,7 @@ pub struct CMsgGccStrike15V2AccountPrivacySettings {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_AccountPrivacySettings`.
 pub mod c_msg_gcc_strike15_v2_account_privacy_settings {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Setting {
         #[prost(uint32, optional, tag="1")]
@@ -1910,6 +2042,7 @@ pub mod c_msg_gcc_strike15_v2_account_privacy_settings {
         pub setting_value: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingGc2ClientAbandon {
     #[prost(uint32, optional, tag="1")]
@@ -1921,16 +2054,19 @@ pub struct CMsgGccStrike15V2MatchmakingGc2ClientAbandon {
     #[prost(uint32, optional, tag="4")]
     pub penalty_reason: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientGcRankUpdate {
     #[prost(message, repeated, tag="1")]
     pub rankings: ::prost::alloc::vec::Vec<PlayerRankingInfo>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingOperator2GcBlogUpdate {
     #[prost(string, optional, tag="1")]
     pub main_post_url: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ServerNotificationForUserPenalty {
     #[prost(uint32, optional, tag="1")]
@@ -1942,6 +2078,7 @@ pub struct CMsgGccStrike15V2ServerNotificationForUserPenalty {
     #[prost(bool, optional, tag="4")]
     pub communication_cooldown: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientReportPlayer {
     #[prost(uint32, optional, tag="1")]
@@ -1963,6 +2100,7 @@ pub struct CMsgGccStrike15V2ClientReportPlayer {
     #[prost(bool, optional, tag="9")]
     pub report_from_demo: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientCommendPlayer {
     #[prost(uint32, optional, tag="1")]
@@ -1974,6 +2112,7 @@ pub struct CMsgGccStrike15V2ClientCommendPlayer {
     #[prost(uint32, optional, tag="10")]
     pub tokens: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientReportServer {
     #[prost(uint32, optional, tag="1")]
@@ -1989,6 +2128,7 @@ pub struct CMsgGccStrike15V2ClientReportServer {
     #[prost(uint64, optional, tag="8")]
     pub match_id: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientReportResponse {
     #[prost(uint64, optional, tag="1")]
@@ -2004,6 +2144,7 @@ pub struct CMsgGccStrike15V2ClientReportResponse {
     #[prost(uint32, optional, tag="6")]
     pub tokens: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientRequestWatchInfoFriends {
     #[prost(uint32, optional, tag="1")]
@@ -2019,6 +2160,7 @@ pub struct CMsgGccStrike15V2ClientRequestWatchInfoFriends {
     #[prost(message, repeated, tag="6")]
     pub data_center_pings: ::prost::alloc::vec::Vec<DataCenterPing>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WatchableMatchInfo {
     #[prost(uint32, optional, tag="1")]
@@ -2048,6 +2190,7 @@ pub struct WatchableMatchInfo {
     #[prost(uint64, optional, tag="13")]
     pub reservation_id: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientRequestJoinFriendData {
     #[prost(uint32, optional, tag="1")]
@@ -2063,6 +2206,7 @@ pub struct CMsgGccStrike15V2ClientRequestJoinFriendData {
     #[prost(string, optional, tag="6")]
     pub errormsg: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientRequestJoinServerData {
     #[prost(uint32, optional, tag="1")]
@@ -2080,6 +2224,7 @@ pub struct CMsgGccStrike15V2ClientRequestJoinServerData {
     #[prost(string, optional, tag="7")]
     pub errormsg: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGcCstrike15V2ClientRedeemMissionReward {
     #[prost(uint32, optional, tag="1")]
@@ -2093,6 +2238,7 @@ pub struct CMsgGcCstrike15V2ClientRedeemMissionReward {
     #[prost(int32, optional, tag="5")]
     pub bid_control: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGcCstrike15V2ClientRedeemFreeReward {
     #[prost(uint32, optional, tag="1")]
@@ -2102,6 +2248,7 @@ pub struct CMsgGcCstrike15V2ClientRedeemFreeReward {
     #[prost(uint64, repeated, packed="false", tag="3")]
     pub items: ::prost::alloc::vec::Vec<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGcCstrike15V2Gc2ServerNotifyXpRewarded {
     #[prost(message, repeated, tag="1")]
@@ -2125,11 +2272,13 @@ pub struct CMsgGcCstrike15V2Gc2ServerNotifyXpRewarded {
     #[prost(uint32, optional, tag="10")]
     pub xp_trail_level: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientNetworkConfig {
     #[prost(bytes="bytes", optional, tag="1")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15ClientDeepStats {
     #[prost(uint32, optional, tag="1")]
@@ -2141,6 +2290,7 @@ pub struct CMsgGccStrike15ClientDeepStats {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_ClientDeepStats`.
 pub mod c_msg_gcc_strike15_client_deep_stats {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DeepStatsRange {
         #[prost(uint32, optional, tag="1")]
@@ -2150,6 +2300,7 @@ pub mod c_msg_gcc_strike15_client_deep_stats {
         #[prost(bool, optional, tag="3")]
         pub frozen: ::core::option::Option<bool>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct DeepStatsMatch {
         #[prost(message, optional, tag="1")]
@@ -2158,6 +2309,7 @@ pub mod c_msg_gcc_strike15_client_deep_stats {
         pub events: ::prost::alloc::vec::Vec<super::DeepPlayerMatchEvent>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2WatchInfoUsers {
     #[prost(uint32, optional, tag="1")]
@@ -2169,6 +2321,7 @@ pub struct CMsgGccStrike15V2WatchInfoUsers {
     #[prost(uint32, optional, tag="5")]
     pub extended_timeout: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientRequestPlayersProfile {
     #[prost(uint32, optional, tag="1")]
@@ -2180,6 +2333,7 @@ pub struct CMsgGccStrike15V2ClientRequestPlayersProfile {
     #[prost(uint32, optional, tag="4")]
     pub request_level: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PlayersProfile {
     #[prost(uint32, optional, tag="1")]
@@ -2187,6 +2341,7 @@ pub struct CMsgGccStrike15V2PlayersProfile {
     #[prost(message, repeated, tag="2")]
     pub account_profiles: ::prost::alloc::vec::Vec<CMsgGccStrike15V2MatchmakingGc2ClientHello>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PremierSeasonSummary {
     #[prost(uint32, optional, tag="1")]
@@ -2200,6 +2355,7 @@ pub struct CMsgGccStrike15V2PremierSeasonSummary {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_PremierSeasonSummary`.
 pub mod c_msg_gcc_strike15_v2_premier_season_summary {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DataPerWeek {
         #[prost(uint64, optional, tag="1")]
@@ -2209,6 +2365,7 @@ pub mod c_msg_gcc_strike15_v2_premier_season_summary {
         #[prost(uint32, optional, tag="3")]
         pub matches_played: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DataPerMap {
         #[prost(uint32, optional, tag="1")]
@@ -2239,6 +2396,7 @@ pub mod c_msg_gcc_strike15_v2_premier_season_summary {
         pub rounds_5k: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PlayerOverwatchCaseUpdate {
     #[prost(uint64, optional, tag="1")]
@@ -2258,6 +2416,7 @@ pub struct CMsgGccStrike15V2PlayerOverwatchCaseUpdate {
     #[prost(uint32, optional, tag="9")]
     pub reason: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PlayerOverwatchCaseAssignment {
     #[prost(uint64, optional, tag="1")]
@@ -2283,6 +2442,7 @@ pub struct CMsgGccStrike15V2PlayerOverwatchCaseAssignment {
     #[prost(uint32, optional, tag="11")]
     pub reason: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PlayerOverwatchCaseStatus {
     #[prost(uint64, optional, tag="1")]
@@ -2290,6 +2450,7 @@ pub struct CMsgGccStrike15V2PlayerOverwatchCaseStatus {
     #[prost(uint32, optional, tag="2")]
     pub statusid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CClientHeaderOverwatchEvidence {
     #[prost(uint32, optional, tag="1")]
@@ -2297,6 +2458,7 @@ pub struct CClientHeaderOverwatchEvidence {
     #[prost(uint64, optional, tag="2")]
     pub caseid: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Gc2ClientTextMsg {
     #[prost(uint32, optional, tag="1")]
@@ -2306,6 +2468,7 @@ pub struct CMsgGccStrike15V2Gc2ClientTextMsg {
     #[prost(bytes="bytes", optional, tag="3")]
     pub payload: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Client2GcTextMsg {
     #[prost(uint32, optional, tag="1")]
@@ -2313,6 +2476,7 @@ pub struct CMsgGccStrike15V2Client2GcTextMsg {
     #[prost(bytes="bytes", repeated, tag="2")]
     pub args: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchEndRunRewardDrops {
     #[prost(message, optional, tag="3")]
@@ -2320,6 +2484,7 @@ pub struct CMsgGccStrike15V2MatchEndRunRewardDrops {
     #[prost(message, optional, tag="4")]
     pub match_end_quest_data: ::core::option::Option<CMsgGcServerQuestUpdateData>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CEconItemPreviewDataBlock {
     #[prost(uint32, optional, tag="1")]
@@ -2371,6 +2536,7 @@ pub struct CEconItemPreviewDataBlock {
 }
 /// Nested message and enum types in `CEconItemPreviewDataBlock`.
 pub mod c_econ_item_preview_data_block {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Sticker {
         #[prost(uint32, optional, tag="1")]
@@ -2399,16 +2565,19 @@ pub mod c_econ_item_preview_data_block {
         pub wrapped_sticker: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchEndRewardDropsNotification {
     #[prost(message, optional, tag="6")]
     pub iteminfo: ::core::option::Option<CEconItemPreviewDataBlock>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgItemAcknowledged {
     #[prost(message, optional, tag="1")]
     pub iteminfo: ::core::option::Option<CEconItemPreviewDataBlock>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Client2GcEconPreviewDataBlockRequest {
     #[prost(uint64, optional, tag="1")]
@@ -2420,29 +2589,35 @@ pub struct CMsgGccStrike15V2Client2GcEconPreviewDataBlockRequest {
     #[prost(uint64, optional, tag="4")]
     pub param_m: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Client2GcEconPreviewDataBlockResponse {
     #[prost(message, optional, tag="1")]
     pub iteminfo: ::core::option::Option<CEconItemPreviewDataBlock>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchListRequestCurrentLiveGames {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchListRequestLiveGameForUser {
     #[prost(uint32, optional, tag="1")]
     pub accountid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchListRequestRecentUserGames {
     #[prost(uint32, optional, tag="1")]
     pub accountid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchListRequestTournamentGames {
     #[prost(int32, optional, tag="1")]
     pub eventid: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchListRequestFullGameInfo {
     #[prost(uint64, optional, tag="1")]
@@ -2452,6 +2627,7 @@ pub struct CMsgGccStrike15V2MatchListRequestFullGameInfo {
     #[prost(uint32, optional, tag="3")]
     pub token: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDataGccStrike15V2MatchInfo {
     #[prost(uint64, optional, tag="1")]
@@ -2465,6 +2641,7 @@ pub struct CDataGccStrike15V2MatchInfo {
     #[prost(message, repeated, tag="5")]
     pub roundstatsall: ::prost::alloc::vec::Vec<CMsgGccStrike15V2MatchmakingServerRoundStats>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CDataGccStrike15V2TournamentGroupTeam {
     #[prost(int32, optional, tag="1")]
@@ -2474,6 +2651,7 @@ pub struct CDataGccStrike15V2TournamentGroupTeam {
     #[prost(bool, optional, tag="3")]
     pub correctpick: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDataGccStrike15V2TournamentGroup {
     #[prost(uint32, optional, tag="1")]
@@ -2499,12 +2677,14 @@ pub struct CDataGccStrike15V2TournamentGroup {
 }
 /// Nested message and enum types in `CDataGCCStrike15_v2_TournamentGroup`.
 pub mod c_data_gcc_strike15_v2_tournament_group {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Picks {
         #[prost(int32, repeated, packed="false", tag="1")]
         pub pickids: ::prost::alloc::vec::Vec<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDataGccStrike15V2TournamentSection {
     #[prost(uint32, optional, tag="1")]
@@ -2516,6 +2696,7 @@ pub struct CDataGccStrike15V2TournamentSection {
     #[prost(message, repeated, tag="4")]
     pub groups: ::prost::alloc::vec::Vec<CDataGccStrike15V2TournamentGroup>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDataGccStrike15V2TournamentInfo {
     #[prost(message, repeated, tag="1")]
@@ -2525,6 +2706,7 @@ pub struct CDataGccStrike15V2TournamentInfo {
     #[prost(message, repeated, tag="3")]
     pub tournament_teams: ::prost::alloc::vec::Vec<TournamentTeam>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchList {
     #[prost(uint32, optional, tag="1")]
@@ -2540,6 +2722,7 @@ pub struct CMsgGccStrike15V2MatchList {
     #[prost(message, optional, tag="6")]
     pub tournamentinfo: ::core::option::Option<CDataGccStrike15V2TournamentInfo>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchListTournamentOperatorMgmt {
     #[prost(int32, optional, tag="1")]
@@ -2549,6 +2732,7 @@ pub struct CMsgGccStrike15V2MatchListTournamentOperatorMgmt {
     #[prost(uint32, optional, tag="3")]
     pub accountid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Predictions {
     #[prost(uint32, optional, tag="1")]
@@ -2558,6 +2742,7 @@ pub struct CMsgGccStrike15V2Predictions {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_Predictions`.
 pub mod c_msg_gcc_strike15_v2_predictions {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct GroupMatchTeamPick {
         #[prost(int32, optional, tag="1")]
@@ -2572,6 +2757,7 @@ pub mod c_msg_gcc_strike15_v2_predictions {
         pub itemid: ::core::option::Option<u64>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Fantasy {
     #[prost(uint32, optional, tag="1")]
@@ -2581,6 +2767,7 @@ pub struct CMsgGccStrike15V2Fantasy {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_Fantasy`.
 pub mod c_msg_gcc_strike15_v2_fantasy {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct FantasySlot {
         #[prost(int32, optional, tag="1")]
@@ -2590,6 +2777,7 @@ pub mod c_msg_gcc_strike15_v2_fantasy {
         #[prost(uint64, optional, tag="3")]
         pub itemid: ::core::option::Option<u64>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct FantasyTeam {
         #[prost(int32, optional, tag="1")]
@@ -2598,11 +2786,13 @@ pub mod c_msg_gcc_strike15_v2_fantasy {
         pub slots: ::prost::alloc::vec::Vec<FantasySlot>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CAttributeString {
     #[prost(string, optional, tag="1")]
     pub value: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgLegacySource1ClientWelcome {
     #[prost(uint32, optional, tag="1")]
@@ -2630,6 +2820,7 @@ pub struct CMsgLegacySource1ClientWelcome {
 }
 /// Nested message and enum types in `CMsgLegacySource1ClientWelcome`.
 pub mod c_msg_legacy_source1_client_welcome {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Location {
         #[prost(float, optional, tag="1")]
@@ -2640,6 +2831,7 @@ pub mod c_msg_legacy_source1_client_welcome {
         pub country: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgCStrike15Welcome {
     #[prost(uint32, optional, tag="5")]
@@ -2657,6 +2849,7 @@ pub struct CMsgCStrike15Welcome {
     #[prost(uint64, optional, tag="19")]
     pub uniqueid: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientVarValueNotificationInfo {
     #[prost(string, optional, tag="1")]
@@ -2670,6 +2863,7 @@ pub struct CMsgGccStrike15V2ClientVarValueNotificationInfo {
     #[prost(string, repeated, tag="5")]
     pub choked_blocks: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ServerVarValueNotificationInfo {
     #[prost(uint32, optional, tag="1")]
@@ -2681,9 +2875,11 @@ pub struct CMsgGccStrike15V2ServerVarValueNotificationInfo {
     #[prost(uint32, repeated, packed="false", tag="4")]
     pub userdata: ::prost::alloc::vec::Vec<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2GiftsLeaderboardRequest {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2GiftsLeaderboardResponse {
     #[prost(uint32, optional, tag="1")]
@@ -2699,6 +2895,7 @@ pub struct CMsgGccStrike15V2GiftsLeaderboardResponse {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_GiftsLeaderboardResponse`.
 pub mod c_msg_gcc_strike15_v2_gifts_leaderboard_response {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct GiftLeaderboardEntry {
         #[prost(uint32, optional, tag="1")]
@@ -2707,6 +2904,7 @@ pub mod c_msg_gcc_strike15_v2_gifts_leaderboard_response {
         pub gifts: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientSubmitSurveyVote {
     #[prost(uint32, optional, tag="1")]
@@ -2714,11 +2912,13 @@ pub struct CMsgGccStrike15V2ClientSubmitSurveyVote {
     #[prost(uint32, optional, tag="2")]
     pub vote: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Server2GcClientValidate {
     #[prost(uint32, optional, tag="1")]
     pub accountid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Gc2ClientTournamentInfo {
     #[prost(uint32, optional, tag="1")]
@@ -2730,6 +2930,7 @@ pub struct CMsgGccStrike15V2Gc2ClientTournamentInfo {
     #[prost(uint32, repeated, packed="false", tag="4")]
     pub teamids: ::prost::alloc::vec::Vec<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsoEconCoupon {
     #[prost(uint32, optional, tag="1")]
@@ -2739,6 +2940,7 @@ pub struct CsoEconCoupon {
     #[prost(fixed32, optional, tag="3")]
     pub expiration_date: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsoAccountItemPersonalStore {
     #[prost(uint32, optional, tag="1")]
@@ -2748,6 +2950,7 @@ pub struct CsoAccountItemPersonalStore {
     #[prost(uint64, repeated, packed="false", tag="3")]
     pub items: ::prost::alloc::vec::Vec<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsoAccountXpShop {
     #[prost(uint32, optional, tag="1")]
@@ -2757,6 +2960,7 @@ pub struct CsoAccountXpShop {
     #[prost(uint32, repeated, packed="false", tag="3")]
     pub xp_tracks: ::prost::alloc::vec::Vec<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsoAccountXpShopBids {
     #[prost(uint32, optional, tag="1")]
@@ -2768,6 +2972,7 @@ pub struct CsoAccountXpShopBids {
     #[prost(uint32, optional, tag="4")]
     pub generation_time: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsoVolatileItemOffer {
     #[prost(uint32, optional, tag="1")]
@@ -2777,6 +2982,7 @@ pub struct CsoVolatileItemOffer {
     #[prost(uint32, repeated, packed="false", tag="3")]
     pub generation_time: ::prost::alloc::vec::Vec<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsoVolatileItemClaimedRewards {
     #[prost(uint32, optional, tag="1")]
@@ -2786,11 +2992,13 @@ pub struct CsoVolatileItemClaimedRewards {
     #[prost(uint32, repeated, packed="false", tag="3")]
     pub generation_time: ::prost::alloc::vec::Vec<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsoAccountKeychainRemoveToolCharges {
     #[prost(uint32, optional, tag="1")]
     pub charges: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsoQuestProgress {
     #[prost(uint32, optional, tag="1")]
@@ -2800,6 +3008,7 @@ pub struct CsoQuestProgress {
     #[prost(uint32, optional, tag="3")]
     pub bonus_points: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsoAccountSeasonalOperation {
     #[prost(uint32, optional, tag="1")]
@@ -2817,6 +3026,7 @@ pub struct CsoAccountSeasonalOperation {
     #[prost(uint32, optional, tag="7")]
     pub season_pass_time: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsoAccountRecurringSubscription {
     #[prost(uint32, optional, tag="1")]
@@ -2824,6 +3034,7 @@ pub struct CsoAccountRecurringSubscription {
     #[prost(uint32, optional, tag="2")]
     pub time_initiated: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsoGameAccountSteamChina {
     #[prost(uint32, optional, tag="1")]
@@ -2833,6 +3044,7 @@ pub struct CsoGameAccountSteamChina {
     #[prost(uint32, optional, tag="3")]
     pub time_play_ban: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsoPersonaDataPublic {
     #[prost(int32, optional, tag="1")]
@@ -2846,6 +3058,7 @@ pub struct CsoPersonaDataPublic {
     #[prost(uint32, optional, tag="5")]
     pub xp_trail_level: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsoAccountRecurringMission {
     #[prost(uint32, optional, tag="1")]
@@ -2857,6 +3070,7 @@ pub struct CsoAccountRecurringMission {
     #[prost(uint32, optional, tag="4")]
     pub progress: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Gc2ClientNotifyXpShop {
     #[prost(message, optional, tag="1")]
@@ -2868,9 +3082,11 @@ pub struct CMsgGccStrike15V2Gc2ClientNotifyXpShop {
     #[prost(uint32, optional, tag="4")]
     pub current_level: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Client2GcAckXpShopTracks {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingGc2ClientSearchStats {
     #[prost(uint32, optional, tag="1")]
@@ -2884,16 +3100,19 @@ pub struct CMsgGccStrike15V2MatchmakingGc2ClientSearchStats {
     #[prost(uint32, optional, tag="5")]
     pub note_level: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGcGlobalGameSubscribe {
     #[prost(uint64, optional, tag="1")]
     pub ticket: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGcGlobalGameUnsubscribe {
     #[prost(int32, optional, tag="1")]
     pub timeleft: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGcGlobalGamePlay {
     #[prost(uint64, optional, tag="1")]
@@ -2903,11 +3122,13 @@ pub struct CMsgGcGlobalGamePlay {
     #[prost(uint32, optional, tag="3")]
     pub msperpoint: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2AcknowledgePenalty {
     #[prost(int32, optional, tag="1")]
     pub acknowledged: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Client2GcRequestPrestigeCoin {
     #[prost(uint32, optional, tag="1")]
@@ -2919,6 +3140,7 @@ pub struct CMsgGccStrike15V2Client2GcRequestPrestigeCoin {
     #[prost(uint32, optional, tag="4")]
     pub prestigetime: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Client2GcStreamUnlock {
     #[prost(uint64, optional, tag="1")]
@@ -2926,11 +3148,13 @@ pub struct CMsgGccStrike15V2Client2GcStreamUnlock {
     #[prost(int32, optional, tag="2")]
     pub os: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientToGcRequestElevate {
     #[prost(uint32, optional, tag="1")]
     pub stage: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientToGcChat {
     #[prost(uint64, optional, tag="1")]
@@ -2938,6 +3162,7 @@ pub struct CMsgGccStrike15V2ClientToGcChat {
     #[prost(string, optional, tag="2")]
     pub text: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2GcToClientChat {
     #[prost(uint32, optional, tag="1")]
@@ -2945,6 +3170,7 @@ pub struct CMsgGccStrike15V2GcToClientChat {
     #[prost(string, optional, tag="2")]
     pub text: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientAuthKeyCode {
     #[prost(uint32, optional, tag="1")]
@@ -2952,11 +3178,13 @@ pub struct CMsgGccStrike15V2ClientAuthKeyCode {
     #[prost(string, optional, tag="2")]
     pub code: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15GotvSyncPacket {
     #[prost(message, optional, tag="1")]
     pub data: ::core::option::Option<CEngineGotvSyncPacket>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlayerDecalDigitalSignature {
     #[prost(bytes="bytes", optional, tag="1")]
@@ -2988,6 +3216,7 @@ pub struct PlayerDecalDigitalSignature {
     #[prost(uint32, optional, tag="14")]
     pub tint_id: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientPlayerDecalSign {
     #[prost(message, optional, tag="1")]
@@ -2995,11 +3224,13 @@ pub struct CMsgGccStrike15V2ClientPlayerDecalSign {
     #[prost(uint64, optional, tag="2")]
     pub itemid: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2BetaEnrollment {
     #[prost(uint32, optional, tag="1")]
     pub eresult: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientLogonFatalError {
     #[prost(uint32, optional, tag="1")]
@@ -3009,6 +3240,7 @@ pub struct CMsgGccStrike15V2ClientLogonFatalError {
     #[prost(string, optional, tag="3")]
     pub country: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientPollState {
     #[prost(uint32, optional, tag="1")]
@@ -3018,6 +3250,7 @@ pub struct CMsgGccStrike15V2ClientPollState {
     #[prost(int32, repeated, packed="false", tag="3")]
     pub values: ::prost::alloc::vec::Vec<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PartyRegister {
     #[prost(uint32, optional, tag="1")]
@@ -3039,6 +3272,7 @@ pub struct CMsgGccStrike15V2PartyRegister {
     #[prost(uint32, optional, tag="9")]
     pub game_type: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PartySearch {
     #[prost(uint32, optional, tag="1")]
@@ -3054,6 +3288,7 @@ pub struct CMsgGccStrike15V2PartySearch {
     #[prost(uint32, optional, tag="6")]
     pub game_type: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PartySearchResults {
     #[prost(message, repeated, tag="1")]
@@ -3061,6 +3296,7 @@ pub struct CMsgGccStrike15V2PartySearchResults {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_Party_SearchResults`.
 pub mod c_msg_gcc_strike15_v2_party_search_results {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Entry {
         #[prost(uint32, optional, tag="1")]
@@ -3079,6 +3315,7 @@ pub mod c_msg_gcc_strike15_v2_party_search_results {
         pub accountid: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PartyInvite {
     #[prost(uint32, optional, tag="1")]
@@ -3086,6 +3323,7 @@ pub struct CMsgGccStrike15V2PartyInvite {
     #[prost(uint32, optional, tag="2")]
     pub lobbyid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2AccountRequestCoPlays {
     #[prost(message, repeated, tag="1")]
@@ -3095,6 +3333,7 @@ pub struct CMsgGccStrike15V2AccountRequestCoPlays {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_Account_RequestCoPlays`.
 pub mod c_msg_gcc_strike15_v2_account_request_co_plays {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Player {
         #[prost(uint32, optional, tag="1")]
@@ -3105,6 +3344,7 @@ pub mod c_msg_gcc_strike15_v2_account_request_co_plays {
         pub online: ::core::option::Option<bool>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientToGcRequestTicket {
     #[prost(fixed64, optional, tag="1")]
@@ -3116,14 +3356,17 @@ pub struct CMsgGccStrike15V2ClientToGcRequestTicket {
     #[prost(string, optional, tag="5")]
     pub gameserver_sdr_routing: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGcToClientSteamDatagramTicket {
     #[prost(bytes="bytes", optional, tag="16")]
     pub serialized_ticket: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientRequestOffers {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientRequestSouvenir {
     #[prost(uint64, optional, tag="1")]
@@ -3133,6 +3376,7 @@ pub struct CMsgGccStrike15V2ClientRequestSouvenir {
     #[prost(int32, optional, tag="3")]
     pub eventid: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientAccountBalance {
     #[prost(uint64, optional, tag="1")]
@@ -3140,6 +3384,7 @@ pub struct CMsgGccStrike15V2ClientAccountBalance {
     #[prost(string, optional, tag="2")]
     pub url: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientPartyJoinRelay {
     #[prost(uint32, optional, tag="1")]
@@ -3147,6 +3392,7 @@ pub struct CMsgGccStrike15V2ClientPartyJoinRelay {
     #[prost(uint64, optional, tag="2")]
     pub lobbyid: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientPartyWarning {
     #[prost(message, repeated, tag="1")]
@@ -3154,6 +3400,7 @@ pub struct CMsgGccStrike15V2ClientPartyWarning {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_ClientPartyWarning`.
 pub mod c_msg_gcc_strike15_v2_client_party_warning {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Entry {
         #[prost(uint32, optional, tag="1")]
@@ -3162,6 +3409,7 @@ pub mod c_msg_gcc_strike15_v2_client_party_warning {
         pub warntype: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2SetEventFavorite {
     #[prost(uint64, optional, tag="1")]
@@ -3169,11 +3417,13 @@ pub struct CMsgGccStrike15V2SetEventFavorite {
     #[prost(bool, optional, tag="2")]
     pub is_favorite: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2GetEventFavoritesRequest {
     #[prost(bool, optional, tag="1")]
     pub all_events: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2GetEventFavoritesResponse {
     #[prost(bool, optional, tag="1")]
@@ -3183,6 +3433,7 @@ pub struct CMsgGccStrike15V2GetEventFavoritesResponse {
     #[prost(string, optional, tag="3")]
     pub json_featured: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientPerfReport {
     #[prost(message, repeated, tag="1")]
@@ -3190,6 +3441,7 @@ pub struct CMsgGccStrike15V2ClientPerfReport {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_ClientPerfReport`.
 pub mod c_msg_gcc_strike15_v2_client_perf_report {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Entry {
         #[prost(uint32, optional, tag="1")]
@@ -3206,6 +3458,7 @@ pub mod c_msg_gcc_strike15_v2_client_perf_report {
         pub status: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CvDiagnostic {
     #[prost(uint32, optional, tag="1")]
@@ -3217,6 +3470,7 @@ pub struct CvDiagnostic {
     #[prost(string, optional, tag="4")]
     pub string_value: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientReportValidation {
     #[prost(string, optional, tag="1")]
@@ -3260,6 +3514,7 @@ pub struct CMsgGccStrike15V2ClientReportValidation {
     #[prost(message, repeated, tag="20")]
     pub diagnostics: ::prost::alloc::vec::Vec<CvDiagnostic>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Gc2ClientRefuseSecureMode {
     #[prost(string, optional, tag="1")]
@@ -3281,6 +3536,7 @@ pub struct CMsgGccStrike15V2Gc2ClientRefuseSecureMode {
     #[prost(string, optional, tag="9")]
     pub files_prevented_trusted: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Gc2ClientRequestValidation {
     #[prost(bool, optional, tag="1")]
@@ -3288,6 +3544,7 @@ pub struct CMsgGccStrike15V2Gc2ClientRequestValidation {
     #[prost(string, optional, tag="2")]
     pub module: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Gc2ClientInitSystem {
     #[prost(bool, optional, tag="1")]
@@ -3309,6 +3566,7 @@ pub struct CMsgGccStrike15V2Gc2ClientInitSystem {
     #[prost(bool, optional, tag="9")]
     pub load_system: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Gc2ClientInitSystemResponse {
     #[prost(bool, optional, tag="1")]
@@ -3332,14 +3590,17 @@ pub struct CMsgGccStrike15V2Gc2ClientInitSystemResponse {
     #[prost(int32, optional, tag="10")]
     pub aux_system2: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2SetPlayerLeaderboardSafeName {
     #[prost(string, optional, tag="1")]
     pub leaderboard_safe_name: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgRequestRecurringMissionSchedule {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgRecurringMissionSchema {
     #[prost(message, repeated, tag="1")]
@@ -3347,6 +3608,7 @@ pub struct CMsgRecurringMissionSchema {
 }
 /// Nested message and enum types in `CMsgRecurringMissionSchema`.
 pub mod c_msg_recurring_mission_schema {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct MissionTemplateList {
         #[prost(uint32, optional, tag="1")]
@@ -4219,6 +4481,7 @@ impl ENetworkDisconnectionReason {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgVector {
     #[prost(float, optional, tag="1")]
@@ -4230,6 +4493,7 @@ pub struct CMsgVector {
     #[prost(float, optional, tag="4")]
     pub w: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgVector2D {
     #[prost(float, optional, tag="1")]
@@ -4237,6 +4501,7 @@ pub struct CMsgVector2D {
     #[prost(float, optional, tag="2")]
     pub y: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgQAngle {
     #[prost(float, optional, tag="1")]
@@ -4246,6 +4511,7 @@ pub struct CMsgQAngle {
     #[prost(float, optional, tag="3")]
     pub z: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgQuaternion {
     #[prost(float, optional, tag="1")]
@@ -4257,6 +4523,7 @@ pub struct CMsgQuaternion {
     #[prost(float, optional, tag="4")]
     pub w: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgTransform {
     #[prost(message, optional, tag="1")]
@@ -4266,6 +4533,7 @@ pub struct CMsgTransform {
     #[prost(message, optional, tag="3")]
     pub orientation: ::core::option::Option<CMsgQuaternion>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgRgba {
     #[prost(int32, optional, tag="1")]
@@ -4277,6 +4545,7 @@ pub struct CMsgRgba {
     #[prost(int32, optional, tag="4")]
     pub a: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgPlayerInfo {
     #[prost(string, optional, tag="1")]
@@ -4292,11 +4561,13 @@ pub struct CMsgPlayerInfo {
     #[prost(bool, optional, tag="6")]
     pub ishltv: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CEntityMsg {
     #[prost(uint32, optional, tag="1", default="16777215")]
     pub target_entity: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgCVars {
     #[prost(message, repeated, tag="1")]
@@ -4304,6 +4575,7 @@ pub struct CMsgCVars {
 }
 /// Nested message and enum types in `CMsg_CVars`.
 pub mod c_msg_c_vars {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct CVar {
         #[prost(string, optional, tag="1")]
@@ -4312,14 +4584,17 @@ pub mod c_msg_c_vars {
         pub value: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CnetMsgNop {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CnetMsgSplitScreenUser {
     #[prost(int32, optional, tag="1")]
     pub slot: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CnetMsgTick {
     #[prost(uint32, optional, tag="1")]
@@ -4343,6 +4618,7 @@ pub struct CnetMsgTick {
     #[prost(uint32, optional, tag="13")]
     pub host_frame_irregular_arrival_pct_x10: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CnetMsgStringCmd {
     #[prost(string, optional, tag="1")]
@@ -4350,11 +4626,13 @@ pub struct CnetMsgStringCmd {
     #[prost(uint32, optional, tag="2")]
     pub prediction_sync: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CnetMsgSetConVar {
     #[prost(message, optional, tag="1")]
     pub convars: ::core::option::Option<CMsgCVars>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CnetMsgSignonState {
     #[prost(enumeration="SignonStateT", optional, tag="1", default="SignonstateNone")]
@@ -4370,6 +4648,7 @@ pub struct CnetMsgSignonState {
     #[prost(string, optional, tag="6")]
     pub addons: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgGameEvent {
     #[prost(string, optional, tag="1")]
@@ -4381,6 +4660,7 @@ pub struct CsvcMsgGameEvent {
 }
 /// Nested message and enum types in `CSVCMsg_GameEvent`.
 pub mod csvc_msg_game_event {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct KeyT {
         #[prost(int32, optional, tag="1")]
@@ -4401,6 +4681,7 @@ pub mod csvc_msg_game_event {
         pub val_uint64: ::core::option::Option<u64>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgListGameEvents {
     #[prost(message, repeated, tag="1")]
@@ -4408,6 +4689,7 @@ pub struct CsvcMsgListGameEvents {
 }
 /// Nested message and enum types in `CSVCMsgList_GameEvents`.
 pub mod csvc_msg_list_game_events {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct EventT {
         #[prost(int32, optional, tag="1")]
@@ -4416,6 +4698,7 @@ pub mod csvc_msg_list_game_events {
         pub event: ::core::option::Option<super::CsvcMsgGameEvent>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CnetMsgSpawnGroupLoad {
     #[prost(string, optional, tag="1")]
@@ -4459,6 +4742,7 @@ pub struct CnetMsgSpawnGroupLoad {
     #[prost(string, optional, tag="20")]
     pub worldgroupname: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CnetMsgSpawnGroupManifestUpdate {
     #[prost(uint32, optional, tag="1")]
@@ -4468,6 +4752,7 @@ pub struct CnetMsgSpawnGroupManifestUpdate {
     #[prost(bool, optional, tag="3")]
     pub manifestincomplete: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CnetMsgSpawnGroupSetCreationTick {
     #[prost(uint32, optional, tag="1")]
@@ -4477,6 +4762,7 @@ pub struct CnetMsgSpawnGroupSetCreationTick {
     #[prost(uint32, optional, tag="3")]
     pub creationsequence: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CnetMsgSpawnGroupUnload {
     #[prost(uint32, optional, tag="1")]
@@ -4486,11 +4772,13 @@ pub struct CnetMsgSpawnGroupUnload {
     #[prost(int32, optional, tag="3")]
     pub tickcount: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CnetMsgSpawnGroupLoadCompleted {
     #[prost(uint32, optional, tag="1")]
     pub spawngrouphandle: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgGameSessionConfiguration {
     #[prost(bool, optional, tag="1")]
@@ -4532,6 +4820,7 @@ pub struct CsvcMsgGameSessionConfiguration {
     #[prost(string, optional, tag="18")]
     pub landmarkname: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CnetMsgDebugOverlay {
     #[prost(int32, optional, tag="1")]
@@ -4701,6 +4990,7 @@ impl SpawnGroupFlagsT {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgVguiMenu {
     #[prost(string, optional, tag="1")]
@@ -4712,6 +5002,7 @@ pub struct CcsUsrMsgVguiMenu {
 }
 /// Nested message and enum types in `CCSUsrMsg_VGUIMenu`.
 pub mod ccs_usr_msg_vgui_menu {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Keys {
         #[prost(string, optional, tag="1")]
@@ -4720,21 +5011,25 @@ pub mod ccs_usr_msg_vgui_menu {
         pub value: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgGeiger {
     #[prost(int32, optional, tag="1")]
     pub range: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgTrain {
     #[prost(int32, optional, tag="1")]
     pub train: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgHudText {
     #[prost(string, optional, tag="1")]
     pub text: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgHudMsg {
     #[prost(int32, optional, tag="1")]
@@ -4758,6 +5053,7 @@ pub struct CcsUsrMsgHudMsg {
     #[prost(string, optional, tag="11")]
     pub text: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgShake {
     #[prost(int32, optional, tag="1")]
@@ -4769,6 +5065,7 @@ pub struct CcsUsrMsgShake {
     #[prost(float, optional, tag="4")]
     pub duration: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgFade {
     #[prost(int32, optional, tag="1")]
@@ -4780,6 +5077,7 @@ pub struct CcsUsrMsgFade {
     #[prost(message, optional, tag="4")]
     pub clr: ::core::option::Option<CMsgRgba>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgRumble {
     #[prost(int32, optional, tag="1")]
@@ -4789,6 +5087,7 @@ pub struct CcsUsrMsgRumble {
     #[prost(int32, optional, tag="3")]
     pub flags: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgCloseCaption {
     #[prost(uint32, optional, tag="1")]
@@ -4800,6 +5099,7 @@ pub struct CcsUsrMsgCloseCaption {
     #[prost(string, optional, tag="4")]
     pub cctoken: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgCloseCaptionDirect {
     #[prost(uint32, optional, tag="1")]
@@ -4809,11 +5109,13 @@ pub struct CcsUsrMsgCloseCaptionDirect {
     #[prost(bool, optional, tag="3")]
     pub from_player: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgSendAudio {
     #[prost(string, optional, tag="1")]
     pub radio_sound: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgRawAudio {
     #[prost(int32, optional, tag="1")]
@@ -4825,6 +5127,7 @@ pub struct CcsUsrMsgRawAudio {
     #[prost(string, optional, tag="4")]
     pub voice_filename: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgVoiceMask {
     #[prost(message, repeated, tag="1")]
@@ -4834,6 +5137,7 @@ pub struct CcsUsrMsgVoiceMask {
 }
 /// Nested message and enum types in `CCSUsrMsg_VoiceMask`.
 pub mod ccs_usr_msg_voice_mask {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct PlayerMask {
         #[prost(int32, optional, tag="1")]
@@ -4842,6 +5146,7 @@ pub mod ccs_usr_msg_voice_mask {
         pub ban_masks: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgDamage {
     #[prost(int32, optional, tag="1")]
@@ -4851,6 +5156,7 @@ pub struct CcsUsrMsgDamage {
     #[prost(int32, optional, tag="3", default="-1")]
     pub victim_entindex: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgRadioText {
     #[prost(int32, optional, tag="1")]
@@ -4862,16 +5168,19 @@ pub struct CcsUsrMsgRadioText {
     #[prost(string, repeated, tag="4")]
     pub params: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgHintText {
     #[prost(string, optional, tag="1")]
     pub message: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgKeyHintText {
     #[prost(string, repeated, tag="1")]
     pub messages: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgProcessSpottedEntityUpdate {
     #[prost(bool, optional, tag="1")]
@@ -4881,6 +5190,7 @@ pub struct CcsUsrMsgProcessSpottedEntityUpdate {
 }
 /// Nested message and enum types in `CCSUsrMsg_ProcessSpottedEntityUpdate`.
 pub mod ccs_usr_msg_process_spotted_entity_update {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct SpottedEntityUpdate {
         #[prost(int32, optional, tag="1", default="-1")]
@@ -4903,11 +5213,13 @@ pub mod ccs_usr_msg_process_spotted_entity_update {
         pub player_has_c4: ::core::option::Option<bool>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgSendPlayerItemDrops {
     #[prost(message, repeated, tag="1")]
     pub entity_updates: ::prost::alloc::vec::Vec<CEconItemPreviewDataBlock>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgSendPlayerItemFound {
     #[prost(message, optional, tag="1")]
@@ -4915,6 +5227,7 @@ pub struct CcsUsrMsgSendPlayerItemFound {
     #[prost(int32, optional, tag="2", default="-1")]
     pub playerslot: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgReloadEffect {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -4928,6 +5241,7 @@ pub struct CcsUsrMsgReloadEffect {
     #[prost(float, optional, tag="5")]
     pub origin_z: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgWeaponSound {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -4945,6 +5259,7 @@ pub struct CcsUsrMsgWeaponSound {
     #[prost(fixed32, optional, tag="7")]
     pub source_soundscapeid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgUpdateScreenHealthBar {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -4956,6 +5271,7 @@ pub struct CcsUsrMsgUpdateScreenHealthBar {
     #[prost(int32, optional, tag="4")]
     pub style: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgEntityOutlineHighlight {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -4963,11 +5279,13 @@ pub struct CcsUsrMsgEntityOutlineHighlight {
     #[prost(bool, optional, tag="2")]
     pub removehighlight: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgAdjustMoney {
     #[prost(int32, optional, tag="1")]
     pub amount: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgReportHit {
     #[prost(float, optional, tag="1")]
@@ -4979,6 +5297,7 @@ pub struct CcsUsrMsgReportHit {
     #[prost(float, optional, tag="3")]
     pub pos_z: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgKillCam {
     #[prost(int32, optional, tag="1")]
@@ -4988,6 +5307,7 @@ pub struct CcsUsrMsgKillCam {
     #[prost(int32, optional, tag="3", default="-1")]
     pub second_target: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgDesiredTimescale {
     #[prost(float, optional, tag="1")]
@@ -4999,11 +5319,13 @@ pub struct CcsUsrMsgDesiredTimescale {
     #[prost(float, optional, tag="4")]
     pub start_blend_time: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgCurrentTimescale {
     #[prost(float, optional, tag="1")]
     pub cur_timescale: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgAchievementEvent {
     #[prost(int32, optional, tag="1")]
@@ -5013,6 +5335,7 @@ pub struct CcsUsrMsgAchievementEvent {
     #[prost(int32, optional, tag="3")]
     pub user_id: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgMatchEndConditions {
     #[prost(int32, optional, tag="1")]
@@ -5024,6 +5347,7 @@ pub struct CcsUsrMsgMatchEndConditions {
     #[prost(float, optional, tag="4")]
     pub mp_timelimit: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgPlayerStatsUpdate {
     #[prost(int32, optional, tag="1")]
@@ -5037,6 +5361,7 @@ pub struct CcsUsrMsgPlayerStatsUpdate {
 }
 /// Nested message and enum types in `CCSUsrMsg_PlayerStatsUpdate`.
 pub mod ccs_usr_msg_player_stats_update {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Stat {
         #[prost(int32, optional, tag="1")]
@@ -5045,6 +5370,7 @@ pub mod ccs_usr_msg_player_stats_update {
         pub delta: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgQuestProgress {
     #[prost(uint32, optional, tag="1")]
@@ -5056,16 +5382,19 @@ pub struct CcsUsrMsgQuestProgress {
     #[prost(bool, optional, tag="4")]
     pub is_event_quest: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgScoreLeaderboardData {
     #[prost(message, optional, tag="1")]
     pub data: ::core::option::Option<ScoreLeaderboardData>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgPlayerDecalDigitalSignature {
     #[prost(message, optional, tag="1")]
     pub data: ::core::option::Option<PlayerDecalDigitalSignature>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgXRankGet {
     #[prost(int32, optional, tag="1")]
@@ -5073,6 +5402,7 @@ pub struct CcsUsrMsgXRankGet {
     #[prost(int32, optional, tag="2")]
     pub controller: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgXRankUpd {
     #[prost(int32, optional, tag="1")]
@@ -5082,6 +5412,7 @@ pub struct CcsUsrMsgXRankUpd {
     #[prost(int32, optional, tag="3")]
     pub ranking: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgCallVoteFailed {
     #[prost(int32, optional, tag="1")]
@@ -5089,6 +5420,7 @@ pub struct CcsUsrMsgCallVoteFailed {
     #[prost(int32, optional, tag="2")]
     pub time: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgVoteStart {
     #[prost(int32, optional, tag="1")]
@@ -5108,6 +5440,7 @@ pub struct CcsUsrMsgVoteStart {
     #[prost(int32, optional, tag="8", default="-1")]
     pub player_slot_target: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgVotePass {
     #[prost(int32, optional, tag="1")]
@@ -5119,6 +5452,7 @@ pub struct CcsUsrMsgVotePass {
     #[prost(string, optional, tag="4")]
     pub details_str: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgVoteFailed {
     #[prost(int32, optional, tag="1")]
@@ -5126,11 +5460,13 @@ pub struct CcsUsrMsgVoteFailed {
     #[prost(int32, optional, tag="2")]
     pub reason: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgVoteSetup {
     #[prost(string, repeated, tag="1")]
     pub potential_issues: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgSendLastKillerDamageToClient {
     #[prost(int32, optional, tag="1")]
@@ -5146,6 +5482,7 @@ pub struct CcsUsrMsgSendLastKillerDamageToClient {
     #[prost(int32, optional, tag="6")]
     pub actual_damage_taken: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgServerRankUpdate {
     #[prost(message, repeated, tag="1")]
@@ -5153,6 +5490,7 @@ pub struct CcsUsrMsgServerRankUpdate {
 }
 /// Nested message and enum types in `CCSUsrMsg_ServerRankUpdate`.
 pub mod ccs_usr_msg_server_rank_update {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct RankUpdate {
         #[prost(int32, optional, tag="1")]
@@ -5169,16 +5507,19 @@ pub mod ccs_usr_msg_server_rank_update {
         pub rank_type_id: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgXpUpdate {
     #[prost(message, optional, tag="1")]
     pub data: ::core::option::Option<CMsgGcCstrike15V2Gc2ServerNotifyXpRewarded>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgItemPickup {
     #[prost(string, optional, tag="1")]
     pub item: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgShowMenu {
     #[prost(int32, optional, tag="1")]
@@ -5188,26 +5529,31 @@ pub struct CcsUsrMsgShowMenu {
     #[prost(string, optional, tag="3")]
     pub menu_string: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgBarTime {
     #[prost(string, optional, tag="1")]
     pub time: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgAmmoDenied {
     #[prost(int32, optional, tag="1")]
     pub ammoidx: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgMarkAchievement {
     #[prost(string, optional, tag="1")]
     pub achievement: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgMatchStatsUpdate {
     #[prost(string, optional, tag="1")]
     pub update: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgItemDrop {
     #[prost(int64, optional, tag="1")]
@@ -5215,6 +5561,7 @@ pub struct CcsUsrMsgItemDrop {
     #[prost(bool, optional, tag="2")]
     pub death: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgRoundBackupFilenames {
     #[prost(int32, optional, tag="1")]
@@ -5226,6 +5573,7 @@ pub struct CcsUsrMsgRoundBackupFilenames {
     #[prost(string, optional, tag="4")]
     pub nicename: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgSsui {
     #[prost(bool, optional, tag="1")]
@@ -5235,6 +5583,7 @@ pub struct CcsUsrMsgSsui {
     #[prost(float, optional, tag="3")]
     pub end_time: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgSurvivalStats {
     #[prost(uint64, optional, tag="1")]
@@ -5250,6 +5599,7 @@ pub struct CcsUsrMsgSurvivalStats {
 }
 /// Nested message and enum types in `CCSUsrMsg_SurvivalStats`.
 pub mod ccs_usr_msg_survival_stats {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Fact {
         #[prost(int32, optional, tag="1")]
@@ -5261,6 +5611,7 @@ pub mod ccs_usr_msg_survival_stats {
         #[prost(float, optional, tag="4")]
         pub interestingness: ::core::option::Option<f32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Placement {
         #[prost(uint64, optional, tag="1")]
@@ -5270,6 +5621,7 @@ pub mod ccs_usr_msg_survival_stats {
         #[prost(int32, optional, tag="3")]
         pub placement: ::core::option::Option<i32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Damage {
         #[prost(uint64, optional, tag="1")]
@@ -5284,6 +5636,7 @@ pub mod ccs_usr_msg_survival_stats {
         pub from_hits: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgEndOfMatchAllPlayersData {
     #[prost(message, repeated, tag="1")]
@@ -5293,6 +5646,7 @@ pub struct CcsUsrMsgEndOfMatchAllPlayersData {
 }
 /// Nested message and enum types in `CCSUsrMsg_EndOfMatchAllPlayersData`.
 pub mod ccs_usr_msg_end_of_match_all_players_data {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Accolade {
         #[prost(int32, optional, tag="1")]
@@ -5302,6 +5656,7 @@ pub mod ccs_usr_msg_end_of_match_all_players_data {
         #[prost(int32, optional, tag="3")]
         pub position: ::core::option::Option<i32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct PlayerData {
         #[prost(int32, optional, tag="1", default="-1")]
@@ -5322,6 +5677,7 @@ pub mod ccs_usr_msg_end_of_match_all_players_data {
         pub isbot: ::core::option::Option<bool>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgRoundEndReportData {
     #[prost(message, optional, tag="1")]
@@ -5331,6 +5687,7 @@ pub struct CcsUsrMsgRoundEndReportData {
 }
 /// Nested message and enum types in `CCSUsrMsg_RoundEndReportData`.
 pub mod ccs_usr_msg_round_end_report_data {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct RerEvent {
         #[prost(float, optional, tag="1")]
@@ -5350,6 +5707,7 @@ pub mod ccs_usr_msg_round_end_report_data {
     }
     /// Nested message and enum types in `RerEvent`.
     pub mod rer_event {
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct Victim {
             #[prost(int32, optional, tag="1")]
@@ -5365,11 +5723,13 @@ pub mod ccs_usr_msg_round_end_report_data {
             #[prost(bool, optional, tag="6")]
             pub is_dead: ::core::option::Option<bool>,
         }
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct Objective {
             #[prost(int32, optional, tag="1")]
             pub r#type: ::core::option::Option<i32>,
         }
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct Damage {
             #[prost(int32, optional, tag="1", default="-1")]
@@ -5386,6 +5746,7 @@ pub mod ccs_usr_msg_round_end_report_data {
             pub return_num_hits: ::core::option::Option<i32>,
         }
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct InitialConditions {
         #[prost(int32, optional, tag="1")]
@@ -5396,6 +5757,7 @@ pub mod ccs_usr_msg_round_end_report_data {
         pub terrorist_odds: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgPostRoundDamageReport {
     #[prost(uint64, optional, tag="1")]
@@ -5413,16 +5775,19 @@ pub struct CcsUsrMsgPostRoundDamageReport {
     #[prost(int32, optional, tag="7")]
     pub taken_num_hits: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgCurrentRoundOdds {
     #[prost(int32, optional, tag="1")]
     pub odds: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgDeepStats {
     #[prost(message, optional, tag="1")]
     pub stats: ::core::option::Option<CMsgGccStrike15ClientDeepStats>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgShootInfo {
     #[prost(int32, optional, tag="1")]
@@ -5434,36 +5799,43 @@ pub struct CcsUsrMsgShootInfo {
     #[prost(message, optional, tag="4")]
     pub shoot_dir: ::core::option::Option<CMsgQAngle>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgResetHud {
     #[prost(bool, optional, tag="1")]
     pub reset: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgGameTitle {
     #[prost(int32, optional, tag="1")]
     pub dummy: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgRequestState {
     #[prost(int32, optional, tag="1")]
     pub dummy: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgStopSpectatorMode {
     #[prost(int32, optional, tag="1")]
     pub dummy: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgDisconnectToLobby {
     #[prost(int32, optional, tag="1")]
     pub dummy: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgClientInfo {
     #[prost(int32, optional, tag="1")]
     pub dummy: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgServerRankRevealAll {
     #[prost(int32, optional, tag="1")]
@@ -5471,6 +5843,7 @@ pub struct CcsUsrMsgServerRankRevealAll {
     #[prost(message, optional, tag="2")]
     pub reservation: ::core::option::Option<CMsgGccStrike15V2MatchmakingGc2ServerReserve>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgPreMatchSayText {
     #[prost(uint32, optional, tag="1")]
@@ -5480,6 +5853,7 @@ pub struct CcsUsrMsgPreMatchSayText {
     #[prost(bool, optional, tag="3")]
     pub all_chat: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgCounterStrafe {
     #[prost(int32, optional, tag="1")]
@@ -5487,6 +5861,7 @@ pub struct CcsUsrMsgCounterStrafe {
     #[prost(int32, optional, tag="2")]
     pub total_keys_down: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgDamagePrediction {
     #[prost(int32, optional, tag="1")]
@@ -5506,6 +5881,7 @@ pub struct CcsUsrMsgDamagePrediction {
     #[prost(message, optional, tag="8")]
     pub aim_punch: ::core::option::Option<CMsgQAngle>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgRecurringMissionSchema {
     #[prost(uint32, optional, tag="1")]
@@ -5513,6 +5889,7 @@ pub struct CcsUsrMsgRecurringMissionSchema {
     #[prost(bytes="bytes", optional, tag="2")]
     pub mission_schema: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgSendPlayerLoadout {
     #[prost(message, repeated, tag="1")]
@@ -5522,6 +5899,7 @@ pub struct CcsUsrMsgSendPlayerLoadout {
 }
 /// Nested message and enum types in `CCSUsrMsg_SendPlayerLoadout`.
 pub mod ccs_usr_msg_send_player_loadout {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct LoadoutItem {
         #[prost(message, optional, tag="1")]
@@ -5811,11 +6189,13 @@ impl EcsUsrMsgDisconnectToLobbyAction {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageAchievementEvent {
     #[prost(uint32, optional, tag="1")]
     pub achievement: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageCloseCaption {
     #[prost(fixed32, optional, tag="1")]
@@ -5827,6 +6207,7 @@ pub struct CUserMessageCloseCaption {
     #[prost(int32, optional, tag="4", default="-1")]
     pub ent_index: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageCloseCaptionDirect {
     #[prost(fixed32, optional, tag="1")]
@@ -5838,6 +6219,7 @@ pub struct CUserMessageCloseCaptionDirect {
     #[prost(int32, optional, tag="4", default="-1")]
     pub ent_index: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageCloseCaptionPlaceholder {
     #[prost(string, optional, tag="1")]
@@ -5849,11 +6231,13 @@ pub struct CUserMessageCloseCaptionPlaceholder {
     #[prost(int32, optional, tag="4", default="-1")]
     pub ent_index: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageCurrentTimescale {
     #[prost(float, optional, tag="1")]
     pub current: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageDesiredTimescale {
     #[prost(float, optional, tag="1")]
@@ -5865,6 +6249,7 @@ pub struct CUserMessageDesiredTimescale {
     #[prost(float, optional, tag="4")]
     pub blenddeltamultiplier: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageFade {
     #[prost(uint32, optional, tag="1")]
@@ -5876,6 +6261,7 @@ pub struct CUserMessageFade {
     #[prost(fixed32, optional, tag="4")]
     pub color: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageShake {
     #[prost(uint32, optional, tag="1")]
@@ -5887,6 +6273,7 @@ pub struct CUserMessageShake {
     #[prost(float, optional, tag="4")]
     pub duration: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageShakeDir {
     #[prost(message, optional, tag="1")]
@@ -5894,6 +6281,7 @@ pub struct CUserMessageShakeDir {
     #[prost(message, optional, tag="2")]
     pub direction: ::core::option::Option<CMsgVector>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageWaterShake {
     #[prost(uint32, optional, tag="1")]
@@ -5905,6 +6293,7 @@ pub struct CUserMessageWaterShake {
     #[prost(float, optional, tag="4")]
     pub duration: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageScreenTilt {
     #[prost(uint32, optional, tag="1")]
@@ -5918,6 +6307,7 @@ pub struct CUserMessageScreenTilt {
     #[prost(float, optional, tag="5")]
     pub time: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageSayText {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -5927,6 +6317,7 @@ pub struct CUserMessageSayText {
     #[prost(bool, optional, tag="3")]
     pub chat: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageSayText2 {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -5944,6 +6335,7 @@ pub struct CUserMessageSayText2 {
     #[prost(string, optional, tag="7")]
     pub param4: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageHudMsg {
     #[prost(uint32, optional, tag="1")]
@@ -5961,11 +6353,13 @@ pub struct CUserMessageHudMsg {
     #[prost(string, optional, tag="11")]
     pub message: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageHudText {
     #[prost(string, optional, tag="1")]
     pub message: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageTextMsg {
     #[prost(uint32, optional, tag="1")]
@@ -5973,12 +6367,15 @@ pub struct CUserMessageTextMsg {
     #[prost(string, repeated, tag="2")]
     pub param: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageGameTitle {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageResetHud {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageSendAudio {
     #[prost(string, optional, tag="1")]
@@ -5986,6 +6383,7 @@ pub struct CUserMessageSendAudio {
     #[prost(bool, optional, tag="2")]
     pub stop: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageAudioParameter {
     #[prost(uint32, optional, tag="1")]
@@ -5997,6 +6395,7 @@ pub struct CUserMessageAudioParameter {
     #[prost(uint32, optional, tag="4")]
     pub int_value: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageVoiceMask {
     #[prost(uint32, repeated, packed="false", tag="1")]
@@ -6006,9 +6405,11 @@ pub struct CUserMessageVoiceMask {
     #[prost(bool, optional, tag="3")]
     pub mod_enable: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageRequestState {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageRumble {
     #[prost(int32, optional, tag="1")]
@@ -6018,6 +6419,7 @@ pub struct CUserMessageRumble {
     #[prost(int32, optional, tag="3")]
     pub flags: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageSayTextChannel {
     #[prost(int32, optional, tag="1")]
@@ -6027,6 +6429,7 @@ pub struct CUserMessageSayTextChannel {
     #[prost(string, optional, tag="3")]
     pub text: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageColoredText {
     #[prost(uint32, optional, tag="1")]
@@ -6042,16 +6445,19 @@ pub struct CUserMessageColoredText {
     #[prost(int32, optional, tag="6")]
     pub context_team_id: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageItemPickup {
     #[prost(string, optional, tag="1")]
     pub itemname: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageAmmoDenied {
     #[prost(uint32, optional, tag="1")]
     pub ammo_id: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageShowMenu {
     #[prost(uint32, optional, tag="1")]
@@ -6063,6 +6469,7 @@ pub struct CUserMessageShowMenu {
     #[prost(string, optional, tag="4")]
     pub menustring: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageCreditsMsg {
     #[prost(enumeration="ERollType", optional, tag="1", default="RollNone")]
@@ -6070,11 +6477,13 @@ pub struct CUserMessageCreditsMsg {
     #[prost(float, optional, tag="2")]
     pub logo_length: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CEntityMessagePlayJingle {
     #[prost(message, optional, tag="1")]
     pub entity_msg: ::core::option::Option<CEntityMsg>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CEntityMessageScreenOverlay {
     #[prost(bool, optional, tag="1")]
@@ -6082,6 +6491,7 @@ pub struct CEntityMessageScreenOverlay {
     #[prost(message, optional, tag="2")]
     pub entity_msg: ::core::option::Option<CEntityMsg>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CEntityMessageRemoveAllDecals {
     #[prost(bool, optional, tag="1")]
@@ -6089,6 +6499,7 @@ pub struct CEntityMessageRemoveAllDecals {
     #[prost(message, optional, tag="2")]
     pub entity_msg: ::core::option::Option<CEntityMsg>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CEntityMessagePropagateForce {
     #[prost(message, optional, tag="1")]
@@ -6096,6 +6507,7 @@ pub struct CEntityMessagePropagateForce {
     #[prost(message, optional, tag="2")]
     pub entity_msg: ::core::option::Option<CEntityMsg>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CEntityMessageDoSpark {
     #[prost(message, optional, tag="1")]
@@ -6115,6 +6527,7 @@ pub struct CEntityMessageDoSpark {
     #[prost(message, optional, tag="8")]
     pub entity_msg: ::core::option::Option<CEntityMsg>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CEntityMessageFixAngle {
     #[prost(bool, optional, tag="1")]
@@ -6124,6 +6537,7 @@ pub struct CEntityMessageFixAngle {
     #[prost(message, optional, tag="3")]
     pub entity_msg: ::core::option::Option<CEntityMsg>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageCameraTransition {
     #[prost(uint32, optional, tag="1")]
@@ -6135,6 +6549,7 @@ pub struct CUserMessageCameraTransition {
 }
 /// Nested message and enum types in `CUserMessageCameraTransition`.
 pub mod c_user_message_camera_transition {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct TransitionDataDriven {
         #[prost(string, optional, tag="1")]
@@ -6145,6 +6560,7 @@ pub mod c_user_message_camera_transition {
         pub duration: ::core::option::Option<f32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMsgParticleManager {
     #[prost(enumeration="ParticleMessage", optional, tag="1", default="GameParticleManagerEventCreate")]
@@ -6232,9 +6648,11 @@ pub struct CUserMsgParticleManager {
 }
 /// Nested message and enum types in `CUserMsg_ParticleManager`.
 pub mod c_user_msg_particle_manager {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct ReleaseParticleIndex {
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct CreateParticle {
         #[prost(fixed64, optional, tag="1")]
@@ -6258,11 +6676,13 @@ pub mod c_user_msg_particle_manager {
         #[prost(message, optional, tag="10")]
         pub aggregation_position: ::core::option::Option<super::CMsgVector>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DestroyParticle {
         #[prost(bool, optional, tag="1")]
         pub destroy_immediately: ::core::option::Option<bool>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DestroyParticleInvolving {
         #[prost(bool, optional, tag="1")]
@@ -6270,6 +6690,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(uint32, optional, tag="3", default="16777215")]
         pub entity_handle: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DestroyParticleNamed {
         #[prost(fixed64, optional, tag="1")]
@@ -6281,6 +6702,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(bool, optional, tag="4")]
         pub play_endcap: ::core::option::Option<bool>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleObsolete {
         #[prost(int32, optional, tag="1")]
@@ -6288,6 +6710,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(message, optional, tag="2")]
         pub position: ::core::option::Option<super::CMsgVector>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleFwdObsolete {
         #[prost(int32, optional, tag="1")]
@@ -6295,6 +6718,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(message, optional, tag="2")]
         pub forward: ::core::option::Option<super::CMsgVector>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleOrientObsolete {
         #[prost(int32, optional, tag="1")]
@@ -6308,6 +6732,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(message, optional, tag="5")]
         pub left: ::core::option::Option<super::CMsgVector>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleTransform {
         #[prost(int32, optional, tag="1")]
@@ -6319,6 +6744,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(float, optional, tag="4")]
         pub interpolation_interval: ::core::option::Option<f32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleFallback {
         #[prost(int32, optional, tag="1")]
@@ -6326,6 +6752,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(message, optional, tag="2")]
         pub position: ::core::option::Option<super::CMsgVector>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleOffset {
         #[prost(int32, optional, tag="1")]
@@ -6335,6 +6762,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(message, optional, tag="3")]
         pub angle_offset: ::core::option::Option<super::CMsgQAngle>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleEnt {
         #[prost(int32, optional, tag="1")]
@@ -6354,6 +6782,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(message, optional, tag="8")]
         pub offset_angles: ::core::option::Option<super::CMsgQAngle>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleSetFrozen {
         #[prost(bool, optional, tag="1")]
@@ -6361,11 +6790,13 @@ pub mod c_user_msg_particle_manager {
         #[prost(float, optional, tag="2")]
         pub transition_duration: ::core::option::Option<f32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleShouldDraw {
         #[prost(bool, optional, tag="1")]
         pub should_draw: ::core::option::Option<bool>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct ChangeControlPointAttachment {
         #[prost(int32, optional, tag="1")]
@@ -6375,6 +6806,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(uint32, optional, tag="3", default="16777215")]
         pub entity_handle: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateEntityPosition {
         #[prost(uint32, optional, tag="1", default="16777215")]
@@ -6382,6 +6814,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(message, optional, tag="2")]
         pub position: ::core::option::Option<super::CMsgVector>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct SetParticleFoWProperties {
         #[prost(int32, optional, tag="1")]
@@ -6391,11 +6824,13 @@ pub mod c_user_msg_particle_manager {
         #[prost(float, optional, tag="3")]
         pub fow_radius: ::core::option::Option<f32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct SetParticleShouldCheckFoW {
         #[prost(bool, optional, tag="1")]
         pub check_fow: ::core::option::Option<bool>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SetControlPointModel {
         #[prost(int32, optional, tag="1")]
@@ -6403,6 +6838,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(string, optional, tag="2")]
         pub model_name: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SetControlPointSnapshot {
         #[prost(int32, optional, tag="1")]
@@ -6410,11 +6846,13 @@ pub mod c_user_msg_particle_manager {
         #[prost(string, optional, tag="2")]
         pub snapshot_name: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SetParticleText {
         #[prost(string, optional, tag="1")]
         pub text: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SetTextureAttribute {
         #[prost(string, optional, tag="1")]
@@ -6422,11 +6860,13 @@ pub mod c_user_msg_particle_manager {
         #[prost(string, optional, tag="2")]
         pub texture_name: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct SetSceneObjectGenericFlag {
         #[prost(bool, optional, tag="1")]
         pub flag_value: ::core::option::Option<bool>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct SetSceneObjectTintAndDesat {
         #[prost(fixed32, optional, tag="1")]
@@ -6434,21 +6874,25 @@ pub mod c_user_msg_particle_manager {
         #[prost(float, optional, tag="2")]
         pub desat: ::core::option::Option<f32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct ParticleSkipToTime {
         #[prost(float, optional, tag="1")]
         pub skip_to_time: ::core::option::Option<f32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct ParticleCanFreeze {
         #[prost(bool, optional, tag="1")]
         pub can_freeze: ::core::option::Option<bool>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct ParticleFreezeTransitionOverride {
         #[prost(float, optional, tag="1")]
         pub freeze_transition_override: ::core::option::Option<f32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct FreezeParticleInvolving {
         #[prost(bool, optional, tag="1")]
@@ -6458,6 +6902,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(uint32, optional, tag="3", default="16777215")]
         pub entity_handle: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct AddModellistOverrideElement {
         #[prost(string, optional, tag="1")]
@@ -6467,11 +6912,13 @@ pub mod c_user_msg_particle_manager {
         #[prost(uint32, optional, tag="3")]
         pub groupid: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct ClearModellistOverride {
         #[prost(uint32, optional, tag="1")]
         pub groupid: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SetParticleNamedValueContext {
         #[prost(message, repeated, tag="1")]
@@ -6485,6 +6932,7 @@ pub mod c_user_msg_particle_manager {
     }
     /// Nested message and enum types in `SetParticleNamedValueContext`.
     pub mod set_particle_named_value_context {
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct FloatContextValue {
             #[prost(uint32, optional, tag="1")]
@@ -6492,6 +6940,7 @@ pub mod c_user_msg_particle_manager {
             #[prost(float, optional, tag="2")]
             pub value: ::core::option::Option<f32>,
         }
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct VectorContextValue {
             #[prost(uint32, optional, tag="1")]
@@ -6499,6 +6948,7 @@ pub mod c_user_msg_particle_manager {
             #[prost(message, optional, tag="2")]
             pub value: ::core::option::Option<super::super::CMsgVector>,
         }
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct TransformContextValue {
             #[prost(uint32, optional, tag="1")]
@@ -6508,6 +6958,7 @@ pub mod c_user_msg_particle_manager {
             #[prost(message, optional, tag="3")]
             pub translation: ::core::option::Option<super::super::CMsgVector>,
         }
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct EHandleContext {
             #[prost(uint32, optional, tag="1")]
@@ -6516,6 +6967,7 @@ pub mod c_user_msg_particle_manager {
             pub ent_index: ::core::option::Option<u32>,
         }
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct CreatePhysicsSim {
         #[prost(string, optional, tag="1")]
@@ -6525,14 +6977,17 @@ pub mod c_user_msg_particle_manager {
         #[prost(uint32, optional, tag="3")]
         pub max_particle_count: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DestroyPhysicsSim {
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SetVData {
         #[prost(string, optional, tag="1")]
         pub vdata_name: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SetMaterialOverride {
         #[prost(string, optional, tag="1")]
@@ -6540,6 +6995,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(bool, optional, tag="2")]
         pub include_children: ::core::option::Option<bool>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct AddFan {
         #[prost(bool, optional, tag="1")]
@@ -6579,6 +7035,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(string, optional, tag="18")]
         pub attachment_name: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateFan {
         #[prost(bool, optional, tag="1")]
@@ -6596,9 +7053,11 @@ pub mod c_user_msg_particle_manager {
         #[prost(message, optional, tag="6")]
         pub bounds_maxs: ::core::option::Option<super::CMsgVector>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct RemoveFan {
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct SetParticleClusterGrowth {
         #[prost(float, optional, tag="1")]
@@ -6607,11 +7066,13 @@ pub mod c_user_msg_particle_manager {
         pub origin: ::core::option::Option<super::CMsgVector>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMsgHudError {
     #[prost(int32, optional, tag="1")]
     pub order_id: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMsgCustomGameEvent {
     #[prost(string, optional, tag="1")]
@@ -6619,6 +7080,7 @@ pub struct CUserMsgCustomGameEvent {
     #[prost(bytes="bytes", optional, tag="2")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageHapticsManagerPulse {
     #[prost(int32, optional, tag="1")]
@@ -6630,6 +7092,7 @@ pub struct CUserMessageHapticsManagerPulse {
     #[prost(float, optional, tag="4")]
     pub effect_duration: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageHapticsManagerEffect {
     #[prost(int32, optional, tag="1")]
@@ -6639,6 +7102,7 @@ pub struct CUserMessageHapticsManagerEffect {
     #[prost(float, optional, tag="3")]
     pub effect_scale: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageAnimStateGraphState {
     #[prost(int32, optional, tag="1")]
@@ -6646,6 +7110,7 @@ pub struct CUserMessageAnimStateGraphState {
     #[prost(bytes="bytes", optional, tag="2")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageUpdateCssClasses {
     #[prost(int32, optional, tag="1")]
@@ -6655,16 +7120,19 @@ pub struct CUserMessageUpdateCssClasses {
     #[prost(bool, optional, tag="3")]
     pub is_add: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageServerFrameTime {
     #[prost(float, optional, tag="1")]
     pub frame_time: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageLagCompensationError {
     #[prost(float, optional, tag="1")]
     pub distance: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageRequestDllStatus {
     #[prost(string, optional, tag="1")]
@@ -6672,6 +7140,7 @@ pub struct CUserMessageRequestDllStatus {
     #[prost(bool, optional, tag="2")]
     pub full_report: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageRequestUtilAction {
     #[prost(int32, optional, tag="2")]
@@ -6685,6 +7154,7 @@ pub struct CUserMessageRequestUtilAction {
     #[prost(int32, optional, tag="6")]
     pub util5: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageUtilMsgResponse {
     #[prost(fixed32, optional, tag="1")]
@@ -6714,6 +7184,7 @@ pub struct CUserMessageUtilMsgResponse {
 }
 /// Nested message and enum types in `CUserMessage_UtilMsg_Response`.
 pub mod c_user_message_util_msg_response {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ItemDetail {
         #[prost(int32, optional, tag="1")]
@@ -6726,6 +7197,7 @@ pub mod c_user_message_util_msg_response {
         pub name: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageDllStatus {
     #[prost(string, optional, tag="1")]
@@ -6747,6 +7219,7 @@ pub struct CUserMessageDllStatus {
 }
 /// Nested message and enum types in `CUserMessage_DllStatus`.
 pub mod c_user_message_dll_status {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct CvDiagnostic {
         #[prost(uint32, optional, tag="1")]
@@ -6758,6 +7231,7 @@ pub mod c_user_message_dll_status {
         #[prost(string, optional, tag="4")]
         pub string_value: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct CModule {
         #[prost(uint64, optional, tag="1")]
@@ -6770,6 +7244,7 @@ pub mod c_user_message_dll_status {
         pub timestamp: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageRequestInventory {
     #[prost(int32, optional, tag="1")]
@@ -6779,6 +7254,7 @@ pub struct CUserMessageRequestInventory {
     #[prost(int32, optional, tag="3")]
     pub options: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageInventoryResponse {
     #[prost(fixed32, optional, tag="1")]
@@ -6810,6 +7286,7 @@ pub struct CUserMessageInventoryResponse {
 }
 /// Nested message and enum types in `CUserMessage_Inventory_Response`.
 pub mod c_user_message_inventory_response {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct InventoryDetail {
         #[prost(int32, optional, tag="1")]
@@ -6834,6 +7311,7 @@ pub mod c_user_message_inventory_response {
         pub base_hash: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageRequestDiagnostic {
     #[prost(message, repeated, tag="1")]
@@ -6841,6 +7319,7 @@ pub struct CUserMessageRequestDiagnostic {
 }
 /// Nested message and enum types in `CUserMessageRequestDiagnostic`.
 pub mod c_user_message_request_diagnostic {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Diagnostic {
         #[prost(int32, optional, tag="1")]
@@ -6871,6 +7350,7 @@ pub mod c_user_message_request_diagnostic {
         pub context: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageDiagnosticResponse {
     #[prost(message, repeated, tag="1")]
@@ -6888,6 +7368,7 @@ pub struct CUserMessageDiagnosticResponse {
 }
 /// Nested message and enum types in `CUserMessage_Diagnostic_Response`.
 pub mod c_user_message_diagnostic_response {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Diagnostic {
         #[prost(int32, optional, tag="1")]
@@ -6922,6 +7403,7 @@ pub mod c_user_message_diagnostic_response {
         pub placebo: ::core::option::Option<i64>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageExtraUserData {
     #[prost(int32, optional, tag="1")]
@@ -6935,6 +7417,7 @@ pub struct CUserMessageExtraUserData {
     #[prost(bytes="bytes", repeated, tag="5")]
     pub detail2: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageNotifyResponseFound {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -6964,6 +7447,7 @@ pub struct CUserMessageNotifyResponseFound {
 }
 /// Nested message and enum types in `CUserMessage_NotifyResponseFound`.
 pub mod c_user_message_notify_response_found {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Criteria {
         #[prost(uint32, optional, tag="1")]
@@ -6972,6 +7456,7 @@ pub mod c_user_message_notify_response_found {
         pub value: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessagePlayResponseConditional {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -7407,6 +7892,7 @@ impl EHapticPulseType {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSource2SystemSpecs {
     #[prost(string, optional, tag="1")]
@@ -7438,6 +7924,7 @@ pub struct CMsgSource2SystemSpecs {
     #[prost(uint32, optional, tag="52")]
     pub backbuffer_height: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSource2VProfLiteReportItem {
     #[prost(string, optional, tag="1")]
@@ -7477,6 +7964,7 @@ pub struct CMsgSource2VProfLiteReportItem {
     #[prost(uint32, optional, tag="44")]
     pub usec_1secmax_p99_all: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSource2VProfLiteReport {
     #[prost(message, optional, tag="1")]
@@ -7486,6 +7974,7 @@ pub struct CMsgSource2VProfLiteReport {
     #[prost(uint32, optional, tag="3")]
     pub discarded_frames: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSource2NetworkFlowQuality {
     #[prost(uint32, optional, tag="1")]
@@ -7577,6 +8066,7 @@ pub struct CMsgSource2NetworkFlowQuality {
     #[prost(uint32, optional, tag="82")]
     pub net_ping_p95: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSource2PerfIntervalSample {
     #[prost(float, optional, tag="1")]
@@ -7594,6 +8084,7 @@ pub struct CMsgSource2PerfIntervalSample {
 }
 /// Nested message and enum types in `CMsgSource2PerfIntervalSample`.
 pub mod c_msg_source2_perf_interval_sample {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Tag {
         #[prost(string, optional, tag="1")]
@@ -7602,6 +8093,7 @@ pub mod c_msg_source2_perf_interval_sample {
         pub max_value: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CSource2MetricsMatchPerfSummaryNotification {
     #[prost(uint32, optional, tag="1")]
@@ -7621,6 +8113,7 @@ pub struct CSource2MetricsMatchPerfSummaryNotification {
 }
 /// Nested message and enum types in `CSource2Metrics_MatchPerfSummary_Notification`.
 pub mod c_source2_metrics_match_perf_summary_notification {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Client {
         #[prost(message, optional, tag="1")]
@@ -7639,6 +8132,7 @@ pub mod c_source2_metrics_match_perf_summary_notification {
         pub perf_samples: ::prost::alloc::vec::Vec<super::CMsgSource2PerfIntervalSample>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSource2PlayStatsPackedRecordList {
     #[prost(string, optional, tag="1")]
@@ -7678,6 +8172,7 @@ pub struct CMsgSource2PlayStatsPackedRecordList {
 }
 /// Nested message and enum types in `CMsgSource2PlayStatsPackedRecordList`.
 pub mod c_msg_source2_play_stats_packed_record_list {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct FieldDef {
         #[prost(string, optional, tag="1")]
@@ -7686,6 +8181,7 @@ pub mod c_msg_source2_play_stats_packed_record_list {
         pub field_type: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CSource2MetricsRecordPlayStatsNotification {
     #[prost(message, repeated, tag="1")]
@@ -7759,6 +8255,7 @@ impl ESource2PlayStatsFieldType {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgClientInfo {
     #[prost(fixed32, optional, tag="1")]
@@ -7772,6 +8269,7 @@ pub struct CclcMsgClientInfo {
     #[prost(string, optional, tag="6")]
     pub friends_name: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgMove {
     #[prost(bytes="bytes", optional, tag="3")]
@@ -7779,6 +8277,7 @@ pub struct CclcMsgMove {
     #[prost(uint32, optional, tag="4")]
     pub last_command_number: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgVoiceAudio {
     #[prost(enumeration="VoiceDataFormatT", optional, tag="1", default="VoicedataFormatSteam")]
@@ -7800,6 +8299,7 @@ pub struct CMsgVoiceAudio {
     #[prost(float, optional, tag="9")]
     pub voice_level: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgVoiceData {
     #[prost(message, optional, tag="1")]
@@ -7809,6 +8309,7 @@ pub struct CclcMsgVoiceData {
     #[prost(uint32, optional, tag="3")]
     pub tick: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CclcMsgBaselineAck {
     #[prost(int32, optional, tag="1")]
@@ -7816,11 +8317,13 @@ pub struct CclcMsgBaselineAck {
     #[prost(int32, optional, tag="2")]
     pub baseline_nr: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgListenEvents {
     #[prost(fixed32, repeated, packed="false", tag="1")]
     pub event_mask: ::prost::alloc::vec::Vec<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgRespondCvarValue {
     #[prost(int32, optional, tag="1")]
@@ -7832,26 +8335,31 @@ pub struct CclcMsgRespondCvarValue {
     #[prost(string, optional, tag="4")]
     pub value: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CclcMsgLoadingProgress {
     #[prost(int32, optional, tag="1")]
     pub progress: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgSplitPlayerConnect {
     #[prost(string, optional, tag="1")]
     pub playername: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CclcMsgSplitPlayerDisconnect {
     #[prost(int32, optional, tag="1")]
     pub slot: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CclcMsgServerStatus {
     #[prost(bool, optional, tag="1")]
     pub simplified: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CclcMsgRequestPause {
     #[prost(enumeration="RequestPauseT", optional, tag="1", default="RpPause")]
@@ -7859,16 +8367,19 @@ pub struct CclcMsgRequestPause {
     #[prost(int32, optional, tag="2")]
     pub pause_group: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgCmdKeyValues {
     #[prost(bytes="bytes", optional, tag="1")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgRconServerDetails {
     #[prost(bytes="bytes", optional, tag="1")]
     pub token: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgDiagnostic {
     #[prost(message, optional, tag="1")]
@@ -7882,6 +8393,7 @@ pub struct CclcMsgDiagnostic {
     #[prost(message, repeated, tag="5")]
     pub perf_samples: ::prost::alloc::vec::Vec<CMsgSource2PerfIntervalSample>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgServerInfo {
     #[prost(int32, optional, tag="1")]
@@ -7917,6 +8429,7 @@ pub struct CsvcMsgServerInfo {
     #[prost(bytes="bytes", optional, tag="20")]
     pub game_session_manifest: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgClassInfo {
     #[prost(bool, optional, tag="1")]
@@ -7926,6 +8439,7 @@ pub struct CsvcMsgClassInfo {
 }
 /// Nested message and enum types in `CSVCMsg_ClassInfo`.
 pub mod csvc_msg_class_info {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ClassT {
         #[prost(int32, optional, tag="1")]
@@ -7934,11 +8448,13 @@ pub mod csvc_msg_class_info {
         pub class_name: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgSetPause {
     #[prost(bool, optional, tag="1")]
     pub paused: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgVoiceInit {
     #[prost(int32, optional, tag="1")]
@@ -7948,11 +8464,13 @@ pub struct CsvcMsgVoiceInit {
     #[prost(int32, optional, tag="3", default="0")]
     pub version: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgPrint {
     #[prost(string, optional, tag="1")]
     pub text: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgSounds {
     #[prost(bool, optional, tag="1")]
@@ -7962,6 +8480,7 @@ pub struct CsvcMsgSounds {
 }
 /// Nested message and enum types in `CSVCMsg_Sounds`.
 pub mod csvc_msg_sounds {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct SounddataT {
         #[prost(sint32, optional, tag="1")]
@@ -8004,6 +8523,7 @@ pub mod csvc_msg_sounds {
         pub sound_resource_id: ::core::option::Option<u64>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgPrefetch {
     #[prost(int32, optional, tag="1")]
@@ -8011,6 +8531,7 @@ pub struct CsvcMsgPrefetch {
     #[prost(enumeration="PrefetchType", optional, tag="2", default="PftSound")]
     pub resource_type: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgSetView {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -8018,6 +8539,7 @@ pub struct CsvcMsgSetView {
     #[prost(int32, optional, tag="2", default="-1")]
     pub slot: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgFixAngle {
     #[prost(bool, optional, tag="1")]
@@ -8025,11 +8547,13 @@ pub struct CsvcMsgFixAngle {
     #[prost(message, optional, tag="2")]
     pub angle: ::core::option::Option<CMsgQAngle>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgCrosshairAngle {
     #[prost(message, optional, tag="1")]
     pub angle: ::core::option::Option<CMsgQAngle>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgBspDecal {
     #[prost(message, optional, tag="1")]
@@ -8043,6 +8567,7 @@ pub struct CsvcMsgBspDecal {
     #[prost(bool, optional, tag="5")]
     pub low_priority: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgSplitScreen {
     #[prost(enumeration="ESplitScreenMessageType", optional, tag="1", default="MsgSplitscreenAdduser")]
@@ -8052,6 +8577,7 @@ pub struct CsvcMsgSplitScreen {
     #[prost(int32, optional, tag="3", default="-1")]
     pub player_index: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgGetCvarValue {
     #[prost(int32, optional, tag="1")]
@@ -8059,6 +8585,7 @@ pub struct CsvcMsgGetCvarValue {
     #[prost(string, optional, tag="2")]
     pub cvar_name: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgMenu {
     #[prost(int32, optional, tag="1")]
@@ -8066,6 +8593,7 @@ pub struct CsvcMsgMenu {
     #[prost(bytes="bytes", optional, tag="2")]
     pub menu_key_values: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgUserMessage {
     #[prost(int32, optional, tag="1")]
@@ -8075,6 +8603,7 @@ pub struct CsvcMsgUserMessage {
     #[prost(int32, optional, tag="3")]
     pub passthrough: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgSendTable {
     #[prost(bool, optional, tag="1")]
@@ -8088,6 +8617,7 @@ pub struct CsvcMsgSendTable {
 }
 /// Nested message and enum types in `CSVCMsg_SendTable`.
 pub mod csvc_msg_send_table {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SendpropT {
         #[prost(int32, optional, tag="1")]
@@ -8110,6 +8640,7 @@ pub mod csvc_msg_send_table {
         pub num_bits: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgGameEventList {
     #[prost(message, repeated, tag="1")]
@@ -8117,6 +8648,7 @@ pub struct CsvcMsgGameEventList {
 }
 /// Nested message and enum types in `CSVCMsg_GameEventList`.
 pub mod csvc_msg_game_event_list {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct KeyT {
         #[prost(int32, optional, tag="1")]
@@ -8124,6 +8656,7 @@ pub mod csvc_msg_game_event_list {
         #[prost(string, optional, tag="2")]
         pub name: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct DescriptorT {
         #[prost(int32, optional, tag="1")]
@@ -8134,6 +8667,7 @@ pub mod csvc_msg_game_event_list {
         pub keys: ::prost::alloc::vec::Vec<KeyT>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgPacketEntities {
     #[prost(int32, optional, tag="1")]
@@ -8183,6 +8717,7 @@ pub struct CsvcMsgPacketEntities {
 }
 /// Nested message and enum types in `CSVCMsg_PacketEntities`.
 pub mod csvc_msg_packet_entities {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct AlternateBaselineT {
         #[prost(int32, optional, tag="1")]
@@ -8190,6 +8725,7 @@ pub mod csvc_msg_packet_entities {
         #[prost(int32, optional, tag="2")]
         pub baseline_index: ::core::option::Option<i32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct NonTransmittedEntitiesT {
         #[prost(int32, optional, tag="1")]
@@ -8197,6 +8733,7 @@ pub mod csvc_msg_packet_entities {
         #[prost(bytes="bytes", optional, tag="2")]
         pub data: ::core::option::Option<::prost::bytes::Bytes>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct OutofpvsEntityUpdatesT {
         #[prost(int32, optional, tag="1")]
@@ -8205,6 +8742,7 @@ pub mod csvc_msg_packet_entities {
         pub data: ::core::option::Option<::prost::bytes::Bytes>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgTempEntities {
     #[prost(bool, optional, tag="1")]
@@ -8214,6 +8752,7 @@ pub struct CsvcMsgTempEntities {
     #[prost(bytes="bytes", optional, tag="3")]
     pub entity_data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgCreateStringTable {
     #[prost(string, optional, tag="1")]
@@ -8237,6 +8776,7 @@ pub struct CsvcMsgCreateStringTable {
     #[prost(bool, optional, tag="10")]
     pub using_varint_bitcounts: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgUpdateStringTable {
     #[prost(int32, optional, tag="1")]
@@ -8246,6 +8786,7 @@ pub struct CsvcMsgUpdateStringTable {
     #[prost(bytes="bytes", optional, tag="3")]
     pub string_data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgVoiceData {
     #[prost(message, optional, tag="1")]
@@ -8263,6 +8804,7 @@ pub struct CsvcMsgVoiceData {
     #[prost(int32, optional, tag="7")]
     pub passthrough: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgPacketReliable {
     #[prost(int32, optional, tag="1")]
@@ -8272,6 +8814,7 @@ pub struct CsvcMsgPacketReliable {
     #[prost(bool, optional, tag="3")]
     pub state: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgFullFrameSplit {
     #[prost(int32, optional, tag="1")]
@@ -8283,6 +8826,7 @@ pub struct CsvcMsgFullFrameSplit {
     #[prost(bytes="bytes", optional, tag="4")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgHltvStatus {
     #[prost(string, optional, tag="1")]
@@ -8294,16 +8838,19 @@ pub struct CsvcMsgHltvStatus {
     #[prost(int32, optional, tag="4")]
     pub proxies: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgServerSteamId {
     #[prost(uint64, optional, tag="1")]
     pub steam_id: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgCmdKeyValues {
     #[prost(bytes="bytes", optional, tag="1")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgRconServerDetails {
     #[prost(bytes="bytes", optional, tag="1")]
@@ -8311,6 +8858,7 @@ pub struct CsvcMsgRconServerDetails {
     #[prost(string, optional, tag="2")]
     pub details: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgIpcAddress {
     #[prost(fixed64, optional, tag="1")]
@@ -8318,6 +8866,7 @@ pub struct CMsgIpcAddress {
     #[prost(uint32, optional, tag="2")]
     pub process_id: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgServerPeer {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -8333,11 +8882,13 @@ pub struct CMsgServerPeer {
     #[prost(bool, optional, tag="6")]
     pub is_listenserver_host: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgPeerList {
     #[prost(message, repeated, tag="1")]
     pub peer: ::prost::alloc::vec::Vec<CMsgServerPeer>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgClearAllStringTables {
     #[prost(string, optional, tag="1")]
@@ -8345,6 +8896,7 @@ pub struct CsvcMsgClearAllStringTables {
     #[prost(bool, optional, tag="3")]
     pub create_tables_skipped: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoFlattenedSerializerFieldT {
     #[prost(int32, optional, tag="1")]
@@ -8374,6 +8926,7 @@ pub struct ProtoFlattenedSerializerFieldT {
 }
 /// Nested message and enum types in `ProtoFlattenedSerializerField_t`.
 pub mod proto_flattened_serializer_field_t {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct PolymorphicFieldT {
         #[prost(int32, optional, tag="1")]
@@ -8382,6 +8935,7 @@ pub mod proto_flattened_serializer_field_t {
         pub polymorphic_field_serializer_version: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoFlattenedSerializerT {
     #[prost(int32, optional, tag="1")]
@@ -8391,6 +8945,7 @@ pub struct ProtoFlattenedSerializerT {
     #[prost(int32, repeated, packed="false", tag="3")]
     pub fields_index: ::prost::alloc::vec::Vec<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgFlattenedSerializer {
     #[prost(message, repeated, tag="1")]
@@ -8400,11 +8955,13 @@ pub struct CsvcMsgFlattenedSerializer {
     #[prost(message, repeated, tag="3")]
     pub fields: ::prost::alloc::vec::Vec<ProtoFlattenedSerializerFieldT>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgStopSound {
     #[prost(fixed32, optional, tag="1")]
     pub guid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CBidirMsgRebroadcastGameEvent {
     #[prost(bool, optional, tag="1")]
@@ -8416,11 +8973,13 @@ pub struct CBidirMsgRebroadcastGameEvent {
     #[prost(uint64, optional, tag="4")]
     pub receivingclients: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CBidirMsgRebroadcastSource {
     #[prost(int32, optional, tag="1")]
     pub eventsource: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CBidirMsgPredictionEvent {
     #[prost(uint32, optional, tag="1")]
@@ -8462,6 +9021,7 @@ pub mod c_bidir_msg_prediction_event {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgServerNetworkStats {
     #[prost(bool, optional, tag="1")]
@@ -8517,6 +9077,7 @@ pub struct CMsgServerNetworkStats {
 }
 /// Nested message and enum types in `CMsgServerNetworkStats`.
 pub mod c_msg_server_network_stats {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Port {
         #[prost(int32, optional, tag="1")]
@@ -8524,6 +9085,7 @@ pub mod c_msg_server_network_stats {
         #[prost(string, optional, tag="2")]
         pub name: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Player {
         #[prost(uint64, optional, tag="1")]
@@ -8544,6 +9106,7 @@ pub mod c_msg_server_network_stats {
         pub engine_latency_ms: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgHltvReplay {
     #[prost(int32, optional, tag="1")]
@@ -8563,6 +9126,7 @@ pub struct CsvcMsgHltvReplay {
     #[prost(int32, optional, tag="8")]
     pub reason: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CclcMsgHltvReplay {
     #[prost(int32, optional, tag="1")]
@@ -8576,11 +9140,13 @@ pub struct CclcMsgHltvReplay {
     #[prost(float, optional, tag="5")]
     pub event_time: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgBroadcastCommand {
     #[prost(string, optional, tag="1")]
     pub cmd: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgHltvFixupOperatorTick {
     #[prost(int32, optional, tag="1")]
@@ -8600,6 +9166,7 @@ pub struct CclcMsgHltvFixupOperatorTick {
     #[prost(message, optional, tag="8")]
     pub view_offset: ::core::option::Option<CMsgVector>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgHltvFixupOperatorStatus {
     #[prost(uint32, optional, tag="1")]
@@ -8607,6 +9174,7 @@ pub struct CsvcMsgHltvFixupOperatorStatus {
     #[prost(string, optional, tag="2")]
     pub override_operator_name: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgServerUserCmd {
     #[prost(bytes="bytes", optional, tag="1")]
@@ -8620,11 +9188,13 @@ pub struct CMsgServerUserCmd {
     #[prost(int32, optional, tag="5")]
     pub client_tick: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgUserCommands {
     #[prost(message, repeated, tag="1")]
     pub commands: ::prost::alloc::vec::Vec<CMsgServerUserCmd>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgNextMsgPredicted {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -9109,6 +9679,7 @@ impl ReplayEventTypeT {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CInButtonStatePb {
     #[prost(uint64, optional, tag="1")]
@@ -9118,6 +9689,7 @@ pub struct CInButtonStatePb {
     #[prost(uint64, optional, tag="3")]
     pub buttonstate3: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CSubtickMoveStep {
     #[prost(uint64, optional, tag="1")]
@@ -9135,11 +9707,13 @@ pub struct CSubtickMoveStep {
     #[prost(float, optional, tag="9")]
     pub yaw_delta: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CBaseUserCmdExecutionNotes {
     #[prost(string, optional, tag="1")]
     pub ignored_reason: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CBaseUserCmdPb {
     #[prost(int32, optional, tag="1")]
@@ -9181,11 +9755,13 @@ pub struct CBaseUserCmdPb {
     #[prost(message, optional, tag="22")]
     pub execution_notes: ::core::option::Option<CBaseUserCmdExecutionNotes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserCmdBasePb {
     #[prost(message, optional, tag="1")]
     pub base: ::core::option::Option<CBaseUserCmdPb>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsgoInterpolationInfoPb {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -9195,11 +9771,13 @@ pub struct CsgoInterpolationInfoPb {
     #[prost(float, optional, tag="3", default="0")]
     pub frac: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsgoInterpolationInfoPbCl {
     #[prost(float, optional, tag="3", default="0")]
     pub frac: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsgoInputHistoryEntryPb {
     #[prost(message, optional, tag="2")]
@@ -9233,6 +9811,7 @@ pub struct CsgoInputHistoryEntryPb {
     #[prost(message, optional, tag="69")]
     pub target_abs_ang_check: ::core::option::Option<CMsgQAngle>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsgoUserCmdPb {
     #[prost(message, optional, tag="1")]
@@ -9252,6 +9831,7 @@ pub struct CsgoUserCmdPb {
     #[prost(bool, optional, tag="13", default="false")]
     pub is_predicting_kill_ragdolls: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgVDebugGameSessionIdEvent {
     #[prost(int32, optional, tag="1")]
@@ -9259,6 +9839,7 @@ pub struct CMsgVDebugGameSessionIdEvent {
     #[prost(string, optional, tag="2")]
     pub gamesessionid: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgPlaceDecalEvent {
     #[prost(message, optional, tag="1")]
@@ -9288,16 +9869,19 @@ pub struct CMsgPlaceDecalEvent {
     #[prost(uint32, optional, tag="12")]
     pub sequence_name: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgClearWorldDecalsEvent {
     #[prost(uint32, optional, tag="1")]
     pub flagstoclear: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgClearEntityDecalsEvent {
     #[prost(uint32, optional, tag="1")]
     pub flagstoclear: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgClearDecalsForEntityEvent {
     #[prost(uint32, optional, tag="1")]
@@ -9305,6 +9889,7 @@ pub struct CMsgClearDecalsForEntityEvent {
     #[prost(uint32, optional, tag="2", default="16777215")]
     pub entityhandle: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSource1LegacyGameEventList {
     #[prost(message, repeated, tag="1")]
@@ -9312,6 +9897,7 @@ pub struct CMsgSource1LegacyGameEventList {
 }
 /// Nested message and enum types in `CMsgSource1LegacyGameEventList`.
 pub mod c_msg_source1_legacy_game_event_list {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct KeyT {
         #[prost(int32, optional, tag="1")]
@@ -9319,6 +9905,7 @@ pub mod c_msg_source1_legacy_game_event_list {
         #[prost(string, optional, tag="2")]
         pub name: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct DescriptorT {
         #[prost(int32, optional, tag="1")]
@@ -9329,6 +9916,7 @@ pub mod c_msg_source1_legacy_game_event_list {
         pub keys: ::prost::alloc::vec::Vec<KeyT>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSource1LegacyListenEvents {
     #[prost(int32, optional, tag="1")]
@@ -9336,6 +9924,7 @@ pub struct CMsgSource1LegacyListenEvents {
     #[prost(uint32, repeated, packed="false", tag="2")]
     pub eventarraybits: ::prost::alloc::vec::Vec<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSource1LegacyGameEvent {
     #[prost(string, optional, tag="1")]
@@ -9351,6 +9940,7 @@ pub struct CMsgSource1LegacyGameEvent {
 }
 /// Nested message and enum types in `CMsgSource1LegacyGameEvent`.
 pub mod c_msg_source1_legacy_game_event {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct KeyT {
         #[prost(int32, optional, tag="1")]
@@ -9371,6 +9961,7 @@ pub mod c_msg_source1_legacy_game_event {
         pub val_uint64: ::core::option::Option<u64>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSosStartSoundEvent {
     #[prost(int32, optional, tag="1")]
@@ -9386,11 +9977,13 @@ pub struct CMsgSosStartSoundEvent {
     #[prost(float, optional, tag="6")]
     pub start_time: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSosStopSoundEvent {
     #[prost(int32, optional, tag="1")]
     pub soundevent_guid: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSosStopSoundEventHash {
     #[prost(fixed32, optional, tag="1")]
@@ -9398,6 +9991,7 @@ pub struct CMsgSosStopSoundEventHash {
     #[prost(int32, optional, tag="2", default="-1")]
     pub source_entity_index: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSosSetSoundEventParams {
     #[prost(int32, optional, tag="1")]
@@ -9405,6 +9999,7 @@ pub struct CMsgSosSetSoundEventParams {
     #[prost(bytes="bytes", optional, tag="5")]
     pub packed_params: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSosSetLibraryStackFields {
     #[prost(fixed32, optional, tag="1")]
@@ -9472,6 +10067,7 @@ impl EBaseGameEvents {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgTePlayerAnimEvent {
     #[prost(fixed32, optional, tag="1", default="16777215")]
@@ -9481,11 +10077,13 @@ pub struct CMsgTePlayerAnimEvent {
     #[prost(int32, optional, tag="3")]
     pub data: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgTeRadioIcon {
     #[prost(fixed32, optional, tag="1", default="16777215")]
     pub player: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgTeFireBullets {
     #[prost(message, optional, tag="1")]
@@ -9529,6 +10127,7 @@ pub struct CMsgTeFireBullets {
 }
 /// Nested message and enum types in `CMsgTEFireBullets`.
 pub mod c_msg_te_fire_bullets {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Extra {
         #[prost(message, optional, tag="1")]
@@ -9549,6 +10148,7 @@ pub mod c_msg_te_fire_bullets {
         pub r#type: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgPlayerBulletHit {
     #[prost(int32, optional, tag="1", default="-1")]
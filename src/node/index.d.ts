@@ -4,15 +4,93 @@
 /* auto-generated by NAPI-RS */
 
 export function parseVoice(pathOrBuf: string | Buffer): Record<string, Array<number>>
+export function parseVoiceAsync(pathOrBuf: string | Buffer): Promise<Array<VoiceData>>
+/** Contents of the string tables at the end of the demo and every update to them. All tables if names is not given. */
+export function parseStringTables(pathOrBuf: string | Buffer, names?: Array<string> | undefined | null): StringTables
+export function parseStringTablesAsync(pathOrBuf: string | Buffer, names?: Array<string> | undefined | null): Promise<StringTables>
+/** Every change of the userinfo string table, so name changes mid-match are new entries with the same steamid. */
+export function parsePlayerIdentity(pathOrBuf: string | Buffer): Array<PlayerIdentityData>
+export function parsePlayerIdentityAsync(pathOrBuf: string | Buffer): Promise<Array<PlayerIdentityData>>
 export function listGameEvents(pathOrBuf: string | Buffer): any
+export function listGameEventsAsync(pathOrBuf: string | Buffer): Promise<any>
 export function parseGrenades(pathOrBuf: string | Buffer): any
+export function parseGrenadesAsync(pathOrBuf: string | Buffer): Promise<any>
 export function parseHeader(pathOrBuf: string | Buffer): any
-export function parseEvent(pathOrBuf: string | Buffer, eventName: string, playerExtra?: Array<string> | undefined | null, otherExtra?: Array<string> | undefined | null): any
-export function parseEvents(pathOrBuf: string | Buffer, eventNames?: Array<string> | undefined | null, playerExtra?: Array<string> | undefined | null, otherExtra?: Array<string> | undefined | null): any
-export function parseTicks(pathOrBuf: string | Buffer, wantedProps: Array<string>, wantedTicks?: Array<number> | undefined | null, wantedPlayers?: Array<string> | undefined | null, structOfArrays?: boolean | undefined | null, orderBySteamid?: boolean | undefined | null, propStates?: Array<WantedPropState> | undefined | null): any
+export function parseHeaderAsync(pathOrBuf: string | Buffer): Promise<any>
+export function parseEvent(pathOrBuf: string | Buffer, eventName: string, playerExtra?: Array<string> | undefined | null, otherExtra?: Array<string> | undefined | null, gameEventListBytes?: Buffer | undefined | null, lenient?: boolean | undefined | null): any
+export function parseEventAsync(pathOrBuf: string | Buffer, eventName: string, playerExtra?: Array<string> | undefined | null, otherExtra?: Array<string> | undefined | null, gameEventListBytes?: Buffer | undefined | null, lenient?: boolean | undefined | null): Promise<any>
+export function parseEvents(pathOrBuf: string | Buffer, eventNames?: Array<string> | undefined | null, playerExtra?: Array<string> | undefined | null, otherExtra?: Array<string> | undefined | null, gameEventListBytes?: Buffer | undefined | null, lenient?: boolean | undefined | null): any
+export function parseEventsAsync(pathOrBuf: string | Buffer, eventNames?: Array<string> | undefined | null, playerExtra?: Array<string> | undefined | null, otherExtra?: Array<string> | undefined | null, gameEventListBytes?: Buffer | undefined | null, lenient?: boolean | undefined | null): Promise<any>
+export function parseUserMessages(pathOrBuf: string | Buffer, messageNames?: Array<string> | undefined | null): any
+export function parseUserMessagesAsync(pathOrBuf: string | Buffer, messageNames?: Array<string> | undefined | null): Promise<any>
+export function parseVotes(pathOrBuf: string | Buffer): any
+export function parseVotesAsync(pathOrBuf: string | Buffer): Promise<any>
+export function validateDemo(pathOrBuf: string | Buffer): any
+export function validateDemoAsync(pathOrBuf: string | Buffer): Promise<any>
+/**
+ * Props of the players in every eventName event at event tick + start, + start + step ... up to
+ * event tick + end, parsed in a single pass. Rows have eventIndex (index in parseEvent(eventName)),
+ * eventTick, offset and role ("attacker", "user" ...) next to the props.
+ */
+export function parseEventWindows(pathOrBuf: string | Buffer, eventName: string, wantedProps: Array<string>, start: number, end?: number | undefined | null, step?: number | undefined | null): any
+export function parseEventWindowsAsync(pathOrBuf: string | Buffer, eventName: string, wantedProps: Array<string>, start: number, end?: number | undefined | null, step?: number | undefined | null): Promise<any>
+export function parseTicks(pathOrBuf: string | Buffer, wantedProps: Array<string>, wantedTicks?: Array<number> | undefined | null, wantedPlayers?: Array<string> | undefined | null, structOfArrays?: boolean | undefined | null, orderBySteamid?: boolean | undefined | null, propStates?: Array<WantedPropState> | undefined | null, filter?: string | undefined | null, derivedColumns?: Array<string> | undefined | null, lenient?: boolean | undefined | null): any
+/** Same as parseTicks but runs in the libuv thread pool. Parsing stops when the signal is aborted. */
+export function parseTicksAsync(pathOrBuf: string | Buffer, wantedProps: Array<string>, wantedTicks?: Array<number> | undefined | null, wantedPlayers?: Array<string> | undefined | null, structOfArrays?: boolean | undefined | null, orderBySteamid?: boolean | undefined | null, propStates?: Array<WantedPropState> | undefined | null, signal?: AbortSignal | undefined | null, onProgress?: ((bytesProcessed: number, totalBytes: number) => void) | undefined | null, filter?: string | undefined | null, derivedColumns?: Array<string> | undefined | null, lenient?: boolean | undefined | null): Promise<any>
 export function parsePlayerInfo(pathOrBuf: string | Buffer): any
+export function parsePlayerInfoAsync(pathOrBuf: string | Buffer): Promise<any>
 export function parsePlayerSkins(pathOrBuf: string | Buffer): any
+export function parsePlayerSkinsAsync(pathOrBuf: string | Buffer): Promise<any>
 export function listUpdatedFields(pathOrBuf: string | Buffer): any
+export function listUpdatedFieldsAsync(pathOrBuf: string | Buffer): Promise<any>
+/**
+ * Every prop path in the demo with its class, sendtable type and encoding, decoder,
+ * the type it is parsed into, what kind of entity it is collected from and its friendly name.
+ */
+export function describeSchema(pathOrBuf: string | Buffer): any
+export function describeSchemaAsync(pathOrBuf: string | Buffer): Promise<any>
+/**
+ * Props, serializers and game events that were added, removed or changed type from demoA to
+ * demoB, for example after a game update. Every list is empty if both are from the same build.
+ */
+export function diffSchemas(demoA: string | Buffer, demoB: string | Buffer): any
+export function diffSchemasAsync(demoA: string | Buffer, demoB: string | Buffer): Promise<any>
+export interface VoiceData {
+  tick: number
+  data: Buffer
+  steamid: string
+}
+export interface StringTableEntryData {
+  idx: number
+  key: string
+  value: Buffer
+  /** Only for tables with a known format (userinfo and lightstyles) */
+  decoded?: any
+}
+export interface StringTableData {
+  name: string
+  entries: Array<StringTableEntryData>
+}
+export interface StringTableUpdateData {
+  tick: number
+  table: string
+  entry: StringTableEntryData
+}
+export interface StringTables {
+  tables: Array<StringTableData>
+  updates: Array<StringTableUpdateData>
+}
+export interface PlayerIdentityData {
+  tick: number
+  /** Index of the entry in the userinfo string table */
+  slot: number
+  userid: number
+  /** "0" for bots */
+  steamid: string
+  name: string
+  fakePlayer: boolean
+  isHltv: boolean
+}
 export class JsVariant { }
 export class WantedPropState {
   prop: string
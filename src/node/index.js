@@ -252,17 +252,43 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { JsVariant, WantedPropState, parseVoice, listGameEvents, parseGrenades, parseHeader, parseEvent, parseEvents, parseTicks, parsePlayerInfo, parsePlayerSkins, listUpdatedFields } = nativeBinding
+const { JsVariant, WantedPropState, parseVoice, parseVoiceAsync, parseStringTables, parseStringTablesAsync, parsePlayerIdentity, parsePlayerIdentityAsync, listGameEvents, listGameEventsAsync, parseGrenades, parseGrenadesAsync, parseHeader, parseHeaderAsync, parseEvent, parseEventAsync, parseEvents, parseEventsAsync, parseUserMessages, parseUserMessagesAsync, parseVotes, parseVotesAsync, validateDemo, validateDemoAsync, parseEventWindows, parseEventWindowsAsync, parseTicks, parseTicksAsync, parsePlayerInfo, parsePlayerInfoAsync, parsePlayerSkins, parsePlayerSkinsAsync, listUpdatedFields, listUpdatedFieldsAsync, describeSchema, describeSchemaAsync, diffSchemas, diffSchemasAsync } = nativeBinding
 
 module.exports.JsVariant = JsVariant
 module.exports.WantedPropState = WantedPropState
 module.exports.parseVoice = parseVoice
+module.exports.parseVoiceAsync = parseVoiceAsync
+module.exports.parseStringTables = parseStringTables
+module.exports.parseStringTablesAsync = parseStringTablesAsync
+module.exports.parsePlayerIdentity = parsePlayerIdentity
+module.exports.parsePlayerIdentityAsync = parsePlayerIdentityAsync
 module.exports.listGameEvents = listGameEvents
+module.exports.listGameEventsAsync = listGameEventsAsync
 module.exports.parseGrenades = parseGrenades
+module.exports.parseGrenadesAsync = parseGrenadesAsync
 module.exports.parseHeader = parseHeader
+module.exports.parseHeaderAsync = parseHeaderAsync
 module.exports.parseEvent = parseEvent
+module.exports.parseEventAsync = parseEventAsync
 module.exports.parseEvents = parseEvents
+module.exports.parseEventsAsync = parseEventsAsync
+module.exports.parseUserMessages = parseUserMessages
+module.exports.parseUserMessagesAsync = parseUserMessagesAsync
+module.exports.parseVotes = parseVotes
+module.exports.parseVotesAsync = parseVotesAsync
+module.exports.validateDemo = validateDemo
+module.exports.validateDemoAsync = validateDemoAsync
+module.exports.parseEventWindows = parseEventWindows
+module.exports.parseEventWindowsAsync = parseEventWindowsAsync
 module.exports.parseTicks = parseTicks
+module.exports.parseTicksAsync = parseTicksAsync
 module.exports.parsePlayerInfo = parsePlayerInfo
+module.exports.parsePlayerInfoAsync = parsePlayerInfoAsync
 module.exports.parsePlayerSkins = parsePlayerSkins
+module.exports.parsePlayerSkinsAsync = parsePlayerSkinsAsync
 module.exports.listUpdatedFields = listUpdatedFields
+module.exports.listUpdatedFieldsAsync = listUpdatedFieldsAsync
+module.exports.describeSchema = describeSchema
+module.exports.describeSchemaAsync = describeSchemaAsync
+module.exports.diffSchemas = diffSchemas
+module.exports.diffSchemasAsync = diffSchemasAsync
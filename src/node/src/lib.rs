@@ -6,20 +6,40 @@ use ahash::AHashMap;
 use memmap2::MmapOptions;
 use napi::bindgen_prelude::*;
 use napi::threadsafe_function::JsValuesTupleIntoVec;
+use napi::threadsafe_function::{
+  ErrorStrategy, ThreadSafeCallContext, ThreadsafeFunction, ThreadsafeFunctionCallMode,
+};
 use napi::Either;
+use napi::Env;
 use napi::JsBigInt;
+use napi::JsFunction;
+use napi::JsObject;
 use napi::JsUnknown;
+use napi::Task;
 use parser::first_pass::parser_settings::rm_map_user_friendly_names;
 use parser::first_pass::parser_settings::rm_user_friendly_names;
 use parser::first_pass::parser_settings::FirstPassParser;
 use parser::first_pass::parser_settings::ParserInputs;
+use parser::first_pass::read_bits::DemoParserError;
+use parser::first_pass::schema::DemoSchema;
+use parser::first_pass::stringtables::decode_string_table_value;
+use parser::first_pass::stringtables::StringTableEntry;
+use parser::first_pass::stringtables::StringTableValue;
+use parser::first_pass::validate::validate_demo as validate_demo_bytes;
+use parser::parse_control::ParseControl;
 use parser::parse_demo::DemoOutput;
 use parser::parse_demo::Parser;
+use parser::second_pass::derived_column::DerivedColumn;
+use parser::second_pass::event_windows::EventWindow;
 use parser::second_pass::parser_settings::create_huffman_lookup_table;
+use parser::second_pass::player_identity::player_identity_timeline;
+use parser::second_pass::row_filter::RowFilter;
 use parser::second_pass::variants::soa_to_aos;
 use parser::second_pass::variants::BytesVariant;
 use parser::second_pass::variants::OutputSerdeHelperStruct;
 use parser::second_pass::variants::Variant;
+use parser::second_pass::votes::collect_votes;
+use parser::second_pass::votes::VOTE_EVENTS;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::fs::File;
@@ -129,17 +149,92 @@ impl FromNapiValue for WantedPropState {
 }
 
 fn parse_demo(bytes: BytesVariant, parser: &mut Parser) -> Result<DemoOutput, Error> {
-  match bytes {
-    BytesVariant::Mmap(m) => match parser.parse_demo(&m) {
-      Ok(output) => Ok(output),
-      Err(e) => return Err(Error::new(Status::InvalidArg, format!("{}", e).to_owned())),
-    },
-    BytesVariant::Vec(v) => match parser.parse_demo(&v) {
-      Ok(output) => Ok(output),
-      Err(e) => return Err(Error::new(Status::InvalidArg, format!("{}", e).to_owned())),
-    },
+  let output = match bytes {
+    BytesVariant::Mmap(m) => parser.parse_demo(&m),
+    BytesVariant::Vec(v) => parser.parse_demo(&v),
+  };
+  match output {
+    Ok(output) => Ok(output),
+    Err(e) if matches!(e.root(), DemoParserError::Cancelled) => Err(Error::new(
+      Status::Cancelled,
+      "AbortError: parsing was aborted".to_owned(),
+    )),
+    Err(e) => Err(Error::new(Status::InvalidArg, format!("{}", e).to_owned())),
   }
 }
+
+// Runs a parse in the libuv thread pool. Errors reject the Promise.
+pub struct ParseTask<T> {
+  job: Option<Box<dyn FnOnce() -> napi::Result<T> + Send>>,
+}
+
+impl<T> ParseTask<T> {
+  fn new<F: FnOnce() -> napi::Result<T> + Send + 'static>(job: F) -> Self {
+    ParseTask {
+      job: Some(Box::new(job)),
+    }
+  }
+}
+
+impl<T: ToNapiValue + TypeName + Send + 'static> Task for ParseTask<T> {
+  type Output = T;
+  type JsValue = T;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    match self.job.take() {
+      Some(job) => job(),
+      None => Err(Error::new(
+        Status::GenericFailure,
+        "parse task was already run".to_owned(),
+      )),
+    }
+  }
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+// Hooks up an AbortSignal and a progress callback to the parser
+fn create_parse_control(
+  env: &Env,
+  signal: Option<JsObject>,
+  on_progress: Option<JsFunction>,
+) -> napi::Result<ParseControl> {
+  let mut control = ParseControl::new();
+  if let Some(on_progress) = on_progress {
+    let mut tsfn: ThreadsafeFunction<(usize, usize), ErrorStrategy::Fatal> = on_progress
+      .create_threadsafe_function(0, |ctx: ThreadSafeCallContext<(usize, usize)>| {
+        Ok(vec![ctx.value.0 as f64, ctx.value.1 as f64])
+      })?;
+    // Progress updates alone should not keep the process alive
+    tsfn.unref(env)?;
+    control = control.with_progress_callback(move |bytes_processed, total_bytes| {
+      tsfn.call(
+        (bytes_processed, total_bytes),
+        ThreadsafeFunctionCallMode::NonBlocking,
+      );
+    });
+  }
+  if let Some(signal) = signal {
+    if signal.get_named_property::<bool>("aborted")? {
+      control.cancel();
+    }
+    let handle = control.clone();
+    let on_abort = env.create_function_from_closure("onAbort", move |ctx| {
+      handle.cancel();
+      ctx.env.get_undefined()
+    })?;
+    let add_event_listener: JsFunction = signal.get_named_property("addEventListener")?;
+    add_event_listener.call(
+      Some(&signal),
+      &[
+        env.create_string("abort")?.into_unknown(),
+        on_abort.into_unknown(),
+      ],
+    )?;
+  }
+  Ok(control)
+}
 #[napi(object)]
 pub struct VoiceData {
   pub tick: i32,
@@ -149,7 +244,19 @@ pub struct VoiceData {
 
 #[napi]
 pub fn parse_voice(path_or_buf: Either<String, Buffer>) -> napi::Result<Vec<VoiceData>> {
-  let bytes = resolve_byte_type(path_or_buf).unwrap();
+  parse_voice_from(to_demo_source(path_or_buf))
+}
+
+#[napi(ts_return_type = "Promise<Array<VoiceData>>")]
+pub fn parse_voice_async(
+  path_or_buf: Either<String, Buffer>,
+) -> AsyncTask<ParseTask<Vec<VoiceData>>> {
+  let source = to_demo_source(path_or_buf);
+  AsyncTask::new(ParseTask::new(move || parse_voice_from(source)))
+}
+
+fn parse_voice_from(source: DemoSource) -> napi::Result<Vec<VoiceData>> {
+  let bytes = resolve_byte_type(source)?;
   let settings = ParserInputs {
     wanted_players: vec![],
     wanted_player_props: vec![],
@@ -165,6 +272,15 @@ pub fn parse_voice(path_or_buf: Either<String, Buffer>) -> napi::Result<Vec<Voic
     only_convars: false,
     huffman_lookup_table: &vec![],
     order_by_steamid: false,
+    wanted_user_messages: vec![],
+    lenient: false,
+    parse_control: ParseControl::default(),
+    row_filters: vec![],
+    event_windows: vec![],
+    only_prop_changes: false,
+    wanted_string_tables: vec![],
+    include_non_players: false,
+    derived_columns: vec![],
     fallback_bytes: None,
     parse_grenades: false,
   };
@@ -184,9 +300,222 @@ pub fn parse_voice(path_or_buf: Either<String, Buffer>) -> napi::Result<Vec<Voic
   return Ok(out);
 }
 
+#[napi(object)]
+pub struct StringTableEntryData {
+  pub idx: i32,
+  pub key: String,
+  pub value: Buffer,
+  /// Only for tables with a known format (userinfo and lightstyles)
+  pub decoded: Option<Value>,
+}
+#[napi(object)]
+pub struct StringTableData {
+  pub name: String,
+  pub entries: Vec<StringTableEntryData>,
+}
+#[napi(object)]
+pub struct StringTableUpdateData {
+  pub tick: i32,
+  pub table: String,
+  pub entry: StringTableEntryData,
+}
+#[napi(object)]
+pub struct StringTables {
+  pub tables: Vec<StringTableData>,
+  pub updates: Vec<StringTableUpdateData>,
+}
+
+/// Contents of the string tables at the end of the demo and every update to them. All tables if names is not given.
+#[napi]
+pub fn parse_string_tables(
+  path_or_buf: Either<String, Buffer>,
+  names: Option<Vec<String>>,
+) -> napi::Result<StringTables> {
+  parse_string_tables_from(to_demo_source(path_or_buf), names)
+}
+
+#[napi(ts_return_type = "Promise<StringTables>")]
+pub fn parse_string_tables_async(
+  path_or_buf: Either<String, Buffer>,
+  names: Option<Vec<String>>,
+) -> AsyncTask<ParseTask<StringTables>> {
+  let source = to_demo_source(path_or_buf);
+  AsyncTask::new(ParseTask::new(move || {
+    parse_string_tables_from(source, names)
+  }))
+}
+
+fn parse_string_tables_from(
+  source: DemoSource,
+  names: Option<Vec<String>>,
+) -> napi::Result<StringTables> {
+  let bytes = resolve_byte_type(source)?;
+  let settings = ParserInputs {
+    wanted_players: vec![],
+    wanted_player_props: vec![],
+    wanted_other_props: vec![],
+    wanted_events: vec![],
+    wanted_ticks: vec![],
+    wanted_prop_states: AHashMap::default(),
+    real_name_to_og_name: AHashMap::default(),
+    parse_ents: false,
+    parse_projectiles: false,
+    only_header: false,
+    list_props: false,
+    only_convars: false,
+    huffman_lookup_table: &vec![],
+    order_by_steamid: false,
+    wanted_user_messages: vec![],
+    lenient: false,
+    parse_control: ParseControl::default(),
+    row_filters: vec![],
+    event_windows: vec![],
+    only_prop_changes: false,
+    wanted_string_tables: names.unwrap_or_else(|| vec!["all".to_string()]),
+    include_non_players: false,
+    derived_columns: vec![],
+    fallback_bytes: None,
+    parse_grenades: false,
+  };
+  let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+  let output = parse_demo(bytes, &mut parser)?;
+  let entry_data = |table: &str, entry: &StringTableEntry| StringTableEntryData {
+    idx: entry.idx,
+    key: entry.key.clone(),
+    value: entry.value.clone().into(),
+    decoded: match decode_string_table_value(table, &entry.value) {
+      Some(StringTableValue::UserInfo(player)) => Some(serde_json::json!({
+        "steamid": player.steamid.to_string(),
+        "name": player.name,
+        "userid": player.userid,
+        "is_hltv": player.is_hltv,
+      })),
+      Some(StringTableValue::String(s)) => Some(Value::String(s)),
+      None => None,
+    },
+  };
+  let tables = output
+    .string_tables
+    .tables
+    .iter()
+    .map(|table| StringTableData {
+      name: table.name.clone(),
+      entries: table
+        .data
+        .iter()
+        .map(|entry| entry_data(&table.name, entry))
+        .collect(),
+    })
+    .collect();
+  let updates = output
+    .string_tables
+    .updates
+    .iter()
+    .map(|update| StringTableUpdateData {
+      tick: update.tick,
+      table: update.table.clone(),
+      entry: entry_data(
+        &update.table,
+        &StringTableEntry {
+          idx: update.idx,
+          key: update.key.clone(),
+          value: update.value.clone(),
+        },
+      ),
+    })
+    .collect();
+  Ok(StringTables { tables, updates })
+}
+
+#[napi(object)]
+pub struct PlayerIdentityData {
+  pub tick: i32,
+  /// Index of the entry in the userinfo string table
+  pub slot: i32,
+  pub userid: i32,
+  /// "0" for bots
+  pub steamid: String,
+  pub name: String,
+  pub fake_player: bool,
+  pub is_hltv: bool,
+}
+
+/// Every change of the userinfo string table, so name changes mid-match are new entries with the same steamid.
+#[napi]
+pub fn parse_player_identity(
+  path_or_buf: Either<String, Buffer>,
+) -> napi::Result<Vec<PlayerIdentityData>> {
+  parse_player_identity_from(to_demo_source(path_or_buf))
+}
+
+#[napi(ts_return_type = "Promise<Array<PlayerIdentityData>>")]
+pub fn parse_player_identity_async(
+  path_or_buf: Either<String, Buffer>,
+) -> AsyncTask<ParseTask<Vec<PlayerIdentityData>>> {
+  let source = to_demo_source(path_or_buf);
+  AsyncTask::new(ParseTask::new(move || parse_player_identity_from(source)))
+}
+
+fn parse_player_identity_from(source: DemoSource) -> napi::Result<Vec<PlayerIdentityData>> {
+  let bytes = resolve_byte_type(source)?;
+  let settings = ParserInputs {
+    wanted_players: vec![],
+    wanted_player_props: vec![],
+    wanted_other_props: vec![],
+    wanted_events: vec![],
+    wanted_ticks: vec![],
+    wanted_prop_states: AHashMap::default(),
+    real_name_to_og_name: AHashMap::default(),
+    parse_ents: false,
+    parse_projectiles: false,
+    only_header: false,
+    list_props: false,
+    only_convars: false,
+    huffman_lookup_table: &vec![],
+    order_by_steamid: false,
+    wanted_user_messages: vec![],
+    lenient: false,
+    parse_control: ParseControl::default(),
+    row_filters: vec![],
+    event_windows: vec![],
+    only_prop_changes: false,
+    wanted_string_tables: vec!["userinfo".to_string()],
+    include_non_players: false,
+    derived_columns: vec![],
+    fallback_bytes: None,
+    parse_grenades: false,
+  };
+  let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+  let output = parse_demo(bytes, &mut parser)?;
+  Ok(
+    player_identity_timeline(&output.string_tables.updates)
+      .into_iter()
+      .map(|p| PlayerIdentityData {
+        tick: p.tick,
+        slot: p.slot,
+        userid: p.userid,
+        steamid: p.steamid.to_string(),
+        name: p.name,
+        fake_player: p.fake_player,
+        is_hltv: p.is_hltv,
+      })
+      .collect(),
+  )
+}
+
 #[napi]
 pub fn list_game_events(path_or_buf: Either<String, Buffer>) -> napi::Result<Value> {
-  let bytes = resolve_byte_type(path_or_buf)?;
+  list_game_events_from(to_demo_source(path_or_buf))
+}
+
+#[napi(ts_return_type = "Promise<any>")]
+pub fn list_game_events_async(path_or_buf: Either<String, Buffer>) -> AsyncTask<ParseTask<Value>> {
+  let source = to_demo_source(path_or_buf);
+  AsyncTask::new(ParseTask::new(move || list_game_events_from(source)))
+}
+
+fn list_game_events_from(source: DemoSource) -> napi::Result<Value> {
+  let bytes = resolve_byte_type(source)?;
 
   let huf = create_huffman_lookup_table();
   let settings = ParserInputs {
@@ -204,6 +533,15 @@ pub fn list_game_events(path_or_buf: Either<String, Buffer>) -> napi::Result<Val
     only_convars: false,
     huffman_lookup_table: &huf,
     order_by_steamid: false,
+    wanted_user_messages: vec![],
+    lenient: false,
+    parse_control: ParseControl::default(),
+    row_filters: vec![],
+    event_windows: vec![],
+    only_prop_changes: false,
+    wanted_string_tables: vec![],
+    include_non_players: false,
+    derived_columns: vec![],
     fallback_bytes: None,
     parse_grenades: false,
   };
@@ -225,7 +563,27 @@ pub fn parse_grenades(
   extra: Option<Vec<String>>,
   grenades: Option<bool>,
 ) -> napi::Result<Value> {
-  let bytes = resolve_byte_type(path_or_buf)?;
+  parse_grenades_from(to_demo_source(path_or_buf), extra, grenades)
+}
+
+#[napi(ts_return_type = "Promise<any>")]
+pub fn parse_grenades_async(
+  path_or_buf: Either<String, Buffer>,
+  extra: Option<Vec<String>>,
+  grenades: Option<bool>,
+) -> AsyncTask<ParseTask<Value>> {
+  let source = to_demo_source(path_or_buf);
+  AsyncTask::new(ParseTask::new(move || {
+    parse_grenades_from(source, extra, grenades)
+  }))
+}
+
+fn parse_grenades_from(
+  source: DemoSource,
+  extra: Option<Vec<String>>,
+  grenades: Option<bool>,
+) -> napi::Result<Value> {
+  let bytes = resolve_byte_type(source)?;
   let huf = create_huffman_lookup_table();
   let mut extra_props = match extra {
     Some(p) => p,
@@ -252,6 +610,15 @@ pub fn parse_grenades(
     only_convars: false,
     huffman_lookup_table: &huf,
     order_by_steamid: false,
+    wanted_user_messages: vec![],
+    lenient: false,
+    parse_control: ParseControl::default(),
+    row_filters: vec![],
+    event_windows: vec![],
+    only_prop_changes: false,
+    wanted_string_tables: vec![],
+    include_non_players: false,
+    derived_columns: vec![],
     fallback_bytes: None,
     parse_grenades: grenades,
   };
@@ -283,7 +650,17 @@ pub fn parse_grenades(
 }
 #[napi]
 pub fn parse_header(path_or_buf: Either<String, Buffer>) -> napi::Result<Value> {
-  let bytes = resolve_byte_type(path_or_buf)?;
+  parse_header_from(to_demo_source(path_or_buf))
+}
+
+#[napi(ts_return_type = "Promise<any>")]
+pub fn parse_header_async(path_or_buf: Either<String, Buffer>) -> AsyncTask<ParseTask<Value>> {
+  let source = to_demo_source(path_or_buf);
+  AsyncTask::new(ParseTask::new(move || parse_header_from(source)))
+}
+
+fn parse_header_from(source: DemoSource) -> napi::Result<Value> {
+  let bytes = resolve_byte_type(source)?;
   let huf = create_huffman_lookup_table();
 
   let settings = ParserInputs {
@@ -301,6 +678,15 @@ pub fn parse_header(path_or_buf: Either<String, Buffer>) -> napi::Result<Value>
     only_convars: false,
     huffman_lookup_table: &huf,
     order_by_steamid: false,
+    wanted_user_messages: vec![],
+    lenient: false,
+    parse_control: ParseControl::default(),
+    row_filters: vec![],
+    event_windows: vec![],
+    only_prop_changes: false,
+    wanted_string_tables: vec![],
+    include_non_players: false,
+    derived_columns: vec![],
     fallback_bytes: None,
     parse_grenades: false,
   };
@@ -333,6 +719,48 @@ pub fn parse_event(
   player_extra: Option<Vec<String>>,
   other_extra: Option<Vec<String>>,
   game_event_list_bytes: Option<Buffer>,
+  lenient: Option<bool>,
+) -> napi::Result<Value> {
+  parse_event_from(
+    to_demo_source(path_or_buf),
+    event_name,
+    player_extra,
+    other_extra,
+    game_event_list_bytes.map(|b| b.to_vec()),
+    lenient.unwrap_or(false),
+  )
+}
+
+#[napi(ts_return_type = "Promise<any>")]
+pub fn parse_event_async(
+  path_or_buf: Either<String, Buffer>,
+  event_name: String,
+  player_extra: Option<Vec<String>>,
+  other_extra: Option<Vec<String>>,
+  game_event_list_bytes: Option<Buffer>,
+  lenient: Option<bool>,
+) -> AsyncTask<ParseTask<Value>> {
+  let source = to_demo_source(path_or_buf);
+  let game_event_list_bytes = game_event_list_bytes.map(|b| b.to_vec());
+  AsyncTask::new(ParseTask::new(move || {
+    parse_event_from(
+      source,
+      event_name,
+      player_extra,
+      other_extra,
+      game_event_list_bytes,
+      lenient.unwrap_or(false),
+    )
+  }))
+}
+
+fn parse_event_from(
+  source: DemoSource,
+  event_name: String,
+  player_extra: Option<Vec<String>>,
+  other_extra: Option<Vec<String>>,
+  game_event_list_bytes: Option<Vec<u8>>,
+  lenient: bool,
 ) -> napi::Result<Value> {
   let player_props = match player_extra {
     Some(p) => p,
@@ -359,7 +787,7 @@ pub fn parse_event(
     real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
   }
 
-  let bytes = resolve_byte_type(path_or_buf)?;
+  let bytes = resolve_byte_type(source)?;
   let huf = create_huffman_lookup_table();
 
   let game_event_list_bytes = if let Some(b) = game_event_list_bytes {
@@ -383,6 +811,15 @@ pub fn parse_event(
     only_convars: false,
     huffman_lookup_table: &huf,
     order_by_steamid: false,
+    wanted_user_messages: vec![],
+    lenient,
+    parse_control: ParseControl::default(),
+    row_filters: vec![],
+    event_windows: vec![],
+    only_prop_changes: false,
+    wanted_string_tables: vec![],
+    include_non_players: false,
+    derived_columns: vec![],
     fallback_bytes: game_event_list_bytes,
     parse_grenades: false,
   };
@@ -401,6 +838,48 @@ pub fn parse_events(
   player_extra: Option<Vec<String>>,
   other_extra: Option<Vec<String>>,
   game_event_list_bytes: Option<Buffer>,
+  lenient: Option<bool>,
+) -> napi::Result<Value> {
+  parse_events_from(
+    to_demo_source(path_or_buf),
+    event_names,
+    player_extra,
+    other_extra,
+    game_event_list_bytes.map(|b| b.to_vec()),
+    lenient.unwrap_or(false),
+  )
+}
+
+#[napi(ts_return_type = "Promise<any>")]
+pub fn parse_events_async(
+  path_or_buf: Either<String, Buffer>,
+  event_names: Option<Vec<String>>,
+  player_extra: Option<Vec<String>>,
+  other_extra: Option<Vec<String>>,
+  game_event_list_bytes: Option<Buffer>,
+  lenient: Option<bool>,
+) -> AsyncTask<ParseTask<Value>> {
+  let source = to_demo_source(path_or_buf);
+  let game_event_list_bytes = game_event_list_bytes.map(|b| b.to_vec());
+  AsyncTask::new(ParseTask::new(move || {
+    parse_events_from(
+      source,
+      event_names,
+      player_extra,
+      other_extra,
+      game_event_list_bytes,
+      lenient.unwrap_or(false),
+    )
+  }))
+}
+
+fn parse_events_from(
+  source: DemoSource,
+  event_names: Option<Vec<String>>,
+  player_extra: Option<Vec<String>>,
+  other_extra: Option<Vec<String>>,
+  game_event_list_bytes: Option<Vec<u8>>,
+  lenient: bool,
 ) -> napi::Result<Value> {
   let event_names = match event_names {
     None => return Err(Error::new(Status::InvalidArg, "No events provided!")),
@@ -431,7 +910,7 @@ pub fn parse_events(
     real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
   }
 
-  let bytes = resolve_byte_type(path_or_buf)?;
+  let bytes = resolve_byte_type(source)?;
   let huf = create_huffman_lookup_table();
 
   let game_event_list_bytes = if let Some(b) = game_event_list_bytes {
@@ -455,6 +934,15 @@ pub fn parse_events(
     only_convars: false,
     huffman_lookup_table: &huf,
     order_by_steamid: false,
+    wanted_user_messages: vec![],
+    lenient,
+    parse_control: ParseControl::default(),
+    row_filters: vec![],
+    event_windows: vec![],
+    only_prop_changes: false,
+    wanted_string_tables: vec![],
+    include_non_players: false,
+    derived_columns: vec![],
     fallback_bytes: game_event_list_bytes,
     parse_grenades: false,
   };
@@ -467,6 +955,260 @@ pub fn parse_events(
   Ok(s)
 }
 
+/// message_names: names of net/user messages, for example "CS_UM_RadioText". "all" returns every message.
+#[napi]
+pub fn parse_user_messages(
+  path_or_buf: Either<String, Buffer>,
+  message_names: Option<Vec<String>>,
+) -> napi::Result<Value> {
+  parse_user_messages_from(to_demo_source(path_or_buf), message_names)
+}
+
+#[napi(ts_return_type = "Promise<any>")]
+pub fn parse_user_messages_async(
+  path_or_buf: Either<String, Buffer>,
+  message_names: Option<Vec<String>>,
+) -> AsyncTask<ParseTask<Value>> {
+  let source = to_demo_source(path_or_buf);
+  AsyncTask::new(ParseTask::new(move || {
+    parse_user_messages_from(source, message_names)
+  }))
+}
+
+fn parse_user_messages_from(
+  source: DemoSource,
+  message_names: Option<Vec<String>>,
+) -> napi::Result<Value> {
+  let message_names = match message_names {
+    None => return Err(Error::new(Status::InvalidArg, "No messages provided!")),
+    Some(v) => v,
+  };
+  let bytes = resolve_byte_type(source)?;
+  let huf = create_huffman_lookup_table();
+
+  let settings = ParserInputs {
+    real_name_to_og_name: AHashMap::default(),
+    wanted_players: vec![],
+    wanted_player_props: vec![],
+    wanted_other_props: vec![],
+    wanted_prop_states: AHashMap::default(),
+    wanted_events: vec![],
+    parse_ents: false,
+    wanted_ticks: vec![],
+    parse_projectiles: false,
+    only_header: true,
+    list_props: false,
+    only_convars: false,
+    huffman_lookup_table: &huf,
+    order_by_steamid: false,
+    wanted_user_messages: message_names,
+    lenient: false,
+    parse_control: ParseControl::default(),
+    row_filters: vec![],
+    event_windows: vec![],
+    only_prop_changes: false,
+    wanted_string_tables: vec![],
+    include_non_players: false,
+    derived_columns: vec![],
+    fallback_bytes: None,
+    parse_grenades: false,
+  };
+  let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+  let output = parse_demo(bytes, &mut parser)?;
+  let s = match serde_json::to_value(&output.user_messages) {
+    Ok(s) => s,
+    Err(e) => return Err(Error::new(Status::InvalidArg, format!("{}", e).to_owned())),
+  };
+  Ok(s)
+}
+
+#[napi]
+pub fn parse_votes(path_or_buf: Either<String, Buffer>) -> napi::Result<Value> {
+  parse_votes_from(to_demo_source(path_or_buf))
+}
+
+#[napi(ts_return_type = "Promise<any>")]
+pub fn parse_votes_async(path_or_buf: Either<String, Buffer>) -> AsyncTask<ParseTask<Value>> {
+  let source = to_demo_source(path_or_buf);
+  AsyncTask::new(ParseTask::new(move || parse_votes_from(source)))
+}
+
+fn parse_votes_from(source: DemoSource) -> napi::Result<Value> {
+  let bytes = resolve_byte_type(source)?;
+  let huf = create_huffman_lookup_table();
+
+  let settings = ParserInputs {
+    real_name_to_og_name: AHashMap::default(),
+    wanted_players: vec![],
+    wanted_player_props: vec![],
+    wanted_other_props: vec![],
+    wanted_prop_states: AHashMap::default(),
+    wanted_events: VOTE_EVENTS.iter().map(|x| x.to_string()).collect(),
+    parse_ents: true,
+    wanted_ticks: vec![],
+    parse_projectiles: false,
+    only_header: true,
+    list_props: false,
+    only_convars: false,
+    huffman_lookup_table: &huf,
+    order_by_steamid: false,
+    wanted_user_messages: vec![],
+    lenient: false,
+    parse_control: ParseControl::default(),
+    row_filters: vec![],
+    event_windows: vec![],
+    only_prop_changes: false,
+    wanted_string_tables: vec![],
+    include_non_players: false,
+    derived_columns: vec![],
+    fallback_bytes: None,
+    parse_grenades: false,
+  };
+  let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+  let output = parse_demo(bytes, &mut parser)?;
+  let s = match serde_json::to_value(collect_votes(&output.game_events)) {
+    Ok(s) => s,
+    Err(e) => return Err(Error::new(Status::InvalidArg, format!("{}", e).to_owned())),
+  };
+  Ok(s)
+}
+
+#[napi]
+pub fn validate_demo(path_or_buf: Either<String, Buffer>) -> napi::Result<Value> {
+  validate_demo_from(to_demo_source(path_or_buf))
+}
+
+#[napi(ts_return_type = "Promise<any>")]
+pub fn validate_demo_async(path_or_buf: Either<String, Buffer>) -> AsyncTask<ParseTask<Value>> {
+  let source = to_demo_source(path_or_buf);
+  AsyncTask::new(ParseTask::new(move || validate_demo_from(source)))
+}
+
+fn validate_demo_from(source: DemoSource) -> napi::Result<Value> {
+  let bytes = resolve_byte_type(source)?;
+  let report = validate_demo_bytes(&bytes[..]);
+  let s = match serde_json::to_value(report) {
+    Ok(s) => s,
+    Err(e) => return Err(Error::new(Status::InvalidArg, format!("{}", e).to_owned())),
+  };
+  Ok(s)
+}
+
+/// Props of the players in every eventName event at event tick + start, + start + step ... up to
+/// event tick + end, parsed in a single pass. Rows have eventIndex (index in parseEvent(eventName)),
+/// eventTick, offset and role ("attacker", "user" ...) next to the props.
+#[napi]
+pub fn parse_event_windows(
+  path_or_buf: Either<String, Buffer>,
+  event_name: String,
+  wanted_props: Vec<String>,
+  start: i32,
+  end: Option<i32>,
+  step: Option<i32>,
+) -> napi::Result<Value> {
+  parse_event_windows_from(
+    to_demo_source(path_or_buf),
+    event_name,
+    wanted_props,
+    start,
+    end,
+    step,
+  )
+}
+
+#[napi(ts_return_type = "Promise<any>")]
+pub fn parse_event_windows_async(
+  path_or_buf: Either<String, Buffer>,
+  event_name: String,
+  wanted_props: Vec<String>,
+  start: i32,
+  end: Option<i32>,
+  step: Option<i32>,
+) -> AsyncTask<ParseTask<Value>> {
+  let source = to_demo_source(path_or_buf);
+  AsyncTask::new(ParseTask::new(move || {
+    parse_event_windows_from(source, event_name, wanted_props, start, end, step)
+  }))
+}
+
+fn parse_event_windows_from(
+  source: DemoSource,
+  event_name: String,
+  wanted_props: Vec<String>,
+  start: i32,
+  end: Option<i32>,
+  step: Option<i32>,
+) -> napi::Result<Value> {
+  let end = end.unwrap_or(0);
+  let step = step.unwrap_or(1);
+  if step < 1 || start > end {
+    return Err(Error::new(
+      Status::InvalidArg,
+      "step must be at least 1 and start can not be after end".to_owned(),
+    ));
+  }
+  let real_names = match rm_user_friendly_names(&wanted_props) {
+    Ok(names) => names,
+    Err(e) => return Err(Error::new(Status::InvalidArg, format!("{}", e).to_owned())),
+  };
+  let mut real_name_to_og_name = AHashMap::default();
+  for (real_name, user_friendly_name) in real_names.iter().zip(&wanted_props) {
+    real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+  }
+  let bytes = resolve_byte_type(source)?;
+  let huf = create_huffman_lookup_table();
+  let settings = ParserInputs {
+    real_name_to_og_name: real_name_to_og_name,
+    wanted_players: vec![],
+    wanted_player_props: real_names,
+    wanted_other_props: vec![],
+    wanted_events: vec![],
+    wanted_prop_states: AHashMap::default(),
+    parse_ents: true,
+    wanted_ticks: vec![],
+    parse_projectiles: false,
+    only_header: false,
+    list_props: false,
+    only_convars: false,
+    huffman_lookup_table: &huf,
+    order_by_steamid: false,
+    wanted_user_messages: vec![],
+    lenient: false,
+    parse_control: ParseControl::default(),
+    row_filters: vec![],
+    event_windows: vec![EventWindow::new(&event_name, start, end, step)],
+    only_prop_changes: false,
+    wanted_string_tables: vec![],
+    include_non_players: false,
+    derived_columns: vec![],
+    fallback_bytes: None,
+    parse_grenades: false,
+  };
+  let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+  let output = parse_demo(bytes, &mut parser)?;
+
+  let mut prop_infos = output.prop_controller.prop_infos.clone();
+  prop_infos.sort_by_key(|x| x.prop_name.clone());
+  let helper = OutputSerdeHelperStruct {
+    prop_infos: prop_infos,
+    inner: output.event_windows.df.into(),
+  };
+  let mut rows = soa_to_aos(helper);
+  for (row, key) in rows.iter_mut().zip(&output.event_windows.keys) {
+    row.insert(
+      "eventIndex".to_owned(),
+      Some(Variant::U32(key.event_index as u32)),
+    );
+    row.insert("eventTick".to_owned(), Some(Variant::I32(key.event_tick)));
+    row.insert("offset".to_owned(), Some(Variant::I32(key.offset)));
+    row.insert("role".to_owned(), key.role.clone().map(Variant::String));
+  }
+  match serde_json::to_value(&rows) {
+    Ok(s) => Ok(s),
+    Err(e) => Err(Error::new(Status::InvalidArg, format!("{}", e).to_owned())),
+  }
+}
+
 #[napi]
 pub fn parse_ticks(
   path_or_buf: Either<String, Buffer>,
@@ -476,7 +1218,111 @@ pub fn parse_ticks(
   struct_of_arrays: Option<bool>,
   order_by_steamid: Option<bool>,
   prop_states: Option<Vec<WantedPropState>>,
+  filter: Option<String>,
+  derived_columns: Option<Vec<String>>,
+  lenient: Option<bool>,
+) -> napi::Result<Value> {
+  parse_ticks_with_control(
+    to_demo_source(path_or_buf),
+    wanted_props,
+    wanted_ticks,
+    wanted_players,
+    struct_of_arrays,
+    order_by_steamid,
+    prop_states_to_map(prop_states),
+    filter,
+    derived_columns,
+    lenient.unwrap_or(false),
+    ParseControl::default(),
+  )
+}
+
+/// Same as parseTicks but runs in the libuv thread pool. Parsing stops when the signal is aborted.
+#[napi(ts_return_type = "Promise<any>")]
+pub fn parse_ticks_async(
+  env: Env,
+  path_or_buf: Either<String, Buffer>,
+  wanted_props: Vec<String>,
+  wanted_ticks: Option<Vec<i32>>,
+  wanted_players: Option<Vec<String>>,
+  struct_of_arrays: Option<bool>,
+  order_by_steamid: Option<bool>,
+  prop_states: Option<Vec<WantedPropState>>,
+  #[napi(ts_arg_type = "AbortSignal")] signal: Option<JsObject>,
+  #[napi(ts_arg_type = "(bytesProcessed: number, totalBytes: number) => void")] on_progress: Option<
+    JsFunction,
+  >,
+  filter: Option<String>,
+  derived_columns: Option<Vec<String>>,
+  lenient: Option<bool>,
+) -> napi::Result<AsyncTask<ParseTask<Value>>> {
+  let source = to_demo_source(path_or_buf);
+  let wanted_prop_states = prop_states_to_map(prop_states);
+  let control = create_parse_control(&env, signal, on_progress)?;
+  Ok(AsyncTask::new(ParseTask::new(move || {
+    parse_ticks_with_control(
+      source,
+      wanted_props,
+      wanted_ticks,
+      wanted_players,
+      struct_of_arrays,
+      order_by_steamid,
+      wanted_prop_states,
+      filter,
+      derived_columns,
+      lenient.unwrap_or(false),
+      control,
+    )
+  })))
+}
+
+fn prop_states_to_map(prop_states: Option<Vec<WantedPropState>>) -> AHashMap<String, Variant> {
+  prop_states
+    .unwrap_or_default()
+    .into_iter()
+    .map(|prop| (prop.prop.clone(), prop.state.0.clone()))
+    .collect()
+}
+
+fn parse_ticks_with_control(
+  source: DemoSource,
+  wanted_props: Vec<String>,
+  wanted_ticks: Option<Vec<i32>>,
+  wanted_players: Option<Vec<String>>,
+  struct_of_arrays: Option<bool>,
+  order_by_steamid: Option<bool>,
+  wanted_prop_states: AHashMap<String, Variant>,
+  filter: Option<String>,
+  derived_columns: Option<Vec<String>>,
+  lenient: bool,
+  parse_control: ParseControl,
 ) -> napi::Result<Value> {
+  let row_filters: Vec<RowFilter> = match filter.map(|filter| RowFilter::parse(&filter)).transpose()
+  {
+    Ok(row_filter) => row_filter.into_iter().collect(),
+    Err(e) => return Err(Error::new(Status::InvalidArg, format!("{}", e).to_owned())),
+  };
+  let derived_columns = match derived_columns
+    .unwrap_or_default()
+    .iter()
+    .map(|column| DerivedColumn::parse(column))
+    .collect::<Result<Vec<_>, _>>()
+  {
+    Ok(derived_columns) => derived_columns,
+    Err(e) => return Err(Error::new(Status::InvalidArg, format!("{}", e).to_owned())),
+  };
+  // Props in the filter are only available to it if they are parsed
+  let mut wanted_props = wanted_props;
+  for filter in &row_filters {
+    for prop in filter.props() {
+      if !["tick", "steamid", "name"].contains(&prop)
+        && !wanted_props.iter().any(|p| p == prop)
+        && !derived_columns.iter().any(|c| c.name == prop)
+      {
+        wanted_props.push(prop.to_string());
+      }
+    }
+  }
   let mut real_names = match rm_user_friendly_names(&wanted_props) {
     Ok(names) => names,
     Err(e) => return Err(Error::new(Status::InvalidArg, format!("{}", e).to_owned())),
@@ -485,11 +1331,6 @@ pub fn parse_ticks(
     Some(v) => v.iter().map(|x| x.parse::<u64>().unwrap_or(0)).collect(),
     None => vec![],
   };
-  let wanted_prop_states: AHashMap<String, Variant> = prop_states
-    .unwrap_or_default()
-    .into_iter()
-    .map(|prop| (prop.prop.clone(), prop.state.0.clone()))
-    .collect();
 
   let real_wanted_prop_states = rm_map_user_friendly_names(&wanted_prop_states);
   let real_wanted_prop_states = match real_wanted_prop_states {
@@ -497,7 +1338,7 @@ pub fn parse_ticks(
     Err(e) => return Err(Error::new(Status::InvalidArg, format!("{}", e).to_owned())),
   };
 
-  let bytes = resolve_byte_type(path_or_buf)?;
+  let bytes = resolve_byte_type(source)?;
   let huf = create_huffman_lookup_table();
   let mut real_name_to_og_name = AHashMap::default();
 
@@ -535,6 +1376,15 @@ pub fn parse_ticks(
     only_convars: false,
     huffman_lookup_table: &huf,
     order_by_steamid: order_by_steamid,
+    wanted_user_messages: vec![],
+    lenient,
+    parse_control: parse_control,
+    row_filters: row_filters,
+    event_windows: vec![],
+    only_prop_changes: false,
+    wanted_string_tables: vec![],
+    include_non_players: false,
+    derived_columns,
     fallback_bytes: None,
     parse_grenades: false,
   };
@@ -592,7 +1442,17 @@ pub fn parse_ticks(
 
 #[napi]
 pub fn parse_player_info(path_or_buf: Either<String, Buffer>) -> napi::Result<Value> {
-  let bytes = resolve_byte_type(path_or_buf)?;
+  parse_player_info_from(to_demo_source(path_or_buf))
+}
+
+#[napi(ts_return_type = "Promise<any>")]
+pub fn parse_player_info_async(path_or_buf: Either<String, Buffer>) -> AsyncTask<ParseTask<Value>> {
+  let source = to_demo_source(path_or_buf);
+  AsyncTask::new(ParseTask::new(move || parse_player_info_from(source)))
+}
+
+fn parse_player_info_from(source: DemoSource) -> napi::Result<Value> {
+  let bytes = resolve_byte_type(source)?;
   let huf = create_huffman_lookup_table();
 
   let settings = ParserInputs {
@@ -610,6 +1470,15 @@ pub fn parse_player_info(path_or_buf: Either<String, Buffer>) -> napi::Result<Va
     only_convars: false,
     huffman_lookup_table: &huf,
     order_by_steamid: false,
+    wanted_user_messages: vec![],
+    lenient: false,
+    parse_control: ParseControl::default(),
+    row_filters: vec![],
+    event_windows: vec![],
+    only_prop_changes: false,
+    wanted_string_tables: vec![],
+    include_non_players: false,
+    derived_columns: vec![],
     fallback_bytes: None,
     parse_grenades: false,
   };
@@ -624,7 +1493,19 @@ pub fn parse_player_info(path_or_buf: Either<String, Buffer>) -> napi::Result<Va
 
 #[napi]
 pub fn parse_player_skins(path_or_buf: Either<String, Buffer>) -> napi::Result<Value> {
-  let bytes = resolve_byte_type(path_or_buf)?;
+  parse_player_skins_from(to_demo_source(path_or_buf))
+}
+
+#[napi(ts_return_type = "Promise<any>")]
+pub fn parse_player_skins_async(
+  path_or_buf: Either<String, Buffer>,
+) -> AsyncTask<ParseTask<Value>> {
+  let source = to_demo_source(path_or_buf);
+  AsyncTask::new(ParseTask::new(move || parse_player_skins_from(source)))
+}
+
+fn parse_player_skins_from(source: DemoSource) -> napi::Result<Value> {
+  let bytes = resolve_byte_type(source)?;
   let huf = create_huffman_lookup_table();
 
   let settings = ParserInputs {
@@ -642,6 +1523,15 @@ pub fn parse_player_skins(path_or_buf: Either<String, Buffer>) -> napi::Result<V
     only_convars: false,
     huffman_lookup_table: &huf,
     order_by_steamid: false,
+    wanted_user_messages: vec![],
+    lenient: false,
+    parse_control: ParseControl::default(),
+    row_filters: vec![],
+    event_windows: vec![],
+    only_prop_changes: false,
+    wanted_string_tables: vec![],
+    include_non_players: false,
+    derived_columns: vec![],
     fallback_bytes: None,
     parse_grenades: false,
   };
@@ -655,7 +1545,19 @@ pub fn parse_player_skins(path_or_buf: Either<String, Buffer>) -> napi::Result<V
 }
 #[napi]
 pub fn list_updated_fields(path_or_buf: Either<String, Buffer>) -> napi::Result<Value> {
-  let bytes = resolve_byte_type(path_or_buf)?;
+  list_updated_fields_from(to_demo_source(path_or_buf))
+}
+
+#[napi(ts_return_type = "Promise<any>")]
+pub fn list_updated_fields_async(
+  path_or_buf: Either<String, Buffer>,
+) -> AsyncTask<ParseTask<Value>> {
+  let source = to_demo_source(path_or_buf);
+  AsyncTask::new(ParseTask::new(move || list_updated_fields_from(source)))
+}
+
+fn list_updated_fields_from(source: DemoSource) -> napi::Result<Value> {
+  let bytes = resolve_byte_type(source)?;
   let huf = create_huffman_lookup_table();
 
   let settings = ParserInputs {
@@ -673,6 +1575,15 @@ pub fn list_updated_fields(path_or_buf: Either<String, Buffer>) -> napi::Result<
     only_convars: false,
     huffman_lookup_table: &huf,
     order_by_steamid: false,
+    wanted_user_messages: vec![],
+    lenient: false,
+    parse_control: ParseControl::default(),
+    row_filters: vec![],
+    event_windows: vec![],
+    only_prop_changes: false,
+    wanted_string_tables: vec![],
+    include_non_players: false,
+    derived_columns: vec![],
     fallback_bytes: None,
     parse_grenades: false,
   };
@@ -685,7 +1596,116 @@ pub fn list_updated_fields(path_or_buf: Either<String, Buffer>) -> napi::Result<
   Ok(s)
 }
 
-fn resolve_byte_type(path_or_buf: Either<String, Buffer>) -> Result<BytesVariant, napi::Error> {
+/// Every prop path in the demo with its class, sendtable type and encoding, decoder,
+/// the type it is parsed into, what kind of entity it is collected from and its friendly name.
+#[napi]
+pub fn describe_schema(path_or_buf: Either<String, Buffer>) -> napi::Result<Value> {
+  describe_schema_from(to_demo_source(path_or_buf))
+}
+
+#[napi(ts_return_type = "Promise<any>")]
+pub fn describe_schema_async(path_or_buf: Either<String, Buffer>) -> AsyncTask<ParseTask<Value>> {
+  let source = to_demo_source(path_or_buf);
+  AsyncTask::new(ParseTask::new(move || describe_schema_from(source)))
+}
+
+fn describe_schema_from(source: DemoSource) -> napi::Result<Value> {
+  let schema = demo_schema_from(source)?;
+  let s = match serde_json::to_value(&schema.props) {
+    Ok(s) => s,
+    Err(e) => return Err(Error::new(Status::InvalidArg, format!("{}", e).to_owned())),
+  };
+  Ok(s)
+}
+
+/// Props, serializers and game events that were added, removed or changed type from demoA to
+/// demoB, for example after a game update. Every list is empty if both are from the same build.
+#[napi]
+pub fn diff_schemas(
+  demo_a: Either<String, Buffer>,
+  demo_b: Either<String, Buffer>,
+) -> napi::Result<Value> {
+  diff_schemas_from(to_demo_source(demo_a), to_demo_source(demo_b))
+}
+
+#[napi(ts_return_type = "Promise<any>")]
+pub fn diff_schemas_async(
+  demo_a: Either<String, Buffer>,
+  demo_b: Either<String, Buffer>,
+) -> AsyncTask<ParseTask<Value>> {
+  let source_a = to_demo_source(demo_a);
+  let source_b = to_demo_source(demo_b);
+  AsyncTask::new(ParseTask::new(move || {
+    diff_schemas_from(source_a, source_b)
+  }))
+}
+
+fn diff_schemas_from(source_a: DemoSource, source_b: DemoSource) -> napi::Result<Value> {
+  let a = demo_schema_from(source_a)?;
+  let b = demo_schema_from(source_b)?;
+  let s = match serde_json::to_value(&parser::first_pass::schema::diff_schemas(&a, &b)) {
+    Ok(s) => s,
+    Err(e) => return Err(Error::new(Status::InvalidArg, format!("{}", e).to_owned())),
+  };
+  Ok(s)
+}
+
+fn demo_schema_from(source: DemoSource) -> napi::Result<DemoSchema> {
+  let bytes = resolve_byte_type(source)?;
+  let huf = create_huffman_lookup_table();
+
+  let settings = ParserInputs {
+    wanted_players: vec![],
+    real_name_to_og_name: AHashMap::default(),
+    wanted_player_props: vec![],
+    wanted_other_props: vec![],
+    wanted_prop_states: AHashMap::default(),
+    wanted_events: vec![],
+    parse_ents: false,
+    wanted_ticks: vec![],
+    parse_projectiles: false,
+    only_header: false,
+    list_props: false,
+    only_convars: false,
+    huffman_lookup_table: &huf,
+    order_by_steamid: false,
+    wanted_user_messages: vec![],
+    lenient: false,
+    parse_control: ParseControl::default(),
+    row_filters: vec![],
+    event_windows: vec![],
+    only_prop_changes: false,
+    wanted_string_tables: vec![],
+    include_non_players: false,
+    derived_columns: vec![],
+    fallback_bytes: None,
+    parse_grenades: false,
+  };
+  let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+  let schema = match bytes {
+    BytesVariant::Mmap(m) => parser.demo_schema(&m),
+    BytesVariant::Vec(v) => parser.demo_schema(&v),
+  };
+  match schema {
+    Ok(schema) => Ok(schema),
+    Err(e) => Err(Error::new(Status::InvalidArg, format!("{}", e).to_owned())),
+  }
+}
+
+/*
+Path or a copy of the buffer. Unlike Buffer this can be moved to the thread pool, where the
+file is opened so that a bad path rejects the Promise instead of throwing.
+*/
+type DemoSource = Either<String, Vec<u8>>;
+
+fn to_demo_source(path_or_buf: Either<String, Buffer>) -> DemoSource {
+  match path_or_buf {
+    Either::A(path) => Either::A(path),
+    Either::B(buf) => Either::B(buf.into()),
+  }
+}
+
+fn resolve_byte_type(path_or_buf: DemoSource) -> Result<BytesVariant, napi::Error> {
   match path_or_buf {
     Either::A(path) => {
       let file = match File::open(path.clone()) {
@@ -698,6 +1718,6 @@ fn resolve_byte_type(path_or_buf: Either<String, Buffer>) -> Result<BytesVariant
       };
       Ok(BytesVariant::Mmap(mmap))
     }
-    Either::B(buf) => Ok(BytesVariant::Vec(buf.into())),
+    Either::B(buf) => Ok(BytesVariant::Vec(buf)),
   }
 }
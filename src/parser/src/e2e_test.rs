@@ -1,6 +1,7 @@
 #![allow(non_snake_case)]
 
 use crate::first_pass::parser_settings::ParserInputs;
+use crate::parse_control::ParseControl;
 use crate::first_pass::prop_controller::PropController;
 use crate::first_pass::prop_controller::*;
 use crate::parse_demo::DemoOutput;
@@ -315,6 +316,15 @@ pub fn _create_ge_tests() {
         huffman_lookup_table: &huf,
         order_by_steamid: false,
         wanted_prop_states: AHashMap::default(),
+        wanted_user_messages: vec![],
+        lenient: false,
+        parse_control: ParseControl::default(),
+        row_filters: vec![],
+        event_windows: vec![],
+        only_prop_changes: false,
+        wanted_string_tables: vec![],
+        include_non_players: false,
+        derived_columns: vec![],
         fallback_bytes: None,
     };
 
@@ -691,6 +701,15 @@ pub fn _create_tests() {
         huffman_lookup_table: &huf,
         order_by_steamid: false,
         wanted_prop_states: AHashMap::default(),
+        wanted_user_messages: vec![],
+        lenient: false,
+        parse_control: ParseControl::default(),
+        row_filters: vec![],
+        event_windows: vec![],
+        only_prop_changes: false,
+        wanted_string_tables: vec![],
+        include_non_players: false,
+        derived_columns: vec![],
         fallback_bytes: None,
     };
 
@@ -1048,6 +1067,15 @@ fn create_data() -> (DemoOutput, PropController, BTreeMap<String, Vec<GameEvent>
     let huf = create_huffman_lookup_table();
 
     let settings = ParserInputs {
+        wanted_user_messages: vec![],
+        lenient: false,
+        parse_control: ParseControl::default(),
+        row_filters: vec![],
+        event_windows: vec![],
+        only_prop_changes: false,
+        wanted_string_tables: vec![],
+        include_non_players: false,
+        derived_columns: vec![],
         fallback_bytes: None,
         wanted_player_props: wanted_props.clone(),
         wanted_events: wanted_events,
@@ -1090,6 +1118,15 @@ fn create_data() -> (DemoOutput, PropController, BTreeMap<String, Vec<GameEvent>
         huffman_lookup_table: &huf,
         order_by_steamid: false,
         wanted_prop_states: AHashMap::default(),
+        wanted_user_messages: vec![],
+        lenient: false,
+        parse_control: ParseControl::default(),
+        row_filters: vec![],
+        event_windows: vec![],
+        only_prop_changes: false,
+        wanted_string_tables: vec![],
+        include_non_players: false,
+        derived_columns: vec![],
         fallback_bytes: None,
     };
     let mut ds = Parser::new(settings, crate::parse_demo::ParsingMode::ForceSingleThreaded);
@@ -1156,6 +1193,7 @@ fn create_data() -> (DemoOutput, PropController, BTreeMap<String, Vec<GameEvent>
 #[cfg(test)]
 mod tests {
     use crate::e2e_test::create_data;
+    use crate::first_pass::parser_settings::default_inputs;
     use crate::first_pass::parser_settings::ParserInputs;
     use crate::first_pass::prop_controller::PropController;
     use crate::first_pass::prop_controller::PITCH_ID;
@@ -1164,17 +1202,25 @@ mod tests {
     use crate::first_pass::prop_controller::WEAPON_ORIGINGAL_OWNER_ID;
     use crate::first_pass::prop_controller::YAW_ID;
     use crate::first_pass::prop_controller::*;
+    use crate::first_pass::read_bits::read_varint;
+    use crate::first_pass::stringtables::{decode_string_table_value, StringTableValue, UserInfo};
+    use crate::parse_control::ParseControl;
     use crate::parse_demo::DemoOutput;
     use crate::parse_demo::Parser;
+    use crate::second_pass::derived_column::DerivedColumn;
+    use crate::second_pass::event_windows::EventWindow;
     use crate::second_pass::game_events::EventField;
     use crate::second_pass::game_events::GameEvent;
     use crate::second_pass::parser_settings::create_huffman_lookup_table;
+    use crate::second_pass::row_filter::{CompareOp, RowFilter};
     use crate::second_pass::variants::PropColumn;
     use crate::second_pass::variants::Sticker;
+    use crate::second_pass::variants::Variant;
     use crate::second_pass::variants::VarVec;
     use crate::second_pass::variants::VarVec::String;
     use crate::second_pass::variants::VarVec::*;
     use ahash::AHashMap;
+    use csgoproto::EDemoCommands;
     use lazy_static::lazy_static;
     use memmap2::MmapOptions;
     use std::collections::BTreeMap;
@@ -1202,6 +1248,15 @@ mod tests {
             huffman_lookup_table: &huf,
             order_by_steamid: false,
             wanted_prop_states: AHashMap::default(),
+            wanted_user_messages: vec![],
+            lenient: false,
+            parse_control: ParseControl::default(),
+            row_filters: vec![],
+            event_windows: vec![],
+            only_prop_changes: false,
+            wanted_string_tables: vec![],
+            include_non_players: false,
+            derived_columns: vec![],
             fallback_bytes: None,
         };
         let mut ds = Parser::new(settings, crate::parse_demo::ParsingMode::ForceSingleThreaded);
@@ -1214,6 +1269,368 @@ mod tests {
         assert_eq!(steamids.data, Some(VarVec::U64(vec![Some(76561198244754626), Some(76561198244754626)])));
     }
 
+    #[test]
+    fn test_lenient_resumes_from_next_fullpacket() {
+        let file = File::open("test_demo.dem").unwrap();
+        let mmap = unsafe { MmapOptions::new().map(&file).unwrap() };
+        let mut bytes = mmap.to_vec();
+        // Break the data of the first DemPacket after the middle of the demo, its header stays intact
+        let mut ptr = crate::parse_demo::HEADER_ENDS_AT_BYTE;
+        let broken_frame_at = loop {
+            let frame_starts_at = ptr;
+            let cmd = read_varint(&bytes, &mut ptr).unwrap();
+            read_varint(&bytes, &mut ptr).unwrap();
+            let size = read_varint(&bytes, &mut ptr).unwrap() as usize;
+            if frame_starts_at > bytes.len() / 2 && (cmd & !64) as i32 == EDemoCommands::DemPacket as i32 && size > 0 {
+                bytes[ptr..ptr + size].fill(0xff);
+                break frame_starts_at;
+            }
+            ptr += size;
+        };
+        let huf = create_huffman_lookup_table();
+        let settings = |lenient: bool| ParserInputs {
+            wanted_events: vec!["player_death".to_string()],
+            lenient,
+            ..default_inputs(&huf)
+        };
+        let mut ds = Parser::new(settings(false), crate::parse_demo::ParsingMode::ForceSingleThreaded);
+        assert!(ds.parse_demo(&bytes).is_err());
+
+        let mut ds = Parser::new(settings(true), crate::parse_demo::ParsingMode::ForceSingleThreaded);
+        let output = ds.parse_demo(&bytes).unwrap();
+        assert_eq!(output.warnings.len(), 1);
+        let warning = &output.warnings[0];
+        assert_eq!(warning.byte_offset, broken_frame_at);
+        assert!(warning.resumed_at.is_some_and(|offset| offset > broken_frame_at));
+        // Events after the broken frame are still found
+        assert!(output.game_events.iter().any(|e| e.tick > warning.tick));
+        assert!(output.game_events.len() <= out.2["player_death"].len());
+    }
+
+    #[test]
+    fn test_row_filters() {
+        let huf = create_huffman_lookup_table();
+
+        let settings = ParserInputs {
+            wanted_player_props: vec!["X".to_string()],
+            row_filters: vec![
+                RowFilter::new("steamid", CompareOp::Eq, Variant::U64(76561198244754626)),
+                RowFilter::new("tick", CompareOp::GtEq, Variant::I32(10000)),
+                RowFilter::new("tick", CompareOp::Lt, Variant::I32(10002)),
+            ],
+            ..default_inputs(&huf)
+        };
+        let mut ds = Parser::new(settings, crate::parse_demo::ParsingMode::ForceSingleThreaded);
+        let file = File::open("test_demo.dem").unwrap();
+        let mmap = unsafe { MmapOptions::new().map(&file).unwrap() };
+        let output = ds.parse_demo(&mmap).unwrap();
+
+        assert_eq!(output.df[&STEAMID_ID].data, Some(VarVec::U64(vec![Some(76561198244754626), Some(76561198244754626)])));
+        assert_eq!(output.df[&TICK_ID].data, Some(VarVec::I32(vec![Some(10000), Some(10001)])));
+    }
+
+    #[test]
+    fn test_row_filter_expression() {
+        let huf = create_huffman_lookup_table();
+
+        let settings = ParserInputs {
+            wanted_player_props: vec!["X".to_string()],
+            row_filters: vec![RowFilter::parse("steamid == 76561198244754626 && tick in 10000..10002").unwrap()],
+            ..default_inputs(&huf)
+        };
+        let mut ds = Parser::new(settings, crate::parse_demo::ParsingMode::ForceSingleThreaded);
+        let file = File::open("test_demo.dem").unwrap();
+        let mmap = unsafe { MmapOptions::new().map(&file).unwrap() };
+        let output = ds.parse_demo(&mmap).unwrap();
+
+        assert_eq!(output.df[&STEAMID_ID].data, Some(VarVec::U64(vec![Some(76561198244754626), Some(76561198244754626)])));
+        assert_eq!(output.df[&TICK_ID].data, Some(VarVec::I32(vec![Some(10000), Some(10001)])));
+    }
+
+    #[test]
+    fn test_derived_columns() {
+        let huf = create_huffman_lookup_table();
+
+        let settings = ParserInputs {
+            wanted_player_props: vec!["X".to_string()],
+            row_filters: vec![RowFilter::parse("steamid == 76561198244754626 && tick in 10000..10002").unwrap()],
+            derived_columns: vec![
+                DerivedColumn::parse("speed_2d = sqrt(velocity_X^2 + velocity_Y^2)").unwrap(),
+                DerivedColumn::parse("hp_lost = prev(health) - health").unwrap(),
+                DerivedColumn::parse("x_plus_one = X + 1").unwrap(),
+            ],
+            ..default_inputs(&huf)
+        };
+        let mut ds = Parser::new(settings, crate::parse_demo::ParsingMode::Normal);
+        let file = File::open("test_demo.dem").unwrap();
+        let mmap = unsafe { MmapOptions::new().map(&file).unwrap() };
+        let output = ds.parse_demo(&mmap).unwrap();
+
+        let names: Vec<&str> = output.prop_controller.prop_infos.iter().map(|info| info.prop_friendly_name.as_str()).collect();
+        assert!(names.contains(&"speed_2d") && names.contains(&"hp_lost"));
+        assert!(!names.contains(&"CCSPlayerPawn.m_iHealth") && !names.contains(&"velocity_X"));
+        let x = match &output.df[&PLAYER_X_ID].data {
+            Some(VarVec::F32(x)) => x.clone(),
+            _ => panic!("X is not f32"),
+        };
+        let x_plus_one = match &output.df[&(DERIVED_COLUMN_BASEID + 2)].data {
+            Some(VarVec::F32(x)) => x.clone(),
+            _ => panic!("x_plus_one is not f32"),
+        };
+        assert_eq!(x.len(), 2);
+        assert_eq!(x_plus_one, x.iter().map(|x| x.map(|x| x + 1.0)).collect::<Vec<_>>());
+        assert_eq!(output.df[&(DERIVED_COLUMN_BASEID + 1)].len(), 2);
+    }
+
+    #[test]
+    fn test_event_windows() {
+        let huf = create_huffman_lookup_table();
+
+        let settings = ParserInputs {
+            wanted_player_props: vec!["X".to_string()],
+            event_windows: vec![EventWindow::new("player_death", -64, 0, 32)],
+            ..default_inputs(&huf)
+        };
+        let mut ds = Parser::new(settings, crate::parse_demo::ParsingMode::ForceSingleThreaded);
+        let file = File::open("test_demo.dem").unwrap();
+        let mmap = unsafe { MmapOptions::new().map(&file).unwrap() };
+        let output = ds.parse_demo(&mmap).unwrap();
+
+        let deaths = output.game_events.iter().filter(|e| e.name == "player_death").count();
+        let windows = &output.event_windows;
+        assert!(deaths > 0);
+        assert_eq!(windows.keys.iter().map(|k| k.event_index).max(), Some(deaths - 1));
+        assert_eq!(windows.df[&TICK_ID].len(), windows.keys.len());
+        assert_eq!(windows.df[&PLAYER_X_ID].len(), windows.keys.len());
+        let Some(VarVec::I32(ticks)) = &windows.df[&TICK_ID].data else {
+            panic!("tick column is not I32");
+        };
+        for (tick, key) in ticks.iter().zip(&windows.keys) {
+            assert!([-64, -32, 0].contains(&key.offset));
+            assert_eq!(*tick, Some(key.event_tick + key.offset));
+        }
+    }
+
+    // Bots (steamid 0) are in the default output since they have a player_key, only non-players are left out
+    #[test]
+    fn test_bots_kept_by_default() {
+        let huf = create_huffman_lookup_table();
+
+        let settings = ParserInputs {
+            wanted_player_props: vec!["role".to_string(), "player_key".to_string()],
+            wanted_ticks: (10000..10064).collect(),
+            ..default_inputs(&huf)
+        };
+        let file = File::open("test_demo.dem").unwrap();
+        let mmap = unsafe { MmapOptions::new().map(&file).unwrap() };
+        let default = Parser::new(settings.clone(), crate::parse_demo::ParsingMode::Normal).parse_demo(&mmap).unwrap();
+        let everyone = Parser::new(ParserInputs { include_non_players: true, ..settings }, crate::parse_demo::ParsingMode::Normal)
+            .parse_demo(&mmap)
+            .unwrap();
+
+        let rows = |output: &DemoOutput| {
+            let (Some(VarVec::I32(ticks)), Some(VarVec::U64(steamids)), Some(VarVec::U64(keys)), Some(VarVec::String(roles))) =
+                (&output.df[&TICK_ID].data, &output.df[&STEAMID_ID].data, &output.df[&PLAYER_KEY_ID].data, &output.df[&ROLE_ID].data)
+            else {
+                panic!("unexpected column types");
+            };
+            let mut rows: Vec<(i32, u64, u64, std::string::String)> =
+                (0..ticks.len()).map(|idx| (ticks[idx].unwrap(), steamids[idx].unwrap(), keys[idx].unwrap(), roles[idx].clone().unwrap())).collect();
+            rows.sort();
+            rows
+        };
+        let default_rows = rows(&default);
+        for (_, steamid, key, role) in &default_rows {
+            assert!(role == "player" || role == "bot");
+            if role == "bot" {
+                assert_eq!(*steamid, 0);
+                assert!(key >> 63 == 1);
+            }
+        }
+        let players_and_bots: Vec<_> = rows(&everyone).into_iter().filter(|row| row.3 == "player" || row.3 == "bot").collect();
+        assert_eq!(default_rows, players_and_bots);
+    }
+
+    #[test]
+    fn test_only_prop_changes() {
+        let huf = create_huffman_lookup_table();
+
+        let settings = ParserInputs {
+            wanted_player_props: vec!["X".to_string()],
+            only_prop_changes: true,
+            ..default_inputs(&huf)
+        };
+        let file = File::open("test_demo.dem").unwrap();
+        let mmap = unsafe { MmapOptions::new().map(&file).unwrap() };
+        let changes = Parser::new(settings.clone(), crate::parse_demo::ParsingMode::Normal).parse_demo(&mmap).unwrap();
+        let dense = Parser::new(ParserInputs { only_prop_changes: false, ..settings }, crate::parse_demo::ParsingMode::Normal)
+            .parse_demo(&mmap)
+            .unwrap();
+
+        let (Some(VarVec::I32(ticks)), Some(VarVec::U64(steamids)), Some(VarVec::F32(xs))) =
+            (&dense.df[&TICK_ID].data, &dense.df[&STEAMID_ID].data, &dense.df[&PLAYER_X_ID].data)
+        else {
+            panic!("unexpected column types");
+        };
+        let x_by_row: AHashMap<(i32, u64), Option<f32>> = ticks.iter().zip(steamids).zip(xs).map(|((t, s), x)| ((t.unwrap(), s.unwrap()), *x)).collect();
+        let prop_changes = &changes.prop_changes;
+        assert!(prop_changes.len() < x_by_row.len());
+        for idx in 0..prop_changes.len() {
+            // Bots all have steamid 0 so they can't be matched by (tick, steamid)
+            if prop_changes.prop_ids[idx] == PLAYER_X_ID && prop_changes.steamids[idx] != 0 {
+                let x = match &prop_changes.values[idx] {
+                    Some(Variant::F32(x)) => Some(*x),
+                    _ => None,
+                };
+                assert_eq!(x_by_row[&(prop_changes.ticks[idx], prop_changes.steamids[idx])], x);
+            }
+        }
+    }
+
+    #[test]
+    fn test_only_prop_changes_dense_with_filter() {
+        let huf = create_huffman_lookup_table();
+
+        let settings = ParserInputs {
+            wanted_player_props: vec!["X".to_string(), "health".to_string()],
+            row_filters: vec![RowFilter::parse("health > 50").unwrap()],
+            only_prop_changes: true,
+            ..default_inputs(&huf)
+        };
+        let file = File::open("test_demo.dem").unwrap();
+        let mmap = unsafe { MmapOptions::new().map(&file).unwrap() };
+        let changes = Parser::new(settings.clone(), crate::parse_demo::ParsingMode::Normal).parse_demo(&mmap).unwrap();
+        let normal = Parser::new(ParserInputs { only_prop_changes: false, ..settings }, crate::parse_demo::ParsingMode::Normal)
+            .parse_demo(&mmap)
+            .unwrap();
+        let dense = changes.prop_changes.to_dense(&[], &changes.prop_controller.prop_infos);
+
+        // Players that dropped below the filter (or left) must not be forward-filled
+        let rows = |df: &AHashMap<u32, PropColumn>| {
+            let (Some(VarVec::I32(ticks)), Some(VarVec::U64(steamids)), Some(VarVec::F32(xs))) = (&df[&TICK_ID].data, &df[&STEAMID_ID].data, &df[&PLAYER_X_ID].data) else {
+                panic!("unexpected column types");
+            };
+            let mut rows: Vec<(i32, u64, Option<u32>)> = ticks.iter().zip(steamids).zip(xs).map(|((t, s), x)| (t.unwrap(), s.unwrap(), x.map(f32::to_bits))).collect();
+            rows.sort();
+            rows
+        };
+        assert_eq!(dense[&TICK_ID].len(), normal.df[&TICK_ID].len());
+        assert_eq!(rows(&dense), rows(&normal.df));
+    }
+
+    #[test]
+    fn test_string_tables() {
+        let huf = create_huffman_lookup_table();
+
+        let settings = ParserInputs {
+            parse_ents: false,
+            wanted_string_tables: vec!["userinfo".to_string(), "modelprecache".to_string()],
+            ..default_inputs(&huf)
+        };
+        let file = File::open("test_demo.dem").unwrap();
+        let mmap = unsafe { MmapOptions::new().map(&file).unwrap() };
+        let output = Parser::new(settings, crate::parse_demo::ParsingMode::Normal).parse_demo(&mmap).unwrap();
+
+        let mut names: Vec<&str> = output.string_tables.tables.iter().map(|t| t.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["modelprecache", "userinfo"]);
+        assert!(output.string_tables.updates.iter().all(|u| u.table == "userinfo" || u.table == "modelprecache"));
+        let modelprecache = output.string_tables.tables.iter().find(|t| t.name == "modelprecache").unwrap();
+        assert!(modelprecache.data.iter().any(|e| e.key.ends_with(".vmdl")));
+        // userinfo values decode to players
+        let userinfo = output.string_tables.tables.iter().find(|t| t.name == "userinfo").unwrap();
+        let players: Vec<UserInfo> = userinfo
+            .data
+            .iter()
+            .filter_map(|e| match decode_string_table_value("userinfo", &e.value) {
+                Some(StringTableValue::UserInfo(player)) if player.steamid != 0 => Some(player),
+                _ => None,
+            })
+            .collect();
+        assert!(!players.is_empty());
+    }
+
+    #[test]
+    fn test_prop_types_match_parsed_columns() {
+        let huf = create_huffman_lookup_table();
+        let wanted_props = [
+            "health", "team_num", "X", "Y", "Z", "velocity", "velocity_X", "pitch", "yaw", "FORWARD", "game_time", "entity_id", "user_id",
+            "weapon_name", "weapon_skin", "weapon_skin_id", "weapon_paint_seed", "weapon_float", "weapon_stickers", "glove_skin",
+            "glove_paint_id", "glove_paint_seed", "glove_paint_float", "agent_skin", "active_weapon_original_owner", "inventory",
+            "inventory_as_ids", "inventory_as_bitmask", "is_alive", "is_airborne", "player_key", "controlled_by_steamid",
+            "CCSPlayerPawn.m_bSpottedByMask", "CCSPlayerController.m_iCompTeammateColor", "balance", "active_weapon_ammo",
+        ];
+        let settings = || ParserInputs {
+            wanted_player_props: wanted_props.iter().map(|p| p.to_string()).collect(),
+            wanted_ticks: (10000..10064).collect(),
+            ..default_inputs(&huf)
+        };
+        let file = File::open("test_demo.dem").unwrap();
+        let mmap = unsafe { MmapOptions::new().map(&file).unwrap() };
+        let prop_types = Parser::new(settings(), crate::parse_demo::ParsingMode::Normal).prop_types(&mmap).unwrap();
+        let output = Parser::new(settings(), crate::parse_demo::ParsingMode::Normal).parse_demo(&mmap).unwrap();
+
+        let mut n_checked = 0;
+        for info in &output.prop_controller.prop_infos {
+            let (_, prop_type) = prop_types.iter().find(|(name, _)| *name == info.prop_friendly_name).unwrap();
+            if let Some(PropColumn { data: Some(data), .. }) = output.df.get(&info.id) {
+                let prop_type = prop_type.as_ref().unwrap();
+                assert_eq!(std::mem::discriminant(data), std::mem::discriminant(&VarVec::new(prop_type)), "{}", info.prop_friendly_name);
+                n_checked += 1;
+            }
+        }
+        assert!(n_checked >= wanted_props.len() / 2);
+    }
+
+    #[test]
+    fn test_time_props() {
+        let huf = create_huffman_lookup_table();
+        let settings = ParserInputs {
+            wanted_other_props: vec!["game_time".to_string(), "round_time".to_string(), "clock_time".to_string()],
+            wanted_ticks: vec![10000, 10001],
+            ..default_inputs(&huf)
+        };
+        let file = File::open("test_demo.dem").unwrap();
+        let mmap = unsafe { MmapOptions::new().map(&file).unwrap() };
+        let output = Parser::new(settings, crate::parse_demo::ParsingMode::Normal).parse_demo(&mmap).unwrap();
+        let column = |id: u32| match &output.df.get(&id).unwrap().data {
+            Some(VarVec::F32(v)) => v.iter().map(|x| x.unwrap()).collect::<Vec<f32>>(),
+            other => panic!("{:?}", other),
+        };
+        let (game_time, round_time, clock_time) = (column(GAME_TIME_ID), column(ROUND_TIME_ID), column(CLOCK_TIME_ID));
+        assert!(!game_time.is_empty());
+        // Rows are per player so the same tick repeats, the round started at the same game time for every row
+        let round_start_time = game_time[0] - round_time[0];
+        for i in 0..game_time.len() {
+            assert!((game_time[i] - round_time[i] - round_start_time).abs() < 0.01);
+            assert!(clock_time[i] >= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_describe_schema() {
+        let huf = create_huffman_lookup_table();
+
+        let settings = ParserInputs {
+            parse_ents: false,
+            ..default_inputs(&huf)
+        };
+        let file = File::open("test_demo.dem").unwrap();
+        let mmap = unsafe { MmapOptions::new().map(&file).unwrap() };
+        let schema = Parser::new(settings, crate::parse_demo::ParsingMode::Normal).describe_schema(&mmap).unwrap();
+
+        let health = schema.iter().find(|p| p.path == "CCSPlayerPawn.m_iHealth").unwrap();
+        assert_eq!(health.class, "CCSPlayerPawn");
+        assert_eq!(health.friendly_name.as_deref(), Some("health"));
+        assert_eq!(health.kind, Some("player"));
+        assert!(health.var_type.is_some());
+        let clip = schema.iter().find(|p| p.path == "CAK47.m_iClip1").unwrap();
+        assert_eq!(clip.prop_name, "m_iClip1");
+        assert_eq!(clip.kind, Some("weapon"));
+        let team_name = schema.iter().find(|p| p.path == "CCSTeam.m_szTeamname").unwrap();
+        assert_eq!(team_name.variant_type, "string");
+    }
+
     #[test]
     fn CEconItemAttribute_m_nRefundableCurrency() {
         let prop = (
@@ -0,0 +1,110 @@
+/*
+Friendly names generated from the sendtables for props that are not in FRIENDLY_NAMES_MAPPING.
+CCSPlayerPawn.CCSPlayer_MovementServices.m_flDuckAmount => duck_amount
+CCSPlayerController.CCSPlayerController_ActionTrackingServices.CSPerRoundStats_t.m_iKills => per_round_stats_kills
+
+The class and service serializers are dropped, m_ and Hungarian prefixes are stripped and the rest is
+turned into snake_case. Wanted props can be any suffix of the generated name ("kills" above) as long
+as only one prop matches. The hand-written map always wins.
+*/
+
+// Prefixes stripped only when followed by an uppercase letter: m_flDuckAmount => DuckAmount but m_cellX stays cellX
+const HUNGARIAN_PREFIXES: [&str; 21] = [
+    "psz", "isz", "vec", "ang", "arr", "clr", "fl", "sz", "ui", "un", "us", "bv", "ch", "qa", "b", "i", "n", "h", "e", "f", "u",
+];
+const CLASS_PREFIXES: [&str; 3] = ["CCS", "CS", "C"];
+
+pub fn auto_friendly_name(prop_name: &str, grenade_or_weapon: bool) -> String {
+    let segments: Vec<&str> = prop_name.split('.').collect();
+    // Weapon and grenade prop names already have their class stripped
+    let segments = match grenade_or_weapon {
+        true => &segments[..],
+        false => &segments[1.min(segments.len())..],
+    };
+    segments
+        .iter()
+        .filter(|segment| !segment.ends_with("Services"))
+        .map(|segment| to_snake_case(strip_segment(segment)))
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<String>>()
+        .join("_")
+}
+
+// Does the wanted name refer to this generated name, either fully or as a suffix that ends at a word boundary
+pub fn matches_auto_name(auto_name: &str, wanted: &str) -> bool {
+    match auto_name.strip_suffix(wanted) {
+        Some("") => true,
+        Some(rest) => rest.ends_with('_'),
+        None => false,
+    }
+}
+
+// Generated names only contain these, anything else is a real prop name or a custom prop
+pub fn could_be_auto_name(wanted: &str) -> bool {
+    !wanted.is_empty() && wanted.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn strip_segment(segment: &str) -> &str {
+    if let Some(field) = segment.strip_prefix("m_") {
+        return strip_prefix_before_uppercase(field, &HUNGARIAN_PREFIXES);
+    }
+    // Serializer names: CSPerRoundStats_t => PerRoundStats
+    let segment = segment.strip_suffix("_t").unwrap_or(segment);
+    strip_prefix_before_uppercase(segment, &CLASS_PREFIXES)
+}
+
+fn strip_prefix_before_uppercase<'a>(name: &'a str, prefixes: &[&str]) -> &'a str {
+    for prefix in prefixes {
+        if let Some(rest) = name.strip_prefix(prefix) {
+            if rest.starts_with(|c: char| c.is_ascii_uppercase()) {
+                return rest;
+            }
+        }
+    }
+    name
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    let mut prev: Option<char> = None;
+    for c in name.chars() {
+        if c.is_ascii_uppercase() && prev.is_some_and(|p| p.is_ascii_lowercase() || p.is_ascii_digit()) {
+            out.push('_');
+        }
+        out.push(c.to_ascii_lowercase());
+        prev = Some(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_friendly_name() {
+        assert_eq!(auto_friendly_name("CCSPlayerPawn.CCSPlayer_MovementServices.m_flDuckAmount", false), "duck_amount");
+        assert_eq!(auto_friendly_name("CCSPlayerPawn.CCSPlayer_WeaponServices.m_hActiveWeapon", false), "active_weapon");
+        assert_eq!(
+            auto_friendly_name("CCSPlayerController.CCSPlayerController_ActionTrackingServices.CSPerRoundStats_t.m_iKills", false),
+            "per_round_stats_kills"
+        );
+        assert_eq!(auto_friendly_name("CCSGameRulesProxy.CCSGameRules.m_bFreezePeriod", false), "game_rules_freeze_period");
+        assert_eq!(auto_friendly_name("CCSPlayerController.m_iMVPs", false), "mvps");
+        assert_eq!(auto_friendly_name("m_iClip1", true), "clip1");
+        assert_eq!(auto_friendly_name("CBodyComponentBaseAnimGraph.m_cellX", true), "body_component_base_anim_graph_cell_x");
+        assert_eq!(auto_friendly_name("CCSGameRulesProxy.CCSGameRules.CCSGameModeRules_Deathmatch.m_flDMBonusStartTime", false), "game_rules_game_mode_rules_deathmatch_dmbonus_start_time");
+    }
+
+    #[test]
+    fn test_matches_auto_name() {
+        assert!(matches_auto_name("per_round_stats_kills", "kills"));
+        assert!(matches_auto_name("per_round_stats_kills", "round_stats_kills"));
+        assert!(matches_auto_name("per_round_stats_kills", "per_round_stats_kills"));
+        assert!(!matches_auto_name("per_round_stats_kills", "tats_kills"));
+        assert!(!matches_auto_name("duck_amount", "amount_x"));
+        assert!(could_be_auto_name("duck_amount"));
+        assert!(!could_be_auto_name("CCSPlayerPawn.m_iHealth"));
+        assert!(!could_be_auto_name("X"));
+    }
+}
@@ -1,8 +1,11 @@
 pub mod fallbackbytes;
 pub mod frameparser;
+pub mod friendly_names;
 pub mod parser;
 pub mod parser_settings;
 pub mod prop_controller;
 pub mod read_bits;
+pub mod schema;
 pub mod sendtables;
 pub mod stringtables;
+pub mod validate;
@@ -4,6 +4,7 @@ use crate::first_pass::prop_controller::PropController;
 use crate::first_pass::read_bits::read_varint;
 use crate::first_pass::read_bits::Bitreader;
 use crate::first_pass::read_bits::DemoParserError;
+use crate::first_pass::read_bits::ParseWarning;
 use crate::first_pass::sendtables::Serializer;
 use crate::first_pass::stringtables::parse_userinfo;
 use crate::first_pass::stringtables::StringTable;
@@ -12,6 +13,7 @@ use crate::maps::demo_cmd_type_from_int;
 
 use crate::second_pass::decoder::QfMapper;
 use crate::second_pass::other_netmessages::Class;
+use crate::second_pass::player_role::track_hltv_userid;
 use ahash::AHashMap;
 use ahash::AHashSet;
 use csgoproto::csvc_msg_game_event_list::DescriptorT;
@@ -22,6 +24,7 @@ use csgoproto::CDemoFullPacket;
 use csgoproto::CDemoPacket;
 use csgoproto::CDemoSendTables;
 use csgoproto::CsvcMsgGameEventList;
+use csgoproto::CsvcMsgServerInfo;
 use csgoproto::EDemoCommands;
 use prost::Message;
 use snap::raw::decompress_len;
@@ -62,11 +65,14 @@ pub struct FirstPassOutput<'a> {
     pub wanted_ticks: AHashSet<i32>,
     pub string_tables: Vec<StringTable>,
     pub stringtable_players: BTreeMap<i32, UserInfo>,
+    pub hltv_userids: AHashSet<i32>,
     pub added_temp_props: Vec<String>,
     pub wanted_players: AHashSet<u64>,
     pub header: AHashMap<String, String>,
     pub order_by_steamid: bool,
     pub list_props: bool,
+    pub tick_interval: f32,
+    pub warnings: Vec<ParseWarning>,
 }
 #[derive(Debug)]
 pub struct Frame {
@@ -76,6 +82,15 @@ pub struct Frame {
     pub is_compressed: bool,
     pub demo_cmd: EDemoCommands,
 }
+impl Frame {
+    pub fn add_error_context(&self, e: DemoParserError) -> DemoParserError {
+        e.with_context(|context| {
+            context.byte_offset = Some(self.frame_starts_at);
+            context.tick = Some(self.tick);
+            context.demo_cmd = Some(format!("{:?}", self.demo_cmd));
+        })
+    }
+}
 
 impl<'a> FirstPassParser<'a> {
     pub fn parse_header_only(&mut self, demo_bytes: &'a [u8]) -> Result<AHashMap<String, String>, DemoParserError> {
@@ -89,9 +104,17 @@ impl<'a> FirstPassParser<'a> {
         Ok(self.header.clone())
     }
     pub fn parse_demo(&mut self, demo_bytes: &'a [u8], exit_early: bool) -> Result<FirstPassOutput, DemoParserError> {
-        self.handle_short_header(demo_bytes.len(), &demo_bytes[..HEADER_ENDS_AT_BYTE])?;
+        self.parse_frames(demo_bytes, exit_early, &mut |_, _| {})?;
+        self.fallback_if_first_pass_missing_data()?;
+        self.create_first_pass_output()
+    }
+    /*
+    Loop that goes trough the entire file. on_frame gets the header of every frame and the byte offset
+    where its data starts, before the frame is parsed or skipped. validate_demo uses it to check ticks and sizes.
+    */
+    pub fn parse_frames(&mut self, demo_bytes: &'a [u8], exit_early: bool, on_frame: &mut dyn FnMut(&Frame, usize)) -> Result<(), DemoParserError> {
+        self.handle_short_header(demo_bytes.len(), &demo_bytes[..HEADER_ENDS_AT_BYTE.min(demo_bytes.len())])?;
         let mut reuseable_buffer = vec![0_u8; 100_000];
-        // Loop that goes trough the entire file
         loop {
             // Need at least a few bytes to read frame header (3 varints, minimum 1 byte each)
             if self.ptr + 3 > demo_bytes.len() {
@@ -100,12 +123,28 @@ impl<'a> FirstPassParser<'a> {
             if exit_early && self.cls_by_id.is_some() && !self.ge_list.is_empty() {
                 break;
             }
+            self.settings.parse_control.check_cancelled()?;
+            let frame_starts_at = self.ptr;
             let frame = match self.read_frame(demo_bytes) {
                 Ok(f) => f,
-                Err(DemoParserError::OutOfBytesError) => break,
-                Err(e) => return Err(e),
+                Err(e) if e.root() == &DemoParserError::OutOfBytesError => break,
+                Err(e) => {
+                    let e = e.with_context(|context| context.byte_offset = Some(frame_starts_at));
+                    // Frame header is broken so we can't know where the next frame starts
+                    if self.lenient {
+                        self.warnings.push(ParseWarning {
+                            tick: self.tick,
+                            byte_offset: frame_starts_at,
+                            error: e.to_string(),
+                            resumed_at: None,
+                        });
+                        break;
+                    }
+                    return Err(e);
+                }
             };
-            if self.is_packet_we_skip_on_first_pass(frame.demo_cmd) {
+            on_frame(&frame, self.ptr);
+            if FirstPassParser::is_packet_we_skip_on_first_pass(frame.demo_cmd) {
                 self.ptr += frame.size;
                 continue;
             }
@@ -116,26 +155,40 @@ impl<'a> FirstPassParser<'a> {
                     continue;
                 }
             };
-            let bytes = self.decompress_if_needed(&mut reuseable_buffer, bytes, &frame)?;
-            self.ptr += frame.size;
-            match frame.demo_cmd {
-                EDemoCommands::DemSendTables => self.parse_sendtable_bytes(bytes)?,
-                EDemoCommands::DemFileHeader => self.parse_header(bytes)?,
-                EDemoCommands::DemClassInfo => self.parse_class_info(bytes)?,
-                EDemoCommands::DemSignonPacket => self.parse_packet(bytes)?,
-                EDemoCommands::DemFullPacket => self.parse_full_packet(bytes, &frame)?,
-                EDemoCommands::DemStop => break,
-                _ => {}
+            let ok = match self.decompress_if_needed(&mut reuseable_buffer, bytes, &frame) {
+                Ok(bytes) => match frame.demo_cmd {
+                    EDemoCommands::DemSendTables => self.parse_sendtable_bytes(bytes),
+                    EDemoCommands::DemFileHeader => self.parse_header(bytes),
+                    EDemoCommands::DemClassInfo => self.parse_class_info(bytes),
+                    EDemoCommands::DemSignonPacket => self.parse_packet(bytes),
+                    EDemoCommands::DemFullPacket => self.parse_full_packet(bytes, &frame),
+                    EDemoCommands::DemStop => break,
+                    _ => Ok(()),
+                },
+                Err(e) => Err(e),
             };
+            if let Err(e) = ok {
+                let e = frame.add_error_context(e);
+                if !self.lenient {
+                    return Err(e);
+                }
+                // Frame size is known so just move on to the next frame
+                self.warnings.push(ParseWarning {
+                    tick: frame.tick,
+                    byte_offset: frame.frame_starts_at,
+                    error: e.to_string(),
+                    resumed_at: Some(self.ptr + frame.size),
+                });
+            }
+            self.ptr += frame.size;
         }
-        self.fallback_if_first_pass_missing_data()?;
-        self.create_first_pass_output()
+        Ok(())
     }
 
     fn parse_sendtable_bytes(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
         self.sendtable_message = match CDemoSendTables::decode(bytes) {
             Ok(m) => Some(m),
-            Err(_e) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
         };
         Ok(())
     }
@@ -158,7 +211,7 @@ impl<'a> FirstPassParser<'a> {
             tick: self.tick,
         })
     }
-    fn is_packet_we_skip_on_first_pass(&self, demo_cmd: EDemoCommands) -> bool {
+    pub fn is_packet_we_skip_on_first_pass(demo_cmd: EDemoCommands) -> bool {
         demo_cmd == EDemoCommands::DemPacket || demo_cmd == EDemoCommands::DemAnimationData
     }
     fn slice_packet_bytes(&mut self, demo_bytes: &'a [u8], frame_size: usize) -> Result<&'a [u8], DemoParserError> {
@@ -173,7 +226,7 @@ impl<'a> FirstPassParser<'a> {
                 FirstPassParser::resize_if_needed(buf, decompress_len(possibly_uncompressed_bytes))?;
                 match SnapDecoder::new().decompress(possibly_uncompressed_bytes, buf) {
                     Ok(idx) => Ok(&buf[..idx]),
-                    Err(e) => return Err(DemoParserError::DecompressionFailure(format!("{}", e))),
+                    Err(e) => return Err(DemoParserError::DecompressionFailure(e)),
                 }
             }
             false => Ok(possibly_uncompressed_bytes),
@@ -186,7 +239,7 @@ impl<'a> FirstPassParser<'a> {
                     buf.resize(len, 0)
                 }
             }
-            Err(e) => return Err(DemoParserError::DecompressionFailure(e.to_string())),
+            Err(e) => return Err(DemoParserError::DecompressionFailure(e)),
         };
         Ok(())
     }
@@ -197,7 +250,7 @@ impl<'a> FirstPassParser<'a> {
         };
         let event_list = match CsvcMsgGameEventList::decode(bytes) {
             Ok(list) => list,
-            Err(_) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
         };
         for event_desc in event_list.descriptors {
             self.ge_list.insert(event_desc.eventid(), event_desc);
@@ -224,8 +277,11 @@ impl<'a> FirstPassParser<'a> {
             wanted_ticks: self.wanted_ticks.clone(),
             string_tables: self.string_tables.clone(),
             stringtable_players: self.stringtable_players.clone(),
+            hltv_userids: self.hltv_userids.clone(),
             added_temp_props: self.added_temp_props.clone(),
             list_props: self.list_props,
+            tick_interval: self.tick_interval,
+            warnings: self.warnings.clone(),
         })
     }
     fn fallback_if_first_pass_missing_data(&mut self) -> Result<(), DemoParserError> {
@@ -241,7 +297,7 @@ impl<'a> FirstPassParser<'a> {
     pub fn parse_game_event_list(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
         let event_list = match CsvcMsgGameEventList::decode(bytes) {
             Ok(list) => list,
-            Err(_) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
         };
         let mut hm: AHashMap<i32, DescriptorT> = AHashMap::default();
         for event_desc in event_list.descriptors {
@@ -255,7 +311,7 @@ impl<'a> FirstPassParser<'a> {
 
         let full_packet = match CDemoFullPacket::decode(bytes) {
             Ok(list) => list,
-            Err(_) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
         };
         if let Some(string_table) = full_packet.string_table {
             for item in &string_table.tables {
@@ -268,6 +324,7 @@ impl<'a> FirstPassParser<'a> {
                 if item.table_name() == "userinfo" {
                     for i in &item.items {
                         if let Ok(player) = parse_userinfo(&i.data()) {
+                            track_hltv_userid(&mut self.hltv_userids, &player);
                             if player.steamid != 0 {
                                 self.stringtable_players.insert(player.userid, player);
                             }
@@ -283,7 +340,7 @@ impl<'a> FirstPassParser<'a> {
 impl<'a> FirstPassParser<'a> {
     pub fn parse_packet(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
         let msg = match CDemoPacket::decode(bytes) {
-            Err(_) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
             Ok(msg) => msg,
         };
         let mut bitreader = Bitreader::new(msg.data());
@@ -298,9 +355,10 @@ impl<'a> FirstPassParser<'a> {
                 svc_CreateStringTable => self.parse_create_stringtable(&msg_bytes),
                 svc_UpdateStringTable => self.update_string_table(&msg_bytes),
                 svc_ClearAllStringTables => self.clear_stringtables(),
+                svc_ServerInfo => self.parse_server_info(&msg_bytes),
                 _ => Ok(()),
             };
-            ok?
+            ok.map_err(|e| e.with_context(|context| context.net_message = Some(format!("{:?}", msg_type))))?
         }
         Ok(())
     }
@@ -308,10 +366,21 @@ impl<'a> FirstPassParser<'a> {
         self.string_tables = vec![];
         Ok(())
     }
+    fn parse_server_info(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
+        let server_info = match CsvcMsgServerInfo::decode(bytes) {
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
+            Ok(msg) => msg,
+        };
+        // Community servers can run at 128 tick so don't assume 64
+        if server_info.tick_interval() > 0.0 {
+            self.tick_interval = server_info.tick_interval();
+        }
+        Ok(())
+    }
     pub fn parse_header(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
         let header = match CDemoFileHeader::decode(bytes) {
             Ok(list) => list,
-            Err(_) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
         };
         self.header.insert("demo_file_stamp".to_string(), header.demo_file_stamp.to_string());
         self.header.insert("demo_version_guid".to_string(), header.demo_version_guid().to_string());
@@ -378,7 +447,7 @@ impl<'a> FirstPassParser<'a> {
     pub fn parse_class_info(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
         let (mut serializers, qf_mapper, p) = self.parse_sendtable()?;
         let msg = match CDemoClassInfo::decode(bytes) {
-            Err(_) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
             Ok(msg) => msg,
         };
         let mut cls_by_id = vec![
@@ -3,13 +3,18 @@ use super::stringtables::StringTable;
 use crate::first_pass::prop_controller::PropController;
 use crate::first_pass::prop_controller::PropInfo;
 use crate::first_pass::read_bits::DemoParserError;
+use crate::first_pass::read_bits::ParseWarning;
 use crate::first_pass::stringtables::UserInfo;
 use crate::maps::FRIENDLY_NAMES_MAPPING;
+use crate::parse_control::ParseControl;
 use crate::maps::NON_MULTITHREADABLE_PROPS;
 use crate::second_pass::decoder::QfMapper;
 use crate::second_pass::other_netmessages::Class;
 use crate::second_pass::parser_settings::PlayerEndMetaData;
 use crate::second_pass::parser_settings::SpecialIDs;
+use crate::second_pass::event_windows::EventWindow;
+use crate::second_pass::derived_column::DerivedColumn;
+use crate::second_pass::row_filter::RowFilter;
 use crate::second_pass::variants::Variant;
 use ahash::AHashMap;
 use ahash::AHashSet;
@@ -22,6 +27,9 @@ use std::collections::BTreeMap;
 use std::fs::File;
 use std::sync::Arc;
 
+// Used until svc_ServerInfo tells us the real tick interval (64 tick)
+pub const DEFAULT_TICK_INTERVAL: f32 = 1.0 / 64.0;
+
 #[derive(Debug, Clone)]
 pub struct ParserInputs<'a> {
     pub real_name_to_og_name: AHashMap<String, String>,
@@ -31,6 +39,7 @@ pub struct ParserInputs<'a> {
     pub wanted_prop_states: AHashMap<String, Variant>,
     pub wanted_ticks: Vec<i32>,
     pub wanted_events: Vec<String>,
+    pub wanted_user_messages: Vec<String>,
     pub parse_ents: bool,
     pub parse_projectiles: bool,
     pub parse_grenades: bool,
@@ -39,9 +48,56 @@ pub struct ParserInputs<'a> {
     pub huffman_lookup_table: &'a Vec<(u8, u8)>,
     pub order_by_steamid: bool,
     pub list_props: bool,
+    // Skip over broken frames/messages instead of failing. See ParseWarning.
+    pub lenient: bool,
+    // Progress reporting and cancellation. ParseControl::default() if not needed.
+    pub parse_control: ParseControl,
+    // Only collect (tick, player) rows where every filter matches. See RowFilter.
+    pub row_filters: Vec<RowFilter>,
+    // Sample wanted_player_props around every event with these names. See EventWindow.
+    pub event_windows: Vec<EventWindow>,
+    // Only output a row when a prop changes instead of every prop on every tick. See PropChanges.
+    pub only_prop_changes: bool,
+    // String tables to record with every update, ["all"] for every table. See StringTableOutput.
+    pub wanted_string_tables: Vec<String>,
+    // Keep coaches, spectators and GOTV in df, roster and player_md. See PlayerRole.
+    pub include_non_players: bool,
+    // Columns computed from other props while collecting. See DerivedColumn.
+    pub derived_columns: Vec<DerivedColumn>,
     pub fallback_bytes: Option<Vec<u8>>,
 }
 
+// Inputs with nothing wanted, set the fields that are needed with ..default_inputs(huffman_lookup_table)
+pub fn default_inputs(huffman_lookup_table: &Vec<(u8, u8)>) -> ParserInputs<'_> {
+    ParserInputs {
+        real_name_to_og_name: AHashMap::default(),
+        wanted_players: vec![],
+        wanted_player_props: vec![],
+        wanted_other_props: vec![],
+        wanted_prop_states: AHashMap::default(),
+        wanted_ticks: vec![],
+        wanted_events: vec![],
+        wanted_user_messages: vec![],
+        parse_ents: true,
+        parse_projectiles: false,
+        parse_grenades: false,
+        only_header: false,
+        only_convars: false,
+        huffman_lookup_table,
+        order_by_steamid: false,
+        list_props: false,
+        lenient: false,
+        parse_control: ParseControl::default(),
+        row_filters: vec![],
+        event_windows: vec![],
+        only_prop_changes: false,
+        wanted_string_tables: vec![],
+        include_non_players: false,
+        derived_columns: vec![],
+        fallback_bytes: None,
+    }
+}
+
 pub struct FirstPassParser<'a> {
     pub added_temp_props: Vec<String>,
     pub real_name_to_og_name: AHashMap<String, String>,
@@ -60,6 +116,7 @@ pub struct FirstPassParser<'a> {
     pub ge_list: AHashMap<i32, DescriptorT>,
     pub qf_mapper: QfMapper,
     pub stringtable_players: BTreeMap<i32, UserInfo>,
+    pub hltv_userids: AHashSet<i32>,
     pub qf_map_set: bool,
     pub ge_list_set: bool,
     pub cls_by_id_set: bool,
@@ -85,6 +142,9 @@ pub struct FirstPassParser<'a> {
     pub order_by_steamid: bool,
     pub list_props: bool,
     pub fallback_bytes: Option<&'a [u8]>,
+    pub tick_interval: f32,
+    pub lenient: bool,
+    pub warnings: Vec<ParseWarning>,
 }
 pub fn needs_velocity(props: &[String]) -> bool {
     for prop in props {
@@ -105,6 +165,7 @@ impl<'a> FirstPassParser<'a> {
             added_temp_props: vec![],
             is_multithreadable: check_multithreadability(&inputs.wanted_player_props),
             stringtable_players: BTreeMap::default(),
+            hltv_userids: AHashSet::default(),
             only_header: inputs.only_header,
             ge_list_set: false,
             cls_by_id_set: false,
@@ -151,6 +212,9 @@ impl<'a> FirstPassParser<'a> {
             prop_infos: vec![],
             header: AHashMap::default(),
             list_props: inputs.list_props,
+            tick_interval: DEFAULT_TICK_INTERVAL,
+            lenient: inputs.lenient,
+            warnings: vec![],
         }
     }
 }
@@ -1,3 +1,7 @@
+use crate::first_pass::friendly_names::auto_friendly_name;
+use crate::first_pass::friendly_names::could_be_auto_name;
+use crate::first_pass::friendly_names::matches_auto_name;
+use crate::first_pass::read_bits::DemoParserError;
 use crate::first_pass::sendtables::Field;
 use crate::first_pass::sendtables::Serializer;
 use crate::first_pass::sendtables::ValueField;
@@ -5,6 +9,8 @@ use crate::maps::BUTTONMAP;
 use crate::maps::CUSTOM_PLAYER_PROP_IDS;
 use crate::maps::TYPEHM;
 use crate::second_pass::collect_data::PropType;
+use crate::second_pass::decoder::Decoder;
+use crate::second_pass::derived_column::DerivedColumn;
 use crate::second_pass::parser_settings::SpecialIDs;
 use crate::second_pass::variants::Variant;
 use ahash::AHashMap;
@@ -38,6 +44,10 @@ pub const PLAYER_X_ID: u32 = 100000016;
 pub const PLAYER_Y_ID: u32 = 100000017;
 pub const PLAYER_Z_ID: u32 = 100000018;
 pub const WEAPON_STICKERS_ID: u32 = 100000019;
+pub const ROUND_TIME_ID: u32 = 100000020;
+pub const CLOCK_TIME_ID: u32 = 100000021;
+// Props computed from the tick interval and game rules
+pub const TIME_PROPS: [(&str, u32); 3] = [("game_time", GAME_TIME_ID), ("round_time", ROUND_TIME_ID), ("clock_time", CLOCK_TIME_ID)];
 
 pub const WEAPON_SKIN_ID: u32 = 10000000;
 pub const WEAPON_PAINT_SEED: u32 = 10000001;
@@ -93,6 +103,11 @@ pub const GRENADE_X: u32 = 100100023;
 pub const GRENADE_Y: u32 = 100100024;
 pub const GRENADE_Z: u32 = 100100025;
 pub const INVENTORY_AS_IDS_BITMASK: u32 = 100100026;
+pub const PLAYER_KEY_ID: u32 = 100100027;
+pub const CONTROLLED_BY_STEAMID_ID: u32 = 100100028;
+pub const ROLE_ID: u32 = 100100029;
+// + index in ParserInputs::derived_columns
+pub const DERIVED_COLUMN_BASEID: u32 = 100200000;
 
 #[derive(Clone, Debug)]
 pub struct PropController {
@@ -112,6 +127,10 @@ pub struct PropController {
     pub wanted_prop_states: AHashMap<String, Variant>,
     pub wanted_prop_state_infos: Vec<WantedPropStateInfo>,
     pub parse_projectiles: bool,
+    pub id_to_decoder: AHashMap<u32, Decoder>,
+    // Wanted props that are not real prop names, resolved against the generated friendly names
+    pub auto_name_candidates: Vec<String>,
+    pub auto_name_matches: Vec<AutoNameMatch>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -123,6 +142,15 @@ pub struct PropInfo {
     pub is_player_prop: bool,
 }
 
+#[derive(Debug, Clone)]
+pub struct AutoNameMatch {
+    pub wanted: String,
+    pub exact: bool,
+    pub prop_name: String,
+    pub id: u32,
+    pub prop_type: PropType,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct WantedPropStateInfo {
     pub base: PropInfo,
@@ -145,6 +173,15 @@ impl PropController {
         wanted_events: &[String],
         parse_projectiles: bool,
     ) -> Self {
+        let mut auto_name_candidates: Vec<String> = wanted_player_props
+            .iter()
+            .chain(wanted_other_props.iter())
+            .chain(wanted_prop_states.keys())
+            .filter(|name| could_be_auto_name(name) && !is_known_prop_name(name))
+            .cloned()
+            .collect();
+        auto_name_candidates.sort();
+        auto_name_candidates.dedup();
         PropController {
             id: NORMAL_PROP_BASEID,
             wanted_player_props,
@@ -162,6 +199,26 @@ impl PropController {
             wanted_prop_states,
             wanted_prop_state_infos: vec![],
             parse_projectiles: parse_projectiles,
+            id_to_decoder: AHashMap::default(),
+            auto_name_candidates,
+            auto_name_matches: vec![],
+        }
+    }
+
+    // Type of the values collected for a prop, as an empty Variant like VarVec::new() takes.
+    // Known after the first pass so the output schema can be given before parsing any ticks.
+    pub fn prop_type(&self, prop_info: &PropInfo) -> Option<Variant> {
+        if let Some(t) = custom_prop_type(&prop_info.prop_name) {
+            return Some(t);
+        }
+        match prop_info.prop_type {
+            PropType::Tick => Some(Variant::I32(0)),
+            PropType::Steamid => Some(Variant::U64(0)),
+            PropType::Name => Some(Variant::String(String::new())),
+            PropType::Button => Some(Variant::Bool(false)),
+            PropType::GameTime => Some(Variant::F32(0.0)),
+            PropType::Derived => Some(Variant::F32(0.0)),
+            _ => self.id_to_decoder.get(&prop_info.id).map(|decoder| decoder.output_type()),
         }
     }
 
@@ -227,14 +284,16 @@ impl PropController {
             }
         }
 
-        if self.wanted_player_props.contains(&("game_time".to_string())) {
-            self.prop_infos.push(PropInfo {
-                id: GAME_TIME_ID,
-                prop_type: PropType::GameTime,
-                prop_name: "game_time".to_string(),
-                prop_friendly_name: "game_time".to_string(),
-                is_player_prop: true,
-            });
+        for (time_prop_name, time_prop_id) in TIME_PROPS {
+            if self.wanted_player_props.contains(&(time_prop_name.to_string())) {
+                self.prop_infos.push(PropInfo {
+                    id: time_prop_id,
+                    prop_type: PropType::GameTime,
+                    prop_name: time_prop_name.to_string(),
+                    prop_friendly_name: time_prop_name.to_string(),
+                    is_player_prop: true,
+                });
+            }
         }
         if self.wanted_player_props.contains(&("glove_paint_id".to_string())) {
             self.prop_infos.push(PropInfo {
@@ -264,28 +323,29 @@ impl PropController {
             });
         }
 
-        if let Some(wanted_state) = self.wanted_prop_states.get(&("game_time".to_string())) {
-            self.wanted_prop_state_infos.push(WantedPropStateInfo {
-                base: PropInfo {
-                    id: GAME_TIME_ID,
+        for (time_prop_name, time_prop_id) in TIME_PROPS {
+            if let Some(wanted_state) = self.wanted_prop_states.get(&(time_prop_name.to_string())) {
+                self.wanted_prop_state_infos.push(WantedPropStateInfo {
+                    base: PropInfo {
+                        id: time_prop_id,
+                        prop_type: PropType::GameTime,
+                        prop_name: time_prop_name.to_string(),
+                        prop_friendly_name: time_prop_name.to_string(),
+                        is_player_prop: true,
+                    },
+                    wanted_prop_state: wanted_state.clone(),
+                });
+            }
+            // Can also be non-player prop
+            if self.wanted_other_props.contains(&(time_prop_name.to_string())) {
+                self.prop_infos.push(PropInfo {
+                    id: time_prop_id,
                     prop_type: PropType::GameTime,
-                    prop_name: "game_time".to_string(),
-                    prop_friendly_name: "game_time".to_string(),
-                    is_player_prop: true,
-                },
-                wanted_prop_state: wanted_state.clone(),
-            });
-        }
-
-        // Can also be non-player prop
-        if self.wanted_other_props.contains(&("game_time".to_string())) {
-            self.prop_infos.push(PropInfo {
-                id: GAME_TIME_ID,
-                prop_type: PropType::GameTime,
-                prop_name: "game_time".to_string(),
-                prop_friendly_name: "game_time".to_string(),
-                is_player_prop: false,
-            });
+                    prop_name: time_prop_name.to_string(),
+                    prop_friendly_name: time_prop_name.to_string(),
+                    is_player_prop: false,
+                });
+            }
         }
         // Parse grenades specific
         if self.parse_projectiles {
@@ -347,6 +407,18 @@ impl PropController {
             is_player_prop: true,
         });
     }
+    // Derived columns come last, after the props they are computed from
+    pub fn set_derived_propinfos(&mut self, derived_columns: &[DerivedColumn]) {
+        for (idx, column) in derived_columns.iter().enumerate() {
+            self.prop_infos.push(PropInfo {
+                id: DERIVED_COLUMN_BASEID + idx as u32,
+                prop_type: PropType::Derived,
+                prop_name: column.name.clone(),
+                prop_friendly_name: column.name.clone(),
+                is_player_prop: true,
+            });
+        }
+    }
     pub fn find_prop_name_paths(&mut self, ser: &mut Serializer) {
         self.traverse_fields(&mut ser.fields, ser.name.clone(), vec![])
     }
@@ -380,67 +452,98 @@ impl PropController {
             true => split_at_dot[1..].join("."),
             false => prop_name.to_string(),
         };
-        let mut prefix_type = match prop_name.split(".").next() {
-            Some("CCSGameRulesProxy") => Some(PropType::Rules),
-            Some("CCSTeam") => Some(PropType::Team),
-            Some("CCSPlayerPawn") => Some(PropType::Player),
-            Some("CCSPlayerController") => Some(PropType::Controller),
-            _ => None,
-        };
-
-        if grenade_or_weapon {
-            prefix_type = Some(PropType::Weapon);
+        if let Some(prop_type) = prop_type_from_name(&prop_name, grenade_or_weapon) {
+            self.push_wanted_propinfos(&prop_name, f.prop_id, prop_type);
+            if !self.auto_name_candidates.is_empty() {
+                self.match_auto_name(&prop_name, grenade_or_weapon, f.prop_id, prop_type);
+            }
         }
-
-        // If any custom mapping found use that one
-        if let Some(mapping) = TYPEHM.get(&prop_name) {
-            prefix_type = Some(*mapping);
+    }
+    fn push_wanted_propinfos(&mut self, prop_name: &str, id: u32, prop_type: PropType) {
+        let prop_friendly_name = self.real_name_to_og_name.get(prop_name).map(|s| s.as_str()).unwrap_or(prop_name).to_string();
+        if self.wanted_player_props.iter().any(|p| p == prop_name) {
+            self.prop_infos.push(PropInfo {
+                id,
+                prop_type,
+                prop_name: prop_name.to_string(),
+                prop_friendly_name: prop_friendly_name.clone(),
+                is_player_prop: true,
+            });
         }
-
-        if let Some(prop_type) = prefix_type {
-            if self.wanted_player_props.contains(&prop_name.to_string()) {
-                self.prop_infos.push(PropInfo {
-                    id: f.prop_id as u32,
-                    prop_type: prop_type,
+        if self.wanted_other_props.iter().any(|p| p == prop_name) {
+            self.prop_infos.push(PropInfo {
+                id,
+                prop_type,
+                prop_name: prop_name.to_string(),
+                prop_friendly_name: prop_friendly_name.clone(),
+                is_player_prop: false,
+            })
+        }
+        if let Some(wanted_state) = self.wanted_prop_states.get(prop_name) {
+            self.wanted_prop_state_infos.push(WantedPropStateInfo {
+                base: PropInfo {
+                    id,
+                    prop_type,
                     prop_name: prop_name.to_string(),
-                    prop_friendly_name: self
-                        .real_name_to_og_name
-                        .get(&prop_name.to_string())
-                        .unwrap_or(&prop_name.to_string())
-                        .to_string(),
+                    prop_friendly_name,
                     is_player_prop: true,
+                },
+                wanted_prop_state: wanted_state.clone(),
+            });
+        }
+    }
+    fn match_auto_name(&mut self, prop_name: &str, grenade_or_weapon: bool, id: u32, prop_type: PropType) {
+        let auto_name = auto_friendly_name(prop_name, grenade_or_weapon);
+        for wanted in &self.auto_name_candidates {
+            if matches_auto_name(&auto_name, wanted) {
+                self.auto_name_matches.push(AutoNameMatch {
+                    wanted: wanted.clone(),
+                    exact: auto_name == *wanted,
+                    prop_name: prop_name.to_string(),
+                    id,
+                    prop_type,
                 });
             }
-            if self.wanted_other_props.contains(&prop_name.to_string()) {
-                self.prop_infos.push(PropInfo {
-                    id: f.prop_id as u32,
-                    prop_type: prop_type,
-                    prop_name: prop_name.to_string(),
-                    prop_friendly_name: self
-                        .real_name_to_og_name
-                        .get(&prop_name.to_string())
-                        .unwrap_or(&(prop_name.to_string()))
-                        .to_string(),
-                    is_player_prop: false,
-                })
+        }
+    }
+    /*
+    Called once all serializers are walked. Wanted props that matched a generated name are swapped
+    for the real prop name, output columns keep the name the user asked for. An exact match wins
+    over suffix matches, more than one match left is an error. Names that match nothing are left
+    alone like any other unknown prop.
+    */
+    pub fn resolve_auto_names(&mut self) -> Result<(), DemoParserError> {
+        for wanted in std::mem::take(&mut self.auto_name_candidates) {
+            let matches: Vec<&AutoNameMatch> = self.auto_name_matches.iter().filter(|m| m.wanted == wanted).collect();
+            let exact: Vec<&AutoNameMatch> = matches.iter().copied().filter(|m| m.exact).collect();
+            let mut matches = if exact.is_empty() { matches } else { exact };
+            matches.sort_by(|a, b| a.prop_name.cmp(&b.prop_name));
+            matches.dedup_by(|a, b| a.prop_name == b.prop_name);
+            let m = match matches[..] {
+                [] => continue,
+                [m] => m.clone(),
+                _ => return Err(DemoParserError::AmbiguousPropName(wanted, matches.iter().map(|m| m.prop_name.clone()).collect())),
+            };
+            let og_name = self.real_name_to_og_name.remove(&wanted).unwrap_or(wanted.clone());
+            let already_wanted = self.wanted_player_props.contains(&m.prop_name)
+                || self.wanted_other_props.contains(&m.prop_name)
+                || self.wanted_prop_states.contains_key(&m.prop_name);
+            for props in [&mut self.wanted_player_props, &mut self.wanted_other_props] {
+                match already_wanted {
+                    true => props.retain(|p| *p != wanted),
+                    false => props.iter_mut().filter(|p| **p == wanted).for_each(|p| *p = m.prop_name.clone()),
+                }
             }
-            if let Some(wanted_state) = self.wanted_prop_states.get(&prop_name.to_string()) {
-                self.wanted_prop_state_infos.push(WantedPropStateInfo {
-                    base: PropInfo {
-                        id: f.prop_id as u32,
-                        prop_type: prop_type,
-                        prop_name: prop_name.to_string(),
-                        prop_friendly_name: self
-                            .real_name_to_og_name
-                            .get(&prop_name.to_string())
-                            .unwrap_or(&(prop_name.to_string()))
-                            .to_string(),
-                        is_player_prop: true,
-                    },
-                    wanted_prop_state: wanted_state.clone(),
-                });
+            if let Some(state) = self.wanted_prop_states.remove(&wanted) {
+                self.wanted_prop_states.entry(m.prop_name.clone()).or_insert(state);
+            }
+            if !already_wanted {
+                self.real_name_to_og_name.insert(m.prop_name.clone(), og_name);
+                self.push_wanted_propinfos(&m.prop_name, m.id, m.prop_type);
             }
         }
+        self.auto_name_matches = vec![];
+        Ok(())
     }
     pub fn handle_prop(&mut self, full_name: &str, f: &mut ValueField, path: Vec<i32>) {
         f.full_name = full_name.to_string();
@@ -456,6 +559,7 @@ impl PropController {
 
         let prop_already_exists = self.name_to_id.contains_key(&(prop_name).to_string());
         self.set_id(&prop_name, f, grenade_or_weapon);
+        self.id_to_decoder.entry(f.prop_id).or_insert(f.decoder);
 
         if !prop_already_exists {
             self.insert_propinfo(&full_name, f);
@@ -513,6 +617,9 @@ impl PropController {
         } else {
             match name {
                 "CCSGameRulesProxy.CCSGameRules.m_nRoundStartCount" => self.special_ids.round_start_count = Some(id),
+                "CCSGameRulesProxy.CCSGameRules.m_fRoundStartTime" => self.special_ids.round_start_time = Some(id),
+                "CCSGameRulesProxy.CCSGameRules.m_iRoundTime" => self.special_ids.round_time = Some(id),
+                "CCSGameRulesProxy.CCSGameRules.m_bFreezePeriod" => self.special_ids.freeze_period = Some(id),
                 "CCSGameRulesProxy.CCSGameRules.m_nRoundEndCount" => self.special_ids.round_end_count = Some(id),
                 "CCSGameRulesProxy.CCSGameRules.m_nMatchEndCount" => self.special_ids.match_end_count = Some(id),
                 "CCSGameRulesProxy.CCSGameRules.m_eRoundWinReason" => self.special_ids.round_win_reason = Some(id),
@@ -524,6 +631,9 @@ impl PropController {
                 "CCSPlayerController.m_iszPlayerName" => self.special_ids.player_name = Some(id),
                 "CCSPlayerController.m_steamID" => self.special_ids.steamid = Some(id),
                 "CCSPlayerController.m_hPlayerPawn" => self.special_ids.player_pawn = Some(id),
+                "CCSPlayerController.m_iCoachingTeam" => self.special_ids.coaching_team = Some(id),
+                "CCSPlayerController.m_bControllingBot" => self.special_ids.controlling_bot = Some(id),
+                "CCSPlayerController.m_hOriginalControllerOfCurrentPawn" => self.special_ids.original_controller_of_current_pawn = Some(id),
                 "CCSPlayerPawn.CBodyComponentBaseAnimGraph.m_cellX" => self.special_ids.cell_x_player = Some(id),
                 "CCSPlayerPawn.CCSPlayer_MovementServices.m_nButtonDownMaskPrev" => self.special_ids.buttons = Some(id),
                 "CCSPlayerPawn.CBodyComponentBaseAnimGraph.m_vecX" => self.special_ids.cell_x_offset_player = Some(id),
@@ -588,6 +698,57 @@ impl PropController {
     }
 }
 
+// Names that are handled without looking at the sendtables
+fn is_known_prop_name(name: &str) -> bool {
+    TYPEHM.contains_key(name)
+        || BUTTONMAP.contains_key(name)
+        || CUSTOM_PLAYER_PROP_IDS.contains_key(name)
+        || TIME_PROPS.iter().any(|(time_prop_name, _)| *time_prop_name == name)
+        || custom_prop_type(name).is_some()
+        || matches!(name, "tick" | "steamid" | "name")
+}
+
+// Props that are computed in collect_data instead of read straight from an entity
+fn custom_prop_type(prop_name: &str) -> Option<Variant> {
+    match prop_name {
+        "X" | "Y" | "Z" | "velocity" | "velocity_X" | "velocity_Y" | "velocity_Z" | "pitch" | "yaw" | "weapon_float" | "glove_paint_seed" | "glove_paint_float" => {
+            Some(Variant::F32(0.0))
+        }
+        "usercmd_viewangle_x" | "usercmd_viewangle_y" | "usercmd_viewangle_z" | "usercmd_forward_move" | "usercmd_left_move" => Some(Variant::F32(0.0)),
+        "entity_id" | "user_id" | "usercmd_impulse" | "usercmd_mouse_dx" | "usercmd_mouse_dy" => Some(Variant::I32(0)),
+        "weapon_skin_id" | "weapon_paint_seed" | "glove_paint_id" | "usercmd_consumed_server_angle_changes" => Some(Variant::U32(0)),
+        "inventory_as_bitmask" | "player_key" | "controlled_by_steamid" | "usercmd_buttonstate_1" | "usercmd_buttonstate_2" | "usercmd_buttonstate_3" => Some(Variant::U64(0)),
+        "is_alive" | "is_airborne" => Some(Variant::Bool(false)),
+        "weapon_name" | "weapon_skin" | "glove_skin" | "agent_skin" | "active_weapon_original_owner" | "grenade_type" | "role" | "CCSPlayerController.m_iCompTeammateColor" => {
+            Some(Variant::String(String::new()))
+        }
+        "inventory" => Some(Variant::StringVec(vec![])),
+        "inventory_as_ids" => Some(Variant::U32Vec(vec![])),
+        "CCSPlayerPawn.m_bSpottedByMask" => Some(Variant::U64Vec(vec![])),
+        "weapon_stickers" => Some(Variant::Stickers(vec![])),
+        "usercmd_input_history" => Some(Variant::InputHistory(vec![])),
+        _ => None,
+    }
+}
+
+// Which kind of entity the prop is collected from. None for props that can't be collected.
+pub fn prop_type_from_name(prop_name: &str, grenade_or_weapon: bool) -> Option<PropType> {
+    // If any custom mapping found use that one
+    if let Some(mapping) = TYPEHM.get(prop_name) {
+        return Some(*mapping);
+    }
+    if grenade_or_weapon {
+        return Some(PropType::Weapon);
+    }
+    match prop_name.split(".").next() {
+        Some("CCSGameRulesProxy") => Some(PropType::Rules),
+        Some("CCSTeam") => Some(PropType::Team),
+        Some("CCSPlayerPawn") => Some(PropType::Player),
+        Some("CCSPlayerController") => Some(PropType::Controller),
+        _ => None,
+    }
+}
+
 pub fn split_weapon_prefix_from_prop_name(full_name: &str) -> String {
     let split_at_dot: Vec<&str> = full_name.split(".").collect();
     let grenade_or_weapon = is_grenade_or_weapon(full_name);
@@ -614,3 +775,75 @@ pub fn is_grenade_or_weapon(full_name: &str) -> bool {
         && !split_at_dot[0].contains("Player");
     is_weapon_prop || is_projectile_prop
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn controller_with_matches(wanted: &str, prop_names: &[&str]) -> PropController {
+        let mut prop_controller = PropController::new(vec![wanted.to_string()], vec![], AHashMap::default(), AHashMap::default(), false, &[], false);
+        for (idx, prop_name) in prop_names.iter().enumerate() {
+            prop_controller.match_auto_name(prop_name, false, NORMAL_PROP_BASEID + idx as u32, PropType::Player);
+        }
+        prop_controller
+    }
+
+    #[test]
+    fn test_resolve_auto_names() {
+        let mut prop_controller = controller_with_matches(
+            "max_speed",
+            &["CCSPlayerPawn.CCSPlayer_MovementServices.m_flMaxSpeed", "CCSPlayerPawn.CCSPlayer_MovementServices.m_flDuckSpeed"],
+        );
+        prop_controller.resolve_auto_names().unwrap();
+        assert_eq!(prop_controller.wanted_player_props, vec!["CCSPlayerPawn.CCSPlayer_MovementServices.m_flMaxSpeed"]);
+        assert_eq!(prop_controller.prop_infos.len(), 1);
+        assert_eq!(prop_controller.prop_infos[0].id, NORMAL_PROP_BASEID);
+        assert_eq!(prop_controller.prop_infos[0].prop_friendly_name, "max_speed");
+        assert!(prop_controller.auto_name_matches.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_auto_names_exact_match_wins() {
+        let mut prop_controller = controller_with_matches(
+            "kills",
+            &[
+                "CCSPlayerController.CCSPlayerController_ActionTrackingServices.CSPerRoundStats_t.m_iKills",
+                "CCSPlayerController.CCSPlayerController_ActionTrackingServices.m_iKills",
+            ],
+        );
+        prop_controller.resolve_auto_names().unwrap();
+        assert_eq!(prop_controller.wanted_player_props, vec!["CCSPlayerController.CCSPlayerController_ActionTrackingServices.m_iKills"]);
+    }
+
+    #[test]
+    fn test_resolve_auto_names_ambiguous() {
+        let mut prop_controller = controller_with_matches(
+            "kills",
+            &[
+                "CCSPlayerController.CCSPlayerController_ActionTrackingServices.CSPerRoundStats_t.m_iKills",
+                "CCSPlayerController.CCSPlayerController_ActionTrackingServices.CSMatchStats_t.m_iKills",
+            ],
+        );
+        match prop_controller.resolve_auto_names() {
+            Err(DemoParserError::AmbiguousPropName(wanted, prop_names)) => {
+                assert_eq!(wanted, "kills");
+                assert_eq!(
+                    prop_names,
+                    vec![
+                        "CCSPlayerController.CCSPlayerController_ActionTrackingServices.CSMatchStats_t.m_iKills",
+                        "CCSPlayerController.CCSPlayerController_ActionTrackingServices.CSPerRoundStats_t.m_iKills",
+                    ]
+                );
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_resolve_auto_names_no_match() {
+        let mut prop_controller = controller_with_matches("not_a_prop", &["CCSPlayerPawn.CCSPlayer_MovementServices.m_flMaxSpeed"]);
+        prop_controller.resolve_auto_names().unwrap();
+        assert_eq!(prop_controller.wanted_player_props, vec!["not_a_prop"]);
+        assert!(prop_controller.prop_infos.is_empty());
+    }
+}
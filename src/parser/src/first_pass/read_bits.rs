@@ -1,5 +1,6 @@
 use bitter::BitReader;
 use bitter::LittleEndianReader;
+use serde::Serialize;
 use std::fmt;
 
 pub struct Bitreader<'a> {
@@ -212,7 +213,7 @@ impl<'a> Bitreader<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, Clone)]
 pub enum DemoParserError {
     ClassMapperNotFoundFirstPass,
     FieldNoDecoder,
@@ -220,6 +221,7 @@ pub enum DemoParserError {
     OutOfBytesError,
     FailedByteRead(String),
     UnknownPathOP,
+    ProtobufDecodeError(prost::DecodeError),
     EntityNotFound,
     ClassNotFound,
     MalformedMessage,
@@ -229,6 +231,8 @@ pub enum DemoParserError {
     UnknownFile,
     IncorrectMetaDataProp,
     UnknownPropName(String),
+    // A prop asked for by a generated friendly name or suffix that matches more than one prop
+    AmbiguousPropName(String, Vec<String>),
     GameEventListNotSet,
     PropTypeNotFound(String),
     GameEventUnknownId(String),
@@ -238,7 +242,7 @@ pub enum DemoParserError {
     UnknownGameEventVariant(String),
     FileNotFound(String),
     NoEvents,
-    DecompressionFailure(String),
+    DecompressionFailure(snap::Error),
     NoSendTableMessage,
     UserIdNotFound,
     EventListFallbackNotFound(String),
@@ -249,12 +253,210 @@ pub enum DemoParserError {
     ImpossibleCmd,
     UnkVoiceFormat,
     MalformedVoicePacket,
+    InvalidRowFilter(String),
+    InvalidDerivedColumn(String),
+    // Parsing was stopped with ParseControl::cancel
+    Cancelled,
+    // Any of the above with information about where in the demo it happened
+    WithContext(Box<DemoParserError>, Box<ErrorContext>),
 }
 
-impl std::error::Error for DemoParserError {}
+// Where in the demo an error happened. Filled in by the layers the error passes through.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ErrorContext {
+    pub byte_offset: Option<usize>,
+    pub tick: Option<i32>,
+    pub demo_cmd: Option<String>,
+    pub net_message: Option<String>,
+    pub class_name: Option<String>,
+    pub field_path: Option<Vec<i32>>,
+}
+
+// An error that was skipped over when parsing in lenient mode
+#[derive(Debug, Clone, Serialize)]
+pub struct ParseWarning {
+    pub tick: i32,
+    pub byte_offset: usize,
+    pub error: String,
+    // Where parsing continued from. None if the rest of the demo was skipped.
+    pub resumed_at: Option<usize>,
+}
+
+impl DemoParserError {
+    /*
+    Attach context to the error. If the error already has context, only missing fields
+    are filled in so that the innermost (most specific) information is kept.
+    */
+    pub fn with_context<F: FnOnce(&mut ErrorContext)>(self, f: F) -> DemoParserError {
+        let mut new_context = ErrorContext::default();
+        f(&mut new_context);
+        match self {
+            DemoParserError::WithContext(inner, mut context) => {
+                context.byte_offset = context.byte_offset.or(new_context.byte_offset);
+                context.tick = context.tick.or(new_context.tick);
+                context.demo_cmd = context.demo_cmd.or(new_context.demo_cmd);
+                context.net_message = context.net_message.or(new_context.net_message);
+                context.class_name = context.class_name.or(new_context.class_name);
+                context.field_path = context.field_path.or(new_context.field_path);
+                DemoParserError::WithContext(inner, context)
+            }
+            e => DemoParserError::WithContext(Box::new(e), Box::new(new_context)),
+        }
+    }
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            DemoParserError::WithContext(_, context) => Some(context),
+            _ => None,
+        }
+    }
+    // The error without any context
+    pub fn root(&self) -> &DemoParserError {
+        match self {
+            DemoParserError::WithContext(inner, _) => inner.root(),
+            e => e,
+        }
+    }
+}
+
+// Written out because snap::Error has no PartialEq, decompression errors are compared by their message
+impl PartialEq for DemoParserError {
+    fn eq(&self, other: &Self) -> bool {
+        use DemoParserError::*;
+        match (self, other) {
+            (DecompressionFailure(a), DecompressionFailure(b)) => a.to_string() == b.to_string(),
+            (ProtobufDecodeError(a), ProtobufDecodeError(b)) => a == b,
+            (AmbiguousPropName(a, a_matches), AmbiguousPropName(b, b_matches)) => a == b && a_matches == b_matches,
+            (UnknownDemoCmd(a), UnknownDemoCmd(b)) => a == b,
+            (WithContext(a, a_context), WithContext(b, b_context)) => a == b && a_context == b_context,
+            (FailedByteRead(a), FailedByteRead(b))
+            | (DemoEndsEarly(a), DemoEndsEarly(b))
+            | (UnknownPropName(a), UnknownPropName(b))
+            | (PropTypeNotFound(a), PropTypeNotFound(b))
+            | (GameEventUnknownId(a), GameEventUnknownId(b))
+            | (UnknownPawnPrefix(a), UnknownPawnPrefix(b))
+            | (UnknownEntityHandle(a), UnknownEntityHandle(b))
+            | (UnknownGameEventVariant(a), UnknownGameEventVariant(b))
+            | (FileNotFound(a), FileNotFound(b))
+            | (EventListFallbackNotFound(a), EventListFallbackNotFound(b))
+            | (VoiceDataWriteError(a), VoiceDataWriteError(b))
+            | (InvalidRowFilter(a), InvalidRowFilter(b))
+            | (InvalidDerivedColumn(a), InvalidDerivedColumn(b)) => a == b,
+            (
+                ClassMapperNotFoundFirstPass
+                | FieldNoDecoder
+                | OutOfBitsError
+                | OutOfBytesError
+                | UnknownPathOP
+                | EntityNotFound
+                | ClassNotFound
+                | MalformedMessage
+                | StringTableNotFound
+                | Source1DemoError
+                | UnknownFile
+                | IncorrectMetaDataProp
+                | GameEventListNotSet
+                | ClsIdOutOfBounds
+                | NoEvents
+                | NoSendTableMessage
+                | UserIdNotFound
+                | IllegalPathOp
+                | VectorResizeFailure
+                | ImpossibleCmd
+                | UnkVoiceFormat
+                | MalformedVoicePacket
+                | Cancelled,
+                _,
+            ) => std::mem::discriminant(self) == std::mem::discriminant(other),
+            _ => false,
+        }
+    }
+}
+
+impl std::error::Error for DemoParserError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DemoParserError::WithContext(inner, _) => Some(inner.as_ref()),
+            DemoParserError::ProtobufDecodeError(e) => Some(e),
+            DemoParserError::DecompressionFailure(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 impl fmt::Display for DemoParserError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            DemoParserError::WithContext(inner, context) => write!(f, "{} ({})", inner, context),
+            DemoParserError::ProtobufDecodeError(e) => write!(f, "MalformedMessage: {}", e),
+            DemoParserError::DecompressionFailure(e) => write!(f, "DecompressionFailure: {}", e),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = vec![];
+        if let Some(tick) = self.tick {
+            parts.push(format!("tick: {}", tick));
+        }
+        if let Some(byte_offset) = self.byte_offset {
+            parts.push(format!("byte offset: {}", byte_offset));
+        }
+        if let Some(demo_cmd) = &self.demo_cmd {
+            parts.push(format!("demo command: {}", demo_cmd));
+        }
+        if let Some(net_message) = &self.net_message {
+            parts.push(format!("message: {}", net_message));
+        }
+        if let Some(class_name) = &self.class_name {
+            parts.push(format!("class: {}", class_name));
+        }
+        if let Some(field_path) = &self.field_path {
+            parts.push(format!("field path: {:?}", field_path));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_error_context_keeps_innermost() {
+        let e = DemoParserError::UnknownPathOP
+            .with_context(|context| {
+                context.class_name = Some("CCSPlayerPawn".to_string());
+                context.field_path = Some(vec![1, 2]);
+            })
+            .with_context(|context| context.net_message = Some("svc_PacketEntities".to_string()))
+            .with_context(|context| {
+                context.tick = Some(100);
+                context.class_name = Some("SomethingElse".to_string());
+            });
+        let context = e.context().unwrap();
+        assert_eq!(context.class_name, Some("CCSPlayerPawn".to_string()));
+        assert_eq!(context.tick, Some(100));
+        assert_eq!(context.net_message, Some("svc_PacketEntities".to_string()));
+        assert!(matches!(e.root(), DemoParserError::UnknownPathOP));
+        assert!(e.source().is_some());
+        assert_eq!(
+            e.to_string(),
+            "UnknownPathOP (tick: 100, message: svc_PacketEntities, class: CCSPlayerPawn, field path: [1, 2])"
+        );
+    }
+
+    #[test]
+    fn test_error_partial_eq() {
+        let decompression_error = || snap::raw::Decoder::new().decompress_vec(&[0xff; 4]).unwrap_err();
+        assert_eq!(DemoParserError::DecompressionFailure(decompression_error()), DemoParserError::DecompressionFailure(decompression_error()));
+        assert_eq!(DemoParserError::OutOfBytesError, DemoParserError::OutOfBytesError);
+        assert_ne!(DemoParserError::OutOfBytesError, DemoParserError::OutOfBitsError);
+        assert_ne!(DemoParserError::UnknownPropName("a".to_string()), DemoParserError::UnknownPropName("b".to_string()));
+        let with_context = DemoParserError::OutOfBytesError.with_context(|context| context.tick = Some(5));
+        assert_ne!(with_context, DemoParserError::OutOfBytesError);
+        assert_eq!(with_context.root(), &DemoParserError::OutOfBytesError);
     }
 }
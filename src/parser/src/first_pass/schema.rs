@@ -0,0 +1,270 @@
+use crate::first_pass::friendly_names::auto_friendly_name;
+use crate::first_pass::prop_controller::is_grenade_or_weapon;
+use crate::first_pass::prop_controller::prop_type_from_name;
+use crate::first_pass::prop_controller::split_weapon_prefix_from_prop_name;
+use crate::first_pass::sendtables::Field;
+use crate::first_pass::sendtables::ValueField;
+use crate::maps::FRIENDLY_NAMES_MAPPING;
+use crate::second_pass::collect_data::PropType;
+use crate::second_pass::other_netmessages::Class;
+use ahash::AHashMap;
+use ahash::AHashSet;
+use csgoproto::csvc_msg_game_event_list::DescriptorT;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+/*
+Everything the parser knows about a prop before reading a single tick. Sendtable info (var_type,
+encoder, bitcount, low/high value) comes straight from the demo, decoder and variant_type are what
+the parser turns it into and kind/friendly_name tell how to ask for it.
+
+Only props of the classes the parser assigns ids to are described, the others can't be collected.
+*/
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PropSchema {
+    pub path: String,
+    pub class: String,
+    // Name to pass to parse_ticks etc. Same as path except for weapons and grenades, which drop the class.
+    pub prop_name: String,
+    // From FRIENDLY_NAMES_MAPPING, generated from the path if the prop is not in it
+    pub friendly_name: Option<String>,
+    pub var_type: Option<String>,
+    pub encoder: Option<String>,
+    pub encode_flags: Option<i32>,
+    pub bitcount: Option<i32>,
+    pub low_value: Option<f32>,
+    pub high_value: Option<f32>,
+    pub decoder: String,
+    pub variant_type: &'static str,
+    // player/controller/team/rules/weapon, None if the prop can't be collected
+    pub kind: Option<&'static str>,
+}
+
+pub fn describe_schema(cls_by_id: &[Class]) -> Vec<PropSchema> {
+    let friendly_names: AHashMap<&str, &str> = FRIENDLY_NAMES_MAPPING.entries().map(|(friendly, real)| (*real, *friendly)).collect();
+    let mut seen = AHashSet::default();
+    let mut schema = vec![];
+    for cls in cls_by_id {
+        for field in &cls.serializer.fields {
+            collect_value_fields(field, &mut |f| {
+                if f.should_parse && seen.insert(f.full_name.clone()) {
+                    schema.push(prop_schema(f, &friendly_names));
+                }
+            });
+        }
+    }
+    schema.sort_by(|a, b| a.path.cmp(&b.path));
+    schema
+}
+
+fn collect_value_fields(field: &Field, f: &mut impl FnMut(&ValueField)) {
+    match field {
+        Field::Value(v) => f(v),
+        Field::Array(a) => collect_value_fields(&a.field_enum, f),
+        Field::Vector(v) => collect_value_fields(&v.field_enum, f),
+        Field::Serializer(s) => s.serializer.fields.iter().for_each(|inner| collect_value_fields(inner, f)),
+        Field::Pointer(p) => p.serializer.fields.iter().for_each(|inner| collect_value_fields(inner, f)),
+        Field::None => {}
+    }
+}
+
+fn prop_schema(f: &ValueField, friendly_names: &AHashMap<&str, &str>) -> PropSchema {
+    let grenade_or_weapon = is_grenade_or_weapon(&f.full_name);
+    let prop_name = split_weapon_prefix_from_prop_name(&f.full_name);
+    let decoder = format!("{:?}", f.decoder);
+    // Quantized floats carry an index into the demo's own table, not useful outside the parser
+    let decoder = decoder.split('(').next().unwrap_or_default().to_string();
+    PropSchema {
+        class: f.full_name.split('.').next().unwrap_or_default().to_string(),
+        friendly_name: match friendly_names.get(prop_name.as_str()) {
+            Some(name) => Some(name.to_string()),
+            None => Some(auto_friendly_name(&prop_name, grenade_or_weapon)).filter(|name| !name.is_empty()),
+        },
+        var_type: f.metadata.as_ref().map(|m| m.var_type.clone()),
+        encoder: f.metadata.as_ref().map(|m| m.encoder.clone()),
+        encode_flags: f.metadata.as_ref().map(|m| m.encode_flags),
+        bitcount: f.metadata.as_ref().map(|m| m.bitcount),
+        low_value: f.metadata.as_ref().map(|m| m.low_value),
+        high_value: f.metadata.as_ref().map(|m| m.high_value),
+        decoder,
+        variant_type: f.decoder.output_type().type_name(),
+        kind: prop_type_from_name(&prop_name, grenade_or_weapon).map(kind_name),
+        path: f.full_name.clone(),
+        prop_name,
+    }
+}
+
+fn kind_name(prop_type: PropType) -> &'static str {
+    match prop_type {
+        PropType::Player => "player",
+        PropType::Controller => "controller",
+        PropType::Team => "team",
+        PropType::Rules => "rules",
+        PropType::Weapon => "weapon",
+        // Only set by TYPEHM for props that are computed instead of read
+        _ => "custom",
+    }
+}
+
+// Everything diff_schemas compares. Game event keys are (name, type) in the order the demo sends them.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DemoSchema {
+    pub props: Vec<PropSchema>,
+    pub serializers: BTreeSet<String>,
+    pub game_events: BTreeMap<String, Vec<(String, i32)>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PropChange {
+    pub before: PropSchema,
+    pub after: PropSchema,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GameEventChange {
+    pub name: String,
+    pub added_keys: Vec<String>,
+    pub removed_keys: Vec<String>,
+    pub retyped_keys: Vec<String>,
+}
+
+// What changed from demo a to demo b. Empty when both demos are from the same game build.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SchemaDiff {
+    pub added_props: Vec<PropSchema>,
+    pub removed_props: Vec<PropSchema>,
+    // Props whose C++ type, decoder or output type changed. Encoding changes (bitcount etc.) are not listed.
+    pub retyped_props: Vec<PropChange>,
+    pub added_serializers: Vec<String>,
+    pub removed_serializers: Vec<String>,
+    pub added_game_events: Vec<String>,
+    pub removed_game_events: Vec<String>,
+    pub changed_game_events: Vec<GameEventChange>,
+}
+
+impl SchemaDiff {
+    pub fn is_empty(&self) -> bool {
+        *self == SchemaDiff::default()
+    }
+}
+
+pub fn demo_schema(cls_by_id: &[Class], ge_list: &AHashMap<i32, DescriptorT>) -> DemoSchema {
+    DemoSchema {
+        props: describe_schema(cls_by_id),
+        serializers: cls_by_id.iter().map(|cls| cls.serializer.name.clone()).filter(|name| name != "None").collect(),
+        game_events: ge_list
+            .values()
+            .map(|desc| (desc.name().to_string(), desc.keys.iter().map(|k| (k.name().to_string(), k.r#type())).collect()))
+            .collect(),
+    }
+}
+
+pub fn diff_schemas(a: &DemoSchema, b: &DemoSchema) -> SchemaDiff {
+    let props_a: BTreeMap<&str, &PropSchema> = a.props.iter().map(|p| (p.path.as_str(), p)).collect();
+    let props_b: BTreeMap<&str, &PropSchema> = b.props.iter().map(|p| (p.path.as_str(), p)).collect();
+    let mut diff = SchemaDiff::default();
+    for (path, before) in &props_a {
+        match props_b.get(path) {
+            None => diff.removed_props.push((*before).clone()),
+            Some(after) => {
+                if (&before.var_type, &before.decoder, before.variant_type) != (&after.var_type, &after.decoder, after.variant_type) {
+                    diff.retyped_props.push(PropChange {
+                        before: (*before).clone(),
+                        after: (*after).clone(),
+                    });
+                }
+            }
+        }
+    }
+    diff.added_props = props_b.iter().filter(|(path, _)| !props_a.contains_key(*path)).map(|(_, p)| (*p).clone()).collect();
+    diff.added_serializers = b.serializers.difference(&a.serializers).cloned().collect();
+    diff.removed_serializers = a.serializers.difference(&b.serializers).cloned().collect();
+
+    for (name, keys_a) in &a.game_events {
+        let keys_b = match b.game_events.get(name) {
+            Some(keys) => keys,
+            None => {
+                diff.removed_game_events.push(name.clone());
+                continue;
+            }
+        };
+        let types_a: BTreeMap<&str, i32> = keys_a.iter().map(|(k, t)| (k.as_str(), *t)).collect();
+        let types_b: BTreeMap<&str, i32> = keys_b.iter().map(|(k, t)| (k.as_str(), *t)).collect();
+        let change = GameEventChange {
+            name: name.clone(),
+            added_keys: types_b.keys().filter(|k| !types_a.contains_key(*k)).map(|k| k.to_string()).collect(),
+            removed_keys: types_a.keys().filter(|k| !types_b.contains_key(*k)).map(|k| k.to_string()).collect(),
+            retyped_keys: types_a.iter().filter(|(k, t)| types_b.get(*k).is_some_and(|t_b| t_b != *t)).map(|(k, _)| k.to_string()).collect(),
+        };
+        if !change.added_keys.is_empty() || !change.removed_keys.is_empty() || !change.retyped_keys.is_empty() {
+            diff.changed_game_events.push(change);
+        }
+    }
+    diff.added_game_events = b.game_events.keys().filter(|name| !a.game_events.contains_key(*name)).cloned().collect();
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prop(path: &str, var_type: &str) -> PropSchema {
+        PropSchema {
+            path: path.to_string(),
+            class: path.split('.').next().unwrap().to_string(),
+            prop_name: path.to_string(),
+            friendly_name: None,
+            var_type: Some(var_type.to_string()),
+            encoder: None,
+            encode_flags: None,
+            bitcount: None,
+            low_value: None,
+            high_value: None,
+            decoder: "SignedDecoder".to_string(),
+            variant_type: "i32",
+            kind: Some("player"),
+        }
+    }
+
+    #[test]
+    fn test_diff_schemas() {
+        let a = DemoSchema {
+            props: vec![prop("CCSPlayerPawn.m_iHealth", "int32"), prop("CCSPlayerPawn.m_ArmorValue", "int32"), prop("CCSPlayerPawn.m_iOld", "int32")],
+            serializers: ["CCSPlayerPawn".to_string(), "COldClass".to_string()].into(),
+            game_events: [
+                ("player_death".to_string(), vec![("userid".to_string(), 8), ("weapon".to_string(), 1)]),
+                ("old_event".to_string(), vec![]),
+            ]
+            .into(),
+        };
+        let b = DemoSchema {
+            props: vec![prop("CCSPlayerPawn.m_iHealth", "int32"), prop("CCSPlayerPawn.m_ArmorValue", "uint16"), prop("CCSPlayerPawn.m_iNew", "int32")],
+            serializers: ["CCSPlayerPawn".to_string(), "CNewClass".to_string()].into(),
+            game_events: [
+                ("player_death".to_string(), vec![("userid".to_string(), 9), ("weapon_itemid".to_string(), 1)]),
+                ("new_event".to_string(), vec![]),
+            ]
+            .into(),
+        };
+        let diff = diff_schemas(&a, &b);
+        assert_eq!(diff.added_props.iter().map(|p| p.path.as_str()).collect::<Vec<_>>(), vec!["CCSPlayerPawn.m_iNew"]);
+        assert_eq!(diff.removed_props.iter().map(|p| p.path.as_str()).collect::<Vec<_>>(), vec!["CCSPlayerPawn.m_iOld"]);
+        assert_eq!(diff.retyped_props.len(), 1);
+        assert_eq!(diff.retyped_props[0].after.var_type.as_deref(), Some("uint16"));
+        assert_eq!(diff.added_serializers, vec!["CNewClass"]);
+        assert_eq!(diff.removed_serializers, vec!["COldClass"]);
+        assert_eq!(diff.added_game_events, vec!["new_event"]);
+        assert_eq!(diff.removed_game_events, vec!["old_event"]);
+        assert_eq!(
+            diff.changed_game_events,
+            vec![GameEventChange {
+                name: "player_death".to_string(),
+                added_keys: vec!["weapon_itemid".to_string()],
+                removed_keys: vec!["weapon".to_string()],
+                retyped_keys: vec!["userid".to_string()],
+            }]
+        );
+        assert!(diff_schemas(&a, &a).is_empty());
+    }
+}
@@ -15,6 +15,7 @@ use crate::first_pass::prop_controller::ITEM_PURCHASE_NEW_DEF_IDX;
 use crate::first_pass::prop_controller::MY_WEAPONS_OFFSET;
 use crate::first_pass::prop_controller::WEAPON_SKIN_ID;
 use crate::maps::BASETYPE_DECODERS;
+use crate::maps::FRIENDLY_NAMES_MAPPING;
 use crate::second_pass::decoder::Decoder;
 use crate::second_pass::decoder::Decoder::*;
 use crate::second_pass::decoder::QfMapper;
@@ -86,8 +87,19 @@ impl<'a> FirstPassParser<'a> {
         let bytes = bitreader.read_n_bytes(n_bytes as usize)?;
         let serializer_msg = match CsvcMsgFlattenedSerializer::decode(bytes.as_slice()) {
             Ok(msg) => msg,
-            Err(_) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
         };
+        // Props read by derived columns are parsed but only output if wanted
+        for column in &self.settings.derived_columns {
+            for prop in column.props() {
+                let real_name = FRIENDLY_NAMES_MAPPING.get(prop).copied().unwrap_or(prop).to_string();
+                let is_derived = self.settings.derived_columns.iter().any(|c| c.name == prop);
+                if !is_derived && !self.wanted_player_props.contains(&real_name) {
+                    self.added_temp_props.push(real_name.clone());
+                    self.wanted_player_props.push(real_name);
+                }
+            }
+        }
         // TODO MOVE
         if needs_velocity(&self.wanted_player_props) {
             let new_props = vec!["X".to_string(), "Y".to_string(), "Z".to_string()];
@@ -113,6 +125,7 @@ impl<'a> FirstPassParser<'a> {
             map: AHashMap::default(),
         };
         let serializers = self.create_fields(&serializer_msg, &mut qf_mapper, &mut prop_controller)?;
+        prop_controller.set_derived_propinfos(&self.settings.derived_columns);
         Ok((serializers, qf_mapper, prop_controller))
     }
     fn create_fields(
@@ -159,6 +172,7 @@ impl<'a> FirstPassParser<'a> {
             serializers.insert(ser.name.clone(), ser);
         }
         // Related to prop collection
+        prop_controller.resolve_auto_names()?;
         prop_controller.set_custom_propinfos();
         prop_controller.path_to_name = AHashMap::default();
         Ok(serializers)
@@ -314,6 +328,18 @@ pub struct ValueField {
     pub should_parse: bool,
     pub prop_id: u32,
     pub full_name: String,
+    // Only used by describe_schema
+    pub metadata: Option<Box<FieldMetadata>>,
+}
+// What the sendtable says about a field
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldMetadata {
+    pub var_type: String,
+    pub encoder: String,
+    pub encode_flags: i32,
+    pub bitcount: i32,
+    pub low_value: f32,
+    pub high_value: f32,
 }
 
 #[derive(Debug, Clone)]
@@ -362,6 +388,7 @@ impl ValueField {
             prop_id: 0,
             should_parse: false,
             full_name: "".to_string() + name,
+            metadata: None,
         }
     }
 }
@@ -548,7 +575,18 @@ fn create_field(
                 Field::Serializer(SerializerField::new(ser))
             }
         }
-        None => Field::Value(ValueField::new(fd.decoder, &fd.var_name)),
+        None => {
+            let mut field = ValueField::new(fd.decoder, &fd.var_name);
+            field.metadata = Some(Box::new(FieldMetadata {
+                var_type: fd.var_type.clone(),
+                encoder: fd.encoder.clone(),
+                encode_flags: fd.encode_flags,
+                bitcount: fd.bitcount,
+                low_value: fd.low_value,
+                high_value: fd.high_value,
+            }));
+            Field::Value(field)
+        }
     };
 
     let element_field = match fd.category {
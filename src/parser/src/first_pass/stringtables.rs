@@ -1,6 +1,7 @@
 use super::read_bits::{Bitreader, DemoParserError};
 use crate::first_pass::parser_settings::FirstPassParser;
 use crate::second_pass::parser_settings::SecondPassParser;
+use crate::second_pass::player_role::track_hltv_userid;
 use csgoproto::CMsgPlayerInfo;
 use csgoproto::CsvcMsgCreateStringTable;
 use csgoproto::CsvcMsgUpdateStringTable;
@@ -9,11 +10,10 @@ use snap::raw::Decoder;
 
 #[derive(Clone, Debug)]
 pub struct StringTable {
-    name: String,
+    pub name: String,
     user_data_size: i32,
     user_data_fixed: bool,
-    #[allow(dead_code)]
-    data: Vec<StringTableEntry>,
+    pub data: Vec<StringTableEntry>,
     flags: i32,
     var_bit_counts: bool,
 }
@@ -29,11 +29,34 @@ pub struct UserInfo {
     pub name: String,
     pub userid: i32,
     pub is_hltv: bool,
+    // Bots
+    pub fake_player: bool,
+}
+// An entry that was added or changed in a string table at tick
+#[derive(Clone, Debug)]
+pub struct StringTableUpdate {
+    pub tick: i32,
+    pub table: String,
+    pub idx: i32,
+    pub key: String,
+    pub value: Vec<u8>,
+}
+// Tables at the end of the demo and every update to them. Empty unless ParserInputs::wanted_string_tables is set.
+#[derive(Clone, Debug, Default)]
+pub struct StringTableOutput {
+    pub tables: Vec<StringTable>,
+    pub updates: Vec<StringTableUpdate>,
+}
+// Value of an entry in a table whose format is known. Other values are only available as bytes.
+#[derive(Clone, Debug)]
+pub enum StringTableValue {
+    UserInfo(UserInfo),
+    String(String),
 }
 
 impl<'a> FirstPassParser<'a> {
     pub fn update_string_table(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
-        let table = CsvcMsgUpdateStringTable::decode(bytes).map_err(|_| DemoParserError::MalformedMessage)?;
+        let table = CsvcMsgUpdateStringTable::decode(bytes).map_err(DemoParserError::ProtobufDecodeError)?;
 
         let st = self.string_tables.get(table.table_id() as usize).ok_or(DemoParserError::StringTableNotFound)?;
         self.parse_string_table(
@@ -49,7 +72,7 @@ impl<'a> FirstPassParser<'a> {
     }
 
     pub fn parse_create_stringtable(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
-        let table = CsvcMsgCreateStringTable::decode(bytes).map_err(|_| DemoParserError::MalformedMessage)?;
+        let table = CsvcMsgCreateStringTable::decode(bytes).map_err(DemoParserError::ProtobufDecodeError)?;
 
         if !(table.name() == "instancebaseline" || table.name() == "userinfo") {
             return Ok(());
@@ -57,7 +80,7 @@ impl<'a> FirstPassParser<'a> {
         let bytes = match table.data_compressed() {
             true => snap::raw::Decoder::new()
                 .decompress_vec(table.string_data())
-                .map_err(|_| DemoParserError::MalformedMessage)?,
+                .map_err(DemoParserError::DecompressionFailure)?,
             false => table.string_data().to_vec(),
         };
         self.parse_string_table(
@@ -81,120 +104,163 @@ impl<'a> FirstPassParser<'a> {
         flags: i32,
         variant_bit_count: bool,
     ) -> Result<(), DemoParserError> {
-        let mut bitreader = Bitreader::new(&bytes);
-        let mut idx = -1;
-        let mut keys: Vec<String> = vec![];
-        let mut items = vec![];
+        let items = read_string_table_entries(&bytes, n_updates, udf, user_data_size, flags, variant_bit_count)?;
+        for item in &items {
+            if name == "userinfo" {
+                if let Ok(player) = parse_userinfo(&item.value) {
+                    track_hltv_userid(&mut self.hltv_userids, &player);
+                    if player.steamid != 0 {
+                        self.stringtable_players.insert(player.userid, player);
+                    }
+                }
+            }
+            if name == "instancebaseline" {
+                match item.key.parse::<u32>() {
+                    Ok(cls_id) => self.baselines.insert(cls_id, item.value.clone()),
+                    Err(_e) => None,
+                };
+            }
+        }
+        self.string_tables.push(StringTable {
+            data: items,
+            name,
+            user_data_size,
+            user_data_fixed: udf,
+            flags,
+            var_bit_counts: variant_bit_count,
+        });
+        Ok(())
+    }
+}
+pub fn read_string_table_entries(
+    bytes: &[u8],
+    n_updates: i32,
+    udf: bool,
+    user_data_size: i32,
+    flags: i32,
+    variant_bit_count: bool,
+) -> Result<Vec<StringTableEntry>, DemoParserError> {
+    let mut bitreader = Bitreader::new(bytes);
+    let mut idx = -1;
+    let mut keys: Vec<String> = vec![];
+    let mut items = vec![];
 
-        for _upd in 0..n_updates {
-            let mut key = "".to_owned();
-            let mut value = vec![];
+    for _upd in 0..n_updates {
+        let mut key = "".to_owned();
+        let mut value = vec![];
 
-            // Increment index
+        // Increment index
+        match bitreader.read_boolean()? {
+            true => idx += 1,
+            false => idx += (bitreader.read_varint()? + 1) as i32,
+        };
+        // Does the value have a key
+        if bitreader.read_boolean()? {
+            // Should we refer back to history (similar to LZ77)
             match bitreader.read_boolean()? {
-                true => idx += 1,
-                false => idx += (bitreader.read_varint()? + 1) as i32,
-            };
-            // Does the value have a key
-            if bitreader.read_boolean()? {
-                // Should we refer back to history (similar to LZ77)
-                match bitreader.read_boolean()? {
-                    // If no history then just read the data as one string
-                    false => key = key.to_owned() + &bitreader.read_string()?,
-                    // Refer to history
-                    true => {
-                        // How far into history we should look
-                        let position = bitreader.read_nbits(5)?;
-                        // How many bytes in a row, starting from distance ago, should be copied
-                        let length = bitreader.read_nbits(5)?;
+                // If no history then just read the data as one string
+                false => key = key.to_owned() + &bitreader.read_string()?,
+                // Refer to history
+                true => {
+                    // How far into history we should look
+                    let position = bitreader.read_nbits(5)?;
+                    // How many bytes in a row, starting from distance ago, should be copied
+                    let length = bitreader.read_nbits(5)?;
 
-                        if position >= keys.len() as u32 {
-                            key = key.to_owned() + &bitreader.read_string()?;
-                        } else {
-                            if let Some(s) = &keys.get(position as usize) {
-                                if length > s.len() as u32 {
-                                    key = key.to_owned() + &s + &bitreader.read_string()?;
-                                } else {
-                                    key = key.to_owned() + &s.get(0..length as usize).unwrap_or("") + &bitreader.read_string()?;
-                                }
+                    if position >= keys.len() as u32 {
+                        key = key.to_owned() + &bitreader.read_string()?;
+                    } else {
+                        if let Some(s) = keys.get(position as usize) {
+                            if length > s.len() as u32 {
+                                key = key.to_owned() + s + &bitreader.read_string()?;
+                            } else {
+                                key = key.to_owned() + s.get(0..length as usize).unwrap_or("") + &bitreader.read_string()?;
                             }
                         }
                     }
                 }
-                if keys.len() >= 32 {
-                    keys.remove(0);
-                }
-                keys.push(key.clone());
-                // Does the entry have a value
-                if bitreader.read_boolean()? {
-                    let bits: u32;
-                    let mut is_compressed = false;
+            }
+            if keys.len() >= 32 {
+                keys.remove(0);
+            }
+            keys.push(key.clone());
+        }
+        // Does the entry have a value. Entries without a key still have one (the key is known by idx).
+        if bitreader.read_boolean()? {
+            let bits: u32;
+            let mut is_compressed = false;
 
-                    match udf {
-                        true => bits = user_data_size as u32,
-                        false => {
-                            if (flags & 0x1) != 0 {
-                                is_compressed = bitreader.read_boolean()?;
-                            }
-                            if variant_bit_count {
-                                bits = bitreader.read_u_bit_var()? * 8;
-                            } else {
-                                bits = bitreader.read_nbits(17)? * 8;
-                            }
-                        }
+            match udf {
+                true => bits = user_data_size as u32,
+                false => {
+                    if (flags & 0x1) != 0 {
+                        is_compressed = bitreader.read_boolean()?;
                     }
-                    value = bitreader.read_n_bytes((bits.checked_div(8).unwrap_or(0)) as usize)?;
-                    value = if is_compressed {
-                        match Decoder::new().decompress_vec(&value) {
-                            Ok(bytes) => bytes,
-                            Err(_) => return Err(DemoParserError::MalformedMessage),
-                        }
+                    if variant_bit_count {
+                        bits = bitreader.read_u_bit_var()? * 8;
                     } else {
-                        value
-                    };
-                }
-                if name == "userinfo" {
-                    if let Ok(player) = parse_userinfo(&value) {
-                        if player.steamid != 0 {
-                            self.stringtable_players.insert(player.userid, player);
-                        }
+                        bits = bitreader.read_nbits(17)? * 8;
                     }
                 }
-                if name == "instancebaseline" {
-                    match key.parse::<u32>() {
-                        Ok(cls_id) => self.baselines.insert(cls_id, value.clone()),
-                        Err(_e) => None,
-                    };
+            }
+            value = bitreader.read_n_bytes((bits.checked_div(8).unwrap_or(0)) as usize)?;
+            value = if is_compressed {
+                match Decoder::new().decompress_vec(&value) {
+                    Ok(bytes) => bytes,
+                    Err(e) => return Err(DemoParserError::DecompressionFailure(e)),
+                }
+            } else {
+                value
+            };
+        }
+        items.push(StringTableEntry { idx, key, value });
+    }
+    Ok(items)
+}
+pub fn decode_string_table_value(table: &str, value: &[u8]) -> Option<StringTableValue> {
+    if value.is_empty() {
+        return None;
+    }
+    match table {
+        "userinfo" => parse_userinfo(value).ok().map(StringTableValue::UserInfo),
+        "lightstyles" => std::str::from_utf8(value)
+            .ok()
+            .map(|s| StringTableValue::String(s.trim_end_matches('\0').to_string())),
+        _ => None,
+    }
+}
+impl StringTable {
+    // Entries from an update replace the entries with the same index. Updates without a key keep the old key.
+    fn merge(&mut self, entries: &mut [StringTableEntry]) {
+        for entry in entries {
+            match self.data.iter_mut().find(|e| e.idx == entry.idx) {
+                Some(existing) => {
+                    if entry.key.is_empty() {
+                        entry.key = existing.key.clone();
+                    }
+                    *existing = entry.clone();
                 }
-                items.push(StringTableEntry { idx, key, value });
+                None => self.data.push(entry.clone()),
             }
         }
-        self.string_tables.push(StringTable {
-            data: items,
-            name,
-            user_data_size,
-            user_data_fixed: udf,
-            flags,
-            var_bit_counts: variant_bit_count,
-        });
-        Ok(())
     }
 }
 pub fn parse_userinfo(bytes: &[u8]) -> Result<UserInfo, DemoParserError> {
-    let player = CMsgPlayerInfo::decode(bytes).map_err(|_| DemoParserError::MalformedMessage)?;
+    let player = CMsgPlayerInfo::decode(bytes).map_err(DemoParserError::ProtobufDecodeError)?;
     Ok(UserInfo {
         is_hltv: player.ishltv(),
         steamid: player.xuid(),
         name: player.name().to_string(),
         userid: player.userid() & 0xff,
+        fake_player: player.fakeplayer(),
     })
 }
 
 impl<'a> SecondPassParser<'a> {
     pub fn update_string_table(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
-        let table = CsvcMsgUpdateStringTable::decode(bytes).map_err(|_| DemoParserError::MalformedMessage)?;
-        match self.string_tables.get(table.table_id() as usize) {
-            Some(st) => self.parse_string_table(
+        let table = CsvcMsgUpdateStringTable::decode(bytes).map_err(DemoParserError::ProtobufDecodeError)?;
+        if let Some(st) = self.string_tables.get(table.table_id() as usize) {
+            self.parse_string_table(
                 table.string_data().to_vec(),
                 table.num_changed_entries(),
                 st.name.clone(),
@@ -202,19 +268,19 @@ impl<'a> SecondPassParser<'a> {
                 st.user_data_size,
                 st.flags,
                 st.var_bit_counts,
-            )?,
-            None => {
-                return Ok(());
-            }
+            )?;
+        }
+        if !self.wanted_string_tables.is_empty() {
+            self.record_string_table_update(&table)?;
         }
         Ok(())
     }
     pub fn parse_create_stringtable(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
-        let table = CsvcMsgCreateStringTable::decode(bytes).map_err(|_| DemoParserError::MalformedMessage)?;
+        let table = CsvcMsgCreateStringTable::decode(bytes).map_err(DemoParserError::ProtobufDecodeError)?;
         let bytes = match table.data_compressed() {
             true => snap::raw::Decoder::new()
                 .decompress_vec(table.string_data())
-                .map_err(|_| DemoParserError::MalformedMessage)?,
+                .map_err(DemoParserError::DecompressionFailure)?,
             false => table.string_data().to_vec(),
         };
         self.parse_string_table(
@@ -226,6 +292,13 @@ impl<'a> SecondPassParser<'a> {
             table.flags(),
             table.using_varint_bitcounts(),
         )?;
+        if !self.wanted_string_tables.is_empty() {
+            // parse_string_table pushed the created table last
+            if let Some(created) = self.string_tables.last().cloned() {
+                self.record_string_table_entries(&created.name, &created.data);
+                self.recorded_string_tables.push(created);
+            }
+        }
         Ok(())
     }
     pub fn parse_string_table(
@@ -238,91 +311,21 @@ impl<'a> SecondPassParser<'a> {
         flags: i32,
         variant_bit_count: bool,
     ) -> Result<(), DemoParserError> {
-        let mut bitreader = Bitreader::new(&bytes);
-        let mut idx = -1;
-        let mut keys: Vec<String> = vec![];
-        let mut items = vec![];
-
-        for _upd in 0..n_updates {
-            let mut key = "".to_owned();
-            let mut value = vec![];
-
-            // Increment index
-            match bitreader.read_boolean()? {
-                true => idx += 1,
-                false => idx += (bitreader.read_varint()? + 1) as i32,
-            };
-            // Does the value have a key
-            if bitreader.read_boolean()? {
-                // Should we refer back to history (similar to LZ77)
-                match bitreader.read_boolean()? {
-                    // If no history then just read the data as one string
-                    false => key = key.to_owned() + &bitreader.read_string()?,
-                    // Refer to history
-                    true => {
-                        // How far into history we should look
-                        let position = bitreader.read_nbits(5)?;
-                        // How many bytes in a row, starting from distance ago, should be copied
-                        let length = bitreader.read_nbits(5)?;
-
-                        if position >= keys.len() as u32 {
-                            key = key.to_owned() + &bitreader.read_string()?;
-                        } else {
-                            let s = &keys[position as usize];
-                            if length > s.len() as u32 {
-                                key = key.to_owned() + &s + &bitreader.read_string()?;
-                            } else {
-                                key = key.to_owned() + &s.get(0..length as usize).unwrap_or("") + &bitreader.read_string()?;
-                            }
-                        }
-                    }
-                }
-                if keys.len() >= 32 {
-                    keys.remove(0);
-                }
-                keys.push(key.clone());
-                // Does the entry have a value
-                if bitreader.read_boolean()? {
-                    let bits: u32;
-                    let mut is_compressed = false;
-
-                    match udf {
-                        true => bits = user_data_size as u32,
-                        false => {
-                            if (flags & 0x1) != 0 {
-                                is_compressed = bitreader.read_boolean()?;
-                            }
-                            if variant_bit_count {
-                                bits = bitreader.read_u_bit_var()? * 8;
-                            } else {
-                                bits = bitreader.read_nbits(17)? * 8;
-                            }
-                        }
-                    }
-                    value = bitreader.read_n_bytes((bits.checked_div(8).unwrap_or(0)) as usize)?;
-                    value = if is_compressed {
-                        match Decoder::new().decompress_vec(&value) {
-                            Ok(bytes) => bytes,
-                            Err(_) => return Err(DemoParserError::MalformedMessage),
-                        }
-                    } else {
-                        value
-                    };
-                }
-                if name == "userinfo" {
-                    if let Ok(player) = parse_userinfo(&value) {
-                        if player.steamid != 0 {
-                            self.stringtable_players.insert(player.userid, player);
-                        }
+        let items = read_string_table_entries(&bytes, n_updates, udf, user_data_size, flags, variant_bit_count)?;
+        for item in &items {
+            if name == "userinfo" {
+                if let Ok(player) = parse_userinfo(&item.value) {
+                    track_hltv_userid(&mut self.hltv_userids, &player);
+                    if player.steamid != 0 {
+                        self.stringtable_players.insert(player.userid, player);
                     }
                 }
-                if name == "instancebaseline" {
-                    match key.parse::<u32>() {
-                        Ok(cls_id) => self.baselines.insert(cls_id, value.clone()),
-                        Err(_e) => None,
-                    };
-                }
-                items.push(StringTableEntry { idx, key, value });
+            }
+            if name == "instancebaseline" {
+                match item.key.parse::<u32>() {
+                    Ok(cls_id) => self.baselines.insert(cls_id, item.value.clone()),
+                    Err(_e) => None,
+                };
             }
         }
         self.string_tables.push(StringTable {
@@ -335,4 +338,97 @@ impl<'a> SecondPassParser<'a> {
         });
         Ok(())
     }
+    /*
+    string_tables is reset at every fullpacket, so for ParserInputs::wanted_string_tables a separate copy
+    of every table is kept from its creation. Only cleared by svc_ClearAllStringTables, which is followed
+    by the tables being created again.
+    */
+    fn record_string_table_update(&mut self, table: &CsvcMsgUpdateStringTable) -> Result<(), DemoParserError> {
+        let Some(recorded) = self.recorded_string_tables.get_mut(table.table_id() as usize) else {
+            return Ok(());
+        };
+        let mut entries = read_string_table_entries(
+            table.string_data(),
+            table.num_changed_entries(),
+            recorded.user_data_fixed,
+            recorded.user_data_size,
+            recorded.flags,
+            recorded.var_bit_counts,
+        )?;
+        recorded.merge(&mut entries);
+        let name = recorded.name.clone();
+        self.record_string_table_entries(&name, &entries);
+        Ok(())
+    }
+    fn record_string_table_entries(&mut self, name: &str, entries: &[StringTableEntry]) {
+        if !self.wants_string_table(name) {
+            return;
+        }
+        for entry in entries {
+            self.string_table_updates.push(StringTableUpdate {
+                tick: self.tick,
+                table: name.to_string(),
+                idx: entry.idx,
+                key: entry.key.clone(),
+                value: entry.value.clone(),
+            });
+        }
+    }
+    fn wants_string_table(&self, name: &str) -> bool {
+        self.wanted_string_tables.first().is_some_and(|t| t == "all") || self.wanted_string_tables.iter().any(|t| t == name)
+    }
+    pub fn string_table_output(&self) -> StringTableOutput {
+        StringTableOutput {
+            tables: self.recorded_string_tables.iter().filter(|t| self.wants_string_table(&t.name)).cloned().collect(),
+            updates: self.string_table_updates.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Bits are read starting from the lowest bit of each byte
+    fn push_bits(bits: &mut Vec<bool>, value: u32, n: u32) {
+        for i in 0..n {
+            bits.push(value >> i & 1 == 1);
+        }
+    }
+    fn to_bytes(bits: &[bool]) -> Vec<u8> {
+        let mut bytes = vec![0; bits.len() / 8 + 1];
+        for (i, bit) in bits.iter().enumerate() {
+            bytes[i / 8] |= (*bit as u8) << (i % 8);
+        }
+        // Bitreader reads ahead
+        bytes.extend_from_slice(&[0; 8]);
+        bytes
+    }
+
+    #[test]
+    fn test_read_keyless_entry() {
+        let mut bits = vec![];
+        // Keyless entry with a 2 byte value
+        push_bits(&mut bits, 1, 1);
+        push_bits(&mut bits, 0, 1);
+        push_bits(&mut bits, 1, 1);
+        push_bits(&mut bits, 2, 17);
+        push_bits(&mut bits, 0xAA, 8);
+        push_bits(&mut bits, 0xBB, 8);
+        // Entry with key "ab" (no history) and a 1 byte value
+        push_bits(&mut bits, 1, 1);
+        push_bits(&mut bits, 1, 1);
+        push_bits(&mut bits, 0, 1);
+        for byte in b"ab\0" {
+            push_bits(&mut bits, *byte as u32, 8);
+        }
+        push_bits(&mut bits, 1, 1);
+        push_bits(&mut bits, 1, 17);
+        push_bits(&mut bits, 0x01, 8);
+
+        let entries = read_string_table_entries(&to_bytes(&bits), 2, false, 0, 0, false).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!((entries[0].idx, entries[0].key.as_str(), entries[0].value.as_slice()), (0, "", &[0xAA, 0xBB][..]));
+        assert_eq!((entries[1].idx, entries[1].key.as_str(), entries[1].value.as_slice()), (1, "ab", &[0x01][..]));
+    }
 }
@@ -0,0 +1,327 @@
+use crate::first_pass::parser::HEADER_ENDS_AT_BYTE;
+use crate::first_pass::parser_settings::FirstPassParser;
+use crate::first_pass::parser_settings::default_inputs;
+use crate::first_pass::parser_settings::ParserInputs;
+use crate::first_pass::read_bits::DemoParserError;
+use csgoproto::EDemoCommands;
+use serde::Serialize;
+use snap::raw::decompress_len;
+use snap::raw::Decoder;
+
+// Jumps in ticks longer than this many seconds are reported as gaps
+pub const TICK_GAP_SECONDS: f32 = 2.0;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationIssue {
+    pub byte_offset: usize,
+    pub tick: Option<i32>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TickGap {
+    pub byte_offset: usize,
+    pub from_tick: i32,
+    pub to_tick: i32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationReport {
+    // True if the demo can be parsed from start to end
+    pub is_valid: bool,
+    pub issues: Vec<ValidationIssue>,
+    pub file_length: usize,
+    pub n_frames: usize,
+    pub first_tick: Option<i32>,
+    pub last_tick: Option<i32>,
+    /*
+    Everything before this offset was read without problems. Start of the first broken frame
+    (= end of the frame before it), or end of the last frame if nothing was broken.
+    */
+    pub last_good_offset: usize,
+    pub tick_gaps: Vec<TickGap>,
+    pub has_file_header: bool,
+    pub has_sendtables: bool,
+    pub has_class_info: bool,
+    pub has_game_event_list: bool,
+    pub has_stop: bool,
+}
+
+/*
+Checks that the demo is intact without doing the expensive parsing of entities and events.
+Runs the frame loop of the first pass in lenient mode, so every error it would stop a parse with
+is reported as an issue, and additionally checks the following:
+
+1. Every frame fits in the file
+2. Sendtables, class info and the game event list are found
+3. Ticks never go backwards (gaps are reported but don't make the demo invalid)
+4. The demo ends with DemStop
+5. Compressed packets decompress. The first pass skips packets, so they are decompressed here
+*/
+pub fn validate_demo(demo_bytes: &[u8]) -> ValidationReport {
+    let mut report = ValidationReport {
+        is_valid: false,
+        issues: vec![],
+        file_length: demo_bytes.len(),
+        n_frames: 0,
+        first_tick: None,
+        last_tick: None,
+        last_good_offset: HEADER_ENDS_AT_BYTE,
+        tick_gaps: vec![],
+        has_file_header: false,
+        has_sendtables: false,
+        has_class_info: false,
+        has_game_event_list: false,
+        has_stop: false,
+    };
+    let huffman_lookup_table = vec![];
+    let inputs = validation_inputs(&huffman_lookup_table);
+    let mut parser = FirstPassParser::new(&inputs);
+    let mut first_broken_at: Option<usize> = None;
+    let mut end_of_last_frame = HEADER_ENDS_AT_BYTE;
+    let mut reuseable_buffer = vec![0_u8; 100_000];
+
+    let result = parser.parse_frames(demo_bytes, false, &mut |frame, data_starts_at| {
+        if data_starts_at + frame.size > demo_bytes.len() {
+            first_broken_at = first_broken_at.or(Some(frame.frame_starts_at));
+            report.issues.push(ValidationIssue {
+                byte_offset: frame.frame_starts_at,
+                tick: Some(frame.tick),
+                message: format!(
+                    "frame size is {} bytes but only {} bytes are left. Demo is truncated",
+                    frame.size,
+                    demo_bytes.len() - data_starts_at
+                ),
+            });
+            return;
+        }
+        report.n_frames += 1;
+        end_of_last_frame = data_starts_at + frame.size;
+        if frame.is_compressed && FirstPassParser::is_packet_we_skip_on_first_pass(frame.demo_cmd) {
+            let bytes = &demo_bytes[data_starts_at..data_starts_at + frame.size];
+            let decompressed = FirstPassParser::resize_if_needed(&mut reuseable_buffer, decompress_len(bytes))
+                .and_then(|_| Decoder::new().decompress(bytes, &mut reuseable_buffer).map_err(DemoParserError::DecompressionFailure));
+            if let Err(e) = decompressed {
+                first_broken_at = first_broken_at.or(Some(frame.frame_starts_at));
+                report.issues.push(ValidationIssue {
+                    byte_offset: frame.frame_starts_at,
+                    tick: Some(frame.tick),
+                    message: e.to_string(),
+                });
+            }
+        }
+        report.has_stop |= frame.demo_cmd == EDemoCommands::DemStop;
+        // Signon frames have tick -1
+        if frame.tick < 0 {
+            return;
+        }
+        if let Some(last_tick) = report.last_tick {
+            if frame.tick < last_tick {
+                report.issues.push(ValidationIssue {
+                    byte_offset: frame.frame_starts_at,
+                    tick: Some(frame.tick),
+                    message: format!("tick goes backwards from {} to {}", last_tick, frame.tick),
+                });
+            } else if frame.tick - last_tick > 1 {
+                // Filtered with the tick interval once the whole demo has been read
+                report.tick_gaps.push(TickGap {
+                    byte_offset: frame.frame_starts_at,
+                    from_tick: last_tick,
+                    to_tick: frame.tick,
+                });
+            }
+        }
+        report.first_tick = report.first_tick.or(Some(frame.tick));
+        report.last_tick = Some(frame.tick);
+    });
+    // Only the header check fails in lenient mode, the rest of the file can't be trusted
+    if let Err(e) = result {
+        report.issues.push(ValidationIssue {
+            byte_offset: 0,
+            tick: None,
+            message: e.to_string(),
+        });
+        return report;
+    }
+    let gap_threshold = (TICK_GAP_SECONDS / parser.tick_interval).round() as i32;
+    report.tick_gaps.retain(|gap| gap.to_tick - gap.from_tick > gap_threshold);
+    for warning in &parser.warnings {
+        first_broken_at = Some(first_broken_at.map_or(warning.byte_offset, |offset| offset.min(warning.byte_offset)));
+        report.issues.push(ValidationIssue {
+            byte_offset: warning.byte_offset,
+            tick: Some(warning.tick),
+            message: warning.error.clone(),
+        });
+    }
+    report.last_good_offset = first_broken_at.unwrap_or(end_of_last_frame);
+    report.has_file_header = !parser.header.is_empty();
+    report.has_sendtables = parser.sendtable_message.is_some();
+    report.has_class_info = parser.cls_by_id.is_some();
+    report.has_game_event_list = !parser.ge_list.is_empty();
+
+    if !report.has_file_header {
+        report.issues.push(missing_issue(&report, "file header"));
+    }
+    if !report.has_sendtables {
+        report.issues.push(missing_issue(&report, "sendtables"));
+    }
+    if !report.has_class_info {
+        report.issues.push(missing_issue(&report, "class info"));
+    }
+    if !report.has_game_event_list {
+        report.issues.push(missing_issue(&report, "game event list"));
+    }
+    if !report.has_stop {
+        report.issues.push(missing_issue(&report, "DemStop. Demo is truncated"));
+    }
+    report.is_valid = report.issues.is_empty();
+    report
+}
+
+// Nothing is wanted, only the frames and the messages needed to set up the parser are read
+fn validation_inputs(huffman_lookup_table: &Vec<(u8, u8)>) -> ParserInputs<'_> {
+    ParserInputs {
+        parse_ents: false,
+        lenient: true,
+        ..default_inputs(huffman_lookup_table)
+    }
+}
+
+fn missing_issue(report: &ValidationReport, what: &str) -> ValidationIssue {
+    ValidationIssue {
+        byte_offset: report.last_good_offset,
+        tick: report.last_tick,
+        message: format!("missing {}", what),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_varint(out: &mut Vec<u8>, mut value: u32) {
+        loop {
+            let byte = (value & 0x7F) as u8;
+            value >>= 7;
+            if value == 0 {
+                out.push(byte);
+                return;
+            }
+            out.push(byte | 0x80);
+        }
+    }
+    fn push_frame(out: &mut Vec<u8>, cmd: EDemoCommands, tick: i32, data: &[u8]) {
+        write_varint(out, cmd as u32);
+        write_varint(out, tick as u32);
+        write_varint(out, data.len() as u32);
+        out.extend_from_slice(data);
+    }
+    fn push_compressed_frame(out: &mut Vec<u8>, cmd: EDemoCommands, tick: i32, data: &[u8]) {
+        write_varint(out, cmd as u32 | 64);
+        write_varint(out, tick as u32);
+        write_varint(out, data.len() as u32);
+        out.extend_from_slice(data);
+    }
+    fn demo_header() -> Vec<u8> {
+        let mut bytes = b"PBDEMS2\0".to_vec();
+        bytes.extend_from_slice(&[0; 8]);
+        bytes
+    }
+
+    #[test]
+    fn test_validate_bad_magic() {
+        let report = validate_demo(b"HL2DEMO\0\0\0\0\0\0\0\0\0\0\0");
+        assert!(!report.is_valid);
+        assert_eq!(report.issues.len(), 1);
+        assert_eq!(report.issues[0].message, "Source1DemoError");
+        let report = validate_demo(b"PBDEMS2");
+        assert_eq!(report.issues[0].message, "OutOfBytesError");
+    }
+
+    #[test]
+    fn test_validate_truncated_demo() {
+        let mut bytes = demo_header();
+        push_frame(&mut bytes, EDemoCommands::DemSendTables, -1, &[]);
+        push_frame(&mut bytes, EDemoCommands::DemPacket, 10, &[]);
+        push_frame(&mut bytes, EDemoCommands::DemPacket, 500, &[]);
+        push_frame(&mut bytes, EDemoCommands::DemPacket, 400, &[]);
+        let last_good_offset = bytes.len();
+        // Frame claims more bytes than there are left
+        write_varint(&mut bytes, EDemoCommands::DemPacket as u32);
+        write_varint(&mut bytes, 401);
+        write_varint(&mut bytes, 1000);
+        bytes.extend_from_slice(&[0; 10]);
+
+        let report = validate_demo(&bytes);
+        assert!(!report.is_valid);
+        assert!(report.has_sendtables);
+        assert!(!report.has_stop);
+        assert_eq!(report.n_frames, 4);
+        assert_eq!(report.first_tick, Some(10));
+        assert_eq!(report.last_tick, Some(400));
+        assert_eq!(report.last_good_offset, last_good_offset);
+        assert_eq!(report.tick_gaps.len(), 1);
+        assert_eq!((report.tick_gaps[0].from_tick, report.tick_gaps[0].to_tick), (10, 500));
+        assert!(report.issues.iter().any(|x| x.message.contains("tick goes backwards")));
+        assert!(report.issues.iter().any(|x| x.message.contains("truncated") && x.tick == Some(401)));
+        assert!(report.issues.iter().any(|x| x.message == "missing class info"));
+    }
+
+    #[test]
+    fn test_validate_collects_first_pass_errors() {
+        let mut bytes = demo_header();
+        push_frame(&mut bytes, EDemoCommands::DemPacket, 10, &[]);
+        let broken_frame_at = bytes.len();
+        // Compressed sendtables that aren't valid snappy
+        write_varint(&mut bytes, EDemoCommands::DemSendTables as u32 | 64);
+        write_varint(&mut bytes, 11);
+        write_varint(&mut bytes, 4);
+        bytes.extend_from_slice(&[0xff; 4]);
+        push_frame(&mut bytes, EDemoCommands::DemPacket, 12, &[]);
+        push_frame(&mut bytes, EDemoCommands::DemStop, 12, &[]);
+
+        let report = validate_demo(&bytes);
+        assert!(!report.is_valid);
+        assert!(report.has_stop);
+        assert!(!report.has_sendtables);
+        assert_eq!(report.n_frames, 4);
+        assert_eq!(report.last_good_offset, broken_frame_at);
+        let issue = report.issues.iter().find(|x| x.message.contains("DecompressionFailure")).unwrap();
+        assert_eq!((issue.byte_offset, issue.tick), (broken_frame_at, Some(11)));
+    }
+
+    #[test]
+    fn test_validate_clean_frames() {
+        let mut bytes = demo_header();
+        push_frame(&mut bytes, EDemoCommands::DemPacket, 10, &[]);
+        let compressed = snap::raw::Encoder::new().compress_vec(&[1, 2, 3, 4]).unwrap();
+        push_compressed_frame(&mut bytes, EDemoCommands::DemPacket, 11, &compressed);
+        // Less than TICK_GAP_SECONDS at 64 tick
+        push_frame(&mut bytes, EDemoCommands::DemPacket, 100, &[]);
+        push_frame(&mut bytes, EDemoCommands::DemStop, 100, &[]);
+
+        let report = validate_demo(&bytes);
+        assert_eq!(report.n_frames, 4);
+        assert!(report.has_stop);
+        assert!(report.tick_gaps.is_empty());
+        assert_eq!(report.last_good_offset, bytes.len());
+        assert!(!report.issues.iter().any(|x| x.message.contains("DecompressionFailure")));
+    }
+
+    #[test]
+    fn test_validate_corrupt_compressed_packet() {
+        let mut bytes = demo_header();
+        push_frame(&mut bytes, EDemoCommands::DemPacket, 10, &[]);
+        let broken_frame_at = bytes.len();
+        push_compressed_frame(&mut bytes, EDemoCommands::DemPacket, 11, &[0xff; 4]);
+        push_frame(&mut bytes, EDemoCommands::DemPacket, 12, &[]);
+        push_frame(&mut bytes, EDemoCommands::DemStop, 12, &[]);
+
+        let report = validate_demo(&bytes);
+        assert!(!report.is_valid);
+        assert_eq!(report.n_frames, 4);
+        assert_eq!(report.last_good_offset, broken_frame_at);
+        let issue = report.issues.iter().find(|x| x.message.contains("DecompressionFailure")).unwrap();
+        assert_eq!((issue.byte_offset, issue.tick), (broken_frame_at, Some(11)));
+    }
+}
@@ -2,5 +2,6 @@
 pub mod e2e_test;
 pub mod first_pass;
 pub mod maps;
+pub mod parse_control;
 pub mod parse_demo;
 pub mod second_pass;
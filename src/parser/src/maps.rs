@@ -73,6 +73,46 @@ pub static HIT_GROUP: phf::Map<i32, &'static str> = phf_map! {
     10_i32 => "gear",
 };
 
+// vote_type in CCSUsrMsg_VoteStart / CCSUsrMsg_VotePass
+pub static VOTE_ISSUE: phf::Map<i32, &'static str> = phf_map! {
+    0_i32 => "kick",
+    1_i32 => "changelevel",
+    2_i32 => "nextlevel",
+    3_i32 => "swap_teams",
+    4_i32 => "scramble_teams",
+    5_i32 => "restart_game",
+    6_i32 => "surrender",
+    7_i32 => "rematch",
+    8_i32 => "continue",
+    9_i32 => "pause_match",
+    10_i32 => "unpause_match",
+    11_i32 => "load_backup",
+    12_i32 => "end_warmup",
+    13_i32 => "start_timeout",
+    14_i32 => "end_timeout",
+    15_i32 => "ready_for_match",
+    16_i32 => "not_ready_for_match",
+};
+
+// reason in CCSUsrMsg_VoteFailed / CCSUsrMsg_CallVoteFailed
+pub static VOTE_FAILED_REASON: phf::Map<i32, &'static str> = phf_map! {
+    0_i32 => "generic",
+    1_i32 => "transitioning_players",
+    2_i32 => "rate_exceeded",
+    3_i32 => "yes_must_exceed_no",
+    4_i32 => "quorum_failure",
+    5_i32 => "issue_disabled",
+    6_i32 => "map_not_found",
+    7_i32 => "map_name_required",
+    8_i32 => "failed_recently",
+    9_i32 => "team_cant_call",
+    10_i32 => "waiting_for_players",
+    11_i32 => "player_not_found",
+    12_i32 => "cannot_kick_admin",
+    13_i32 => "scramble_in_progress",
+    14_i32 => "spectator",
+};
+
 pub static PLAYER_COLOR: phf::Map<i32, &'static str> = phf_map! {
     0_i32 => "blue",
     1_i32 => "green",
@@ -200,6 +240,9 @@ pub static CUSTOM_PLAYER_PROP_IDS: phf::Map<&'static str, u32> = phf_map! {
     "inventory" => INVENTORY_ID,
     "inventory_as_ids" => INVENTORY_AS_IDS_ID,
     "inventory_as_bitmask" => INVENTORY_AS_IDS_BITMASK,
+    "player_key" => PLAYER_KEY_ID,
+    "controlled_by_steamid" => CONTROLLED_BY_STEAMID_ID,
+    "role" => ROLE_ID,
     "active_weapon_original_owner" => WEAPON_ORIGINGAL_OWNER_ID,
     "weapon_name" => WEAPON_NAME_ID,
     "weapon_skin" => WEAPON_SKIN_NAME,
@@ -648,6 +691,8 @@ pub static TYPEHM: phf::Map<&'static str, PropType> = phf_map! {
     "weapon_name" => PropType::Custom,
     "active_weapon_original_owner" => PropType::Custom,
     "game_time" => PropType::GameTime,
+    "round_time" => PropType::GameTime,
+    "clock_time" => PropType::GameTime,
     "inventory" => PropType::Custom,
     "inventory_as_ids" => PropType::Custom,
     "CCSPlayerPawn.m_bSpottedByMask" => PropType::Custom,
@@ -657,6 +702,9 @@ pub static TYPEHM: phf::Map<&'static str, PropType> = phf_map! {
     "weapon_float" => PropType::Custom,
     "weapon_stickers" => PropType::Custom,
     "is_airborne" => PropType::Custom,
+    "player_key" => PropType::Custom,
+    "controlled_by_steamid" => PropType::Custom,
+    "role" => PropType::Custom,
     "glove_paint_id" => PropType::Custom,
     "glove_paint_seed" => PropType::Custom,
     "glove_paint_float" => PropType::Custom,
@@ -886,6 +934,8 @@ pub static FRIENDLY_NAMES_MAPPING: phf::Map<&'static str, &'static str> = phf_ma
     "pitch" => "pitch",
     "yaw" => "yaw",
     "game_time" => "game_time",
+    "round_time" => "round_time",
+    "clock_time" => "clock_time",
     "inventory" => "inventory",
     "inventory_as_ids" => "inventory_as_ids",
     "aim_punch_angle" => "CCSPlayerPawn.m_aimPunchAngle",
@@ -1149,6 +1199,9 @@ pub static FRIENDLY_NAMES_MAPPING: phf::Map<&'static str, &'static str> = phf_ma
     "next_secondary_attack_tick" => "m_nNextSecondaryAttackTick",
     "next_secondary_attack_tick_ratio"=> "m_flNextSecondaryAttackTickRatio",
     "is_airborne" => "is_airborne",
+    "player_key" => "player_key",
+    "controlled_by_steamid" => "controlled_by_steamid",
+    "role" => "role",
 };
 
 pub static GRENADE_FRIENDLY_NAMES: phf::Map<&'static str, &'static str> = phf_map! {
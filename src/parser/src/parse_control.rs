@@ -0,0 +1,109 @@
+use crate::first_pass::read_bits::DemoParserError;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+type ProgressCallback = Arc<dyn Fn(usize, usize) + Send + Sync>;
+
+/*
+Handle for observing and stopping a running parse. Cheap to clone, all clones share the same state
+so one clone can be given to the parser (ParserInputs) and another kept for cancelling from another thread.
+
+Progress is reported in bytes of the demo processed by the second pass out of the total demo length.
+The callback is called at most once per percent of progress and may be called from any of the worker threads.
+*/
+#[derive(Clone, Default)]
+pub struct ParseControl {
+    cancelled: Arc<AtomicBool>,
+    bytes_processed: Arc<AtomicUsize>,
+    total_bytes: Arc<AtomicUsize>,
+    on_progress: Option<ProgressCallback>,
+}
+
+impl ParseControl {
+    pub fn new() -> Self {
+        ParseControl::default()
+    }
+    pub fn with_progress_callback<F: Fn(usize, usize) + Send + Sync + 'static>(mut self, callback: F) -> Self {
+        self.on_progress = Some(Arc::new(callback));
+        self
+    }
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+    // (bytes processed, total bytes)
+    pub fn progress(&self) -> (usize, usize) {
+        (self.bytes_processed.load(Ordering::Relaxed), self.total_bytes.load(Ordering::Relaxed))
+    }
+    pub(crate) fn start(&self, total_bytes: usize) {
+        self.total_bytes.store(total_bytes, Ordering::Relaxed);
+        self.bytes_processed.store(0, Ordering::Relaxed);
+    }
+    pub(crate) fn check_cancelled(&self) -> Result<(), DemoParserError> {
+        match self.is_cancelled() {
+            true => Err(DemoParserError::Cancelled),
+            false => Ok(()),
+        }
+    }
+    pub(crate) fn advance(&self, n_bytes: usize) {
+        let total = self.total_bytes.load(Ordering::Relaxed);
+        let before = self.bytes_processed.fetch_add(n_bytes, Ordering::Relaxed);
+        // Chunks in multithreaded mode can overlap slightly so don't go over the total
+        let after = (before + n_bytes).min(total);
+        if let Some(callback) = &self.on_progress {
+            if total > 0 && before.min(total) * 100 / total != after * 100 / total {
+                callback(after, total);
+            }
+        }
+    }
+    pub(crate) fn finish(&self) {
+        let (processed, total) = self.progress();
+        if processed < total {
+            self.advance(total - processed);
+        }
+    }
+}
+
+impl fmt::Debug for ParseControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (bytes_processed, total_bytes) = self.progress();
+        f.debug_struct("ParseControl")
+            .field("cancelled", &self.is_cancelled())
+            .field("bytes_processed", &bytes_processed)
+            .field("total_bytes", &total_bytes)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_progress_reported_once_per_percent() {
+        let calls = Arc::new(Mutex::new(vec![]));
+        let calls_clone = calls.clone();
+        let control = ParseControl::new().with_progress_callback(move |done, total| calls_clone.lock().unwrap().push((done, total)));
+        control.start(1000);
+        for _ in 0..100 {
+            control.advance(5);
+        }
+        assert_eq!(calls.lock().unwrap().len(), 50);
+        control.finish();
+        assert_eq!(control.progress(), (1000, 1000));
+        assert_eq!(calls.lock().unwrap().last(), Some(&(1000, 1000)));
+    }
+
+    #[test]
+    fn test_cancel_shared_between_clones() {
+        let control = ParseControl::new();
+        let handle = control.clone();
+        assert!(control.check_cancelled().is_ok());
+        handle.cancel();
+        assert!(matches!(control.check_cancelled(), Err(DemoParserError::Cancelled)));
+    }
+}
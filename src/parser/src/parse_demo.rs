@@ -4,9 +4,16 @@ use crate::first_pass::parser_settings::check_multithreadability;
 use crate::first_pass::parser_settings::{FirstPassParser, ParserInputs};
 use crate::first_pass::prop_controller::{PropController, NAME_ID, STEAMID_ID, TICK_ID};
 use crate::first_pass::read_bits::DemoParserError;
+use crate::first_pass::read_bits::ParseWarning;
+use crate::first_pass::schema::{demo_schema, describe_schema, DemoSchema, PropSchema};
+use crate::first_pass::stringtables::StringTableOutput;
+use crate::parse_control::ParseControl;
 use crate::second_pass::collect_data::ProjectileRecord;
+use crate::second_pass::event_windows::EventWindowOutput;
 use crate::second_pass::game_events::{EventField, GameEvent};
 use crate::second_pass::parser::SecondPassOutput;
+use crate::second_pass::player_key::PlayerKey;
+use crate::second_pass::prop_changes::PropChanges;
 use crate::second_pass::parser_settings::*;
 use crate::second_pass::variants::VarVec;
 use crate::second_pass::variants::{PropColumn, Variant};
@@ -26,6 +33,7 @@ pub const HEADER_ENDS_AT_BYTE: usize = 16;
 pub struct DemoOutput {
     pub df: AHashMap<u32, PropColumn>,
     pub game_events: Vec<GameEvent>,
+    pub user_messages: Vec<GameEvent>,
     pub skins: Vec<EconItem>,
     pub item_drops: Vec<EconItem>,
     pub chat_messages: Vec<ChatMessageRecord>,
@@ -42,6 +50,14 @@ pub struct DemoOutput {
     pub voice_data: Vec<(i32, CsvcMsgVoiceData)>,
     pub prop_controller: PropController,
     pub df_per_player: AHashMap<u64, AHashMap<u32, PropColumn>>,
+    // Errors that were skipped in lenient mode. Empty if the demo parsed cleanly.
+    pub warnings: Vec<ParseWarning>,
+    // Rows sampled around events. Empty unless ParserInputs::event_windows is set.
+    pub event_windows: EventWindowOutput,
+    // Filled instead of df when ParserInputs::only_prop_changes is set
+    pub prop_changes: PropChanges,
+    // Empty unless ParserInputs::wanted_string_tables is set
+    pub string_tables: StringTableOutput,
 }
 
 pub struct Parser<'a> {
@@ -63,17 +79,58 @@ impl<'a> Parser<'a> {
         }
     }
     pub fn parse_demo(&mut self, demo_bytes: &[u8]) -> Result<DemoOutput, DemoParserError> {
+        self.input.parse_control.start(demo_bytes.len());
         let mut first_pass_parser = FirstPassParser::new(&self.input);
         let first_pass_output = first_pass_parser.parse_demo(&demo_bytes, false)?;
-        if self.parsing_mode == ParsingMode::Normal
-            && check_multithreadability(&self.input.wanted_player_props)
-            && !(self.parsing_mode == ParsingMode::ForceSingleThreaded)
-            || self.parsing_mode == ParsingMode::ForceMultiThreaded
-        {
-            return self.second_pass_multi_threaded(demo_bytes, first_pass_output);
+        // These give wrong results when split into chunks, so they are never multithreaded
+        let needs_single_thread =
+            // changed(prop) compares against the previous tick, which may be in another thread's chunk
+            self.input.row_filters.iter().any(|filter| !filter.changed_props().is_empty())
+            // Same for prev(prop) in derived columns
+            || self.input.derived_columns.iter().any(|column| !column.prev_props().is_empty())
+            // Windows look back at ticks before the event, which may be in another thread's chunk
+            || !self.input.event_windows.is_empty()
+            // Updates can only be decoded from the table's creation, which is only seen by the first chunk
+            || !self.input.wanted_string_tables.is_empty();
+        let multithreaded = match self.parsing_mode {
+            ParsingMode::ForceSingleThreaded => false,
+            ParsingMode::ForceMultiThreaded => !needs_single_thread,
+            ParsingMode::Normal => check_multithreadability(&self.input.wanted_player_props) && !needs_single_thread,
+        };
+        let output = if multithreaded {
+            self.second_pass_multi_threaded(demo_bytes, first_pass_output)?
         } else {
-            self.second_pass_single_threaded(demo_bytes, first_pass_output)
-        }
+            self.second_pass_single_threaded(demo_bytes, first_pass_output)?
+        };
+        self.input.parse_control.finish();
+        Ok(output)
+    }
+    // (column name, type) for each column parse_demo would output, using only the first pass.
+    // Type is None for props that are never collected, those columns are always null.
+    pub fn prop_types(&self, demo_bytes: &[u8]) -> Result<Vec<(String, Option<Variant>)>, DemoParserError> {
+        let mut first_pass_parser = FirstPassParser::new(&self.input);
+        let first_pass_output = first_pass_parser.parse_demo(demo_bytes, true)?;
+        let prop_controller = first_pass_output.prop_controller;
+        Ok(prop_controller
+            .prop_infos
+            .iter()
+            .map(|info| (info.prop_friendly_name.clone(), prop_controller.prop_type(info)))
+            .collect())
+    }
+    // Every prop path in the demo's sendtables with its decoder and how to collect it, using only the first pass
+    pub fn describe_schema(&self, demo_bytes: &[u8]) -> Result<Vec<PropSchema>, DemoParserError> {
+        let mut first_pass_parser = FirstPassParser::new(&self.input);
+        let first_pass_output = first_pass_parser.parse_demo(demo_bytes, true)?;
+        Ok(describe_schema(first_pass_output.cls_by_id))
+    }
+    // Props, serializers and game event descriptors of the demo, compare two with diff_schemas
+    pub fn demo_schema(&self, demo_bytes: &[u8]) -> Result<DemoSchema, DemoParserError> {
+        let mut first_pass_parser = FirstPassParser::new(&self.input);
+        let first_pass_output = first_pass_parser.parse_demo(demo_bytes, true)?;
+        Ok(demo_schema(first_pass_output.cls_by_id, first_pass_output.ge_list))
+    }
+    pub fn parse_control(&self) -> &ParseControl {
+        &self.input.parse_control
     }
 
     fn second_pass_multi_threaded(&self, outer_bytes: &[u8], first_pass_output: FirstPassOutput) -> Result<DemoOutput, DemoParserError> {
@@ -109,7 +166,8 @@ impl<'a> Parser<'a> {
         let mut ids = AHashMap::default();
         for x in v{
             for f in &x.fields{
-                if f.name == "steamid"{
+                // Bots all have steamid 0
+                if f.name == "player_key"{
                     if let Some(Variant::U64(s)) = f.data{
                         match ids.get(&s) {
                             Some(_) => {},
@@ -300,7 +358,8 @@ impl<'a> Parser<'a> {
         let mut prop_controller = first_pass_output.prop_controller.clone();
         for prop in first_pass_output.added_temp_props {
             prop_controller.wanted_player_props.retain(|x| x != &prop);
-            prop_controller.prop_infos.retain(|x| &x.prop_name != &prop);
+            // Generated names are only resolved to the real name in the first pass
+            prop_controller.prop_infos.retain(|x| x.prop_name != prop && x.prop_friendly_name != prop);
         }
         let per_players: Vec<AHashMap<u64, AHashMap<u32, PropColumn>>> = second_pass_outputs.iter().map(|x| x.df_per_player.clone()).collect();
         let mut all_steamids = AHashSet::default();
@@ -328,21 +387,18 @@ impl<'a> Parser<'a> {
             player_md: second_pass_outputs.iter().flat_map(|x| x.player_md.clone()).collect(),
             roster: {
                 // Second-pass segments are sorted by ascending tick (sort_by_key(ptr) above);
-                // each captures a player's state at its last tick. Dedup by steamid keeping the
+                // each captures a player's state at its last tick. Dedup by player key keeping the
                 // LAST entry -> final name/team (after side swaps / renames).
-                let mut by_sid: std::collections::BTreeMap<u64, PlayerEndMetaData> = std::collections::BTreeMap::new();
+                let mut by_key: std::collections::BTreeMap<PlayerKey, PlayerEndMetaData> = std::collections::BTreeMap::new();
                 for o in second_pass_outputs.iter() {
                     for p in &o.roster {
-                        if let Some(sid) = p.steamid {
-                            if sid != 0 {
-                                by_sid.insert(sid, p.clone());
-                            }
-                        }
+                        by_key.insert(p.player_key, p.clone());
                     }
                 }
-                by_sid.into_values().collect()
+                by_key.into_values().collect()
             },
             game_events: second_pass_outputs.iter().flat_map(|x| x.game_events.clone()).collect(),
+            user_messages: second_pass_outputs.iter().flat_map(|x| x.user_messages.clone()).collect(),
             skins: second_pass_outputs.iter().flat_map(|x| x.skins.clone()).collect(),
             convars: second_pass_outputs.iter().flat_map(|x| x.convars.clone()).collect(),
             df: all_dfs_combined,
@@ -352,6 +408,22 @@ impl<'a> Parser<'a> {
             voice_data: second_pass_outputs.iter().flat_map(|x| x.voice_data.clone()).collect_vec(),
             df_per_player: pp,
             uniq_prop_names: all_prop_names,
+            event_windows: EventWindowOutput {
+                df: self.combine_dfs(&mut second_pass_outputs.iter().map(|x| x.event_windows.df.clone()).collect(), false),
+                keys: second_pass_outputs.iter().flat_map(|x| x.event_windows.keys.clone()).collect(),
+            },
+            prop_changes: second_pass_outputs.iter().fold(PropChanges::default(), |mut all, x| {
+                all.extend(&x.prop_changes);
+                all
+            }),
+            // Only set when parsing single-threaded
+            string_tables: second_pass_outputs.last().map(|x| x.string_tables.clone()).unwrap_or_default(),
+            warnings: first_pass_output
+                .warnings
+                .into_iter()
+                .chain(second_pass_outputs.iter().flat_map(|x| x.warnings.clone()))
+                .sorted_by_key(|x| x.byte_offset)
+                .collect(),
         }
     }
 
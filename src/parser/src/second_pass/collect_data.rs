@@ -7,13 +7,21 @@ use crate::maps::BUTTONMAP;
 use crate::maps::PLAYER_COLOR;
 use crate::second_pass::entities::EntityType;
 use crate::second_pass::parser_settings::SecondPassParser;
+use crate::second_pass::player_key::find_player_by_key;
+use crate::second_pass::player_key::insert_player;
+use crate::second_pass::player_key::PlayerKey;
+use crate::second_pass::prop_changes::PlayerLeft;
+use crate::second_pass::player_role::is_hltv_controller;
+use crate::second_pass::player_role::PlayerRole;
 use crate::second_pass::variants::PropColumn;
 use crate::second_pass::variants::VarVec;
 use ahash::AHashMap;
+use ahash::AHashSet;
 use csgoproto::maps::AGENTSMAP;
 use csgoproto::maps::PAINTKITS;
 use csgoproto::maps::STICKER_ID_TO_NAME;
 use csgoproto::maps::WEAPINDICIES;
+use std::collections::BTreeMap;
 use std::fmt;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -29,6 +37,7 @@ pub enum PropType {
     Steamid,
     Tick,
     GameTime,
+    Derived,
 }
 
 // DONT KNOW IF THESE ARE CORRECT. SEEMS TO GIVE CORRECT VALUES
@@ -58,6 +67,9 @@ pub enum CoordinateAxis {
 
 impl<'a> SecondPassParser<'a> {
     pub fn collect_entities(&mut self) {
+        if !self.event_windows.windows.is_empty() {
+            self.collect_event_windows();
+        }
         if !self.prop_controller.event_with_velocity {
             if !self.wanted_ticks.contains(&self.tick) && self.wanted_ticks.len() != 0 || self.wanted_events.len() != 0 {
                 return;
@@ -67,6 +79,8 @@ impl<'a> SecondPassParser<'a> {
             self.collect_projectiles();
             return;
         }
+        let changed_props = self.find_changed_filter_props();
+        let mut prop_changes_present = AHashMap::default();
         // iterate every player and every wanted prop name
         // if either one is missing then push None to output
         for (entity_id, player) in &self.players {
@@ -82,6 +96,29 @@ impl<'a> SecondPassParser<'a> {
                     Err(_e) => return,
                 }
             }
+            if !self.row_matches_filters(entity_id, player, &changed_props) {
+                continue;
+            }
+            if self.only_prop_changes {
+                let player_steamid = player.steamid.unwrap_or(0);
+                if !self.wanted_players.is_empty() && !self.wanted_players.contains(&player_steamid) {
+                    continue;
+                }
+                prop_changes_present.insert(player.player_key.0, player_steamid);
+                for prop_info in &self.prop_controller.prop_infos {
+                    // tick and steamid are part of every change already
+                    if matches!(prop_info.prop_type, PropType::Tick | PropType::Steamid) {
+                        continue;
+                    }
+                    let value = self.find_prop(prop_info, entity_id, player).ok();
+                    let key = (player.player_key.0, prop_info.id);
+                    if self.prop_changes_last.get(&key) != Some(&value) {
+                        self.prop_changes.push(self.tick, player.player_key.0, player_steamid, prop_info.id, value.clone());
+                        self.prop_changes_last.insert(key, value);
+                    }
+                }
+                continue;
+            }
 
             for prop_info in &self.prop_controller.prop_infos {
                 let player_steamid = match player.steamid {
@@ -91,17 +128,18 @@ impl<'a> SecondPassParser<'a> {
                 if !self.wanted_players.is_empty() && !self.wanted_players.contains(&player_steamid) {
                     continue;
                 }
-                if self.order_by_steamid && !self.df_per_player.contains_key(&player_steamid) {
-                    self.df_per_player.insert(player_steamid, AHashMap::default());
+                // By player key instead of steamid so bots (steamid 0) don't end up in the same df
+                if self.order_by_steamid && !self.df_per_player.contains_key(&player.player_key.0) {
+                    self.df_per_player.insert(player.player_key.0, AHashMap::default());
                 }
                 if self.order_by_steamid {
                     match self.find_prop(prop_info, entity_id, player) {
                         Ok(prop) => {
-                            let df_this_player = self.df_per_player.get_mut(&player.steamid.unwrap_or(0)).unwrap();
+                            let df_this_player = self.df_per_player.get_mut(&player.player_key.0).unwrap();
                             df_this_player.entry(prop_info.id).or_insert_with(|| PropColumn::new()).push(Some(prop.clone()));
                         }
                         Err(_e) => {
-                            let df_this_player = self.df_per_player.get_mut(&player.steamid.unwrap_or(0)).unwrap();
+                            let df_this_player = self.df_per_player.get_mut(&player.player_key.0).unwrap();
                             df_this_player.entry(prop_info.id).or_insert_with(|| PropColumn::new()).push(None);
                         }
                     }
@@ -118,6 +156,71 @@ impl<'a> SecondPassParser<'a> {
                 }
             }
         }
+        if !self.derived_columns.is_empty() {
+            self.update_derived_previous();
+        }
+        if self.only_prop_changes {
+            self.finish_prop_changes_tick(prop_changes_present);
+        }
+    }
+    /*
+    Players that were collected on the previous tick but not on this one (left the game or stopped
+    matching the row filters) get a left marker, and their last values are forgotten so that every
+    prop is added again if they come back. The first collected tick of each thread is marked as a
+    reset, see PropChanges.
+    */
+    fn finish_prop_changes_tick(&mut self, present: AHashMap<u64, u64>) {
+        if self.prop_changes.collected_ticks.is_empty() {
+            self.prop_changes.reset_ticks.push(self.tick);
+        }
+        for (player_key, steamid) in &self.prop_changes_present {
+            if !present.contains_key(player_key) {
+                self.prop_changes.left.push(PlayerLeft {
+                    tick: self.tick,
+                    player_key: *player_key,
+                    steamid: *steamid,
+                });
+                self.prop_changes_last.retain(|(key, _), _| key != player_key);
+            }
+        }
+        self.prop_changes_present = present;
+        self.prop_changes.collected_ticks.push(self.tick);
+    }
+
+    fn row_matches_filters(&self, entity_id: &i32, player: &PlayerMetaData, changed_props: &AHashSet<(i32, String)>) -> bool {
+        let value_of = |prop: &str| match self.row_filter_props.get(prop) {
+            Some(prop_info) => self.find_prop(prop_info, entity_id, player).ok(),
+            None => None,
+        };
+        let changed = |prop: &str| changed_props.contains(&(*entity_id, prop.to_string()));
+        self.row_filters.iter().all(|filter| filter.evaluate(&value_of, &changed))
+    }
+    // Compares every changed(prop) in the filters against the previous collected tick.
+    // Done for every player on every collected tick so that the previous value is never stale.
+    fn find_changed_filter_props(&mut self) -> AHashSet<(i32, String)> {
+        let mut changed_props = AHashSet::default();
+        if self.row_filters.is_empty() {
+            return changed_props;
+        }
+        let mut current = vec![];
+        for filter in &self.row_filters {
+            for prop in filter.changed_props() {
+                if let Some(prop_info) = self.row_filter_props.get(prop) {
+                    for (entity_id, player) in &self.players {
+                        current.push(((*entity_id, prop.to_string()), self.find_prop(prop_info, entity_id, player).ok()));
+                    }
+                }
+            }
+        }
+        for (key, value) in current {
+            match self.row_filter_previous.insert(key.clone(), value.clone()) {
+                Some(previous) if previous != value => {
+                    changed_props.insert(key);
+                }
+                _ => {}
+            }
+        }
+        changed_props
     }
 
     pub fn find_prop(&self, prop_info: &PropInfo, entity_id: &i32, player: &PlayerMetaData) -> Result<Variant, PropCollectionError> {
@@ -132,7 +235,8 @@ impl<'a> SecondPassParser<'a> {
             PropType::Button => return self.get_button_prop(&prop_info, &entity_id),
             PropType::Controller => return self.get_controller_prop(&prop_info.id, player),
             PropType::Rules => return self.get_rules_prop(prop_info),
-            PropType::GameTime => return Ok(Variant::F32(self.net_tick as f32 / 64.0)),
+            PropType::GameTime => return self.find_time_prop(prop_info.id),
+            PropType::Derived => self.create_derived_prop(prop_info, entity_id, player),
         }
     }
     pub fn get_prop_from_ent(&self, prop_id: &u32, entity_id: &i32) -> Result<Variant, PropCollectionError> {
@@ -180,6 +284,48 @@ impl<'a> SecondPassParser<'a> {
             None => return Err(PropCollectionError::RulesEntityIdNotSet),
         }
     }
+    pub fn find_time_prop(&self, prop_id: u32) -> Result<Variant, PropCollectionError> {
+        let game_time = self.net_tick as f32 * self.tick_interval;
+        match prop_id {
+            ROUND_TIME_ID => Ok(Variant::F32(game_time - self.get_round_start_time()?)),
+            CLOCK_TIME_ID => {
+                let round_start_time = self.get_round_start_time()?;
+                let round_time_limit = match self.is_freeze_period()? {
+                    true => None,
+                    false => match self.get_rules_special_prop(self.prop_controller.special_ids.round_time)? {
+                        Variant::I32(seconds) => Some(seconds as f32),
+                        Variant::U32(seconds) => Some(seconds as f32),
+                        _ => return Err(PropCollectionError::RoundTimeIncorrectVariant),
+                    },
+                };
+                Ok(Variant::F32(clock_time(game_time, round_start_time, round_time_limit)))
+            }
+            _ => Ok(Variant::F32(game_time)),
+        }
+    }
+    fn get_round_start_time(&self) -> Result<f32, PropCollectionError> {
+        match self.get_rules_special_prop(self.prop_controller.special_ids.round_start_time)? {
+            Variant::F32(t) => Ok(t),
+            _ => Err(PropCollectionError::RoundTimeIncorrectVariant),
+        }
+    }
+    fn is_freeze_period(&self) -> Result<bool, PropCollectionError> {
+        match self.get_rules_special_prop(self.prop_controller.special_ids.freeze_period) {
+            Ok(Variant::Bool(b)) => Ok(b),
+            // Prop is missing until it has been set once
+            _ => Ok(false),
+        }
+    }
+    fn get_rules_special_prop(&self, prop_id: Option<u32>) -> Result<Variant, PropCollectionError> {
+        let prop_id = match prop_id {
+            Some(id) => id,
+            None => return Err(PropCollectionError::RoundTimeSpecialIdNotSet),
+        };
+        match self.rules_entity_id {
+            Some(entid) => self.get_prop_from_ent(&prop_id, &entid),
+            None => Err(PropCollectionError::RulesEntityIdNotSet),
+        }
+    }
     pub fn get_controller_prop(&self, prop_id: &u32, player: &PlayerMetaData) -> Result<Variant, PropCollectionError> {
         match player.controller_entid {
             Some(entid) => {
@@ -205,6 +351,37 @@ impl<'a> SecondPassParser<'a> {
             None => Err(PropCollectionError::PlayerNotFound),
         }
     }
+    // Entity id of the bot controller whose pawn this controller is currently playing (human took over a bot)
+    pub fn find_controlled_bot_controller(&self, controller_entid: &i32) -> Option<i32> {
+        let controlling_bot_id = self.prop_controller.special_ids.controlling_bot?;
+        let original_controller_id = self.prop_controller.special_ids.original_controller_of_current_pawn?;
+        taken_over_bot_controller(
+            *controller_entid,
+            self.get_prop_from_ent(&controlling_bot_id, controller_entid).ok(),
+            self.get_prop_from_ent(&original_controller_id, controller_entid).ok(),
+        )
+    }
+    // Only done when controlled_by_steamid is wanted. Called once per entity update.
+    pub fn update_controlled_by_steamids(&mut self) {
+        if !self.prop_controller.prop_infos.iter().any(|p| p.id == CONTROLLED_BY_STEAMID_ID) {
+            return;
+        }
+        self.controlled_by_steamids = controlled_by_steamids(&self.players, |controller_entid| self.find_controlled_bot_controller(&controller_entid));
+    }
+    // Steamid of whoever is actually playing this pawn: the human for a bot that got taken over, else the player itself
+    pub fn find_controlled_by_steamid(&self, player: &PlayerMetaData) -> Result<Variant, PropCollectionError> {
+        let controller_entid = match player.controller_entid {
+            Some(entid) => entid,
+            None => return Err(PropCollectionError::ControllerEntityIdNotSet),
+        };
+        if let Some(steamid) = self.controlled_by_steamids.get(&controller_entid) {
+            return Ok(Variant::U64(*steamid));
+        }
+        match player.steamid {
+            Some(steamid) if !player.player_key.is_bot() => Ok(Variant::U64(steamid)),
+            _ => Err(PropCollectionError::ControllingPlayerNotFound),
+        }
+    }
     pub fn find_thrower_steamid(&self, entity_id: &i32) -> Result<u64, PropCollectionError> {
         let owner_entid = self.find_owner_entid(entity_id)?;
         let metadata = self.find_player_metadata(owner_entid as i32)?;
@@ -441,6 +618,9 @@ impl<'a> SecondPassParser<'a> {
             "entity_id" => return Ok(Variant::I32(*entity_id)),
             "is_alive" => return self.find_is_alive(entity_id),
             "user_id" => return self.get_userid(player),
+            "player_key" => Ok(Variant::U64(player.player_key.0)),
+            "role" => Ok(Variant::String(player.role.as_str().to_string())),
+            "controlled_by_steamid" => self.find_controlled_by_steamid(player),
             "is_airborne" => self.find_is_airborne(player),
             "agent_skin" => return self.find_agent_skin(player),
             "CCSPlayerController.m_iCompTeammateColor" => return self.find_player_color(player, prop_info),
@@ -452,6 +632,41 @@ impl<'a> SecondPassParser<'a> {
             _ => Err(PropCollectionError::UnknownCustomPropName),
         }
     }
+    // See DerivedColumn. Inputs are looked up like any other prop, prev(...) reads derived_previous.
+    pub fn create_derived_prop(&self, prop_info: &PropInfo, entity_id: &i32, player: &PlayerMetaData) -> Result<Variant, PropCollectionError> {
+        let column = match self.derived_columns.get(&prop_info.id) {
+            Some(column) => column,
+            None => return Err(PropCollectionError::DerivedColumnNotFound),
+        };
+        let value_of = |prop: &str| match self.derived_column_props.get(prop) {
+            Some(prop_info) => self.find_prop(prop_info, entity_id, player).ok(),
+            None => None,
+        };
+        let prev_of = |prop: &str| self.derived_previous.get(&(*entity_id, prop.to_string())).cloned();
+        match column.evaluate(&value_of, &prev_of) {
+            Some(value) => Ok(value),
+            None => Err(PropCollectionError::DerivedColumnInputMissing),
+        }
+    }
+    // Stores the values prev(...) returns on the next collected tick
+    fn update_derived_previous(&mut self) {
+        let mut current = vec![];
+        for column in self.derived_columns.values() {
+            for prop in column.prev_props() {
+                if let Some(prop_info) = self.derived_column_props.get(prop) {
+                    for (entity_id, player) in &self.players {
+                        current.push(((*entity_id, prop.to_string()), self.find_prop(prop_info, entity_id, player).ok()));
+                    }
+                }
+            }
+        }
+        for (key, value) in current {
+            match value {
+                Some(value) => self.derived_previous.insert(key, value),
+                None => self.derived_previous.remove(&key),
+            };
+        }
+    }
     pub fn get_userid(&self, player: &PlayerMetaData) -> Result<Variant, PropCollectionError> {
         for (_, st_player) in &self.stringtable_players {
             if player.steamid == Some(st_player.steamid) {
@@ -1112,15 +1327,17 @@ impl<'a> SecondPassParser<'a> {
                 },
                 _ => None,
             };
+            // Steamid or bot name hasn't arrived yet
+            let Some(player_key) = PlayerKey::new(steamid, name.as_deref()) else {
+                return Ok(());
+            };
+            let role = self.find_player_role(entity_id, team_num, player_key);
+            self.player_roles.insert(player_key, role);
+            let keep = role.is_player() || self.include_non_players;
             if let Some(e) = player_entid {
-                if e != PLAYER_ENTITY_HANDLE_MISSING && steamid != Some(0) && team_num != Some(SPECTATOR_TEAM_NUM) {
-                    match self.should_remove(steamid) {
-                        Some(eid) => {
-                            self.players.remove(&eid);
-                        }
-                        None => {}
-                    }
-                    self.players.insert(
+                if e != PLAYER_ENTITY_HANDLE_MISSING && keep {
+                    insert_player(
+                        &mut self.players,
                         e,
                         PlayerMetaData {
                             name,
@@ -1128,20 +1345,34 @@ impl<'a> SecondPassParser<'a> {
                             player_entity_id: player_entid,
                             steamid,
                             controller_entid: Some(*entity_id),
+                            player_key,
+                            role,
                         },
                     );
+                } else if !keep {
+                    // Player moved to spectator or became a coach
+                    if let Some(eid) = self.should_remove(player_key) {
+                        self.players.remove(&eid);
+                    }
                 }
             }
         }
         Ok(())
     }
-    pub fn should_remove(&self, steamid: Option<u64>) -> Option<i32> {
-        for (entid, player) in &self.players {
-            if player.steamid == steamid {
-                return Some(*entid);
-            }
-        }
-        None
+    pub fn find_player_role(&self, controller_entid: &i32, team_num: Option<u32>, player_key: PlayerKey) -> PlayerRole {
+        let coaching_team = match self.prop_controller.special_ids.coaching_team {
+            Some(id) => match self.get_prop_from_ent(&id, controller_entid) {
+                Ok(Variant::U32(t)) => Some(t),
+                Ok(Variant::I32(t)) => Some(t as u32),
+                _ => None,
+            },
+            None => None,
+        };
+        let is_hltv = is_hltv_controller(&self.hltv_userids, *controller_entid);
+        PlayerRole::new(team_num, coaching_team, is_hltv, player_key)
+    }
+    pub fn should_remove(&self, player_key: PlayerKey) -> Option<i32> {
+        find_player_by_key(&self.players, player_key)
     }
 }
 
@@ -1200,6 +1431,8 @@ pub enum PropCollectionError {
     SpecialidsActiveWeaponNotSet,
     WeaponHandleIncorrectVariant,
     UnknownCustomPropName,
+    DerivedColumnNotFound,
+    DerivedColumnInputMissing,
     UnknownCoordinateAxis,
     WeaponEntityNotFound,
     WeaponEntityWantedPropNotFound,
@@ -1223,6 +1456,9 @@ pub enum PropCollectionError {
     GloveSkinFloatConvertionError,
     GloveSkinIdxIncorrectVariant,
     GloveSkinNoSkinMapping,
+    RoundTimeSpecialIdNotSet,
+    RoundTimeIncorrectVariant,
+    ControllingPlayerNotFound,
 }
 impl std::error::Error for PropCollectionError {}
 impl fmt::Display for PropCollectionError {
@@ -1230,3 +1466,101 @@ impl fmt::Display for PropCollectionError {
         write!(f, "{:?}", self)
     }
 }
+
+/*
+m_bControllingBot and m_hOriginalControllerOfCurrentPawn of a controller to the entity id of the
+bot controller it took over. The handle points back at the controller itself when it plays its own pawn.
+*/
+pub fn taken_over_bot_controller(controller_entid: i32, controlling_bot: Option<Variant>, original_controller: Option<Variant>) -> Option<i32> {
+    if controlling_bot != Some(Variant::Bool(true)) {
+        return None;
+    }
+    match original_controller {
+        Some(Variant::U32(handle)) => {
+            let bot_controller_entid = (handle & 0x7FF) as i32;
+            if bot_controller_entid == PLAYER_ENTITY_HANDLE_MISSING || bot_controller_entid == controller_entid {
+                return None;
+            }
+            Some(bot_controller_entid)
+        }
+        _ => None,
+    }
+}
+
+// Bot controller entity id -> steamid of the human playing that bot
+pub fn controlled_by_steamids(players: &BTreeMap<i32, PlayerMetaData>, find_bot_controller: impl Fn(i32) -> Option<i32>) -> AHashMap<i32, u64> {
+    let mut controlled_by = AHashMap::default();
+    for player in players.values() {
+        if let (Some(controller_entid), Some(steamid)) = (player.controller_entid, player.steamid) {
+            if let Some(bot_controller_entid) = find_bot_controller(controller_entid) {
+                controlled_by.insert(bot_controller_entid, steamid);
+            }
+        }
+    }
+    controlled_by
+}
+
+/*
+Seconds left on the round clock. Round start time is set to when freezetime ends, so during
+freezetime (round_time_limit None) the clock counts down to it.
+*/
+pub fn clock_time(game_time: f32, round_start_time: f32, round_time_limit: Option<f32>) -> f32 {
+    match round_time_limit {
+        Some(round_time_limit) => (round_time_limit - (game_time - round_start_time)).max(0.0),
+        None => (round_start_time - game_time).max(0.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_taken_over_bot_controller() {
+        let handle = |entid: u32| Some(Variant::U32(0x123 << 11 | entid));
+        assert_eq!(taken_over_bot_controller(3, Some(Variant::Bool(true)), handle(7)), Some(7));
+        // Playing its own pawn
+        assert_eq!(taken_over_bot_controller(3, Some(Variant::Bool(true)), handle(3)), None);
+        assert_eq!(taken_over_bot_controller(3, Some(Variant::Bool(false)), handle(7)), None);
+        assert_eq!(taken_over_bot_controller(3, None, handle(7)), None);
+        assert_eq!(taken_over_bot_controller(3, Some(Variant::Bool(true)), Some(Variant::U32(PLAYER_ENTITY_HANDLE_MISSING as u32))), None);
+        assert_eq!(taken_over_bot_controller(3, Some(Variant::Bool(true)), None), None);
+    }
+
+    fn player(controller_entid: i32, steamid: u64, name: &str) -> PlayerMetaData {
+        PlayerMetaData {
+            player_entity_id: Some(controller_entid + 100),
+            steamid: Some(steamid),
+            controller_entid: Some(controller_entid),
+            name: Some(name.to_string()),
+            team_num: Some(2),
+            player_key: PlayerKey::new(Some(steamid), Some(name)).unwrap(),
+            role: PlayerRole::Player,
+        }
+    }
+
+    #[test]
+    fn test_controlled_by_steamids() {
+        let mut players = BTreeMap::new();
+        players.insert(101, player(1, 76561198000000001, "human"));
+        players.insert(102, player(2, 0, "BOT Albert"));
+        players.insert(103, player(3, 76561198000000003, "other human"));
+        players.insert(104, player(4, 0, "BOT Brian"));
+        // Controller 1 took over the bot at controller 2
+        let controlled_by = controlled_by_steamids(&players, |entid| if entid == 1 { Some(2) } else { None });
+        assert_eq!(controlled_by.len(), 1);
+        assert_eq!(controlled_by.get(&2), Some(&76561198000000001));
+    }
+
+    #[test]
+    fn test_clock_time() {
+        // Freezetime ends at 100s
+        assert_eq!(clock_time(85.0, 100.0, None), 15.0);
+        assert_eq!(clock_time(101.0, 100.0, None), 0.0);
+        // 115s round
+        assert_eq!(clock_time(100.0, 100.0, Some(115.0)), 115.0);
+        assert_eq!(clock_time(160.0, 100.0, Some(115.0)), 55.0);
+        // Round ran over the limit, bomb planted for example
+        assert_eq!(clock_time(230.0, 100.0, Some(115.0)), 0.0);
+    }
+}
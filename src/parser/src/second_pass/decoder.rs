@@ -35,6 +35,27 @@ pub struct QfMapper {
     pub idx: u32,
     pub map: AHashMap<u32, QuantalizedFloat>,
 }
+impl Decoder {
+    // Variant that decode() returns for this decoder. Keep in sync with decode()
+    pub fn output_type(&self) -> Variant {
+        match self {
+            NoscaleDecoder | FloatSimulationTimeDecoder | QuantalizedFloatDecoder(_) | FloatCoordDecoder => Variant::F32(0.0),
+            UnsignedDecoder | BaseDecoder | CentityHandleDecoder | AmmoDecoder => Variant::U32(0),
+            SignedDecoder => Variant::I32(0),
+            BooleanDecoder | ComponentDecoder | GameModeRulesDecoder => Variant::Bool(false),
+            StringDecoder | BinaryBlockDecoder => Variant::String(String::new()),
+            Unsigned64Decoder | Fixed64Decoder => Variant::U64(0),
+            Qangle3Decoder(_)
+            | VectorNoscaleDecoder
+            | QanglePitchYawDecoder
+            | QangleVarDecoder
+            | VectorNormalDecoder
+            | VectorFloatCoordDecoder
+            | QanglePresDecoder => Variant::VecXYZ([0.0; 3]),
+        }
+    }
+}
+
 impl<'a> Bitreader<'a> {
     #[inline(always)]
     pub fn decode(&mut self, decoder: &Decoder, qf_map: &QfMapper) -> Result<Variant, DemoParserError> {
@@ -920,6 +941,42 @@ mod tests {
         };
         assert_eq!(qf, correct);
     }
+
+    #[test]
+    fn test_output_type_matches_decode() {
+        let mut qf_map = QfMapper { idx: 0, map: AHashMap::default() };
+        qf_map.map.insert(0, QuantalizedFloat::new(8, None, None, Some(1.0)));
+        let decoders = [
+            QuantalizedFloatDecoder(0),
+            VectorNormalDecoder,
+            VectorNoscaleDecoder,
+            VectorFloatCoordDecoder,
+            Unsigned64Decoder,
+            CentityHandleDecoder,
+            NoscaleDecoder,
+            BooleanDecoder,
+            StringDecoder,
+            SignedDecoder,
+            UnsignedDecoder,
+            ComponentDecoder,
+            FloatCoordDecoder,
+            FloatSimulationTimeDecoder,
+            Fixed64Decoder,
+            QanglePitchYawDecoder,
+            Qangle3Decoder(32),
+            QangleVarDecoder,
+            BaseDecoder,
+            AmmoDecoder,
+            QanglePresDecoder,
+            GameModeRulesDecoder,
+            BinaryBlockDecoder,
+        ];
+        let bytes = [0; 64];
+        for decoder in decoders {
+            let decoded = Bitreader::new(&bytes).decode(&decoder, &qf_map).unwrap();
+            assert_eq!(decoded.type_name(), decoder.output_type().type_name(), "{decoder}");
+        }
+    }
 }
 
 impl fmt::Display for Decoder {
@@ -0,0 +1,363 @@
+use crate::first_pass::read_bits::DemoParserError;
+use crate::second_pass::lexer::tokenize;
+use crate::second_pass::lexer::Token;
+use crate::second_pass::row_filter::as_float;
+use crate::second_pass::variants::Variant;
+
+/*
+Column computed from other props while collecting, evaluated for every (tick, player) row next to
+the custom props. The props it reads are parsed but not output unless they are also wanted, so
+there is no need to collect raw columns only to compute one from them afterwards.
+    speed_2d = sqrt(velocity_X^2 + velocity_Y^2)
+    hp_lost = prev(health) - health
+    eye_z = Z + view_offset_z
+
+Supports + - * / ^ (right associative, binds tighter than unary minus like in python), parentheses,
+numbers, props and the functions sqrt, abs, floor, ceil, round, min and max. prev(prop) is the
+value at the previous collected tick for the same player. Props can be real, friendly or generated
+names and columns defined earlier in the list.
+
+The result is always F32. It's null if any prop is missing or not a number (bools are 1 and 0).
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct DerivedColumn {
+    pub name: String,
+    pub expr: DerivedExpr,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Function {
+    Sqrt,
+    Abs,
+    Floor,
+    Ceil,
+    Round,
+    Min,
+    Max,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DerivedExpr {
+    Number(f64),
+    Prop(String),
+    Prev(String),
+    Neg(Box<DerivedExpr>),
+    Binary(BinaryOp, Box<DerivedExpr>, Box<DerivedExpr>),
+    Call(Function, Vec<DerivedExpr>),
+}
+
+impl DerivedColumn {
+    pub fn new(name: &str, expr: &str) -> Result<Self, DemoParserError> {
+        if name.is_empty() {
+            return Err(DemoParserError::InvalidDerivedColumn(format!("missing column name in {:?}", expr)));
+        }
+        Ok(DerivedColumn {
+            name: name.to_string(),
+            expr: DerivedExpr::parse(expr)?,
+        })
+    }
+    // "name = expression"
+    pub fn parse(column: &str) -> Result<Self, DemoParserError> {
+        match column.split_once('=') {
+            Some((name, expr)) => DerivedColumn::new(name.trim(), expr),
+            None => Err(DemoParserError::InvalidDerivedColumn(format!("expected name = expression but found {:?}", column))),
+        }
+    }
+    // Every prop the column reads, including the ones inside prev(...)
+    pub fn props(&self) -> Vec<&str> {
+        let mut props = vec![];
+        self.expr.visit_props(&mut |prop, _| {
+            if !props.contains(&prop) {
+                props.push(prop)
+            }
+        });
+        props
+    }
+    // Props used inside prev(...)
+    pub fn prev_props(&self) -> Vec<&str> {
+        let mut props = vec![];
+        self.expr.visit_props(&mut |prop, is_prev| {
+            if is_prev && !props.contains(&prop) {
+                props.push(prop)
+            }
+        });
+        props
+    }
+    /*
+    value_of returns the value of a prop for the row being computed and prev_of the value at
+    the previous collected tick, None if missing.
+    */
+    pub fn evaluate(&self, value_of: &impl Fn(&str) -> Option<Variant>, prev_of: &impl Fn(&str) -> Option<Variant>) -> Option<Variant> {
+        let result = self.expr.evaluate(value_of, prev_of)?;
+        match result.is_finite() {
+            true => Some(Variant::F32(result as f32)),
+            false => None,
+        }
+    }
+}
+
+impl DerivedExpr {
+    pub fn parse(expr: &str) -> Result<Self, DemoParserError> {
+        let tokens = tokenize(expr, DemoParserError::InvalidDerivedColumn)?;
+        let mut parser = ExprParser { tokens, pos: 0 };
+        let parsed = parser.parse_sum()?;
+        match parser.tokens.get(parser.pos) {
+            None => Ok(parsed),
+            Some(token) => Err(DemoParserError::InvalidDerivedColumn(format!("unexpected {:?} in {:?}", token, expr))),
+        }
+    }
+    fn visit_props<'a>(&'a self, f: &mut impl FnMut(&'a str, bool)) {
+        match self {
+            DerivedExpr::Number(_) => {}
+            DerivedExpr::Prop(prop) => f(prop, false),
+            DerivedExpr::Prev(prop) => f(prop, true),
+            DerivedExpr::Neg(inner) => inner.visit_props(f),
+            DerivedExpr::Binary(_, left, right) => {
+                left.visit_props(f);
+                right.visit_props(f);
+            }
+            DerivedExpr::Call(_, args) => args.iter().for_each(|arg| arg.visit_props(f)),
+        }
+    }
+    fn evaluate(&self, value_of: &impl Fn(&str) -> Option<Variant>, prev_of: &impl Fn(&str) -> Option<Variant>) -> Option<f64> {
+        match self {
+            DerivedExpr::Number(n) => Some(*n),
+            DerivedExpr::Prop(prop) => to_number(&value_of(prop)?),
+            DerivedExpr::Prev(prop) => to_number(&prev_of(prop)?),
+            DerivedExpr::Neg(inner) => Some(-inner.evaluate(value_of, prev_of)?),
+            DerivedExpr::Binary(op, left, right) => {
+                let left = left.evaluate(value_of, prev_of)?;
+                let right = right.evaluate(value_of, prev_of)?;
+                Some(match op {
+                    BinaryOp::Add => left + right,
+                    BinaryOp::Sub => left - right,
+                    BinaryOp::Mul => left * right,
+                    BinaryOp::Div => left / right,
+                    BinaryOp::Pow => left.powf(right),
+                })
+            }
+            DerivedExpr::Call(function, args) => {
+                let args = args.iter().map(|arg| arg.evaluate(value_of, prev_of)).collect::<Option<Vec<f64>>>()?;
+                match function {
+                    Function::Sqrt => Some(args[0].sqrt()),
+                    Function::Abs => Some(args[0].abs()),
+                    Function::Floor => Some(args[0].floor()),
+                    Function::Ceil => Some(args[0].ceil()),
+                    Function::Round => Some(args[0].round()),
+                    Function::Min => args.into_iter().reduce(f64::min),
+                    Function::Max => args.into_iter().reduce(f64::max),
+                }
+            }
+        }
+    }
+}
+
+fn to_number(v: &Variant) -> Option<f64> {
+    match v {
+        Variant::Bool(b) => Some(*b as i32 as f64),
+        _ => as_float(v),
+    }
+}
+
+impl Function {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "sqrt" => Some(Function::Sqrt),
+            "abs" => Some(Function::Abs),
+            "floor" => Some(Function::Floor),
+            "ceil" => Some(Function::Ceil),
+            "round" => Some(Function::Round),
+            "min" => Some(Function::Min),
+            "max" => Some(Function::Max),
+            _ => None,
+        }
+    }
+    // None for any number of arguments (at least one)
+    fn n_args(&self) -> Option<usize> {
+        match self {
+            Function::Min | Function::Max => None,
+            _ => Some(1),
+        }
+    }
+}
+
+/*
+Recursive descent, lowest precedence first:
+    sum     = product (("+" | "-") product)*
+    product = unary (("*" | "/") unary)*
+    unary   = "-" unary | power
+    power   = primary ["^" unary]
+    primary = number | "(" sum ")" | "prev" "(" ident ")" | function "(" sum ("," sum)* ")" | ident
+*/
+struct ExprParser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl ExprParser {
+    fn parse_sum(&mut self) -> Result<DerivedExpr, DemoParserError> {
+        let mut left = self.parse_product()?;
+        while let Some(op) = self.eat_op(&[BinaryOp::Add, BinaryOp::Sub]) {
+            left = DerivedExpr::Binary(op, Box::new(left), Box::new(self.parse_product()?));
+        }
+        Ok(left)
+    }
+    fn parse_product(&mut self) -> Result<DerivedExpr, DemoParserError> {
+        let mut left = self.parse_unary()?;
+        while let Some(op) = self.eat_op(&[BinaryOp::Mul, BinaryOp::Div]) {
+            left = DerivedExpr::Binary(op, Box::new(left), Box::new(self.parse_unary()?));
+        }
+        Ok(left)
+    }
+    fn parse_unary(&mut self) -> Result<DerivedExpr, DemoParserError> {
+        if self.eat_op(&[BinaryOp::Sub]).is_some() {
+            return Ok(DerivedExpr::Neg(Box::new(self.parse_unary()?)));
+        }
+        self.parse_power()
+    }
+    fn parse_power(&mut self) -> Result<DerivedExpr, DemoParserError> {
+        let base = self.parse_primary()?;
+        if self.eat_op(&[BinaryOp::Pow]).is_some() {
+            return Ok(DerivedExpr::Binary(BinaryOp::Pow, Box::new(base), Box::new(self.parse_unary()?)));
+        }
+        Ok(base)
+    }
+    fn parse_primary(&mut self) -> Result<DerivedExpr, DemoParserError> {
+        match self.next() {
+            Some(Token::Number(text)) => match text.parse::<f64>() {
+                Ok(n) => Ok(DerivedExpr::Number(n)),
+                Err(_) => Err(DemoParserError::InvalidDerivedColumn(format!("invalid number {:?}", text))),
+            },
+            Some(Token::LParen) => {
+                let expr = self.parse_sum()?;
+                self.expect(&Token::RParen)?;
+                Ok(expr)
+            }
+            Some(Token::Ident(name)) if self.tokens.get(self.pos) == Some(&Token::LParen) => {
+                self.pos += 1;
+                if name == "prev" {
+                    let prop = match self.next() {
+                        Some(Token::Ident(prop)) => prop,
+                        other => return Err(unexpected(other, "prop name")),
+                    };
+                    self.expect(&Token::RParen)?;
+                    return Ok(DerivedExpr::Prev(prop));
+                }
+                let function = match Function::from_name(&name) {
+                    Some(function) => function,
+                    None => return Err(DemoParserError::InvalidDerivedColumn(format!("unknown function {:?}", name))),
+                };
+                let mut args = vec![self.parse_sum()?];
+                while self.eat(&Token::Comma) {
+                    args.push(self.parse_sum()?);
+                }
+                self.expect(&Token::RParen)?;
+                if function.n_args().is_some_and(|n| n != args.len()) {
+                    return Err(DemoParserError::InvalidDerivedColumn(format!("{} takes one argument but got {}", name, args.len())));
+                }
+                Ok(DerivedExpr::Call(function, args))
+            }
+            Some(Token::Ident(name)) => Ok(DerivedExpr::Prop(name)),
+            other => Err(unexpected(other, "number, prop or (")),
+        }
+    }
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.tokens.get(self.pos) == Some(token) {
+            self.pos += 1;
+            return true;
+        }
+        false
+    }
+    fn eat_op(&mut self, ops: &[BinaryOp]) -> Option<BinaryOp> {
+        let op = match self.tokens.get(self.pos)? {
+            Token::Plus => BinaryOp::Add,
+            Token::Minus => BinaryOp::Sub,
+            Token::Star => BinaryOp::Mul,
+            Token::Slash => BinaryOp::Div,
+            Token::Caret => BinaryOp::Pow,
+            _ => return None,
+        };
+        if !ops.contains(&op) {
+            return None;
+        }
+        self.pos += 1;
+        Some(op)
+    }
+    fn expect(&mut self, token: &Token) -> Result<(), DemoParserError> {
+        match self.next() {
+            Some(t) if &t == token => Ok(()),
+            other => Err(unexpected(other, &format!("{:?}", token))),
+        }
+    }
+}
+
+fn unexpected(found: Option<Token>, expected: &str) -> DemoParserError {
+    match found {
+        Some(token) => DemoParserError::InvalidDerivedColumn(format!("expected {} but found {:?}", expected, token)),
+        None => DemoParserError::InvalidDerivedColumn(format!("expected {} but the expression ended", expected)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_prev(_: &str) -> Option<Variant> {
+        None
+    }
+
+    #[test]
+    fn test_derived_column_parse() {
+        let column = DerivedColumn::parse("speed_2d = sqrt(velocity_X^2 + velocity_Y^2)").unwrap();
+        assert_eq!(column.name, "speed_2d");
+        assert_eq!(column.props(), vec!["velocity_X", "velocity_Y"]);
+        let column = DerivedColumn::parse("hp_lost = prev(health) - CCSPlayerPawn.m_iHealth").unwrap();
+        assert_eq!(column.props(), vec!["health", "CCSPlayerPawn.m_iHealth"]);
+        assert_eq!(column.prev_props(), vec!["health"]);
+        assert_eq!(DerivedExpr::parse("-2^2").unwrap().evaluate(&|_| None, &no_prev), Some(-4.0));
+        assert_eq!(DerivedExpr::parse("2^3^2").unwrap().evaluate(&|_| None, &no_prev), Some(512.0));
+        assert_eq!(DerivedExpr::parse("1 + 2 * 3 - 4 / 2").unwrap().evaluate(&|_| None, &no_prev), Some(5.0));
+        assert!(DerivedColumn::parse("sqrt(X)").is_err());
+        assert!(DerivedColumn::parse("a = sqrt(X, Y)").is_err());
+        assert!(DerivedColumn::parse("a = foo(X)").is_err());
+        assert!(DerivedColumn::parse("a = (X + 1").is_err());
+        assert!(DerivedColumn::parse("a = X +").is_err());
+    }
+
+    #[test]
+    fn test_derived_column_evaluate() {
+        let row = |prop: &str| match prop {
+            "velocity_X" => Some(Variant::F32(3.0)),
+            "velocity_Y" => Some(Variant::F32(4.0)),
+            "health" => Some(Variant::I32(70)),
+            "is_scoped" => Some(Variant::Bool(true)),
+            "name" => Some(Variant::String("player".to_string())),
+            _ => None,
+        };
+        let prev = |prop: &str| match prop {
+            "health" => Some(Variant::I32(100)),
+            _ => None,
+        };
+        let evaluate = |column: &str| DerivedColumn::parse(column).unwrap().evaluate(&row, &prev);
+        assert_eq!(evaluate("speed_2d = sqrt(velocity_X^2 + velocity_Y^2)"), Some(Variant::F32(5.0)));
+        assert_eq!(evaluate("hp_lost = prev(health) - health"), Some(Variant::F32(30.0)));
+        assert_eq!(evaluate("scoped_hp = is_scoped * max(health, 80, 10)"), Some(Variant::F32(80.0)));
+        assert_eq!(evaluate("missing = health + Z"), None);
+        assert_eq!(evaluate("string = name + 1"), None);
+        assert_eq!(evaluate("div_zero = health / 0"), None);
+    }
+}
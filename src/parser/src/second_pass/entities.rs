@@ -11,6 +11,8 @@ use crate::second_pass::game_events::GameEventInfo;
 use crate::second_pass::other_netmessages::Class;
 use crate::second_pass::parser_settings::SecondPassParser;
 use crate::second_pass::path_ops::*;
+use crate::second_pass::player_key::PlayerKey;
+use crate::second_pass::player_role::PlayerRole;
 use crate::second_pass::variants::Variant;
 use ahash::AHashMap;
 use csgoproto::CsvcMsgPacketEntities;
@@ -35,6 +37,8 @@ pub struct PlayerMetaData {
     pub controller_entid: Option<i32>,
     pub name: Option<String>,
     pub team_num: Option<u32>,
+    pub player_key: PlayerKey,
+    pub role: PlayerRole,
 }
 #[derive(Debug, Clone, PartialEq)]
 pub enum EntityType {
@@ -57,7 +61,7 @@ impl<'a> SecondPassParser<'a> {
             return Ok(());
         }
         let msg = match CsvcMsgPacketEntities::decode(bytes) {
-            Err(_) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
             Ok(msg) => msg,
         };
 
@@ -111,13 +115,22 @@ impl<'a> SecondPassParser<'a> {
         events_to_emit: &mut Vec<GameEventInfo>,
         is_fullpacket: bool,
     ) -> Result<(), DemoParserError> {
-        let n_updates = self.parse_paths(bitreader)?;
-        let n_updated_values = self.decode_entity_update(bitreader, entity_id, n_updates, is_fullpacket, is_baseline, events_to_emit)?;
+        let n_updates = self.parse_paths(bitreader).map_err(|e| self.add_entity_error_context(e, entity_id))?;
+        let n_updated_values = self
+            .decode_entity_update(bitreader, entity_id, n_updates, is_fullpacket, is_baseline, events_to_emit)
+            .map_err(|e| self.add_entity_error_context(e, entity_id))?;
         if n_updated_values > 0 {
             self.gather_extra_info(&entity_id, is_baseline)?;
         }
         Ok(())
     }
+    fn add_entity_error_context(&self, e: DemoParserError, entity_id: i32) -> DemoParserError {
+        let class_name = match self.entities.get(entity_id as usize) {
+            Some(Some(entity)) => self.cls_by_id.get(entity.cls_id as usize).map(|cls| cls.name.clone()),
+            _ => None,
+        };
+        e.with_context(|context| context.class_name = class_name)
+    }
     pub fn parse_paths(&mut self, bitreader: &mut Bitreader) -> Result<usize, DemoParserError> {
         /*
         Create a field path by decoding using a Huffman tree.
@@ -232,10 +245,11 @@ impl<'a> SecondPassParser<'a> {
         };
 
         for path in self.paths.iter().take(n_updates) {
-            let field = find_field(&path, &class.serializer)?;
+            let add_path = |e: DemoParserError| e.with_context(|context| context.field_path = Some(path.path.iter().take(path.last + 1).copied().collect()));
+            let field = find_field(&path, &class.serializer).map_err(add_path)?;
             let field_info = get_propinfo(&field, path);
-            let decoder = get_decoder_from_field(field)?;
-            let result = bitreader.decode(&decoder, self.qf_mapper)?;
+            let decoder = get_decoder_from_field(field).map_err(add_path)?;
+            let result = bitreader.decode(&decoder, self.qf_mapper).map_err(add_path)?;
 
             // listen_to_props()
             if self.list_props {
@@ -0,0 +1,270 @@
+use crate::first_pass::prop_controller::PropInfo;
+use crate::second_pass::collect_data::PropType;
+use crate::second_pass::game_events::EventField;
+use crate::second_pass::game_events::GameEvent;
+use crate::second_pass::parser_settings::SecondPassParser;
+use crate::second_pass::player_key::PlayerKey;
+use crate::second_pass::variants::PropColumn;
+use crate::second_pass::variants::Variant;
+use ahash::AHashMap;
+use std::collections::VecDeque;
+
+/*
+Props sampled at ticks relative to game events, in the same pass that parses the events.
+For every event named event_name, each player in the event (attacker, user, assister ...) is
+sampled at event tick + start, event tick + start + step ... up to event tick + end. Events
+without players sample every player.
+
+Ticks before the event are taken from a short history of recently collected ticks, so the
+ticks don't have to be known before parsing like with wanted_ticks. If nothing was collected
+at exactly the wanted tick, the values from the closest earlier collected tick are used.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventWindow {
+    pub event_name: String,
+    pub start: i32,
+    pub end: i32,
+    pub step: i32,
+}
+
+impl EventWindow {
+    pub fn new(event_name: &str, start: i32, end: i32, step: i32) -> Self {
+        EventWindow {
+            event_name: event_name.to_string(),
+            start,
+            end,
+            step,
+        }
+    }
+    pub fn offsets(&self) -> Vec<i32> {
+        (self.start..=self.end).step_by(self.step.max(1) as usize).collect()
+    }
+}
+
+// Which event (and which player in it) a sampled row belongs to
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventWindowKey {
+    pub event_name: String,
+    // Index among the game_events with the same name
+    pub event_index: usize,
+    pub event_tick: i32,
+    pub offset: i32,
+    // From the event's <role>_steamid field, for example "attacker" or "user". None for events without players.
+    pub role: Option<String>,
+}
+
+// One row per sampled (event, player, offset). keys[i] belongs to row i of df.
+#[derive(Debug, Clone, Default)]
+pub struct EventWindowOutput {
+    pub df: AHashMap<u32, PropColumn>,
+    pub keys: Vec<EventWindowKey>,
+}
+
+// Value of every prop in prop_infos, by player key (bots all have steamid 0)
+type Snapshot = AHashMap<PlayerKey, Vec<Option<Variant>>>;
+
+#[derive(Debug, Clone, Default)]
+pub struct EventWindowState {
+    pub windows: Vec<EventWindow>,
+    pub output: EventWindowOutput,
+    // (tick, snapshot) for recently collected ticks
+    history: VecDeque<(i32, Snapshot)>,
+    pending: Vec<PendingWindow>,
+    events_seen: usize,
+    event_counts: AHashMap<String, usize>,
+}
+
+#[derive(Debug, Clone)]
+struct PendingWindow {
+    event_name: String,
+    event_index: usize,
+    event_tick: i32,
+    players: Vec<(String, PlayerKey)>,
+    offsets: VecDeque<i32>,
+}
+
+impl EventWindowState {
+    pub fn new(windows: Vec<EventWindow>) -> Self {
+        EventWindowState {
+            windows,
+            ..Default::default()
+        }
+    }
+    fn start_windows(&mut self, events: &[GameEvent]) {
+        for event in events {
+            if !self.windows.iter().any(|w| w.event_name == event.name) {
+                continue;
+            }
+            let counter = self.event_counts.entry(event.name.clone()).or_insert(0);
+            let event_index = *counter;
+            *counter += 1;
+            let players = event_players(event);
+            for window in self.windows.iter().filter(|w| w.event_name == event.name) {
+                self.pending.push(PendingWindow {
+                    event_name: event.name.clone(),
+                    event_index,
+                    event_tick: event.tick,
+                    players: players.clone(),
+                    offsets: window.offsets().into(),
+                });
+            }
+        }
+    }
+    fn emit_due_samples(&mut self, current_tick: i32, prop_infos: &[PropInfo]) {
+        for window in &mut self.pending {
+            while let Some(offset) = window.offsets.front().copied() {
+                let sample_tick = window.event_tick + offset;
+                if sample_tick > current_tick {
+                    break;
+                }
+                window.offsets.pop_front();
+                let Some((_, snapshot)) = self.history.iter().rev().find(|(tick, _)| *tick <= sample_tick) else {
+                    continue;
+                };
+                let players: Vec<(Option<String>, PlayerKey)> = if window.players.is_empty() {
+                    let mut player_keys: Vec<PlayerKey> = snapshot.keys().copied().collect();
+                    player_keys.sort();
+                    player_keys.into_iter().map(|player_key| (None, player_key)).collect()
+                } else {
+                    window.players.iter().map(|(role, player_key)| (Some(role.clone()), *player_key)).collect()
+                };
+                for (role, player_key) in players {
+                    // Players that are not in the game at that tick are left out
+                    let Some(values) = snapshot.get(&player_key) else {
+                        continue;
+                    };
+                    for (prop_info, value) in prop_infos.iter().zip(values) {
+                        let value = match prop_info.prop_type {
+                            PropType::Tick => Some(Variant::I32(sample_tick)),
+                            _ => value.clone(),
+                        };
+                        self.output.df.entry(prop_info.id).or_insert_with(PropColumn::new).push(value);
+                    }
+                    self.output.keys.push(EventWindowKey {
+                        event_name: window.event_name.clone(),
+                        event_index: window.event_index,
+                        event_tick: window.event_tick,
+                        offset,
+                        role,
+                    });
+                }
+            }
+        }
+        self.pending.retain(|window| !window.offsets.is_empty());
+    }
+    // Drops ticks that no pending or future window can ask for. Keeps one tick before that for
+    // windows that start between two collected ticks.
+    fn prune_history(&mut self, current_tick: i32) {
+        let lookback = self.windows.iter().map(|w| w.start).min().unwrap_or(0).min(0);
+        let oldest_wanted = self
+            .pending
+            .iter()
+            .filter_map(|w| w.offsets.front().map(|offset| w.event_tick + offset))
+            .fold(current_tick + lookback, i32::min);
+        while self.history.len() >= 2 && self.history[1].0 <= oldest_wanted {
+            self.history.pop_front();
+        }
+    }
+}
+
+// (role, player key) for every <role>_steamid field in the event, bots are told apart by <role>_name
+fn event_players(event: &GameEvent) -> Vec<(String, PlayerKey)> {
+    let mut players = vec![];
+    for field in &event.fields {
+        if let Some(role) = field.name.strip_suffix("_steamid") {
+            let steamid = match &field.data {
+                Some(Variant::String(s)) => s.parse::<u64>().ok(),
+                Some(Variant::U64(s)) => Some(*s),
+                _ => None,
+            };
+            let name_field = format!("{}_name", role);
+            let name = match event.fields.iter().find(|f| f.name == name_field) {
+                Some(EventField {
+                    data: Some(Variant::String(name)),
+                    ..
+                }) => Some(name.as_str()),
+                _ => None,
+            };
+            if let Some(player_key) = PlayerKey::new(steamid, name) {
+                players.push((role.to_string(), player_key));
+            }
+        }
+    }
+    players
+}
+
+impl<'a> SecondPassParser<'a> {
+    // Called on every tick with entities, before any other collection.
+    pub fn collect_event_windows(&mut self) {
+        let mut snapshot = Snapshot::default();
+        for (entity_id, player) in &self.players {
+            let values = self.prop_controller.prop_infos.iter().map(|info| self.find_prop(info, entity_id, player).ok()).collect();
+            snapshot.insert(player.player_key, values);
+        }
+        let state = &mut self.event_windows;
+        if state.history.back().is_some_and(|(tick, _)| *tick == self.tick) {
+            state.history.pop_back();
+        }
+        state.history.push_back((self.tick, snapshot));
+
+        state.start_windows(&self.game_events[state.events_seen..]);
+        state.events_seen = self.game_events.len();
+        state.emit_due_samples(self.tick, &self.prop_controller.prop_infos);
+        state.prune_history(self.tick);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_window_offsets() {
+        assert_eq!(EventWindow::new("player_death", -64, 0, 16).offsets(), vec![-64, -48, -32, -16, 0]);
+        assert_eq!(EventWindow::new("player_death", -3, 3, 4).offsets(), vec![-3, 1]);
+        assert_eq!(EventWindow::new("player_death", 0, 0, 0).offsets(), vec![0]);
+    }
+
+    #[test]
+    fn test_event_players() {
+        let event = GameEvent {
+            name: "player_death".to_string(),
+            tick: 100,
+            fields: vec![
+                EventField {
+                    name: "attacker_steamid".to_string(),
+                    data: Some(Variant::String("76561198000000001".to_string())),
+                },
+                EventField {
+                    name: "user_steamid".to_string(),
+                    data: Some(Variant::String("76561198000000002".to_string())),
+                },
+                EventField {
+                    name: "assister_steamid".to_string(),
+                    data: None,
+                },
+            ],
+        };
+        assert_eq!(
+            event_players(&event),
+            vec![("attacker".to_string(), PlayerKey(76561198000000001)), ("user".to_string(), PlayerKey(76561198000000002))]
+        );
+
+        // Bots are found by name
+        let event = GameEvent {
+            name: "player_death".to_string(),
+            tick: 100,
+            fields: vec![
+                EventField {
+                    name: "user_steamid".to_string(),
+                    data: Some(Variant::String("0".to_string())),
+                },
+                EventField {
+                    name: "user_name".to_string(),
+                    data: Some(Variant::String("BOT Albert".to_string())),
+                },
+            ],
+        };
+        assert_eq!(event_players(&event), vec![("user".to_string(), PlayerKey::new(Some(0), Some("BOT Albert")).unwrap())]);
+    }
+}
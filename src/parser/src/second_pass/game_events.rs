@@ -5,7 +5,6 @@ use crate::first_pass::prop_controller::ITEM_PURCHASE_COST;
 use crate::first_pass::prop_controller::ITEM_PURCHASE_COUNT;
 use crate::first_pass::prop_controller::ITEM_PURCHASE_DEF_IDX;
 use crate::first_pass::prop_controller::ITEM_PURCHASE_NEW_DEF_IDX;
-use crate::first_pass::prop_controller::SPECTATOR_TEAM_NUM;
 use crate::first_pass::prop_controller::WEAPON_FLOAT;
 use crate::first_pass::prop_controller::WEAPON_PAINT_SEED;
 use crate::first_pass::read_bits::DemoParserError;
@@ -13,6 +12,8 @@ use crate::first_pass::sendtables::Field;
 use crate::first_pass::sendtables::FieldInfo;
 use crate::first_pass::stringtables::UserInfo;
 use crate::maps::HIT_GROUP;
+use crate::maps::VOTE_FAILED_REASON;
+use crate::maps::VOTE_ISSUE;
 use crate::maps::ROUND_WIN_REASON;
 use crate::maps::ROUND_WIN_REASON_TO_WINNER;
 use crate::second_pass::collect_data::PropType;
@@ -20,6 +21,7 @@ use crate::second_pass::entities::Entity;
 use crate::second_pass::entities::PlayerMetaData;
 use crate::second_pass::parser_settings::SecondPassParser;
 use crate::second_pass::parser_settings::SpecialIDs;
+use crate::second_pass::player_key::PlayerKey;
 use crate::second_pass::variants::*;
 use csgoproto::CMsgPlayerBulletHit;
 use csgoproto::CMsgTeFireBullets;
@@ -27,7 +29,12 @@ use csgoproto::csvc_msg_game_event::KeyT;
 use csgoproto::maps::WEAPINDICIES;
 use csgoproto::CUserMessageSayText;
 use csgoproto::CUserMessageSayText2;
+use csgoproto::CcsUsrMsgCallVoteFailed;
+use csgoproto::CcsUsrMsgRadioText;
 use csgoproto::CcsUsrMsgServerRankUpdate;
+use csgoproto::CcsUsrMsgVoteFailed;
+use csgoproto::CcsUsrMsgVotePass;
+use csgoproto::CcsUsrMsgVoteStart;
 use csgoproto::CnetMsgSetConVar;
 use csgoproto::CsvcMsgGameEvent;
 use itertools::Itertools;
@@ -69,13 +76,15 @@ pub enum GameEventInfo {
     WeaponCreateDefIdx((Variant, i32, u32)),
     WeaponPurchaseCount((Variant, i32, u32)),
     WeaponCreateDefIdxNew((Variant, i32, u32)),
-    PlayerConnect(i32)
+    PlayerConnect(i32),
+    BotTakeover(i32),
 }
 
 static ENTITIES_FIRST_EVENTS: &'static [&str] = &["inferno_startburn", "decoy_started", "inferno_expire"];
 static REMOVEDEVENTS: &'static [&str] = &["server_cvar", "player_connect"];
 
 const ENTITYIDNONE: i32 = 2047;
+const RADIO_STRING_PREFIX: &str = "#Cstrike_TitlesTXT_";
 // https://developer.valvesoftware.com/wiki/SteamID
 const STEAMID64INDIVIDUALIDENTIFIER: u64 = 0x0110000100000000;
 
@@ -87,7 +96,7 @@ impl<'a> SecondPassParser<'a> {
 
         let event = match CsvcMsgGameEvent::decode(bytes) {
             Ok(event) => event,
-            Err(_) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
         };
         // Check if this events id is found in our game event list
         let event_desc = match self.ge_list.get(&event.eventid()) {
@@ -99,6 +108,22 @@ impl<'a> SecondPassParser<'a> {
         if let Some(event_name) = &event_desc.name {
             self.game_events_counter.insert(event_name.to_owned());
         }
+        // Pings are also emitted as a simplified custom event
+        if event_desc.name() == "player_ping" {
+            self.game_events_counter.insert("ping".to_string());
+            if self.wanted_events.contains(&"ping".to_string()) || self.wanted_events.first() == Some(&"all".to_string()) {
+                let ping_fields = event_desc
+                    .keys
+                    .iter()
+                    .zip(&event.keys)
+                    .map(|(desc, key)| EventField {
+                        name: desc.name().to_owned(),
+                        data: parse_key(key),
+                    })
+                    .collect();
+                self.create_custom_event_ping(ping_fields)?;
+            }
+        }
         // Return early if this is not a wanted event.
         if !self.wanted_events.contains(&event_desc.name().to_string()) && self.wanted_events.first() != Some(&"all".to_string()) {
             return Ok(None);
@@ -311,8 +336,8 @@ impl<'a> SecondPassParser<'a> {
                 PropType::Team => self.find_other_team_props(&prop_info),
                 PropType::Rules => self.find_other_rules_props(&prop_info),
                 PropType::GameTime => vec![EventField {
-                    data: Some(Variant::F32(self.net_tick as f32 / 64.0)),
-                    name: "game_time".to_string(),
+                    data: self.find_time_prop(prop_info.id).ok(),
+                    name: prop_info.prop_friendly_name.clone(),
                 }],
                 _ => vec![],
             };
@@ -465,7 +490,7 @@ impl<'a> SecondPassParser<'a> {
         }
         let convar = match CnetMsgSetConVar::decode(bytes) {
             Ok(m) => m,
-            Err(_e) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
         };
         if let Some(convars) = &convar.convars {
             let mut fields = vec![];
@@ -534,6 +559,7 @@ impl<'a> SecondPassParser<'a> {
             self.create_custom_event_weapon_purchase(&events);
         }
         self.create_custom_event_weapon_sold(&events);
+        self.create_custom_event_bot_takeover(&events);
         Ok(())
     }
     fn handle_player_connect(&mut self, events: &[GameEventInfo]) -> Result<(), DemoParserError>{
@@ -581,9 +607,14 @@ impl<'a> SecondPassParser<'a> {
                     },
                     _ => None,
                 };
+                // Steamid or bot name hasn't arrived yet
+                let Some(player_key) = PlayerKey::new(steamid, name.as_deref()) else {
+                    continue;
+                };
+                let role = self.find_player_role(entity_id, team_num, player_key);
                 if let Some(e) = player_entid {
-                    if e != PLAYER_ENTITY_HANDLE_MISSING && steamid != Some(0) && team_num != Some(SPECTATOR_TEAM_NUM) {
-                        match self.should_remove(steamid) {
+                    if e != PLAYER_ENTITY_HANDLE_MISSING && (role.is_player() || self.include_non_players) {
+                        match self.should_remove(player_key) {
                             Some(eid) => {
                                 self.players.remove(&eid);
                             }
@@ -595,8 +626,10 @@ impl<'a> SecondPassParser<'a> {
                             player_entity_id: player_entid,
                             steamid,
                             controller_entid: Some(*entity_id),
+                            player_key,
+                            role,
                         };
-                        if self.players.iter().all(|x| x.1.steamid != steamid){
+                        if self.players.iter().all(|x| x.1.player_key != player_key){
                             self.create_custom_event_player_connect(&p)?;
                         }
                         self.players.insert(e,p.clone());
@@ -982,7 +1015,7 @@ impl<'a> SecondPassParser<'a> {
         }
         let chat_msg = match CUserMessageSayText2::decode(msg_bytes) {
             Ok(msg) => msg,
-            Err(_) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
         };
         let mut fields = vec![];
         let controller_id = chat_msg.entityindex();
@@ -1011,6 +1044,81 @@ impl<'a> SecondPassParser<'a> {
         self.game_events.push(ge);
         Ok(())
     }
+    /*
+    Radio commands ("Enemy spotted", "Need backup" ...) from CS_UM_RadioText as the event "radio_message".
+    client is the player slot of the sender, like the slots in the vote messages.
+    */
+    pub fn create_custom_event_radio_message(&mut self, msg_bytes: &[u8]) -> Result<(), DemoParserError> {
+        self.game_events_counter.insert("radio_message".to_string());
+        if !self.wanted_events.contains(&"radio_message".to_string()) && self.wanted_events.first() != Some(&"all".to_string()) {
+            return Ok(());
+        }
+        let radio_msg = match CcsUsrMsgRadioText::decode(msg_bytes) {
+            Ok(msg) => msg,
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
+        };
+        let mut fields = vec![];
+        let entity_id = self.entity_id_from_player_slot(radio_msg.client());
+        let (string_id, radio_command) = radio_command(radio_msg.msg_name(), &radio_msg.params);
+        fields.push(self.create_player_name_field(entity_id, "user"));
+        fields.push(self.create_player_steamid_field(entity_id, "user"));
+        fields.extend(self.find_extra_props_events(entity_id, "user"));
+        fields.push(EventField {
+            data: Some(Variant::String(radio_command)),
+            name: "radio_command".to_string(),
+        });
+        fields.push(EventField {
+            data: Some(Variant::String(string_id)),
+            name: "string_id".to_string(),
+        });
+        fields.push(EventField {
+            data: Some(Variant::String(radio_msg.msg_name().to_owned())),
+            name: "msg_name".to_string(),
+        });
+        fields.push(EventField {
+            data: Some(Variant::StringVec(radio_msg.params.clone())),
+            name: "params".to_string(),
+        });
+        fields.push(EventField {
+            data: Some(Variant::I32(self.tick)),
+            name: "tick".to_string(),
+        });
+        fields.extend(self.find_non_player_props());
+        let ge = GameEvent {
+            name: "radio_message".to_string(),
+            fields,
+            tick: self.tick,
+        };
+        self.game_events.push(ge);
+        Ok(())
+    }
+    // "player_ping" with only the position, the pinged entity and the player. Only called if "ping" is wanted.
+    pub fn create_custom_event_ping(&mut self, ping_fields: Vec<EventField>) -> Result<(), DemoParserError> {
+        let mut fields = vec![];
+        let entity_id = match ping_fields.iter().find(|f| f.name == "userid") {
+            Some(EventField {
+                data: Some(Variant::I32(userid)),
+                ..
+            }) => self.entity_id_from_userid(*userid).unwrap_or(ENTITYIDNONE),
+            _ => ENTITYIDNONE,
+        };
+        fields.push(self.create_player_name_field(entity_id, "user"));
+        fields.push(self.create_player_steamid_field(entity_id, "user"));
+        fields.extend(self.find_extra_props_events(entity_id, "user"));
+        fields.extend(ping_position_fields(ping_fields));
+        fields.push(EventField {
+            data: Some(Variant::I32(self.tick)),
+            name: "tick".to_string(),
+        });
+        fields.extend(self.find_non_player_props());
+        let ge = GameEvent {
+            name: "ping".to_string(),
+            fields,
+            tick: self.tick,
+        };
+        self.game_events.push(ge);
+        Ok(())
+    }
     pub fn create_custom_event_server_message(&mut self, msg_bytes: &[u8]) -> Result<(), DemoParserError> {
         self.game_events_counter.insert("server_message".to_string());
         if !self.wanted_events.contains(&"server_message".to_string()) && self.wanted_events.first() != Some(&"all".to_string()) {
@@ -1018,7 +1126,7 @@ impl<'a> SecondPassParser<'a> {
         }
         let chat_msg = match CUserMessageSayText::decode(msg_bytes) {
             Ok(msg) => msg,
-            Err(_) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
         };
         let mut fields = vec![];
         fields.push(EventField {
@@ -1079,7 +1187,7 @@ impl<'a> SecondPassParser<'a> {
 
         let msg = match CMsgPlayerBulletHit::decode(msg_bytes) {
             Ok(msg) => msg,
-            Err(_) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
         };
 
         let mut fields = vec![];
@@ -1149,6 +1257,56 @@ impl<'a> SecondPassParser<'a> {
 
         Ok(())
     }
+    fn create_custom_event_bot_takeover(&mut self, events: &[GameEventInfo]) {
+        for event in events {
+            if let GameEventInfo::BotTakeover(controller_entid) = event {
+                self.game_events_counter.insert("bot_takeover".to_string());
+                if !self.wanted_events.contains(&"bot_takeover".to_string()) && self.wanted_events.first() != Some(&"all".to_string()) {
+                    return;
+                }
+                let bot_controller_entid = match self.find_controlled_bot_controller(controller_entid) {
+                    Some(entid) => entid,
+                    None => continue,
+                };
+                let user = self.players.values().find(|p| p.controller_entid == Some(*controller_entid));
+                let bot = self.players.values().find(|p| p.controller_entid == Some(bot_controller_entid));
+                let (user, bot) = match (user, bot) {
+                    (Some(user), Some(bot)) => (user, bot),
+                    _ => continue,
+                };
+                let mut fields = vec![
+                    EventField {
+                        data: self.create_name(user).ok(),
+                        name: "user_name".to_string(),
+                    },
+                    EventField {
+                        data: Some(Variant::U64(user.steamid.unwrap_or(0))),
+                        name: "user_steamid".to_string(),
+                    },
+                    EventField {
+                        data: self.create_name(bot).ok(),
+                        name: "bot_name".to_string(),
+                    },
+                    EventField {
+                        data: Some(Variant::U64(bot.player_key.0)),
+                        name: "bot_player_key".to_string(),
+                    },
+                    EventField {
+                        data: Some(Variant::I32(self.tick)),
+                        name: "tick".to_string(),
+                    },
+                ];
+                // The pawn being played is the one of the bot
+                fields.extend(self.find_extra_props_events(bot.player_entity_id.unwrap_or(ENTITYIDNONE), "user"));
+                fields.extend(self.find_non_player_props());
+                self.game_events.push(GameEvent {
+                    name: "bot_takeover".to_string(),
+                    fields,
+                    tick: self.tick,
+                });
+            }
+        }
+    }
     pub fn create_custom_event_player_connect(
         &mut self,
         player_metadata: &PlayerMetaData,
@@ -1184,6 +1342,10 @@ impl<'a> SecondPassParser<'a> {
             data: Some(Variant::I32(self.tick)),
             name: "tick".to_string(),
         });
+        fields.push(EventField {
+            data: Some(Variant::U64(player_metadata.player_key.0)),
+            name: "player_key".to_string(),
+        });
         fields.extend(self.find_non_player_props());
         let entity_id = player_metadata.player_entity_id.unwrap_or(0);
         fields.extend(self.find_extra_props_events(entity_id, "user"));
@@ -1214,7 +1376,7 @@ impl<'a> SecondPassParser<'a> {
 
         let msg = match CMsgTeFireBullets::decode(msg_bytes) {
             Ok(msg) => msg,
-            Err(_) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
         };
 
         let mut fields = vec![];
@@ -1364,7 +1526,7 @@ impl<'a> SecondPassParser<'a> {
         }
         let update_msg = match CcsUsrMsgServerRankUpdate::decode(msg_bytes) {
             Ok(m) => m,
-            Err(_e) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
         };
 
         for update in update_msg.rank_update {
@@ -1413,6 +1575,171 @@ impl<'a> SecondPassParser<'a> {
         }
         Ok(())
     }
+    fn entity_id_from_player_slot(&self, player_slot: i32) -> i32 {
+        // Player slots are controller entity ids - 1
+        match self.find_user_by_controller_id(player_slot + 1) {
+            Some(metadata) => metadata.player_entity_id.unwrap_or(ENTITYIDNONE),
+            None => ENTITYIDNONE,
+        }
+    }
+    fn vote_issue_field(vote_type: i32) -> EventField {
+        let vote_type = match VOTE_ISSUE.get(&vote_type) {
+            Some(name) => name.to_string(),
+            None => vote_type.to_string(),
+        };
+        EventField {
+            data: Some(Variant::String(vote_type)),
+            name: "vote_type".to_string(),
+        }
+    }
+    fn vote_failed_reason_field(reason: i32) -> EventField {
+        let reason = match VOTE_FAILED_REASON.get(&reason) {
+            Some(name) => name.to_string(),
+            None => reason.to_string(),
+        };
+        EventField {
+            data: Some(Variant::String(reason)),
+            name: "reason".to_string(),
+        }
+    }
+    pub fn create_custom_event_vote_start(&mut self, msg_bytes: &[u8]) -> Result<(), DemoParserError> {
+        self.game_events_counter.insert("vote_start".to_string());
+        if !self.wanted_events.contains(&"vote_start".to_string()) && self.wanted_events.first() != Some(&"all".to_string()) {
+            return Ok(());
+        }
+        let vote_msg = match CcsUsrMsgVoteStart::decode(msg_bytes) {
+            Ok(msg) => msg,
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
+        };
+        let mut fields = vec![];
+        let issuer_entity_id = self.entity_id_from_player_slot(vote_msg.player_slot());
+        let target_entity_id = self.entity_id_from_player_slot(vote_msg.player_slot_target());
+        fields.push(self.create_player_name_field(issuer_entity_id, "user"));
+        fields.push(self.create_player_steamid_field(issuer_entity_id, "user"));
+        fields.extend(self.find_extra_props_events(issuer_entity_id, "user"));
+        fields.push(self.create_player_name_field(target_entity_id, "target"));
+        fields.push(self.create_player_steamid_field(target_entity_id, "target"));
+        fields.push(SecondPassParser::vote_issue_field(vote_msg.vote_type()));
+        fields.push(EventField {
+            data: Some(Variant::I32(vote_msg.team())),
+            name: "team".to_string(),
+        });
+        fields.push(EventField {
+            data: Some(Variant::String(vote_msg.disp_str().to_owned())),
+            name: "disp_str".to_string(),
+        });
+        fields.push(EventField {
+            data: Some(Variant::String(vote_msg.details_str().to_owned())),
+            name: "details_str".to_string(),
+        });
+        fields.push(EventField {
+            data: Some(Variant::Bool(vote_msg.is_yes_no_vote())),
+            name: "is_yes_no_vote".to_string(),
+        });
+        fields.push(EventField {
+            data: Some(Variant::I32(self.tick)),
+            name: "tick".to_string(),
+        });
+        fields.extend(self.find_non_player_props());
+        let ge = GameEvent {
+            name: "vote_start".to_string(),
+            fields,
+            tick: self.tick,
+        };
+        self.game_events.push(ge);
+        Ok(())
+    }
+    pub fn create_custom_event_vote_pass(&mut self, msg_bytes: &[u8]) -> Result<(), DemoParserError> {
+        self.game_events_counter.insert("vote_pass".to_string());
+        if !self.wanted_events.contains(&"vote_pass".to_string()) && self.wanted_events.first() != Some(&"all".to_string()) {
+            return Ok(());
+        }
+        let vote_msg = match CcsUsrMsgVotePass::decode(msg_bytes) {
+            Ok(msg) => msg,
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
+        };
+        let mut fields = vec![SecondPassParser::vote_issue_field(vote_msg.vote_type())];
+        fields.push(EventField {
+            data: Some(Variant::I32(vote_msg.team())),
+            name: "team".to_string(),
+        });
+        fields.push(EventField {
+            data: Some(Variant::String(vote_msg.disp_str().to_owned())),
+            name: "disp_str".to_string(),
+        });
+        fields.push(EventField {
+            data: Some(Variant::String(vote_msg.details_str().to_owned())),
+            name: "details_str".to_string(),
+        });
+        fields.push(EventField {
+            data: Some(Variant::I32(self.tick)),
+            name: "tick".to_string(),
+        });
+        fields.extend(self.find_non_player_props());
+        let ge = GameEvent {
+            name: "vote_pass".to_string(),
+            fields,
+            tick: self.tick,
+        };
+        self.game_events.push(ge);
+        Ok(())
+    }
+    pub fn create_custom_event_vote_failed(&mut self, msg_bytes: &[u8]) -> Result<(), DemoParserError> {
+        self.game_events_counter.insert("vote_failed".to_string());
+        if !self.wanted_events.contains(&"vote_failed".to_string()) && self.wanted_events.first() != Some(&"all".to_string()) {
+            return Ok(());
+        }
+        let vote_msg = match CcsUsrMsgVoteFailed::decode(msg_bytes) {
+            Ok(msg) => msg,
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
+        };
+        let mut fields = vec![];
+        fields.push(SecondPassParser::vote_failed_reason_field(vote_msg.reason()));
+        fields.push(EventField {
+            data: Some(Variant::I32(vote_msg.team())),
+            name: "team".to_string(),
+        });
+        fields.push(EventField {
+            data: Some(Variant::I32(self.tick)),
+            name: "tick".to_string(),
+        });
+        fields.extend(self.find_non_player_props());
+        let ge = GameEvent {
+            name: "vote_failed".to_string(),
+            fields,
+            tick: self.tick,
+        };
+        self.game_events.push(ge);
+        Ok(())
+    }
+    pub fn create_custom_event_call_vote_failed(&mut self, msg_bytes: &[u8]) -> Result<(), DemoParserError> {
+        self.game_events_counter.insert("call_vote_failed".to_string());
+        if !self.wanted_events.contains(&"call_vote_failed".to_string()) && self.wanted_events.first() != Some(&"all".to_string()) {
+            return Ok(());
+        }
+        let vote_msg = match CcsUsrMsgCallVoteFailed::decode(msg_bytes) {
+            Ok(msg) => msg,
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
+        };
+        let mut fields = vec![];
+        fields.push(SecondPassParser::vote_failed_reason_field(vote_msg.reason()));
+        fields.push(EventField {
+            data: Some(Variant::I32(vote_msg.time())),
+            name: "time".to_string(),
+        });
+        fields.push(EventField {
+            data: Some(Variant::I32(self.tick)),
+            name: "tick".to_string(),
+        });
+        fields.extend(self.find_non_player_props());
+        let ge = GameEvent {
+            name: "call_vote_failed".to_string(),
+            fields,
+            tick: self.tick,
+        };
+        self.game_events.push(ge);
+        Ok(())
+    }
     pub fn listen_for_events(
         entity: &mut Entity,
         result: &Variant,
@@ -1436,6 +1763,13 @@ impl<'a> SecondPassParser<'a> {
             if is_fullpacket{
                 return events;
             }
+            // Human starts controlling a bot
+            if let Some(id) = special_ids.controlling_bot {
+                let is_takeover = result == &Variant::Bool(true) && entity.props.get(&id) != Some(&Variant::Bool(true));
+                if fi.prop_id == id && entity.entity_type == EntityType::PlayerController && is_takeover {
+                    events.push(GameEventInfo::BotTakeover(entity.entity_id));
+                }
+            }
 
             // round end
             if let Some(id) = prop_controller.special_ids.round_end_count {
@@ -1511,6 +1845,36 @@ pub struct PurchaseEvent {
     pub inventory_slot: u32,
 }
 
+/*
+msg_name is usually a generic format like "#Game_radio_location" and the actual radio command is one
+of the params. Returns the string id and the command without the prefix:
+("#Cstrike_TitlesTXT_Enemy_spotted", "enemy_spotted")
+*/
+pub fn radio_command(msg_name: &str, params: &[String]) -> (String, String) {
+    let string_id = match params.iter().find(|p| p.starts_with(RADIO_STRING_PREFIX)) {
+        Some(param) => param.to_owned(),
+        None => msg_name.to_owned(),
+    };
+    let radio_command = string_id.trim_start_matches(RADIO_STRING_PREFIX).trim_start_matches('#').to_lowercase();
+    (string_id, radio_command)
+}
+
+// World position of the ping, whether it was an "urgent" (double) ping and the pinged entity
+pub fn ping_position_fields(ping_fields: Vec<EventField>) -> Vec<EventField> {
+    let mut fields = vec![];
+    for field in ping_fields {
+        match field.name.as_str() {
+            "x" | "y" | "z" | "urgent" => fields.push(field),
+            "entityid" => fields.push(EventField {
+                name: "ping_entity_id".to_string(),
+                data: field.data,
+            }),
+            _ => {}
+        }
+    }
+    fields
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct EventField {
     pub name: String,
@@ -1537,3 +1901,45 @@ impl Serialize for GameEvent {
         map.end()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_radio_command() {
+        let params = vec!["Player".to_string(), "#Cstrike_TitlesTXT_Enemy_spotted".to_string()];
+        assert_eq!(
+            radio_command("#Game_radio_location", &params),
+            ("#Cstrike_TitlesTXT_Enemy_spotted".to_string(), "enemy_spotted".to_string())
+        );
+        assert_eq!(radio_command("#Cstrike_TitlesTXT_Go", &[]), ("#Cstrike_TitlesTXT_Go".to_string(), "go".to_string()));
+        assert_eq!(radio_command("#Game_radio", &[]), ("#Game_radio".to_string(), "game_radio".to_string()));
+    }
+
+    #[test]
+    fn test_ping_position_fields() {
+        let field = |name: &str, data: Variant| EventField {
+            name: name.to_string(),
+            data: Some(data),
+        };
+        let ping_fields = vec![
+            field("userid", Variant::I32(3)),
+            field("entityid", Variant::I32(150)),
+            field("x", Variant::F32(1.0)),
+            field("y", Variant::F32(2.0)),
+            field("z", Variant::F32(3.0)),
+            field("urgent", Variant::Bool(true)),
+        ];
+        assert_eq!(
+            ping_position_fields(ping_fields),
+            vec![
+                field("ping_entity_id", Variant::I32(150)),
+                field("x", Variant::F32(1.0)),
+                field("y", Variant::F32(2.0)),
+                field("z", Variant::F32(3.0)),
+                field("urgent", Variant::Bool(true)),
+            ]
+        );
+    }
+}
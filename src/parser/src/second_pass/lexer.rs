@@ -0,0 +1,144 @@
+use crate::first_pass::read_bits::DemoParserError;
+use crate::second_pass::row_filter::CompareOp;
+
+/*
+Tokens shared by the row filter and derived column languages. Each parser only accepts the
+tokens that mean something to it, for example derived columns have no strings and filters no +.
+Numbers are kept as text so filters can parse them as integers (steamids don't fit in a f64) and
+derived columns as f64. "-" is always its own token, -1 in a filter is handled by the parser.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    // Prop, function or keyword (in, true, false, changed, prev)
+    Ident(String),
+    Number(String),
+    Str(String),
+    Compare(CompareOp),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Caret,
+    And,
+    Or,
+    Not,
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Comma,
+    // .. or ..=
+    Range(bool),
+}
+
+// error is the DemoParserError variant of the language, for example DemoParserError::InvalidRowFilter
+pub fn tokenize(text: &str, error: fn(String) -> DemoParserError) -> Result<Vec<Token>, DemoParserError> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let (token, len) = match (c, next) {
+            (c, _) if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            ('=', Some('=')) => (Token::Compare(CompareOp::Eq), 2),
+            ('!', Some('=')) => (Token::Compare(CompareOp::NotEq), 2),
+            ('<', Some('=')) => (Token::Compare(CompareOp::LtEq), 2),
+            ('>', Some('=')) => (Token::Compare(CompareOp::GtEq), 2),
+            ('<', _) => (Token::Compare(CompareOp::Lt), 1),
+            ('>', _) => (Token::Compare(CompareOp::Gt), 1),
+            ('&', Some('&')) => (Token::And, 2),
+            ('|', Some('|')) => (Token::Or, 2),
+            ('!', _) => (Token::Not, 1),
+            ('+', _) => (Token::Plus, 1),
+            ('-', _) => (Token::Minus, 1),
+            ('*', _) => (Token::Star, 1),
+            ('/', _) => (Token::Slash, 1),
+            ('^', _) => (Token::Caret, 1),
+            ('(', _) => (Token::LParen, 1),
+            (')', _) => (Token::RParen, 1),
+            ('[', _) => (Token::LBracket, 1),
+            (']', _) => (Token::RBracket, 1),
+            (',', _) => (Token::Comma, 1),
+            ('.', Some('.')) if chars.get(i + 2) == Some(&'=') => (Token::Range(true), 3),
+            ('.', Some('.')) => (Token::Range(false), 2),
+            ('"', _) | ('\'', _) => {
+                let end = match chars[i + 1..].iter().position(|x| *x == c) {
+                    Some(end) => i + 1 + end,
+                    None => return Err(error(format!("unterminated string in {:?}", text))),
+                };
+                (Token::Str(chars[i + 1..end].iter().collect()), end + 1 - i)
+            }
+            (c, _) if c.is_ascii_digit() || (c == '.' && next.is_some_and(|n| n.is_ascii_digit())) => {
+                let end = scan(&chars, i, |c| c.is_ascii_digit());
+                (Token::Number(chars[i..end].iter().collect()), end - i)
+            }
+            (c, _) if c.is_alphabetic() || c == '_' => {
+                // Real prop names look like CCSPlayerPawn.m_iHealth
+                let end = scan(&chars, i, |c| c.is_alphanumeric() || c == '_');
+                (Token::Ident(chars[i..end].iter().collect()), end - i)
+            }
+            _ => return Err(error(format!("unexpected character {:?} in {:?}", c, text))),
+        };
+        tokens.push(token);
+        i += len;
+    }
+    Ok(tokens)
+}
+
+// End of a number or name starting at start. Dots are allowed inside but not a ".." range.
+fn scan(chars: &[char], start: usize, allowed: impl Fn(char) -> bool) -> usize {
+    let mut end = start + 1;
+    while end < chars.len() && (allowed(chars[end]) || chars[end] == '.' && chars.get(end + 1) != Some(&'.')) {
+        end += 1;
+    }
+    end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        let tokens = tokenize("tick in 10..=20 && X>=-1.5 || name != 'a b'", DemoParserError::InvalidRowFilter).unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Ident("tick".to_string()),
+                Token::Ident("in".to_string()),
+                Token::Number("10".to_string()),
+                Token::Range(true),
+                Token::Number("20".to_string()),
+                Token::And,
+                Token::Ident("X".to_string()),
+                Token::Compare(CompareOp::GtEq),
+                Token::Minus,
+                Token::Number("1.5".to_string()),
+                Token::Or,
+                Token::Ident("name".to_string()),
+                Token::Compare(CompareOp::NotEq),
+                Token::Str("a b".to_string()),
+            ]
+        );
+        let tokens = tokenize("sqrt(CCSPlayerPawn.m_vecX^2)-.5", DemoParserError::InvalidDerivedColumn).unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Ident("sqrt".to_string()),
+                Token::LParen,
+                Token::Ident("CCSPlayerPawn.m_vecX".to_string()),
+                Token::Caret,
+                Token::Number("2".to_string()),
+                Token::RParen,
+                Token::Minus,
+                Token::Number(".5".to_string()),
+            ]
+        );
+        assert!(matches!(tokenize("a & b", DemoParserError::InvalidDerivedColumn), Err(DemoParserError::InvalidDerivedColumn(_))));
+        assert!(matches!(tokenize("name == \"a", DemoParserError::InvalidRowFilter), Err(DemoParserError::InvalidRowFilter(_))));
+    }
+}
@@ -1,9 +1,20 @@
 pub mod collect_data;
 pub mod decoder;
+pub mod derived_column;
 pub mod entities;
+pub mod event_windows;
 pub mod game_events;
+pub mod lexer;
 pub mod other_netmessages;
 pub mod parser;
 pub mod parser_settings;
 pub mod path_ops;
+pub mod player_identity;
+pub mod player_key;
+pub mod player_role;
+pub mod prop_changes;
+pub mod row_filter;
+pub mod typed_events;
+pub mod user_messages;
 pub mod variants;
+pub mod votes;
@@ -3,6 +3,8 @@ use crate::first_pass::sendtables::Serializer;
 use crate::second_pass::parser_settings::EconItem;
 use crate::second_pass::parser_settings::PlayerEndMetaData;
 use crate::second_pass::parser_settings::SecondPassParser;
+use crate::second_pass::player_key::PlayerKey;
+use crate::second_pass::player_role::PlayerRole;
 use csgoproto::maps::PAINTKITS;
 use csgoproto::maps::WEAPINDICIES;
 use csgoproto::CcsUsrMsgEndOfMatchAllPlayersData;
@@ -20,7 +22,7 @@ impl<'a> SecondPassParser<'a> {
     pub fn parse_item_drops(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
         let drops = match CcsUsrMsgSendPlayerItemDrops::decode(bytes) {
             Ok(msg) => msg,
-            Err(_) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
         };
         for item in &drops.entity_updates {
             let item_name = match WEAPINDICIES.get(&item.defindex.unwrap_or(u32::MAX)) {
@@ -56,7 +58,7 @@ impl<'a> SecondPassParser<'a> {
     pub fn parse_player_end_msg(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
         let end_data = match CcsUsrMsgEndOfMatchAllPlayersData::decode(bytes) {
             Ok(msg) => msg,
-            Err(_) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
         };
         /*
         Todo parse "accolade", seems to be the awards at the end like "most mvps in game"
@@ -76,11 +78,21 @@ impl<'a> SecondPassParser<'a> {
         }
         */
         for player in &end_data.allplayerdata {
-            self.player_end_data.push(PlayerEndMetaData {
-                name: player.name.clone(),
-                steamid: player.xuid,
-                team_number: player.teamnumber,
-            });
+            if let Some(player_key) = PlayerKey::new(player.xuid, player.name.as_deref()) {
+                let role = match self.player_roles.get(&player_key) {
+                    Some(role) => *role,
+                    None => PlayerRole::new(player.teamnumber.map(|t| t as u32), None, false, player_key),
+                };
+                if self.include_non_players || role.is_player() {
+                    self.player_end_data.push(PlayerEndMetaData {
+                        name: player.name.clone(),
+                        steamid: player.xuid,
+                        team_number: player.teamnumber,
+                        player_key,
+                        role,
+                    });
+                }
+            }
             for item in &player.items {
                 if item.itemid() != 0 {
                     let item_name = match WEAPINDICIES.get(&item.defindex.unwrap_or(u32::MAX)) {
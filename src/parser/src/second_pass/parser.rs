@@ -6,13 +6,18 @@ use crate::first_pass::prop_controller::*;
 use crate::first_pass::read_bits::read_varint;
 use crate::first_pass::read_bits::Bitreader;
 use crate::first_pass::read_bits::DemoParserError;
+use crate::first_pass::read_bits::ParseWarning;
 use crate::first_pass::stringtables::parse_userinfo;
+use crate::first_pass::stringtables::StringTableOutput;
 use crate::maps::demo_cmd_type_from_int;
 use crate::second_pass::collect_data::ProjectileRecord;
 use crate::second_pass::entities::Entity;
+use crate::second_pass::event_windows::EventWindowOutput;
 use crate::second_pass::game_events::GameEvent;
 use crate::second_pass::parser_settings::SecondPassParser;
+use crate::second_pass::prop_changes::PropChanges;
 use crate::second_pass::parser_settings::*;
+use crate::second_pass::player_role::track_hltv_userid;
 use crate::second_pass::variants::PropColumn;
 use crate::second_pass::variants::Variant;
 use ahash::AHashMap;
@@ -39,6 +44,7 @@ const INNER_BUF_DEFAULT_LEN: usize = 8192 * 15;
 pub struct SecondPassOutput {
     pub df: AHashMap<u32, PropColumn>,
     pub game_events: Vec<GameEvent>,
+    pub user_messages: Vec<GameEvent>,
     pub skins: Vec<EconItem>,
     pub item_drops: Vec<EconItem>,
     pub chat_messages: Vec<ChatMessageRecord>,
@@ -58,6 +64,10 @@ pub struct SecondPassOutput {
     pub df_per_player: AHashMap<u64, AHashMap<u32, PropColumn>>,
     pub entities: Vec<Option<Entity>>,
     pub last_tick: i32,
+    pub warnings: Vec<ParseWarning>,
+    pub event_windows: EventWindowOutput,
+    pub prop_changes: PropChanges,
+    pub string_tables: StringTableOutput,
 }
 impl<'a> SecondPassParser<'a> {
     pub fn start(&mut self, demo_bytes: &'a [u8]) -> Result<(), DemoParserError> {
@@ -65,16 +75,27 @@ impl<'a> SecondPassParser<'a> {
         // re-use these to avoid allocation
         let mut buf = vec![0_u8; INNER_BUF_DEFAULT_LEN];
         let mut buf2 = vec![0_u8; OUTER_BUF_DEFAULT_LEN];
+        let mut progress_reported_at = self.ptr;
 
         loop {
             // Need at least a few bytes to read frame header (3 varints, minimum 1 byte each)
             if self.ptr + 3 > demo_bytes.len() {
                 break;
             }
+            self.parse_control.check_cancelled()?;
+            self.parse_control.advance(self.ptr.saturating_sub(progress_reported_at));
+            progress_reported_at = self.ptr;
+            let frame_starts_at = self.ptr;
             let frame = match self.read_frame(demo_bytes) {
                 Ok(f) => f,
-                Err(DemoParserError::OutOfBytesError) => break,
-                Err(e) => return Err(e),
+                Err(e) if e.root() == &DemoParserError::OutOfBytesError => break,
+                Err(e) => {
+                    let e = e.with_context(|context| context.byte_offset = Some(frame_starts_at));
+                    match self.recover_from_error(e, frame_starts_at)? {
+                        true => continue,
+                        false => break,
+                    }
+                }
             };
             if frame.demo_cmd == DemAnimationData || frame.demo_cmd == DemSendTables || frame.demo_cmd == DemStringTables {
                 self.ptr += frame.size as usize;
@@ -87,26 +108,55 @@ impl<'a> SecondPassParser<'a> {
                     continue;
                 }
             };
-            let bytes = self.decompress_if_needed(&mut buf, bytes, &frame)?;
             self.ptr += frame.size;
-
-            let ok = match frame.demo_cmd {
-                DemSignonPacket => self.parse_packet(&bytes, &mut buf2),
-                DemPacket => self.parse_packet(&bytes, &mut buf2),
-                DemStop => break,
-                DemUserCmd => Ok(()),
-                DemFullPacket => {
-                    if self.parse_full_packet_and_break_if_needed(&bytes, &mut buf2, started_at)? {
-                        break;
-                    }
-                    Ok(())
-                }
-                _ => Ok(()),
+            let ok = match self.decompress_if_needed(&mut buf, bytes, &frame) {
+                Ok(bytes) => match frame.demo_cmd {
+                    DemSignonPacket => self.parse_packet(&bytes, &mut buf2),
+                    DemPacket => self.parse_packet(&bytes, &mut buf2),
+                    DemStop => break,
+                    DemUserCmd => Ok(()),
+                    DemFullPacket => match self.parse_full_packet_and_break_if_needed(&bytes, &mut buf2, started_at) {
+                        Ok(true) => break,
+                        Ok(false) => Ok(()),
+                        Err(e) => Err(e),
+                    },
+                    _ => Ok(()),
+                },
+                Err(e) => Err(e),
             };
-            ok?;
+            if let Err(e) = ok {
+                if !self.recover_from_error(frame.add_error_context(e), frame.frame_starts_at)? {
+                    break;
+                }
+            }
         }
         Ok(())
     }
+    /*
+    In lenient mode errors are stored as warnings and parsing continues from the next fullpacket,
+    where the entities can be rebuilt from scratch. Returns false if parsing should stop.
+    Chunks (multithreaded parsing) simply stop as the next chunk starts from that fullpacket.
+    */
+    fn recover_from_error(&mut self, e: DemoParserError, byte_offset: usize) -> Result<bool, DemoParserError> {
+        if !self.lenient {
+            return Err(e);
+        }
+        let resume_at = self.fullpacket_offsets.iter().find(|offset| **offset > byte_offset).copied();
+        self.warnings.push(ParseWarning {
+            tick: self.tick,
+            byte_offset,
+            error: e.to_string(),
+            resumed_at: resume_at,
+        });
+        match resume_at {
+            Some(offset) if self.parse_all_packets => {
+                self.ptr = offset;
+                self.resync_entities = true;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
     fn parse_full_packet_and_break_if_needed(&mut self, bytes: &[u8], buf: &mut Vec<u8>, started_at: usize) -> Result<bool, DemoParserError> {
         if let Some(start_end_offset) = self.start_end_offset {
             if self.ptr > start_end_offset.end {
@@ -118,7 +168,15 @@ impl<'a> SecondPassParser<'a> {
         }
         match self.parse_all_packets {
             true => {
-                self.parse_full_packet(&bytes, false, buf)?;
+                if self.resync_entities {
+                    // Entities might be in a broken state after an error so rebuild them from the fullpacket
+                    self.resync_entities = false;
+                    self.entities.iter_mut().for_each(|entity| *entity = None);
+                    self.projectiles.clear();
+                    self.parse_full_packet(bytes, true, buf)?;
+                } else {
+                    self.parse_full_packet(&bytes, false, buf)?;
+                }
             }
             false => {
                 if self.fullpackets_parsed == 0 && started_at != HEADER_ENDS_AT_BYTE {
@@ -162,7 +220,7 @@ impl<'a> SecondPassParser<'a> {
                 FirstPassParser::resize_if_needed(buf, decompress_len(possibly_uncompressed_bytes))?;
                 match SnapDecoder::new().decompress(possibly_uncompressed_bytes, buf) {
                     Ok(idx) => Ok(&buf[..idx]),
-                    Err(e) => return Err(DemoParserError::DecompressionFailure(format!("{}", e))),
+                    Err(e) => return Err(DemoParserError::DecompressionFailure(e)),
                 }
             }
             false => Ok(possibly_uncompressed_bytes),
@@ -175,14 +233,14 @@ impl<'a> SecondPassParser<'a> {
                     buf.resize(len, 0)
                 }
             }
-            Err(e) => return Err(DemoParserError::DecompressionFailure(e.to_string())),
+            Err(e) => return Err(DemoParserError::DecompressionFailure(e)),
         };
         Ok(())
     }
 
     pub fn parse_packet(&mut self, bytes: &[u8], buf: &mut Vec<u8>) -> Result<(), DemoParserError> {
         let msg = match CDemoPacket::decode(bytes) {
-            Err(_) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
             Ok(msg) => msg,
         };
         let mut bitreader = Bitreader::new(msg.data());
@@ -207,15 +265,25 @@ impl<'a> SecondPassParser<'a> {
             }
             bitreader.read_n_bytes_mut(size as usize, buf)?;
             let msg_bytes = &buf[..size as usize];
-            let ok = match NetMessageType::from(msg_type as i32) {
+            let msg_id = msg_type as i32;
+            let msg_type = NetMessageType::from(msg_id);
+            if !self.wanted_user_messages.is_empty() {
+                self.parse_user_message(msg_id, &msg_type, msg_bytes);
+            }
+            let ok = match msg_type {
                 svc_PacketEntities => {
                     if should_parse_entities {
-                        self.parse_packet_ents(&msg_bytes, is_fullpacket)?;
-                        if !is_fullpacket {
+                        let result = self.parse_packet_ents(&msg_bytes, is_fullpacket);
+                        if result.is_ok() {
+                            self.update_controlled_by_steamids();
+                        }
+                        if result.is_ok() && !is_fullpacket {
                             self.collect_entities();
                         }
+                        result
+                    } else {
+                        Ok(())
                     }
-                    Ok(())
                 }
                 svc_CreateStringTable => self.parse_create_stringtable(msg_bytes),
                 svc_UpdateStringTable => self.update_string_table(msg_bytes),
@@ -224,9 +292,14 @@ impl<'a> SecondPassParser<'a> {
                 CS_UM_EndOfMatchAllPlayersData => self.parse_player_end_msg(msg_bytes),
                 UM_SayText2 => self.create_custom_event_chat_message(msg_bytes),
                 UM_SayText => self.create_custom_event_server_message(msg_bytes),
+                CS_UM_RadioText => self.create_custom_event_radio_message(msg_bytes),
                 net_SetConVar => self.create_custom_event_parse_convars(msg_bytes),
                 CS_UM_PlayerStatsUpdate => self.parse_player_stats_update(msg_bytes),
                 CS_UM_ServerRankUpdate => self.create_custom_event_rank_update(msg_bytes),
+                CS_UM_VoteStart => self.create_custom_event_vote_start(msg_bytes),
+                CS_UM_VotePass => self.create_custom_event_vote_pass(msg_bytes),
+                CS_UM_VoteFailed => self.create_custom_event_vote_failed(msg_bytes),
+                CS_UM_CallVoteFailed => self.create_custom_event_call_vote_failed(msg_bytes),
                 net_Tick => self.parse_net_tick(msg_bytes),
                 svc_ClearAllStringTables => self.clear_stringtables(),
                 svc_VoiceData => self.parse_voice_data(msg_bytes),
@@ -236,7 +309,7 @@ impl<'a> SecondPassParser<'a> {
                 GE_PlayerBulletHitId => self.create_custom_event_player_bullet_hit(msg_bytes),
                 _ => Ok(()),
             };
-            ok?
+            ok.map_err(|e| e.with_context(|context| context.net_message = Some(format!("{:?}", msg_type))))?
         }
         if !wrong_order_events.is_empty() {
             self.resolve_wrong_order_event(&mut wrong_order_events)?;
@@ -324,7 +397,7 @@ impl<'a> SecondPassParser<'a> {
     pub fn parse_net_tick(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
         let message = match CnetMsgTick::decode(bytes) {
             Ok(message) => message,
-            Err(_) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
         };
         self.net_tick = message.tick();
         Ok(())
@@ -333,7 +406,7 @@ impl<'a> SecondPassParser<'a> {
     pub fn parse_full_packet(&mut self, bytes: &[u8], should_parse_entities: bool, buf: &mut Vec<u8>) -> Result<(), DemoParserError> {
         self.string_tables = vec![];
         let full_packet = match CDemoFullPacket::decode(bytes) {
-            Err(_e) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
             Ok(p) => p,
         };
         self.parse_full_packet_stringtables(&full_packet);
@@ -357,6 +430,7 @@ impl<'a> SecondPassParser<'a> {
                 if item.table_name == Some("userinfo".to_string()) {
                     for i in &item.items {
                         if let Ok(player) = parse_userinfo(&i.data()) {
+                            track_hltv_userid(&mut self.hltv_userids, &player);
                             if player.steamid != 0 {
                                 self.stringtable_players.insert(player.userid, player);
                             }
@@ -368,13 +442,17 @@ impl<'a> SecondPassParser<'a> {
     }
     fn clear_stringtables(&mut self) -> Result<(), DemoParserError> {
         self.string_tables = vec![];
+        self.recorded_string_tables = vec![];
         Ok(())
     }
     pub fn parse_server_info(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
         let server_info = match CsvcMsgServerInfo::decode(bytes) {
-            Err(_e) => return Err(DemoParserError::MalformedMessage),
+            Err(e) => return Err(DemoParserError::ProtobufDecodeError(e)),
             Ok(p) => p,
         };
+        if server_info.tick_interval() > 0.0 {
+            self.tick_interval = server_info.tick_interval();
+        }
         let class_count = server_info.max_classes();
         self.cls_bits = Some((class_count as f32 + 1.).log2().ceil() as u32);
         Ok(())
@@ -1,25 +1,40 @@
 use crate::first_pass::frameparser::StartEndOffset;
 use crate::first_pass::parser::FirstPassOutput;
 use crate::first_pass::prop_controller::PropController;
+use crate::first_pass::prop_controller::DERIVED_COLUMN_BASEID;
+use crate::first_pass::prop_controller::PropInfo;
 use crate::first_pass::read_bits::DemoParserError;
+use crate::first_pass::read_bits::ParseWarning;
+use crate::parse_control::ParseControl;
 use crate::first_pass::sendtables::Serializer;
 use crate::first_pass::stringtables::StringTable;
+use crate::first_pass::stringtables::StringTableUpdate;
 use crate::first_pass::stringtables::UserInfo;
+use crate::maps::FRIENDLY_NAMES_MAPPING;
 use crate::second_pass::collect_data::ProjectileRecord;
+use crate::second_pass::collect_data::PropType;
 use crate::second_pass::decoder::QfMapper;
+use crate::second_pass::derived_column::DerivedColumn;
 use crate::second_pass::entities::Entity;
 use crate::second_pass::entities::PlayerMetaData;
+use crate::second_pass::event_windows::EventWindowState;
 use crate::second_pass::game_events::GameEvent;
 use crate::second_pass::other_netmessages::Class;
 use crate::second_pass::parser::SecondPassOutput;
 use crate::second_pass::path_ops::FieldPath;
+use crate::second_pass::player_key::PlayerKey;
+use crate::second_pass::player_role::PlayerRole;
+use crate::second_pass::prop_changes::PropChanges;
+use crate::second_pass::row_filter::RowFilter;
 use crate::second_pass::variants::PropColumn;
+use crate::second_pass::variants::Variant;
 use ahash::AHashMap;
 use ahash::AHashSet;
 use ahash::HashMap;
 use ahash::RandomState;
 use csgoproto::csvc_msg_game_event_list::DescriptorT;
 use csgoproto::CsvcMsgVoiceData;
+use itertools::Itertools;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::env;
@@ -32,6 +47,7 @@ pub struct SecondPassParser<'a> {
     pub prop_controller: &'a PropController,
     pub cls_by_id: &'a Vec<Class>,
     pub stringtable_players: BTreeMap<i32, UserInfo>,
+    pub hltv_userids: AHashSet<i32>,
     pub net_tick: u32,
     pub parse_inventory: bool,
     pub paths: Vec<FieldPath>,
@@ -43,9 +59,12 @@ pub struct SecondPassParser<'a> {
     pub entities: Vec<Option<Entity>>,
     pub tick: i32,
     pub players: BTreeMap<i32, PlayerMetaData>,
+    // Bot controller entity id -> steamid of the human that took it over. See update_controlled_by_steamids.
+    pub controlled_by_steamids: AHashMap<i32, u64>,
     pub teams: Teams,
     pub huffman_lookup_table: &'a [(u8, u8)],
     pub game_events: Vec<GameEvent>,
+    pub user_messages: Vec<GameEvent>,
     pub string_tables: Vec<StringTable>,
     pub rules_entity_id: Option<i32>,
     pub c4_entity_id: Option<i32>,
@@ -68,6 +87,9 @@ pub struct SecondPassParser<'a> {
     pub player_end_data: Vec<PlayerEndMetaData>,
     // Settings
     pub wanted_events: Vec<String>,
+    pub wanted_user_messages: Vec<String>,
+    // wanted_user_messages is ["all"]
+    pub all_user_messages: bool,
     pub parse_entities: bool,
     pub parse_projectiles: bool,
     pub parse_grenades: bool,
@@ -77,6 +99,36 @@ pub struct SecondPassParser<'a> {
     pub last_tick: i32,
     pub parse_usercmd: bool,
     pub list_props: bool,
+    pub tick_interval: f32,
+    pub lenient: bool,
+    pub resync_entities: bool,
+    pub fullpacket_offsets: Vec<usize>,
+    pub warnings: Vec<ParseWarning>,
+    pub parse_control: ParseControl,
+    pub row_filters: Vec<RowFilter>,
+    // Props used by row_filters, by the name used in the filter
+    pub row_filter_props: AHashMap<String, PropInfo>,
+    // Value of every changed(prop) at the previous collected tick, by (entity id, prop)
+    pub row_filter_previous: AHashMap<(i32, String), Option<Variant>>,
+    pub event_windows: EventWindowState,
+    pub only_prop_changes: bool,
+    pub prop_changes: PropChanges,
+    // Last value added to prop_changes by (player_key, prop id)
+    pub prop_changes_last: AHashMap<(u64, u32), Option<Variant>>,
+    // Players collected on the previous collected tick, player_key -> steamid
+    pub prop_changes_present: AHashMap<u64, u64>,
+    pub wanted_string_tables: Vec<String>,
+    pub recorded_string_tables: Vec<StringTable>,
+    pub string_table_updates: Vec<StringTableUpdate>,
+    pub include_non_players: bool,
+    // Latest role of every controller seen, including the ones left out of players
+    pub player_roles: AHashMap<PlayerKey, PlayerRole>,
+    // Derived columns whose props were all found, by the id of their PropInfo
+    pub derived_columns: AHashMap<u32, DerivedColumn>,
+    // Props read by derived_columns, by the name used in the expression
+    pub derived_column_props: AHashMap<String, PropInfo>,
+    // Value of every prev(prop) at the previous collected tick, by (entity id, prop)
+    pub derived_previous: AHashMap<(i32, String), Variant>,
 }
 #[derive(Debug, Clone)]
 pub struct Teams {
@@ -126,16 +178,20 @@ pub struct PlayerEndMetaData {
     pub steamid: Option<u64>,
     pub name: Option<String>,
     pub team_number: Option<i32>,
+    pub player_key: PlayerKey,
+    pub role: PlayerRole,
 }
 
 impl<'a> SecondPassParser<'a> {
     pub fn create_output(self) -> SecondPassOutput {
+        let string_tables = self.string_table_output();
         SecondPassOutput {
             voice_data: self.voice_data,
             chat_messages: self.chat_messages,
             convars: self.convars,
             df: self.output,
             game_events: self.game_events,
+            user_messages: self.user_messages,
             skins: self.skins,
             item_drops: self.item_drops,
             header: None,
@@ -143,10 +199,13 @@ impl<'a> SecondPassParser<'a> {
             roster: self
                 .players
                 .values()
+                .filter(|p| self.include_non_players || p.role.is_player())
                 .map(|p| PlayerEndMetaData {
                     steamid: p.steamid,
                     name: p.name.clone(),
                     team_number: p.team_num.map(|t| t as i32),
+                    player_key: p.player_key,
+                    role: p.role,
                 })
                 .collect(),
             game_events_counter: self.game_events_counter,
@@ -157,6 +216,10 @@ impl<'a> SecondPassParser<'a> {
             df_per_player: self.df_per_player,
             entities: self.entities,
             last_tick: self.tick,
+            warnings: self.warnings,
+            event_windows: self.event_windows.output,
+            prop_changes: self.prop_changes,
+            string_tables,
         }
     }
     pub fn new(
@@ -172,6 +235,8 @@ impl<'a> SecondPassParser<'a> {
             .extend(vec!["tick".to_owned(), "steamid".to_owned(), "name".to_owned()]);
         let args: Vec<String> = env::args().collect();
         let debug = if args.len() > 2 { args[2] == "true" } else { false };
+        let (row_filters, row_filter_props) = resolve_row_filters(&first_pass_output.settings.row_filters, &first_pass_output.prop_controller.prop_infos)?;
+        let (derived_columns, derived_column_props) = resolve_derived_columns(&first_pass_output.settings.derived_columns, &first_pass_output.prop_controller.prop_infos)?;
 
         Ok(SecondPassParser {
             uniq_prop_names: AHashSet::default(),
@@ -192,6 +257,7 @@ impl<'a> SecondPassParser<'a> {
             net_tick: 0,
             c4_entity_id: None,
             stringtable_players: first_pass_output.stringtable_players,
+            hltv_userids: first_pass_output.hltv_userids,
             is_debug_mode: debug,
             projectile_records: vec![],
             parse_all_packets: parse_all_packets,
@@ -208,9 +274,21 @@ impl<'a> SecondPassParser<'a> {
             cls_bits: None,
             tick: -99999,
             players: BTreeMap::default(),
+            controlled_by_steamids: AHashMap::default(),
             output: AHashMap::default(),
             game_events: vec![],
-            wanted_events: first_pass_output.settings.wanted_events.clone(),
+            user_messages: vec![],
+            // Events that have windows must be parsed even if they were not asked for
+            wanted_events: first_pass_output
+                .settings
+                .wanted_events
+                .iter()
+                .chain(first_pass_output.settings.event_windows.iter().map(|w| &w.event_name))
+                .unique()
+                .cloned()
+                .collect(),
+            wanted_user_messages: first_pass_output.settings.wanted_user_messages.clone(),
+            all_user_messages: first_pass_output.settings.wanted_user_messages.first().is_some_and(|name| name == "all"),
             parse_entities: first_pass_output.settings.parse_ents,
             projectiles: BTreeSet::default(),
             baselines: first_pass_output.baselines.clone(),
@@ -228,10 +306,81 @@ impl<'a> SecondPassParser<'a> {
             huffman_lookup_table: &first_pass_output.settings.huffman_lookup_table,
             header: HashMap::default(),
             list_props: first_pass_output.list_props,
+            tick_interval: first_pass_output.tick_interval,
+            lenient: first_pass_output.settings.lenient,
+            resync_entities: false,
+            fullpacket_offsets: first_pass_output.fullpacket_offsets.clone(),
+            warnings: vec![],
+            parse_control: first_pass_output.settings.parse_control.clone(),
+            row_filters,
+            row_filter_props,
+            row_filter_previous: AHashMap::default(),
+            event_windows: EventWindowState::new(first_pass_output.settings.event_windows.clone()),
+            only_prop_changes: first_pass_output.settings.only_prop_changes,
+            prop_changes: PropChanges::default(),
+            prop_changes_last: AHashMap::default(),
+            prop_changes_present: AHashMap::default(),
+            wanted_string_tables: first_pass_output.settings.wanted_string_tables.clone(),
+            recorded_string_tables: vec![],
+            string_table_updates: vec![],
+            include_non_players: first_pass_output.settings.include_non_players,
+            player_roles: AHashMap::default(),
+            derived_columns,
+            derived_column_props,
+            derived_previous: AHashMap::default(),
         })
     }
 }
 
+// Props read by filters and derived columns, by the name used in the expression
+type PropsByName = AHashMap<String, PropInfo>;
+
+// A filter with an unknown prop would otherwise never match or always match when negated
+fn resolve_row_filters(filters: &[RowFilter], prop_infos: &[PropInfo]) -> Result<(Vec<RowFilter>, PropsByName), DemoParserError> {
+    let mut props = AHashMap::default();
+    for filter in filters {
+        for prop in filter.props() {
+            match prop_infos.iter().find(|info| info.prop_friendly_name == prop || info.prop_name == prop) {
+                Some(info) => props.insert(prop.to_string(), info.clone()),
+                None => {
+                    return Err(DemoParserError::InvalidRowFilter(format!(
+                        "unknown prop {:?} in filter, props in filters must also be in wanted_player_props",
+                        prop
+                    )))
+                }
+            };
+        }
+    }
+    Ok((filters.to_vec(), props))
+}
+
+/*
+Inputs are added to wanted_player_props by their real name in the first pass, so the name in the
+expression is matched against the friendly, real and FRIENDLY_NAMES_MAPPING name. Other derived
+columns can only be used if they come earlier, so they can't refer to each other. An unknown
+input is an error instead of a column that is always null.
+*/
+fn resolve_derived_columns(columns: &[DerivedColumn], prop_infos: &[PropInfo]) -> Result<(AHashMap<u32, DerivedColumn>, PropsByName), DemoParserError> {
+    let mut resolved = AHashMap::default();
+    let mut props = AHashMap::default();
+    for (idx, column) in columns.iter().enumerate() {
+        let id = DERIVED_COLUMN_BASEID + idx as u32;
+        for prop in column.props() {
+            let real_name = FRIENDLY_NAMES_MAPPING.get(prop).copied().unwrap_or(prop);
+            let info = prop_infos
+                .iter()
+                .filter(|info| info.prop_type != PropType::Derived || info.id < id)
+                .find(|info| info.prop_friendly_name == prop || info.prop_name == prop || info.prop_name == real_name);
+            match info {
+                Some(info) => props.insert(prop.to_string(), info.clone()),
+                None => return Err(DemoParserError::InvalidDerivedColumn(format!("unknown prop {:?} in {}", prop, column.name))),
+            };
+        }
+        resolved.insert(id, column.clone());
+    }
+    Ok((resolved, props))
+}
+
 #[derive(Debug, Clone)]
 pub struct SpecialIDs {
     pub teamnum: Option<u32>,
@@ -275,6 +424,9 @@ pub struct SpecialIDs {
 
     pub round_win_reason: Option<u32>,
     pub round_start_count: Option<u32>,
+    pub round_start_time: Option<u32>,
+    pub round_time: Option<u32>,
+    pub freeze_period: Option<u32>,
     pub round_end_count: Option<u32>,
     pub match_end_count: Option<u32>,
 
@@ -291,11 +443,18 @@ pub struct SpecialIDs {
 
     pub is_airborn: Option<u32>,
     pub initial_velocity: Option<u32>,
+
+    pub controlling_bot: Option<u32>,
+    pub original_controller_of_current_pawn: Option<u32>,
+    pub coaching_team: Option<u32>,
 }
 impl SpecialIDs {
     pub fn new() -> Self {
         SpecialIDs {
             round_start_count: None,
+            round_start_time: None,
+            round_time: None,
+            freeze_period: None,
             round_end_count: None,
             match_end_count: None,
             round_win_reason: None,
@@ -340,6 +499,9 @@ impl SpecialIDs {
             custom_name: None,
             is_airborn: None,
             initial_velocity: None,
+            controlling_bot: None,
+            original_controller_of_current_pawn: None,
+            coaching_team: None,
         }
     }
 }
@@ -357,3 +519,52 @@ pub fn create_huffman_lookup_table() -> Vec<(u8, u8)> {
 fn contains_usercmd_prop(names: &[String]) -> bool {
     names.iter().any(|name| name.contains("usercmd"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prop_info(id: u32, prop_type: PropType, name: &str, friendly_name: &str) -> PropInfo {
+        PropInfo {
+            id,
+            prop_type,
+            prop_name: name.to_string(),
+            prop_friendly_name: friendly_name.to_string(),
+            is_player_prop: true,
+        }
+    }
+
+    #[test]
+    fn test_resolve_row_filters() {
+        let prop_infos = vec![prop_info(1, PropType::Player, "CCSPlayerPawn.m_iHealth", "health")];
+        let filters = vec![RowFilter::parse("health < 30 && CCSPlayerPawn.m_iHealth > 0").unwrap()];
+        let (_, props) = resolve_row_filters(&filters, &prop_infos).unwrap();
+        assert_eq!(props["health"].id, 1);
+        assert_eq!(props["CCSPlayerPawn.m_iHealth"].id, 1);
+
+        let filters = vec![RowFilter::parse("helth < 30").unwrap()];
+        match resolve_row_filters(&filters, &prop_infos) {
+            Err(DemoParserError::InvalidRowFilter(msg)) => assert!(msg.contains("helth")),
+            other => panic!("expected InvalidRowFilter, got {:?}", other.map(|(filters, _)| filters)),
+        }
+    }
+
+    #[test]
+    fn test_resolve_derived_columns() {
+        let prop_infos = vec![
+            prop_info(1, PropType::Player, "CCSPlayerPawn.m_iHealth", "health"),
+            prop_info(DERIVED_COLUMN_BASEID, PropType::Derived, "hp_lost", "hp_lost"),
+            prop_info(DERIVED_COLUMN_BASEID + 1, PropType::Derived, "double_hp_lost", "double_hp_lost"),
+        ];
+        let columns = vec![DerivedColumn::parse("hp_lost = prev(health) - health").unwrap(), DerivedColumn::parse("double_hp_lost = hp_lost * 2").unwrap()];
+        let (resolved, props) = resolve_derived_columns(&columns, &prop_infos).unwrap();
+        assert_eq!(resolved[&(DERIVED_COLUMN_BASEID + 1)].name, "double_hp_lost");
+        assert_eq!(props["hp_lost"].id, DERIVED_COLUMN_BASEID);
+
+        // Unknown prop, and a column that reads a later column
+        for column in ["hp_lost = prev(helth) - health", "hp_lost = double_hp_lost / 2"] {
+            let columns = vec![DerivedColumn::parse(column).unwrap(), DerivedColumn::parse("double_hp_lost = health * 2").unwrap()];
+            assert!(matches!(resolve_derived_columns(&columns, &prop_infos), Err(DemoParserError::InvalidDerivedColumn(_))));
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::first_pass::stringtables::parse_userinfo;
+use crate::first_pass::stringtables::StringTableUpdate;
+use ahash::AHashMap;
+
+/*
+Timeline of the userinfo string table: who was in each player slot, under which name, at which tick.
+Built from the updates recorded with ParserInputs::wanted_string_tables containing "userinfo".
+
+A row is added whenever the info in a slot changes, so a player renaming mid-match shows up as a
+new row with the same steamid. Slots that are emptied (player disconnected) are left out.
+CMsgPlayerInfo has no replay flag, only is_hltv.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerIdentity {
+    pub tick: i32,
+    // Index of the entry in the userinfo table
+    pub slot: i32,
+    pub userid: i32,
+    // 0 for bots
+    pub steamid: u64,
+    pub name: String,
+    pub fake_player: bool,
+    pub is_hltv: bool,
+}
+
+pub fn player_identity_timeline(updates: &[StringTableUpdate]) -> Vec<PlayerIdentity> {
+    let mut timeline: Vec<PlayerIdentity> = vec![];
+    let mut last_by_slot: AHashMap<i32, usize> = AHashMap::default();
+    for update in updates.iter().filter(|u| u.table == "userinfo" && !u.value.is_empty()) {
+        let Ok(info) = parse_userinfo(&update.value) else {
+            continue;
+        };
+        let identity = PlayerIdentity {
+            tick: update.tick,
+            slot: update.idx,
+            userid: info.userid,
+            steamid: info.steamid,
+            name: info.name,
+            fake_player: info.fake_player,
+            is_hltv: info.is_hltv,
+        };
+        // The same info is often sent again, for example when the score of the player changes
+        if let Some(last) = last_by_slot.get(&update.idx) {
+            let last = &timeline[*last];
+            if (last.userid, last.steamid, &last.name, last.fake_player, last.is_hltv)
+                == (identity.userid, identity.steamid, &identity.name, identity.fake_player, identity.is_hltv)
+            {
+                continue;
+            }
+        }
+        last_by_slot.insert(update.idx, timeline.len());
+        timeline.push(identity);
+    }
+    timeline
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use csgoproto::CMsgPlayerInfo;
+    use prost::Message;
+
+    fn userinfo_update(tick: i32, idx: i32, name: &str, steamid: u64) -> StringTableUpdate {
+        let info = CMsgPlayerInfo {
+            name: Some(name.to_string()),
+            xuid: Some(steamid),
+            userid: Some(idx),
+            steamid: Some(steamid),
+            fakeplayer: Some(steamid == 0),
+            ishltv: Some(false),
+        };
+        StringTableUpdate {
+            tick,
+            table: "userinfo".to_string(),
+            idx,
+            key: idx.to_string(),
+            value: info.encode_to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_player_identity_timeline() {
+        let updates = vec![
+            userinfo_update(-1, 0, "player", 76561198000000001),
+            userinfo_update(-1, 1, "BOT Albert", 0),
+            userinfo_update(100, 0, "player", 76561198000000001),
+            userinfo_update(200, 0, "teammate", 76561198000000001),
+        ];
+        let timeline = player_identity_timeline(&updates);
+        assert_eq!(timeline.len(), 3);
+        assert!(timeline[1].fake_player);
+        assert_eq!((timeline[2].tick, timeline[2].steamid, timeline[2].name.as_str()), (200, 76561198000000001, "teammate"));
+    }
+}
@@ -0,0 +1,108 @@
+use crate::second_pass::entities::PlayerMetaData;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/*
+Id of a participant that stays the same across reconnects, team switches and reused entity ids.
+Humans are keyed by their steamid, so for them the key is the steamid. Bots have steamid 0 so they
+are keyed by their name instead, which is unique among the bots in a match.
+
+The key only depends on the steamid and name, so it can be computed for any output that has
+them (df, events, roster, player_md) without knowing anything else about the demo.
+
+No key is made until it is known which one to use: a steamid that hasn't arrived yet would
+otherwise give a human a name key that changes later, and every bot whose name hasn't arrived
+yet would share the key of the empty name.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub struct PlayerKey(pub u64);
+
+// Set on keys made from names. Real steamids never have the top bit set.
+const NAME_KEY_BIT: u64 = 1 << 63;
+
+impl PlayerKey {
+    pub fn new(steamid: Option<u64>, name: Option<&str>) -> Option<Self> {
+        match (steamid, name) {
+            (Some(0), Some(name)) if !name.is_empty() => Some(PlayerKey(NAME_KEY_BIT | fnv1a(name.as_bytes()))),
+            (Some(0), _) | (None, _) => None,
+            (Some(steamid), _) => Some(PlayerKey(steamid)),
+        }
+    }
+    pub fn is_bot(&self) -> bool {
+        self.0 & NAME_KEY_BIT != 0
+    }
+}
+
+// Hash that is the same on every platform and version, unlike the std and ahash hashers
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+// Entity id of the player with the same key, as entity ids change when a player reconnects
+pub fn find_player_by_key(players: &BTreeMap<i32, PlayerMetaData>, player_key: PlayerKey) -> Option<i32> {
+    players.iter().find(|(_, player)| player.player_key == player_key).map(|(entid, _)| *entid)
+}
+
+// Puts the player at its pawn entity id and removes the entry from before a reconnect
+pub fn insert_player(players: &mut BTreeMap<i32, PlayerMetaData>, pawn_entid: i32, player: PlayerMetaData) {
+    if let Some(old_entid) = find_player_by_key(players, player.player_key) {
+        players.remove(&old_entid);
+    }
+    players.insert(pawn_entid, player);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::second_pass::player_role::PlayerRole;
+
+    #[test]
+    fn test_player_key() {
+        let human = PlayerKey::new(Some(76561198000000001), Some("player")).unwrap();
+        assert_eq!(human, PlayerKey(76561198000000001));
+        assert_eq!(Some(human), PlayerKey::new(Some(76561198000000001), Some("renamed")));
+        assert!(!human.is_bot());
+
+        let bot = PlayerKey::new(Some(0), Some("BOT Albert")).unwrap();
+        assert!(bot.is_bot());
+        assert_ne!(Some(bot), PlayerKey::new(Some(0), Some("BOT Brian")));
+
+        // Not known yet
+        assert_eq!(PlayerKey::new(None, Some("player")), None);
+        assert_eq!(PlayerKey::new(Some(0), None), None);
+        assert_eq!(PlayerKey::new(Some(0), Some("")), None);
+    }
+
+    fn player(steamid: u64, name: &str, pawn_entid: i32) -> PlayerMetaData {
+        PlayerMetaData {
+            player_entity_id: Some(pawn_entid),
+            steamid: Some(steamid),
+            controller_entid: Some(1),
+            name: Some(name.to_string()),
+            team_num: Some(2),
+            player_key: PlayerKey::new(Some(steamid), Some(name)).unwrap(),
+            role: PlayerRole::Player,
+        }
+    }
+
+    #[test]
+    fn test_insert_player() {
+        let mut players = BTreeMap::new();
+        insert_player(&mut players, 10, player(76561198000000001, "player", 10));
+        insert_player(&mut players, 11, player(0, "BOT Albert", 11));
+        insert_player(&mut players, 12, player(0, "BOT Brian", 12));
+        assert_eq!(players.len(), 3);
+
+        // Reconnects get a new pawn, the old entry goes away even after a rename
+        insert_player(&mut players, 20, player(76561198000000001, "renamed", 20));
+        insert_player(&mut players, 21, player(0, "BOT Albert", 21));
+        assert_eq!(players.keys().copied().collect::<Vec<i32>>(), vec![12, 20, 21]);
+        assert_eq!(players[&20].name.as_deref(), Some("renamed"));
+        assert_eq!(find_player_by_key(&players, players[&12].player_key), Some(12));
+    }
+}
@@ -0,0 +1,111 @@
+use crate::first_pass::prop_controller::SPECTATOR_TEAM_NUM;
+use crate::first_pass::stringtables::UserInfo;
+use crate::second_pass::player_key::PlayerKey;
+use ahash::AHashSet;
+use serde::Serialize;
+
+/*
+What a participant is doing in the match. Coaches, casters and spectators have a CCSPlayerController
+just like players, so without this they end up in df, roster and player_md. Only Player and Bot are
+kept unless ParserInputs::include_non_players is set.
+
+Coaches are on team 1 or their coached team depending on the version, so m_iCoachingTeam is checked
+before the team number. Players without a team yet (team 0 during warmup or while connecting) are
+still players. GOTV is flagged in userinfo, but it has xuid 0 so it never makes it into
+stringtable_players and its userinfo slot is tracked separately.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlayerRole {
+    Player,
+    Bot,
+    Coach,
+    Spectator,
+    Hltv,
+}
+
+impl PlayerRole {
+    pub fn new(team_num: Option<u32>, coaching_team: Option<u32>, is_hltv: bool, player_key: PlayerKey) -> Self {
+        if is_hltv {
+            return PlayerRole::Hltv;
+        }
+        if matches!(coaching_team, Some(t) if t > SPECTATOR_TEAM_NUM) {
+            return PlayerRole::Coach;
+        }
+        match team_num {
+            Some(SPECTATOR_TEAM_NUM) => PlayerRole::Spectator,
+            _ if player_key.is_bot() => PlayerRole::Bot,
+            _ => PlayerRole::Player,
+        }
+    }
+    pub fn is_player(&self) -> bool {
+        matches!(self, PlayerRole::Player | PlayerRole::Bot)
+    }
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlayerRole::Player => "player",
+            PlayerRole::Bot => "bot",
+            PlayerRole::Coach => "coach",
+            PlayerRole::Spectator => "spectator",
+            PlayerRole::Hltv => "hltv",
+        }
+    }
+}
+
+// Slots are reused, so a non-GOTV client in the same slot clears the flag
+pub fn track_hltv_userid(hltv_userids: &mut AHashSet<i32>, player: &UserInfo) {
+    match player.is_hltv {
+        true => hltv_userids.insert(player.userid),
+        false => hltv_userids.remove(&player.userid),
+    };
+}
+// Controllers are at userinfo slot + 1
+pub fn is_hltv_controller(hltv_userids: &AHashSet<i32>, controller_entid: i32) -> bool {
+    hltv_userids.contains(&(controller_entid - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::first_pass::stringtables::parse_userinfo;
+    use csgoproto::CMsgPlayerInfo;
+    use prost::Message;
+
+    #[test]
+    fn test_player_role() {
+        let human = PlayerKey::new(Some(76561198000000001), Some("player")).unwrap();
+        let bot = PlayerKey::new(Some(0), Some("BOT Albert")).unwrap();
+        assert_eq!(PlayerRole::new(Some(2), Some(0), false, human), PlayerRole::Player);
+        assert_eq!(PlayerRole::new(Some(3), None, false, bot), PlayerRole::Bot);
+        assert_eq!(PlayerRole::new(Some(1), Some(3), false, human), PlayerRole::Coach);
+        assert_eq!(PlayerRole::new(Some(2), Some(2), false, human), PlayerRole::Coach);
+        assert_eq!(PlayerRole::new(Some(1), Some(0), false, human), PlayerRole::Spectator);
+        assert_eq!(PlayerRole::new(Some(0), None, false, human), PlayerRole::Player);
+        assert_eq!(PlayerRole::new(None, None, false, bot), PlayerRole::Bot);
+        assert_eq!(PlayerRole::new(Some(1), None, true, bot), PlayerRole::Hltv);
+        assert!(!PlayerRole::Coach.is_player());
+        assert!(PlayerRole::Bot.is_player());
+    }
+
+    #[test]
+    fn test_hltv_controller() {
+        let userinfo = |userid: i32, xuid: u64, ishltv: bool| {
+            let info = CMsgPlayerInfo {
+                userid: Some(userid),
+                xuid: Some(xuid),
+                ishltv: Some(ishltv),
+                ..Default::default()
+            };
+            parse_userinfo(&info.encode_to_vec()).unwrap()
+        };
+        let mut hltv_userids = AHashSet::default();
+        track_hltv_userid(&mut hltv_userids, &userinfo(4, 0, true));
+        track_hltv_userid(&mut hltv_userids, &userinfo(2, 76561198000000001, false));
+        assert!(is_hltv_controller(&hltv_userids, 5));
+        assert!(!is_hltv_controller(&hltv_userids, 3));
+        assert!(!is_hltv_controller(&hltv_userids, 4));
+        // GOTV left and a player took the slot
+        track_hltv_userid(&mut hltv_userids, &userinfo(4, 76561198000000002, false));
+        assert!(!is_hltv_controller(&hltv_userids, 5));
+    }
+}
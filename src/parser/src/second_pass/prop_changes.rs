@@ -0,0 +1,179 @@
+use crate::first_pass::prop_controller::PropInfo;
+use crate::second_pass::collect_data::PropType;
+use crate::second_pass::variants::PropColumn;
+use crate::second_pass::variants::Variant;
+use ahash::AHashMap;
+use std::collections::BTreeMap;
+
+/*
+Change-only output for tick data. Instead of a value for every wanted prop, for every player,
+on every tick, a row (tick, steamid, prop, value) is only added when the value differs from the
+last value added for that player and prop. Players are told apart by player_key, as all bots
+have steamid 0. The first time a player is seen every prop is added.
+A prop that goes missing is added as None so forward-filling never keeps a stale value.
+A player that is no longer collected (left the game or stopped matching the row filters) gets
+a PlayerLeft marker, and every prop is added again if they come back.
+
+Each thread starts without previous values, so with multithreading the first tick of every
+chunk repeats the current values. That tick is in reset_ticks so to_dense forgets the players
+of the previous chunk, including ones that left right at the chunk boundary.
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PropChanges {
+    pub ticks: Vec<i32>,
+    pub player_keys: Vec<u64>,
+    pub steamids: Vec<u64>,
+    pub prop_ids: Vec<u32>,
+    pub values: Vec<Option<Variant>>,
+    pub left: Vec<PlayerLeft>,
+    // Every tick that rows were collected at, also the ones without changes
+    pub collected_ticks: Vec<i32>,
+    // First collected tick of each thread, every player present has all props added on it
+    pub reset_ticks: Vec<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerLeft {
+    pub tick: i32,
+    pub player_key: u64,
+    pub steamid: u64,
+}
+
+// Order of events on the same tick in to_dense
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum DenseEvent {
+    Reset,
+    Left(usize),
+    Change(usize),
+}
+
+impl PropChanges {
+    pub fn push(&mut self, tick: i32, player_key: u64, steamid: u64, prop_id: u32, value: Option<Variant>) {
+        self.ticks.push(tick);
+        self.player_keys.push(player_key);
+        self.steamids.push(steamid);
+        self.prop_ids.push(prop_id);
+        self.values.push(value);
+    }
+    pub fn len(&self) -> usize {
+        self.ticks.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.ticks.is_empty()
+    }
+    pub fn extend(&mut self, other: &PropChanges) {
+        self.ticks.extend_from_slice(&other.ticks);
+        self.player_keys.extend_from_slice(&other.player_keys);
+        self.steamids.extend_from_slice(&other.steamids);
+        self.prop_ids.extend_from_slice(&other.prop_ids);
+        self.values.extend(other.values.iter().cloned());
+        self.left.extend(other.left.iter().cloned());
+        self.collected_ticks.extend_from_slice(&other.collected_ticks);
+        self.reset_ticks.extend_from_slice(&other.reset_ticks);
+    }
+    /*
+    Forward-fills the changes back to one row per (tick, player) like the normal output.
+    If ticks is empty every collected tick is used. A player gets rows from the tick they
+    were seen at until the tick they left at.
+    */
+    pub fn to_dense(&self, ticks: &[i32], prop_infos: &[PropInfo]) -> AHashMap<u32, PropColumn> {
+        let mut ticks = if ticks.is_empty() { self.collected_ticks.clone() } else { ticks.to_vec() };
+        ticks.sort();
+        ticks.dedup();
+        let mut events: Vec<(i32, DenseEvent)> = self.reset_ticks.iter().map(|tick| (*tick, DenseEvent::Reset)).collect();
+        events.extend(self.left.iter().enumerate().map(|(idx, left)| (left.tick, DenseEvent::Left(idx))));
+        events.extend(self.ticks.iter().enumerate().map(|(idx, tick)| (*tick, DenseEvent::Change(idx))));
+        events.sort();
+
+        // By player key, (steamid, values)
+        let mut current: BTreeMap<u64, (u64, AHashMap<u32, Option<Variant>>)> = BTreeMap::new();
+        let mut df = AHashMap::default();
+        let mut next = 0;
+        for tick in ticks {
+            while next < events.len() && events[next].0 <= tick {
+                match events[next].1 {
+                    DenseEvent::Reset => current.clear(),
+                    DenseEvent::Left(idx) => {
+                        current.remove(&self.left[idx].player_key);
+                    }
+                    DenseEvent::Change(idx) => {
+                        let (steamid, values) = current.entry(self.player_keys[idx]).or_default();
+                        *steamid = self.steamids[idx];
+                        values.insert(self.prop_ids[idx], self.values[idx].clone());
+                    }
+                }
+                next += 1;
+            }
+            for (steamid, values) in current.values() {
+                for prop_info in prop_infos {
+                    let value = match prop_info.prop_type {
+                        PropType::Tick => Some(Variant::I32(tick)),
+                        PropType::Steamid => Some(Variant::U64(*steamid)),
+                        _ => values.get(&prop_info.id).cloned().flatten(),
+                    };
+                    df.entry(prop_info.id).or_insert_with(PropColumn::new).push(value);
+                }
+            }
+        }
+        df
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::first_pass::prop_controller::{STEAMID_ID, TICK_ID};
+    use crate::second_pass::variants::VarVec;
+
+    fn prop_info(id: u32, prop_type: PropType, name: &str) -> PropInfo {
+        PropInfo {
+            id,
+            prop_type,
+            prop_name: name.to_string(),
+            prop_friendly_name: name.to_string(),
+            is_player_prop: true,
+        }
+    }
+
+    #[test]
+    fn test_prop_changes_to_dense() {
+        let mut changes = PropChanges::default();
+        changes.push(10, 1, 1, 5, Some(Variant::I32(100)));
+        changes.push(12, 1, 1, 5, Some(Variant::I32(80)));
+        changes.push(11, 2, 2, 5, Some(Variant::I32(100)));
+        changes.push(13, 2, 2, 5, None);
+        // Two bots
+        changes.push(13, 1 << 63 | 3, 0, 5, Some(Variant::I32(50)));
+        changes.push(13, 1 << 63 | 4, 0, 5, Some(Variant::I32(60)));
+        changes.collected_ticks = vec![10, 11, 12, 13];
+        let prop_infos = vec![prop_info(TICK_ID, PropType::Tick, "tick"), prop_info(STEAMID_ID, PropType::Steamid, "steamid"), prop_info(5, PropType::Player, "health")];
+
+        let df = changes.to_dense(&[], &prop_infos);
+        assert_eq!(df[&TICK_ID].data, Some(VarVec::I32(vec![Some(10), Some(11), Some(11), Some(12), Some(12), Some(13), Some(13), Some(13), Some(13)])));
+        assert_eq!(df[&STEAMID_ID].data, Some(VarVec::U64(vec![Some(1), Some(1), Some(2), Some(1), Some(2), Some(1), Some(2), Some(0), Some(0)])));
+        assert_eq!(df[&5].data, Some(VarVec::I32(vec![Some(100), Some(100), Some(100), Some(80), Some(100), Some(80), None, Some(50), Some(60)])));
+
+        let df = changes.to_dense(&[12], &prop_infos);
+        assert_eq!(df[&5].data, Some(VarVec::I32(vec![Some(80), Some(100)])));
+    }
+
+    #[test]
+    fn test_prop_changes_to_dense_left_and_reset() {
+        let mut changes = PropChanges::default();
+        changes.push(10, 1, 1, 5, Some(Variant::I32(100)));
+        changes.push(10, 2, 2, 5, Some(Variant::I32(50)));
+        changes.left.push(PlayerLeft { tick: 12, player_key: 2, steamid: 2 });
+        // Every prop is added again when the player comes back
+        changes.push(14, 2, 2, 5, Some(Variant::I32(60)));
+        // Second thread starts at 15 and only sees player 1, player 2 left at the chunk boundary
+        changes.push(15, 1, 1, 5, Some(Variant::I32(100)));
+        changes.collected_ticks = vec![10, 11, 12, 13, 14, 15];
+        changes.reset_ticks = vec![10, 15];
+        let prop_infos = vec![prop_info(TICK_ID, PropType::Tick, "tick"), prop_info(STEAMID_ID, PropType::Steamid, "steamid"), prop_info(5, PropType::Player, "health")];
+
+        let df = changes.to_dense(&[], &prop_infos);
+        assert_eq!(df[&TICK_ID].data, Some(VarVec::I32(vec![Some(10), Some(10), Some(11), Some(11), Some(12), Some(13), Some(14), Some(14), Some(15)])));
+        assert_eq!(df[&STEAMID_ID].data, Some(VarVec::U64(vec![Some(1), Some(2), Some(1), Some(2), Some(1), Some(1), Some(1), Some(2), Some(1)])));
+        assert_eq!(df[&5].data, Some(VarVec::I32(vec![Some(100), Some(50), Some(100), Some(50), Some(100), Some(100), Some(100), Some(60), Some(100)])));
+    }
+}
@@ -0,0 +1,382 @@
+use crate::first_pass::read_bits::DemoParserError;
+use crate::second_pass::lexer::tokenize;
+use crate::second_pass::lexer::Token;
+use crate::second_pass::variants::Variant;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompareOp {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+/*
+Predicate on props that is checked while collecting (tick, player) rows in collect_entities.
+Rows where the filter does not match are never collected, like a filter in polars/pandas
+but without materializing the rows first. A comparison against a missing prop never matches.
+
+The props must also be in wanted_player_props (tick, steamid and name always are),
+filters that use other props are an InvalidRowFilter error. Props can be either the real or the user friendly name.
+Numbers of different types are compared by value, for example I32 against U64.
+
+Filters can be built directly or parsed from a string with RowFilter::parse:
+    is_alive && health < 30 && team_num == 3
+    name == "s1mple" || (steamid in [76561198034202275, 76561197987713664])
+    tick in 10000..20000 && !is_scoped
+    changed(active_weapon_name)
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub enum RowFilter {
+    Compare { prop: String, op: CompareOp, value: Variant },
+    In { prop: String, values: Vec<Variant> },
+    // start <= prop < end, or start <= prop <= end if inclusive
+    Range { prop: String, start: Variant, end: Variant, inclusive: bool },
+    // Value is different from the value at the previous collected tick for the same player
+    Changed(String),
+    // Bare prop: true for true and non-zero numbers
+    IsTrue(String),
+    And(Box<RowFilter>, Box<RowFilter>),
+    Or(Box<RowFilter>, Box<RowFilter>),
+    Not(Box<RowFilter>),
+}
+
+impl RowFilter {
+    pub fn new(prop: &str, op: CompareOp, value: Variant) -> Self {
+        RowFilter::Compare {
+            prop: prop.to_string(),
+            op,
+            value,
+        }
+    }
+    pub fn parse(filter: &str) -> Result<Self, DemoParserError> {
+        let tokens = tokenize(filter, DemoParserError::InvalidRowFilter)?;
+        let mut parser = FilterParser { tokens, pos: 0 };
+        let expr = parser.parse_or()?;
+        match parser.tokens.get(parser.pos) {
+            None => Ok(expr),
+            Some(token) => Err(DemoParserError::InvalidRowFilter(format!("unexpected {:?} in {:?}", token, filter))),
+        }
+    }
+    // Every prop the filter reads
+    pub fn props(&self) -> Vec<&str> {
+        let mut props = vec![];
+        self.visit_props(&mut |prop, _| {
+            if !props.contains(&prop) {
+                props.push(prop)
+            }
+        });
+        props
+    }
+    // Props used inside changed(...)
+    pub fn changed_props(&self) -> Vec<&str> {
+        let mut props = vec![];
+        self.visit_props(&mut |prop, is_changed| {
+            if is_changed && !props.contains(&prop) {
+                props.push(prop)
+            }
+        });
+        props
+    }
+    fn visit_props<'a>(&'a self, f: &mut impl FnMut(&'a str, bool)) {
+        match self {
+            RowFilter::Compare { prop, .. } | RowFilter::In { prop, .. } | RowFilter::Range { prop, .. } | RowFilter::IsTrue(prop) => f(prop, false),
+            RowFilter::Changed(prop) => f(prop, true),
+            RowFilter::And(left, right) | RowFilter::Or(left, right) => {
+                left.visit_props(f);
+                right.visit_props(f);
+            }
+            RowFilter::Not(inner) => inner.visit_props(f),
+        }
+    }
+    /*
+    value_of returns the value of a prop for the row being checked (None if missing)
+    and changed returns if the prop changed since the previous collected tick.
+    */
+    pub fn evaluate(&self, value_of: &impl Fn(&str) -> Option<Variant>, changed: &impl Fn(&str) -> bool) -> bool {
+        match self {
+            RowFilter::Compare { prop, op, value } => match value_of(prop) {
+                Some(v) => compare(&v, *op, value),
+                None => false,
+            },
+            RowFilter::In { prop, values } => match value_of(prop) {
+                Some(v) => values.iter().any(|x| compare(&v, CompareOp::Eq, x)),
+                None => false,
+            },
+            RowFilter::Range { prop, start, end, inclusive } => match value_of(prop) {
+                Some(v) => {
+                    let end_op = if *inclusive { CompareOp::LtEq } else { CompareOp::Lt };
+                    compare(&v, CompareOp::GtEq, start) && compare(&v, end_op, end)
+                }
+                None => false,
+            },
+            RowFilter::Changed(prop) => changed(prop),
+            RowFilter::IsTrue(prop) => match value_of(prop) {
+                Some(Variant::Bool(b)) => b,
+                Some(v) => as_float(&v).is_some_and(|f| f != 0.0),
+                None => false,
+            },
+            RowFilter::And(left, right) => left.evaluate(value_of, changed) && right.evaluate(value_of, changed),
+            RowFilter::Or(left, right) => left.evaluate(value_of, changed) || right.evaluate(value_of, changed),
+            RowFilter::Not(inner) => !inner.evaluate(value_of, changed),
+        }
+    }
+}
+
+fn compare(left: &Variant, op: CompareOp, right: &Variant) -> bool {
+    match compare_variants(left, right) {
+        Some(ordering) => match op {
+            CompareOp::Eq => ordering == Ordering::Equal,
+            CompareOp::NotEq => ordering != Ordering::Equal,
+            CompareOp::Lt => ordering == Ordering::Less,
+            CompareOp::LtEq => ordering != Ordering::Greater,
+            CompareOp::Gt => ordering == Ordering::Greater,
+            CompareOp::GtEq => ordering != Ordering::Less,
+        },
+        None => false,
+    }
+}
+
+fn compare_variants(left: &Variant, right: &Variant) -> Option<Ordering> {
+    match (left, right) {
+        (Variant::Bool(l), Variant::Bool(r)) => Some(l.cmp(r)),
+        (Variant::String(l), Variant::String(r)) => Some(l.cmp(r)),
+        _ => match (as_integer(left), as_integer(right)) {
+            // Steamids don't fit in a f64 so compare integers as integers
+            (Some(l), Some(r)) => Some(l.cmp(&r)),
+            _ => as_float(left)?.partial_cmp(&as_float(right)?),
+        },
+    }
+}
+
+fn as_integer(v: &Variant) -> Option<i128> {
+    match v {
+        Variant::I32(i) => Some(*i as i128),
+        Variant::U32(u) => Some(*u as i128),
+        Variant::U64(u) => Some(*u as i128),
+        _ => None,
+    }
+}
+
+pub fn as_float(v: &Variant) -> Option<f64> {
+    match v {
+        Variant::F32(f) => Some(*f as f64),
+        _ => as_integer(v).map(|i| i as f64),
+    }
+}
+
+fn parse_number(text: &str) -> Result<Variant, DemoParserError> {
+    if text.contains('.') {
+        if let Ok(f) = text.parse::<f32>() {
+            return Ok(Variant::F32(f));
+        }
+    } else if let Ok(i) = text.parse::<i32>() {
+        return Ok(Variant::I32(i));
+    } else if let Ok(u) = text.parse::<u64>() {
+        return Ok(Variant::U64(u));
+    }
+    Err(DemoParserError::InvalidRowFilter(format!("invalid number {:?}", text)))
+}
+
+/*
+Recursive descent, lowest precedence first:
+    or      = and ("||" and)*
+    and     = unary ("&&" unary)*
+    unary   = "!" unary | primary
+    primary = "(" or ")" | "changed" "(" ident ")" | ident [op value | "in" "[" values "]" | "in" value (".." | "..=") value]
+*/
+struct FilterParser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl FilterParser {
+    fn parse_or(&mut self) -> Result<RowFilter, DemoParserError> {
+        let mut left = self.parse_and()?;
+        while self.eat(&Token::Or) {
+            left = RowFilter::Or(Box::new(left), Box::new(self.parse_and()?));
+        }
+        Ok(left)
+    }
+    fn parse_and(&mut self) -> Result<RowFilter, DemoParserError> {
+        let mut left = self.parse_unary()?;
+        while self.eat(&Token::And) {
+            left = RowFilter::And(Box::new(left), Box::new(self.parse_unary()?));
+        }
+        Ok(left)
+    }
+    fn parse_unary(&mut self) -> Result<RowFilter, DemoParserError> {
+        if self.eat(&Token::Not) {
+            return Ok(RowFilter::Not(Box::new(self.parse_unary()?)));
+        }
+        self.parse_primary()
+    }
+    fn parse_primary(&mut self) -> Result<RowFilter, DemoParserError> {
+        if self.eat(&Token::LParen) {
+            let expr = self.parse_or()?;
+            self.expect(&Token::RParen)?;
+            return Ok(expr);
+        }
+        let prop = self.ident()?;
+        if prop == "changed" && self.eat(&Token::LParen) {
+            let prop = self.ident()?;
+            self.expect(&Token::RParen)?;
+            return Ok(RowFilter::Changed(prop));
+        }
+        if self.eat_word("in") {
+            if self.eat(&Token::LBracket) {
+                let mut values = vec![self.value()?];
+                while self.eat(&Token::Comma) {
+                    values.push(self.value()?);
+                }
+                self.expect(&Token::RBracket)?;
+                return Ok(RowFilter::In { prop, values });
+            }
+            let start = self.value()?;
+            let inclusive = match self.next() {
+                Some(Token::Range(inclusive)) => inclusive,
+                other => return Err(unexpected(other, "..")),
+            };
+            let end = self.value()?;
+            return Ok(RowFilter::Range { prop, start, end, inclusive });
+        }
+        if let Some(Token::Compare(op)) = self.tokens.get(self.pos) {
+            let op = *op;
+            self.pos += 1;
+            return Ok(RowFilter::Compare { prop, op, value: self.value()? });
+        }
+        Ok(RowFilter::IsTrue(prop))
+    }
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.tokens.get(self.pos) == Some(token) {
+            self.pos += 1;
+            return true;
+        }
+        false
+    }
+    fn expect(&mut self, token: &Token) -> Result<(), DemoParserError> {
+        match self.next() {
+            Some(t) if &t == token => Ok(()),
+            other => Err(unexpected(other, &format!("{:?}", token))),
+        }
+    }
+    fn eat_word(&mut self, word: &str) -> bool {
+        if matches!(self.tokens.get(self.pos), Some(Token::Ident(w)) if w == word) {
+            self.pos += 1;
+            return true;
+        }
+        false
+    }
+    fn ident(&mut self) -> Result<String, DemoParserError> {
+        match self.next() {
+            Some(Token::Ident(name)) => Ok(name),
+            other => Err(unexpected(other, "prop name")),
+        }
+    }
+    fn value(&mut self) -> Result<Variant, DemoParserError> {
+        match self.next() {
+            Some(Token::Number(text)) => parse_number(&text),
+            Some(Token::Minus) => match self.next() {
+                Some(Token::Number(text)) => parse_number(&format!("-{}", text)),
+                other => Err(unexpected(other, "number")),
+            },
+            Some(Token::Str(s)) => Ok(Variant::String(s)),
+            Some(Token::Ident(word)) if word == "true" => Ok(Variant::Bool(true)),
+            Some(Token::Ident(word)) if word == "false" => Ok(Variant::Bool(false)),
+            other => Err(unexpected(other, "value")),
+        }
+    }
+}
+
+fn unexpected(found: Option<Token>, expected: &str) -> DemoParserError {
+    match found {
+        Some(token) => DemoParserError::InvalidRowFilter(format!("expected {} but found {:?}", expected, token)),
+        None => DemoParserError::InvalidRowFilter(format!("expected {} but the filter ended", expected)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_changes(_: &str) -> bool {
+        false
+    }
+
+    #[test]
+    fn test_row_filter_compares_across_number_types() {
+        let steamid = RowFilter::new("steamid", CompareOp::Eq, Variant::I32(5));
+        assert!(steamid.evaluate(&|_| Some(Variant::U64(5)), &no_changes));
+        assert!(!steamid.evaluate(&|_| Some(Variant::U64(76561198000000005)), &no_changes));
+
+        let tick = RowFilter::new("tick", CompareOp::GtEq, Variant::F32(100.5));
+        assert!(tick.evaluate(&|_| Some(Variant::I32(101)), &no_changes));
+        assert!(!tick.evaluate(&|_| Some(Variant::I32(100)), &no_changes));
+    }
+
+    #[test]
+    fn test_row_filter_mismatched_types_never_match() {
+        let name = RowFilter::new("name", CompareOp::NotEq, Variant::String("player1".to_string()));
+        assert!(name.evaluate(&|_| Some(Variant::String("player2".to_string())), &no_changes));
+        assert!(!name.evaluate(&|_| Some(Variant::I32(1)), &no_changes));
+        assert!(!name.evaluate(&|_| Some(Variant::VecXYZ([0.0; 3])), &no_changes));
+        assert!(!name.evaluate(&|_| None, &no_changes));
+    }
+
+    #[test]
+    fn test_row_filter_parse() {
+        let filter = RowFilter::parse("is_alive && health < 30 || !(team_num in [2, 3]) && tick in 10..=20").unwrap();
+        assert_eq!(
+            filter,
+            RowFilter::Or(
+                Box::new(RowFilter::And(
+                    Box::new(RowFilter::IsTrue("is_alive".to_string())),
+                    Box::new(RowFilter::new("health", CompareOp::Lt, Variant::I32(30))),
+                )),
+                Box::new(RowFilter::And(
+                    Box::new(RowFilter::Not(Box::new(RowFilter::In {
+                        prop: "team_num".to_string(),
+                        values: vec![Variant::I32(2), Variant::I32(3)],
+                    }))),
+                    Box::new(RowFilter::Range {
+                        prop: "tick".to_string(),
+                        start: Variant::I32(10),
+                        end: Variant::I32(20),
+                        inclusive: true,
+                    }),
+                )),
+            )
+        );
+        assert_eq!(filter.props(), vec!["is_alive", "health", "team_num", "tick"]);
+
+        let filter = RowFilter::parse("changed(CCSPlayerPawn.m_iHealth) && name == \"s1mple\" && X >= -1.5").unwrap();
+        assert_eq!(filter.changed_props(), vec!["CCSPlayerPawn.m_iHealth"]);
+        assert!(RowFilter::parse("health <").is_err());
+        assert!(RowFilter::parse("(health < 30").is_err());
+        assert!(RowFilter::parse("name == \"s1mple").is_err());
+    }
+
+    #[test]
+    fn test_row_filter_evaluate() {
+        let filter = RowFilter::parse("is_alive && health in 1..30 && !changed(team_num)").unwrap();
+        let row = |alive: bool, health: i32| {
+            move |prop: &str| match prop {
+                "is_alive" => Some(Variant::Bool(alive)),
+                "health" => Some(Variant::I32(health)),
+                _ => None,
+            }
+        };
+        assert!(filter.evaluate(&row(true, 29), &no_changes));
+        assert!(!filter.evaluate(&row(true, 30), &no_changes));
+        assert!(!filter.evaluate(&row(false, 29), &no_changes));
+        assert!(!filter.evaluate(&row(true, 29), &|prop| prop == "team_num"));
+    }
+}
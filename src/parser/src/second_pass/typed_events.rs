@@ -0,0 +1,396 @@
+use crate::second_pass::game_events::EventField;
+use crate::second_pass::game_events::GameEvent;
+use crate::second_pass::variants::Variant;
+use serde::Serialize;
+use std::fmt;
+
+/*
+Typed versions of the common CS2 game events for Rust users, so that
+    let TypedGameEvent::PlayerDeath(death) = TypedGameEvent::from(&event)
+can be used instead of looking fields up by name and matching Variants.
+
+The structs mirror the event descriptors sent in the demo (the same ones as in GAME_EVENT_LIST_FALLBACK_BYTES,
+the tests check that every field still exists with the same type). Player keys like "attacker" become a
+PlayerRef made from the attacker_name and attacker_steamid fields the parser adds.
+
+Only descriptor fields are typed. Extra player props (user_X etc.) and events the parser builds itself
+(round_start, round_end, item_purchase ...) stay in TypedGameEvent::Other, as does any event whose fields
+don't match, for example after a game update.
+*/
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlayerRef {
+    pub name: Option<String>,
+    pub steamid: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypedEventError {
+    WrongEvent { expected: &'static str, found: String },
+    MissingField { event: &'static str, field: &'static str },
+    WrongType { event: &'static str, field: &'static str, found: Variant },
+}
+
+impl fmt::Display for TypedEventError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypedEventError::WrongEvent { expected, found } => write!(f, "expected {} event but found {}", expected, found),
+            TypedEventError::MissingField { event, field } => write!(f, "{} event has no {} field", event, field),
+            TypedEventError::WrongType { event, field, found } => write!(f, "{}.{} has unexpected value {:?}", event, field, found),
+        }
+    }
+}
+
+impl std::error::Error for TypedEventError {}
+
+// Values a descriptor field can be converted to
+pub trait EventValue: Sized {
+    // Descriptor key types the value comes from, see parse_key in game_events.rs
+    const KEY_TYPES: &'static [i32];
+    fn from_variant(v: &Variant) -> Option<Self>;
+}
+
+impl EventValue for String {
+    const KEY_TYPES: &'static [i32] = &[1];
+    fn from_variant(v: &Variant) -> Option<Self> {
+        match v {
+            Variant::String(s) => Some(s.clone()),
+            _ => None,
+        }
+    }
+}
+impl EventValue for f32 {
+    const KEY_TYPES: &'static [i32] = &[2];
+    fn from_variant(v: &Variant) -> Option<Self> {
+        match v {
+            Variant::F32(f) => Some(*f),
+            _ => None,
+        }
+    }
+}
+impl EventValue for i32 {
+    const KEY_TYPES: &'static [i32] = &[3, 4, 5, 8];
+    fn from_variant(v: &Variant) -> Option<Self> {
+        match v {
+            Variant::I32(i) => Some(*i),
+            _ => None,
+        }
+    }
+}
+impl EventValue for bool {
+    const KEY_TYPES: &'static [i32] = &[6];
+    fn from_variant(v: &Variant) -> Option<Self> {
+        match v {
+            Variant::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+}
+impl EventValue for u64 {
+    const KEY_TYPES: &'static [i32] = &[7];
+    fn from_variant(v: &Variant) -> Option<Self> {
+        match v {
+            Variant::U64(u) => Some(*u),
+            _ => None,
+        }
+    }
+}
+
+fn field_value<T: EventValue>(fields: &[EventField], event: &'static str, field: &'static str) -> Result<T, TypedEventError> {
+    match fields.iter().find(|f| f.name == field) {
+        Some(EventField { data: Some(v), .. }) => T::from_variant(v).ok_or_else(|| TypedEventError::WrongType { event, field, found: v.clone() }),
+        _ => Err(TypedEventError::MissingField { event, field }),
+    }
+}
+
+// None if the player could not be found when the event happened
+fn player_ref(fields: &[EventField], prefix: &str) -> Option<PlayerRef> {
+    let find = |suffix: &str| fields.iter().find(|f| f.name == format!("{}_{}", prefix, suffix)).and_then(|f| f.data.as_ref());
+    let name = match find("name") {
+        Some(Variant::String(name)) => Some(name.clone()),
+        _ => None,
+    };
+    // Steamids are strings in events so that they survive javascript numbers
+    let steamid = match find("steamid") {
+        Some(Variant::String(steamid)) => steamid.parse().ok(),
+        Some(Variant::U64(steamid)) => Some(*steamid),
+        _ => None,
+    };
+    match (&name, steamid) {
+        (None, None) => None,
+        _ => Some(PlayerRef { name, steamid }),
+    }
+}
+
+/*
+    Struct = "event_name" {
+        players { field = "descriptor key", ... }
+        fields { descriptor_key: type, ... }
+    }
+*/
+macro_rules! typed_events {
+    ($($variant:ident = $event:literal { players { $($player:ident = $player_key:literal),* $(,)? } fields { $($field:ident: $ty:ty),* $(,)? } })*) => {
+        $(
+            #[derive(Debug, Clone, PartialEq, Serialize)]
+            pub struct $variant {
+                pub tick: i32,
+                $(pub $player: Option<PlayerRef>,)*
+                $(pub $field: $ty,)*
+            }
+
+            impl $variant {
+                pub const NAME: &'static str = $event;
+            }
+
+            impl TryFrom<&GameEvent> for $variant {
+                type Error = TypedEventError;
+                fn try_from(event: &GameEvent) -> Result<Self, Self::Error> {
+                    if event.name != $event {
+                        return Err(TypedEventError::WrongEvent { expected: $event, found: event.name.clone() });
+                    }
+                    Ok($variant {
+                        tick: event.tick,
+                        $($player: player_ref(&event.fields, stringify!($player)),)*
+                        $($field: field_value(&event.fields, $event, stringify!($field))?,)*
+                    })
+                }
+            }
+        )*
+
+        #[derive(Debug, Clone, PartialEq, Serialize)]
+        pub enum TypedGameEvent {
+            $($variant($variant),)*
+            // Any other event, or a typed one whose fields didn't match
+            Other(GameEvent),
+        }
+
+        impl From<&GameEvent> for TypedGameEvent {
+            fn from(event: &GameEvent) -> Self {
+                let typed = match event.name.as_str() {
+                    $($event => $variant::try_from(event).map(TypedGameEvent::$variant),)*
+                    _ => return TypedGameEvent::Other(event.clone()),
+                };
+                typed.unwrap_or_else(|_| TypedGameEvent::Other(event.clone()))
+            }
+        }
+
+        impl TypedGameEvent {
+            pub fn name(&self) -> &str {
+                match self {
+                    $(TypedGameEvent::$variant(_) => $event,)*
+                    TypedGameEvent::Other(event) => &event.name,
+                }
+            }
+            pub fn tick(&self) -> i32 {
+                match self {
+                    $(TypedGameEvent::$variant(e) => e.tick,)*
+                    TypedGameEvent::Other(event) => event.tick,
+                }
+            }
+        }
+
+        // (event, player keys, (field, key types)) of every typed event
+        #[cfg(test)]
+        const TYPED_EVENT_KEYS: &[(&str, &[&str], &[(&str, &[i32])])] = &[
+            $(($event, &[$($player_key),*], &[$((stringify!($field), <$ty as EventValue>::KEY_TYPES)),*]),)*
+        ];
+    };
+}
+
+typed_events! {
+    PlayerDeath = "player_death" {
+        players { user = "userid", attacker = "attacker", assister = "assister" }
+        fields {
+            assistedflash: bool,
+            weapon: String,
+            weapon_itemid: String,
+            weapon_fauxitemid: String,
+            weapon_originalowner_xuid: String,
+            headshot: bool,
+            dominated: i32,
+            revenge: i32,
+            wipe: i32,
+            penetrated: i32,
+            noreplay: bool,
+            noscope: bool,
+            thrusmoke: bool,
+            attackerblind: bool,
+            distance: f32,
+            dmg_health: i32,
+            dmg_armor: i32,
+            // Name of the hitgroup ("head", "chest" ...), see HIT_GROUP
+            hitgroup: String,
+            attackerinair: bool,
+        }
+    }
+    PlayerHurt = "player_hurt" {
+        players { user = "userid", attacker = "attacker" }
+        fields { health: i32, armor: i32, weapon: String, dmg_health: i32, dmg_armor: i32, hitgroup: String }
+    }
+    PlayerBlind = "player_blind" {
+        players { user = "userid", attacker = "attacker" }
+        fields { entityid: i32, blind_duration: f32 }
+    }
+    PlayerSpawn = "player_spawn" {
+        players { user = "userid" }
+        fields {}
+    }
+    PlayerTeam = "player_team" {
+        players { user = "userid" }
+        fields { team: i32, oldteam: i32, disconnect: bool, silent: bool, isbot: bool }
+    }
+    PlayerConnectFull = "player_connect_full" {
+        players { user = "userid" }
+        fields {}
+    }
+    PlayerDisconnect = "player_disconnect" {
+        players { user = "userid" }
+        fields { reason: i32, name: String, networkid: String, xuid: u64 }
+    }
+    PlayerJump = "player_jump" {
+        players { user = "userid" }
+        fields {}
+    }
+    WeaponFire = "weapon_fire" {
+        players { user = "userid" }
+        fields { weapon: String, silenced: bool }
+    }
+    WeaponReload = "weapon_reload" {
+        players { user = "userid" }
+        fields {}
+    }
+    ItemPickup = "item_pickup" {
+        players { user = "userid" }
+        fields { item: String, silent: bool, defindex: i32 }
+    }
+    BombBeginplant = "bomb_beginplant" {
+        players { user = "userid" }
+        fields { site: i32 }
+    }
+    BombPlanted = "bomb_planted" {
+        players { user = "userid" }
+        fields { site: i32 }
+    }
+    BombBegindefuse = "bomb_begindefuse" {
+        players { user = "userid" }
+        fields { haskit: bool }
+    }
+    BombDefused = "bomb_defused" {
+        players { user = "userid" }
+        fields { site: i32 }
+    }
+    BombExploded = "bomb_exploded" {
+        players { user = "userid" }
+        fields { site: i32 }
+    }
+    HegrenadeDetonate = "hegrenade_detonate" {
+        players { user = "userid" }
+        fields { entityid: i32, x: f32, y: f32, z: f32 }
+    }
+    FlashbangDetonate = "flashbang_detonate" {
+        players { user = "userid" }
+        fields { entityid: i32, x: f32, y: f32, z: f32 }
+    }
+    SmokegrenadeDetonate = "smokegrenade_detonate" {
+        players { user = "userid" }
+        fields { entityid: i32, x: f32, y: f32, z: f32 }
+    }
+    // The thrower is found from the grenade entity
+    InfernoStartburn = "inferno_startburn" {
+        players { user = "entityid" }
+        fields { entityid: i32, x: f32, y: f32, z: f32 }
+    }
+    RoundFreezeEnd = "round_freeze_end" {
+        players {}
+        fields {}
+    }
+    RoundAnnounceMatchStart = "round_announce_match_start" {
+        players {}
+        fields {}
+    }
+    BeginNewMatch = "begin_new_match" {
+        players {}
+        fields {}
+    }
+    RoundMvp = "round_mvp" {
+        players { user = "userid" }
+        fields { reason: i32, value: i32, musickitmvps: i32, nomusic: i32, musickitid: i32 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::first_pass::fallbackbytes::GAME_EVENT_LIST_FALLBACK_BYTES;
+    use csgoproto::CsvcMsgGameEventList;
+    use prost::Message;
+
+    // Fields the parser rewrites in cleanups, so their type differs from the descriptor
+    const CLEANED_UP_FIELDS: [&str; 1] = ["hitgroup"];
+
+    #[test]
+    fn test_typed_events_match_descriptors() {
+        let event_list = CsvcMsgGameEventList::decode(GAME_EVENT_LIST_FALLBACK_BYTES).unwrap();
+        for (event, player_keys, fields) in TYPED_EVENT_KEYS {
+            let desc = match event_list.descriptors.iter().find(|d| d.name() == *event) {
+                Some(desc) => desc,
+                None => panic!("{} not in the event list", event),
+            };
+            for key in *player_keys {
+                assert!(desc.keys.iter().any(|k| k.name() == *key), "{} has no {}", event, key);
+            }
+            for (field, key_types) in *fields {
+                let key = match desc.keys.iter().find(|k| k.name() == *field) {
+                    Some(key) => key,
+                    None => panic!("{} has no {}", event, field),
+                };
+                if !CLEANED_UP_FIELDS.contains(field) {
+                    assert!(key_types.contains(&key.r#type()), "{}.{} has type {}", event, field, key.r#type());
+                }
+            }
+        }
+    }
+
+    fn field(name: &str, data: Variant) -> EventField {
+        EventField {
+            name: name.to_string(),
+            data: Some(data),
+        }
+    }
+
+    #[test]
+    fn test_typed_event_from_game_event() {
+        let mut event = GameEvent {
+            name: "weapon_fire".to_string(),
+            fields: vec![
+                field("weapon", Variant::String("weapon_ak47".to_string())),
+                field("silenced", Variant::Bool(false)),
+                field("user_name", Variant::String("player".to_string())),
+                field("user_steamid", Variant::String("76561198000000001".to_string())),
+                field("user_X", Variant::F32(1.0)),
+            ],
+            tick: 100,
+        };
+        let fire = WeaponFire::try_from(&event).unwrap();
+        assert_eq!(fire.tick, 100);
+        assert_eq!(fire.weapon, "weapon_ak47");
+        assert_eq!(
+            fire.user,
+            Some(PlayerRef {
+                name: Some("player".to_string()),
+                steamid: Some(76561198000000001)
+            })
+        );
+        assert_eq!(TypedGameEvent::from(&event), TypedGameEvent::WeaponFire(fire));
+        assert!(matches!(PlayerDeath::try_from(&event), Err(TypedEventError::WrongEvent { .. })));
+
+        event.fields[1] = field("silenced", Variant::I32(0));
+        assert!(matches!(WeaponFire::try_from(&event), Err(TypedEventError::WrongType { field: "silenced", .. })));
+        assert!(matches!(TypedGameEvent::from(&event), TypedGameEvent::Other(_)));
+        event.fields.remove(1);
+        assert_eq!(WeaponFire::try_from(&event), Err(TypedEventError::MissingField { event: "weapon_fire", field: "silenced" }));
+
+        event.name = "some_event".to_string();
+        let other = TypedGameEvent::from(&event);
+        assert_eq!((other.name(), other.tick()), ("some_event", 100));
+    }
+}
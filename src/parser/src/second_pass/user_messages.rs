@@ -0,0 +1,658 @@
+use crate::first_pass::read_bits::DemoParserError;
+use crate::first_pass::read_bits::ParseWarning;
+use crate::second_pass::game_events::EventField;
+use crate::second_pass::game_events::GameEvent;
+use crate::second_pass::parser_settings::SecondPassParser;
+use crate::second_pass::variants::Variant;
+use csgoproto::message_type::NetMessageType::{self, *};
+use csgoproto::*;
+use prost::Message;
+use serde::ser::{
+    Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+    Serializer,
+};
+use std::fmt;
+
+// Maps each NetMessageType to its prost type and returns the decoded message as a tree of its fields.
+// Every type that csgoproto has a message for is listed, see test_every_message_type_is_decoded.
+macro_rules! decode_to_message_value {
+    ($msg_type:expr, $bytes:expr, $($variant:ident => $proto:ty),* $(,)?) => {
+        match $msg_type {
+            $(
+                $variant => match <$proto>::decode($bytes) {
+                    Ok(msg) => Ok(msg.serialize(ValueSerializer).ok()),
+                    Err(e) => Err(DemoParserError::ProtobufDecodeError(e)),
+                },
+            )*
+            _ => Ok(None),
+        }
+    };
+}
+
+pub fn decode_user_message(msg_type: &NetMessageType, bytes: &[u8]) -> Result<Option<MessageValue>, DemoParserError> {
+    decode_to_message_value!(
+        msg_type,
+        bytes,
+        net_NOP => CnetMsgNop,
+        net_SplitScreenUser => CnetMsgSplitScreenUser,
+        net_Tick => CnetMsgTick,
+        net_StringCmd => CnetMsgStringCmd,
+        net_SetConVar => CnetMsgSetConVar,
+        net_SignonState => CnetMsgSignonState,
+        net_SpawnGroup_Load => CnetMsgSpawnGroupLoad,
+        net_SpawnGroup_ManifestUpdate => CnetMsgSpawnGroupManifestUpdate,
+        net_SpawnGroup_SetCreationTick => CnetMsgSpawnGroupSetCreationTick,
+        net_SpawnGroup_Unload => CnetMsgSpawnGroupUnload,
+        net_SpawnGroup_LoadCompleted => CnetMsgSpawnGroupLoadCompleted,
+        net_DebugOverlay => CnetMsgDebugOverlay,
+        svc_ServerInfo => CsvcMsgServerInfo,
+        svc_FlattenedSerializer => CsvcMsgFlattenedSerializer,
+        svc_ClassInfo => CsvcMsgClassInfo,
+        svc_SetPause => CsvcMsgSetPause,
+        svc_CreateStringTable => CsvcMsgCreateStringTable,
+        svc_UpdateStringTable => CsvcMsgUpdateStringTable,
+        svc_VoiceInit => CsvcMsgVoiceInit,
+        svc_VoiceData => CsvcMsgVoiceData,
+        svc_Print => CsvcMsgPrint,
+        svc_Sounds => CsvcMsgSounds,
+        svc_SetView => CsvcMsgSetView,
+        svc_ClearAllStringTables => CsvcMsgClearAllStringTables,
+        svc_CmdKeyValues => CsvcMsgCmdKeyValues,
+        svc_BSPDecal => CsvcMsgBspDecal,
+        svc_SplitScreen => CsvcMsgSplitScreen,
+        svc_PacketEntities => CsvcMsgPacketEntities,
+        svc_Prefetch => CsvcMsgPrefetch,
+        svc_Menu => CsvcMsgMenu,
+        svc_GetCvarValue => CsvcMsgGetCvarValue,
+        svc_StopSound => CsvcMsgStopSound,
+        svc_PeerList => CsvcMsgPeerList,
+        svc_PacketReliable => CsvcMsgPacketReliable,
+        svc_HLTVStatus => CsvcMsgHltvStatus,
+        svc_ServerSteamID => CsvcMsgServerSteamId,
+        svc_FullFrameSplit => CsvcMsgFullFrameSplit,
+        svc_RconServerDetails => CsvcMsgRconServerDetails,
+        svc_UserMessage => CsvcMsgUserMessage,
+        svc_Broadcast_Command => CsvcMsgBroadcastCommand,
+        svc_HltvFixupOperatorStatus => CsvcMsgHltvFixupOperatorStatus,
+        svc_UserCmds => CsvcMsgUserCommands,
+        svc_NextMsgPredicted => CsvcMsgNextMsgPredicted,
+        UM_AchievementEvent => CUserMessageAchievementEvent,
+        UM_CloseCaption => CUserMessageCloseCaption,
+        UM_CloseCaptionDirect => CUserMessageCloseCaptionDirect,
+        UM_CurrentTimescale => CUserMessageCurrentTimescale,
+        UM_DesiredTimescale => CUserMessageDesiredTimescale,
+        UM_Fade => CUserMessageFade,
+        UM_GameTitle => CUserMessageGameTitle,
+        UM_HudMsg => CUserMessageHudMsg,
+        UM_HudText => CUserMessageHudText,
+        UM_ColoredText => CUserMessageColoredText,
+        UM_RequestState => CUserMessageRequestState,
+        UM_ResetHUD => CUserMessageResetHud,
+        UM_Rumble => CUserMessageRumble,
+        UM_SayText => CUserMessageSayText,
+        UM_SayText2 => CUserMessageSayText2,
+        UM_SayTextChannel => CUserMessageSayTextChannel,
+        UM_Shake => CUserMessageShake,
+        UM_ShakeDir => CUserMessageShakeDir,
+        UM_WaterShake => CUserMessageWaterShake,
+        UM_TextMsg => CUserMessageTextMsg,
+        UM_ScreenTilt => CUserMessageScreenTilt,
+        UM_VoiceMask => CUserMessageVoiceMask,
+        UM_SendAudio => CUserMessageSendAudio,
+        UM_ItemPickup => CUserMessageItemPickup,
+        UM_AmmoDenied => CUserMessageAmmoDenied,
+        UM_ShowMenu => CUserMessageShowMenu,
+        UM_CreditsMsg => CUserMessageCreditsMsg,
+        UM_CloseCaptionPlaceholder => CUserMessageCloseCaptionPlaceholder,
+        UM_CameraTransition => CUserMessageCameraTransition,
+        UM_AudioParameter => CUserMessageAudioParameter,
+        UM_ParticleManager => CUserMsgParticleManager,
+        UM_HudError => CUserMsgHudError,
+        UM_CustomGameEvent => CUserMsgCustomGameEvent,
+        UM_HapticsManagerPulse => CUserMessageHapticsManagerPulse,
+        UM_HapticsManagerEffect => CUserMessageHapticsManagerEffect,
+        UM_UpdateCssClasses => CUserMessageUpdateCssClasses,
+        UM_ServerFrameTime => CUserMessageServerFrameTime,
+        UM_LagCompensationError => CUserMessageLagCompensationError,
+        UM_RequestDllStatus => CUserMessageRequestDllStatus,
+        UM_RequestUtilAction => CUserMessageRequestUtilAction,
+        UM_RequestInventory => CUserMessageRequestInventory,
+        UM_InventoryResponse => CUserMessageInventoryResponse,
+        UM_RequestDiagnostic => CUserMessageRequestDiagnostic,
+        UM_DiagnosticResponse => CUserMessageDiagnosticResponse,
+        UM_ExtraUserData => CUserMessageExtraUserData,
+        UM_NotifyResponseFound => CUserMessageNotifyResponseFound,
+        UM_PlayResponseConditional => CUserMessagePlayResponseConditional,
+        UM_DllStatusResponse => CUserMessageDllStatus,
+        GE_VDebugGameSessionIDEvent => CMsgVDebugGameSessionIdEvent,
+        GE_PlaceDecalEvent => CMsgPlaceDecalEvent,
+        GE_ClearWorldDecalsEvent => CMsgClearWorldDecalsEvent,
+        GE_ClearEntityDecalsEvent => CMsgClearEntityDecalsEvent,
+        GE_ClearDecalsForEntityEvent => CMsgClearDecalsForEntityEvent,
+        GE_Source1LegacyGameEventList => CMsgSource1LegacyGameEventList,
+        GE_Source1LegacyListenEvents => CMsgSource1LegacyListenEvents,
+        GE_Source1LegacyGameEvent => CMsgSource1LegacyGameEvent,
+        GE_SosStartSoundEvent => CMsgSosStartSoundEvent,
+        GE_SosStopSoundEvent => CMsgSosStopSoundEvent,
+        GE_SosSetSoundEventParams => CMsgSosSetSoundEventParams,
+        GE_SosSetLibraryStackFields => CMsgSosSetLibraryStackFields,
+        GE_SosStopSoundEventHash => CMsgSosStopSoundEventHash,
+        GE_PlayerAnimEventId => CMsgTePlayerAnimEvent,
+        GE_RadioIconEventId => CMsgTeRadioIcon,
+        GE_FireBulletsId => CMsgTeFireBullets,
+        GE_PlayerBulletHitId => CMsgPlayerBulletHit,
+        CS_UM_VGUIMenu => CcsUsrMsgVguiMenu,
+        CS_UM_Geiger => CcsUsrMsgGeiger,
+        CS_UM_Train => CcsUsrMsgTrain,
+        CS_UM_HudText => CcsUsrMsgHudText,
+        CS_UM_HudMsg => CcsUsrMsgHudMsg,
+        CS_UM_ResetHud => CcsUsrMsgResetHud,
+        CS_UM_GameTitle => CcsUsrMsgGameTitle,
+        CS_UM_Shake => CcsUsrMsgShake,
+        CS_UM_Fade => CcsUsrMsgFade,
+        CS_UM_Rumble => CcsUsrMsgRumble,
+        CS_UM_CloseCaption => CcsUsrMsgCloseCaption,
+        CS_UM_CloseCaptionDirect => CcsUsrMsgCloseCaptionDirect,
+        CS_UM_SendAudio => CcsUsrMsgSendAudio,
+        CS_UM_RawAudio => CcsUsrMsgRawAudio,
+        CS_UM_VoiceMask => CcsUsrMsgVoiceMask,
+        CS_UM_RequestState => CcsUsrMsgRequestState,
+        CS_UM_Damage => CcsUsrMsgDamage,
+        CS_UM_RadioText => CcsUsrMsgRadioText,
+        CS_UM_HintText => CcsUsrMsgHintText,
+        CS_UM_KeyHintText => CcsUsrMsgKeyHintText,
+        CS_UM_ProcessSpottedEntityUpdate => CcsUsrMsgProcessSpottedEntityUpdate,
+        CS_UM_ReloadEffect => CcsUsrMsgReloadEffect,
+        CS_UM_AdjustMoney => CcsUsrMsgAdjustMoney,
+        CS_UM_StopSpectatorMode => CcsUsrMsgStopSpectatorMode,
+        CS_UM_KillCam => CcsUsrMsgKillCam,
+        CS_UM_DesiredTimescale => CcsUsrMsgDesiredTimescale,
+        CS_UM_CurrentTimescale => CcsUsrMsgCurrentTimescale,
+        CS_UM_AchievementEvent => CcsUsrMsgAchievementEvent,
+        CS_UM_MatchEndConditions => CcsUsrMsgMatchEndConditions,
+        CS_UM_DisconnectToLobby => CcsUsrMsgDisconnectToLobby,
+        CS_UM_PlayerStatsUpdate => CcsUsrMsgPlayerStatsUpdate,
+        CS_UM_ClientInfo => CcsUsrMsgClientInfo,
+        CS_UM_XRankGet => CcsUsrMsgXRankGet,
+        CS_UM_XRankUpd => CcsUsrMsgXRankUpd,
+        CS_UM_CallVoteFailed => CcsUsrMsgCallVoteFailed,
+        CS_UM_VoteStart => CcsUsrMsgVoteStart,
+        CS_UM_VotePass => CcsUsrMsgVotePass,
+        CS_UM_VoteFailed => CcsUsrMsgVoteFailed,
+        CS_UM_VoteSetup => CcsUsrMsgVoteSetup,
+        CS_UM_ServerRankRevealAll => CcsUsrMsgServerRankRevealAll,
+        CS_UM_SendLastKillerDamageToClient => CcsUsrMsgSendLastKillerDamageToClient,
+        CS_UM_ServerRankUpdate => CcsUsrMsgServerRankUpdate,
+        CS_UM_ItemPickup => CcsUsrMsgItemPickup,
+        CS_UM_ShowMenu => CcsUsrMsgShowMenu,
+        CS_UM_BarTime => CcsUsrMsgBarTime,
+        CS_UM_AmmoDenied => CcsUsrMsgAmmoDenied,
+        CS_UM_MarkAchievement => CcsUsrMsgMarkAchievement,
+        CS_UM_MatchStatsUpdate => CcsUsrMsgMatchStatsUpdate,
+        CS_UM_ItemDrop => CcsUsrMsgItemDrop,
+        CS_UM_SendPlayerItemDrops => CcsUsrMsgSendPlayerItemDrops,
+        CS_UM_RoundBackupFilenames => CcsUsrMsgRoundBackupFilenames,
+        CS_UM_SendPlayerItemFound => CcsUsrMsgSendPlayerItemFound,
+        CS_UM_ReportHit => CcsUsrMsgReportHit,
+        CS_UM_XpUpdate => CcsUsrMsgXpUpdate,
+        CS_UM_QuestProgress => CcsUsrMsgQuestProgress,
+        CS_UM_ScoreLeaderboardData => CcsUsrMsgScoreLeaderboardData,
+        CS_UM_PlayerDecalDigitalSignature => CcsUsrMsgPlayerDecalDigitalSignature,
+        CS_UM_WeaponSound => CcsUsrMsgWeaponSound,
+        CS_UM_UpdateScreenHealthBar => CcsUsrMsgUpdateScreenHealthBar,
+        CS_UM_EntityOutlineHighlight => CcsUsrMsgEntityOutlineHighlight,
+        CS_UM_SSUI => CcsUsrMsgSsui,
+        CS_UM_SurvivalStats => CcsUsrMsgSurvivalStats,
+        CS_UM_EndOfMatchAllPlayersData => CcsUsrMsgEndOfMatchAllPlayersData,
+        CS_UM_PostRoundDamageReport => CcsUsrMsgPostRoundDamageReport,
+        CS_UM_RoundEndReportData => CcsUsrMsgRoundEndReportData,
+        CS_UM_CurrentRoundOdds => CcsUsrMsgCurrentRoundOdds,
+        CS_UM_DeepStats => CcsUsrMsgDeepStats,
+        CS_UM_ShootInfo => CcsUsrMsgShootInfo,
+        CS_UM_CounterStrafe => CcsUsrMsgCounterStrafe,
+        CS_UM_DamagePrediction => CcsUsrMsgDamagePrediction,
+        CS_UM_RecurringMissionSchema => CcsUsrMsgRecurringMissionSchema,
+        CS_UM_SendPlayerLoadout => CcsUsrMsgSendPlayerLoadout,
+    )
+}
+
+impl<'a> SecondPassParser<'a> {
+    /*
+    Message types without a prost type, for example ones added to the game after this build
+    ("Unknown_<id>"), are returned with their bytes as hex in "raw" so they can be decoded elsewhere.
+    A message that fails to decode is skipped and stored as a warning instead of stopping the parse.
+    */
+    pub fn parse_user_message(&mut self, msg_id: i32, msg_type: &NetMessageType, bytes: &[u8]) {
+        let name = match msg_type {
+            Unknown => format!("Unknown_{}", msg_id),
+            _ => format!("{:?}", msg_type),
+        };
+        if !self.all_user_messages && !self.wanted_user_messages.contains(&name) {
+            return;
+        }
+        let mut fields = match decode_user_message(msg_type, bytes) {
+            Ok(Some(value)) => message_value_to_fields(value),
+            Ok(None) => vec![EventField {
+                name: "raw".to_string(),
+                data: Some(Variant::String(bytes.iter().map(|b| format!("{:02x}", b)).collect())),
+            }],
+            Err(e) => {
+                let e = e.with_context(|context| context.net_message = Some(name));
+                self.warnings.push(ParseWarning {
+                    tick: self.tick,
+                    byte_offset: self.ptr,
+                    error: e.to_string(),
+                    resumed_at: Some(self.ptr),
+                });
+                return;
+            }
+        };
+        // The demo tick goes into "tick", so a field of the message itself with the same name is renamed
+        for field in fields.iter_mut() {
+            if field.name == "tick" {
+                field.name = "message_tick".to_string();
+            }
+        }
+        fields.push(EventField {
+            data: Some(Variant::I32(self.tick)),
+            name: "tick".to_string(),
+        });
+        self.user_messages.push(GameEvent { name, fields, tick: self.tick });
+    }
+}
+
+/*
+The prost types derive Serialize (see csgoproto/build.rs), so the decoded message is serialized
+into a tree of its fields and flattened into event fields. Nested messages get dotted names ("pos.x")
+and repeated messages get their index ("params.0.x"). Enum fields are i32 in prost so they stay numbers.
+
+Example:
+CcsUsrMsgRadioText { msg_dst: Some(3), client: Some(2), msg_name: Some("#Cstrike_TitlesTXT_Go"), params: ["a", "b"] }
+->
+msg_dst: 3, client: 2, msg_name: "#Cstrike_TitlesTXT_Go", params: ["a", "b"]
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessageValue {
+    None,
+    Scalar(Variant),
+    List(Vec<MessageValue>),
+    Struct(Vec<(String, MessageValue)>),
+}
+
+pub fn message_value_to_fields(value: MessageValue) -> Vec<EventField> {
+    let mut fields = vec![];
+    flatten_message_value("", value, &mut fields);
+    fields
+}
+
+fn flatten_message_value(prefix: &str, value: MessageValue, fields: &mut Vec<EventField>) {
+    match value {
+        MessageValue::None => fields.push(EventField {
+            name: prefix.to_string(),
+            data: None,
+        }),
+        MessageValue::Scalar(v) => fields.push(EventField {
+            name: prefix.to_string(),
+            data: Some(v),
+        }),
+        MessageValue::Struct(inner) => {
+            for (name, v) in inner {
+                let name = if prefix.is_empty() { name } else { format!("{}.{}", prefix, name) };
+                flatten_message_value(&name, v, fields);
+            }
+        }
+        MessageValue::List(values) if values.is_empty() => fields.push(EventField {
+            name: prefix.to_string(),
+            data: None,
+        }),
+        MessageValue::List(values) => match list_to_variant(&values) {
+            Some(v) => fields.push(EventField {
+                name: prefix.to_string(),
+                data: Some(v),
+            }),
+            None => {
+                for (idx, v) in values.into_iter().enumerate() {
+                    flatten_message_value(&format!("{}.{}", prefix, idx), v, fields);
+                }
+            }
+        },
+    }
+}
+
+fn list_to_variant(values: &[MessageValue]) -> Option<Variant> {
+    let mut strings = vec![];
+    let mut ints = vec![];
+    for value in values {
+        match value {
+            MessageValue::Scalar(Variant::String(s)) => strings.push(s.clone()),
+            MessageValue::Scalar(Variant::I32(i)) if *i >= 0 => ints.push(*i as u64),
+            MessageValue::Scalar(Variant::U32(i)) => ints.push(*i as u64),
+            MessageValue::Scalar(Variant::U64(i)) => ints.push(*i),
+            _ => return None,
+        }
+    }
+    if strings.len() == values.len() {
+        return Some(Variant::StringVec(strings));
+    }
+    if ints.len() == values.len() {
+        if ints.iter().all(|x| *x <= u32::MAX as u64) {
+            return Some(Variant::U32Vec(ints.iter().map(|x| *x as u32).collect()));
+        }
+        return Some(Variant::U64Vec(ints));
+    }
+    None
+}
+
+// Serializes a prost message into a MessageValue. Messages only hold scalars, strings, bytes, options, lists and other messages.
+struct ValueSerializer;
+
+impl Serializer for ValueSerializer {
+    type Ok = MessageValue;
+    type Error = fmt::Error;
+    type SerializeSeq = ListSerializer;
+    type SerializeTuple = ListSerializer;
+    type SerializeTupleStruct = ListSerializer;
+    type SerializeTupleVariant = ListSerializer;
+    type SerializeMap = StructSerializer;
+    type SerializeStruct = StructSerializer;
+    type SerializeStructVariant = StructSerializer;
+
+    fn serialize_bool(self, v: bool) -> Result<MessageValue, fmt::Error> {
+        Ok(MessageValue::Scalar(Variant::Bool(v)))
+    }
+    fn serialize_i8(self, v: i8) -> Result<MessageValue, fmt::Error> {
+        self.serialize_i32(v as i32)
+    }
+    fn serialize_i16(self, v: i16) -> Result<MessageValue, fmt::Error> {
+        self.serialize_i32(v as i32)
+    }
+    fn serialize_i32(self, v: i32) -> Result<MessageValue, fmt::Error> {
+        Ok(MessageValue::Scalar(Variant::I32(v)))
+    }
+    // There is no i64 variant, negative values that don't fit i32 are kept as strings
+    fn serialize_i64(self, v: i64) -> Result<MessageValue, fmt::Error> {
+        match (i32::try_from(v), u64::try_from(v)) {
+            (Ok(v), _) => self.serialize_i32(v),
+            (_, Ok(v)) => self.serialize_u64(v),
+            _ => self.serialize_str(&v.to_string()),
+        }
+    }
+    fn serialize_u8(self, v: u8) -> Result<MessageValue, fmt::Error> {
+        self.serialize_u32(v as u32)
+    }
+    fn serialize_u16(self, v: u16) -> Result<MessageValue, fmt::Error> {
+        self.serialize_u32(v as u32)
+    }
+    fn serialize_u32(self, v: u32) -> Result<MessageValue, fmt::Error> {
+        Ok(MessageValue::Scalar(Variant::U32(v)))
+    }
+    fn serialize_u64(self, v: u64) -> Result<MessageValue, fmt::Error> {
+        Ok(MessageValue::Scalar(Variant::U64(v)))
+    }
+    fn serialize_f32(self, v: f32) -> Result<MessageValue, fmt::Error> {
+        Ok(MessageValue::Scalar(Variant::F32(v)))
+    }
+    fn serialize_f64(self, v: f64) -> Result<MessageValue, fmt::Error> {
+        self.serialize_f32(v as f32)
+    }
+    fn serialize_char(self, v: char) -> Result<MessageValue, fmt::Error> {
+        self.serialize_str(&v.to_string())
+    }
+    fn serialize_str(self, v: &str) -> Result<MessageValue, fmt::Error> {
+        Ok(MessageValue::Scalar(Variant::String(v.to_string())))
+    }
+    // Bytes fields are given as hex, same as "raw"
+    fn serialize_bytes(self, v: &[u8]) -> Result<MessageValue, fmt::Error> {
+        self.serialize_str(&v.iter().map(|b| format!("{:02x}", b)).collect::<String>())
+    }
+    fn serialize_none(self) -> Result<MessageValue, fmt::Error> {
+        Ok(MessageValue::None)
+    }
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<MessageValue, fmt::Error> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> Result<MessageValue, fmt::Error> {
+        Ok(MessageValue::None)
+    }
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<MessageValue, fmt::Error> {
+        Ok(MessageValue::None)
+    }
+    fn serialize_unit_variant(self, _name: &'static str, _idx: u32, variant: &'static str) -> Result<MessageValue, fmt::Error> {
+        self.serialize_str(variant)
+    }
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(self, _name: &'static str, value: &T) -> Result<MessageValue, fmt::Error> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _idx: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<MessageValue, fmt::Error> {
+        value.serialize(self)
+    }
+    fn serialize_seq(self, len: Option<usize>) -> Result<ListSerializer, fmt::Error> {
+        Ok(ListSerializer {
+            values: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+    fn serialize_tuple(self, len: usize) -> Result<ListSerializer, fmt::Error> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<ListSerializer, fmt::Error> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_tuple_variant(self, _name: &'static str, _idx: u32, _variant: &'static str, len: usize) -> Result<ListSerializer, fmt::Error> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_map(self, _len: Option<usize>) -> Result<StructSerializer, fmt::Error> {
+        Ok(StructSerializer { fields: vec![], key: None })
+    }
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<StructSerializer, fmt::Error> {
+        self.serialize_map(None)
+    }
+    fn serialize_struct_variant(self, _name: &'static str, _idx: u32, _variant: &'static str, _len: usize) -> Result<StructSerializer, fmt::Error> {
+        self.serialize_map(None)
+    }
+}
+
+struct ListSerializer {
+    values: Vec<MessageValue>,
+}
+
+impl ListSerializer {
+    fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), fmt::Error> {
+        self.values.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+}
+impl SerializeSeq for ListSerializer {
+    type Ok = MessageValue;
+    type Error = fmt::Error;
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), fmt::Error> {
+        self.push(value)
+    }
+    fn end(self) -> Result<MessageValue, fmt::Error> {
+        Ok(MessageValue::List(self.values))
+    }
+}
+impl SerializeTuple for ListSerializer {
+    type Ok = MessageValue;
+    type Error = fmt::Error;
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), fmt::Error> {
+        self.push(value)
+    }
+    fn end(self) -> Result<MessageValue, fmt::Error> {
+        Ok(MessageValue::List(self.values))
+    }
+}
+impl SerializeTupleStruct for ListSerializer {
+    type Ok = MessageValue;
+    type Error = fmt::Error;
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), fmt::Error> {
+        self.push(value)
+    }
+    fn end(self) -> Result<MessageValue, fmt::Error> {
+        Ok(MessageValue::List(self.values))
+    }
+}
+impl SerializeTupleVariant for ListSerializer {
+    type Ok = MessageValue;
+    type Error = fmt::Error;
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), fmt::Error> {
+        self.push(value)
+    }
+    fn end(self) -> Result<MessageValue, fmt::Error> {
+        Ok(MessageValue::List(self.values))
+    }
+}
+
+struct StructSerializer {
+    fields: Vec<(String, MessageValue)>,
+    // Map key waiting for its value
+    key: Option<String>,
+}
+
+impl SerializeMap for StructSerializer {
+    type Ok = MessageValue;
+    type Error = fmt::Error;
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), fmt::Error> {
+        self.key = match key.serialize(ValueSerializer)? {
+            MessageValue::Scalar(Variant::String(s)) => Some(s),
+            MessageValue::Scalar(Variant::I32(i)) => Some(i.to_string()),
+            MessageValue::Scalar(Variant::U32(i)) => Some(i.to_string()),
+            MessageValue::Scalar(Variant::U64(i)) => Some(i.to_string()),
+            MessageValue::Scalar(Variant::Bool(b)) => Some(b.to_string()),
+            _ => return Err(fmt::Error),
+        };
+        Ok(())
+    }
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), fmt::Error> {
+        let key = self.key.take().ok_or(fmt::Error)?;
+        self.fields.push((key, value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+    fn end(self) -> Result<MessageValue, fmt::Error> {
+        Ok(MessageValue::Struct(self.fields))
+    }
+}
+impl SerializeStruct for StructSerializer {
+    type Ok = MessageValue;
+    type Error = fmt::Error;
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, name: &'static str, value: &T) -> Result<(), fmt::Error> {
+        self.fields.push((name.to_string(), value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+    fn end(self) -> Result<MessageValue, fmt::Error> {
+        Ok(MessageValue::Struct(self.fields))
+    }
+}
+impl SerializeStructVariant for StructSerializer {
+    type Ok = MessageValue;
+    type Error = fmt::Error;
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, name: &'static str, value: &T) -> Result<(), fmt::Error> {
+        self.fields.push((name.to_string(), value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+    fn end(self) -> Result<MessageValue, fmt::Error> {
+        Ok(MessageValue::Struct(self.fields))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str, data: Option<Variant>) -> EventField {
+        EventField { name: name.to_string(), data }
+    }
+
+    #[test]
+    fn test_flatten_message() {
+        let msg = CcsUsrMsgRadioText {
+            msg_dst: Some(3),
+            client: None,
+            msg_name: Some("#Go \"now\"".to_string()),
+            params: vec!["a".to_string(), "b".to_string()],
+        };
+        let expected = vec![
+            field("msg_dst", Some(Variant::I32(3))),
+            field("client", None),
+            field("msg_name", Some(Variant::String("#Go \"now\"".to_string()))),
+            field("params", Some(Variant::StringVec(vec!["a".to_string(), "b".to_string()]))),
+        ];
+        assert_eq!(message_value_to_fields(msg.serialize(ValueSerializer).unwrap()), expected);
+    }
+    #[test]
+    fn test_flatten_message_nested_list() {
+        let msg = CcsUsrMsgSendPlayerItemDrops {
+            entity_updates: vec![CEconItemPreviewDataBlock {
+                accountid: Some(7),
+                itemid: Some(76561198000000000),
+                ..Default::default()
+            }],
+        };
+        let fields = message_value_to_fields(msg.serialize(ValueSerializer).unwrap());
+        assert!(fields.contains(&field("entity_updates.0.accountid", Some(Variant::U32(7)))));
+        assert!(fields.contains(&field("entity_updates.0.itemid", Some(Variant::U64(76561198000000000)))));
+        assert!(fields.contains(&field("entity_updates.0.stickers", None)));
+    }
+    #[test]
+    fn test_flatten_message_bytes_and_int_list() {
+        let msg = CsvcMsgVoiceData {
+            client: Some(1),
+            audio: Some(CMsgVoiceAudio {
+                voice_data: Some(vec![0xab, 0x01].into()),
+                section_number: Some(5),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let fields = message_value_to_fields(msg.serialize(ValueSerializer).unwrap());
+        assert!(fields.contains(&field("audio.voice_data", Some(Variant::String("ab01".to_string())))));
+        assert!(fields.contains(&field("audio.section_number", Some(Variant::U32(5)))));
+        assert_eq!(
+            list_to_variant(&[MessageValue::Scalar(Variant::U32(1)), MessageValue::Scalar(Variant::U64(2))]),
+            Some(Variant::U32Vec(vec![1, 2]))
+        );
+    }
+    // Types in message_type.rs that have no message in protobuf.rs, these are returned as "raw"
+    const NO_PROST_TYPE: &[&str] = &[
+        "net_Disconnect_Legacy",
+        "UM_AnimGraphUpdate",
+        "UM_UtilActionResponse",
+        "CS_UM_SayText",
+        "CS_UM_SayText2",
+        "CS_UM_TextMsg",
+        "CS_UM_UpdateTeamMoney",
+        "CS_UM_DisconnectToLobby2",
+    ];
+
+    #[test]
+    fn test_every_message_type_is_decoded() {
+        for msg_id in 0..1000 {
+            let msg_type = NetMessageType::from(msg_id);
+            let name = format!("{:?}", msg_type);
+            if msg_type == Unknown || NO_PROST_TYPE.contains(&name.as_str()) {
+                continue;
+            }
+            // Every field is optional so an empty message is valid for every type
+            assert!(decode_user_message(&msg_type, &[]).unwrap().is_some(), "{} has no prost type", name);
+        }
+        assert!(matches!(decode_user_message(&CS_UM_RadioText, &[0xff]), Err(DemoParserError::ProtobufDecodeError(_))));
+    }
+    #[test]
+    fn test_decode_user_message_roundtrip() {
+        let msg = CcsUsrMsgRadioText {
+            msg_dst: Some(3),
+            client: Some(2),
+            msg_name: Some("#Cstrike_TitlesTXT_Go".to_string()),
+            params: vec!["a".to_string()],
+        };
+        let value = decode_user_message(&CS_UM_RadioText, &msg.encode_to_vec()).unwrap().unwrap();
+        let fields = message_value_to_fields(value);
+        assert!(fields.contains(&field("msg_name", Some(Variant::String("#Cstrike_TitlesTXT_Go".to_string())))));
+        assert!(fields.contains(&field("params", Some(Variant::StringVec(vec!["a".to_string()])))));
+    }
+}
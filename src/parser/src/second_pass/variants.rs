@@ -24,6 +24,26 @@ pub enum Variant {
     Stickers(Vec<Sticker>),
     InputHistory(Vec<InputHistory>),
 }
+impl Variant {
+    // Name of the variant, used to describe what a prop or column holds
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Variant::Bool(_) => "bool",
+            Variant::U32(_) => "u32",
+            Variant::I32(_) => "i32",
+            Variant::F32(_) => "f32",
+            Variant::U64(_) => "u64",
+            Variant::String(_) => "string",
+            Variant::VecXY(_) => "vec_xy",
+            Variant::VecXYZ(_) => "vec_xyz",
+            Variant::StringVec(_) => "string_vec",
+            Variant::U32Vec(_) => "u32_vec",
+            Variant::U64Vec(_) => "u64_vec",
+            Variant::Stickers(_) => "stickers",
+            Variant::InputHistory(_) => "input_history",
+        }
+    }
+}
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Sticker {
     pub name: String,
@@ -557,7 +577,7 @@ impl Serialize for PlayerEndMetaData {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_struct("PlayerEndMetaData", 3)?;
+        let mut state = serializer.serialize_struct("PlayerEndMetaData", 5)?;
         state.serialize_field("name", &self.name)?;
         let steamid = match self.steamid {
             Some(u) => Some(u.to_string()),
@@ -565,6 +585,8 @@ impl Serialize for PlayerEndMetaData {
         };
         state.serialize_field("steamid", &steamid)?;
         state.serialize_field("team_number", &self.team_number)?;
+        state.serialize_field("player_key", &self.player_key.0.to_string())?;
+        state.serialize_field("role", &self.role)?;
         state.end()
     }
 }
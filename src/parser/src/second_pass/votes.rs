@@ -0,0 +1,255 @@
+use crate::second_pass::game_events::EventField;
+use crate::second_pass::game_events::GameEvent;
+use crate::second_pass::variants::Variant;
+use serde::Serialize;
+
+// Events needed for building votes. "vote_cast" is a real game event, the rest are custom events.
+pub const VOTE_EVENTS: [&str; 5] = ["vote_start", "vote_pass", "vote_failed", "call_vote_failed", "vote_cast"];
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlayerVote {
+    pub tick: i32,
+    pub steamid: Option<u64>,
+    pub name: Option<String>,
+    pub vote: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VoteRecord {
+    pub start_tick: i32,
+    pub end_tick: Option<i32>,
+    pub vote_type: Option<String>,
+    pub team: Option<i32>,
+    pub issuer_steamid: Option<u64>,
+    pub issuer_name: Option<String>,
+    pub target_steamid: Option<u64>,
+    pub target_name: Option<String>,
+    pub details: Option<String>,
+    // "passed", "failed", "call_failed" or "unfinished"
+    pub outcome: String,
+    pub failed_reason: Option<String>,
+    pub votes: Vec<PlayerVote>,
+}
+
+/*
+Builds votes from the vote events. Each team can have its own vote running at the same time
+(team -1 is a vote for everyone), so "vote_cast", "vote_pass" and "vote_failed" are matched to the
+active vote by their team. A "vote_start" while the same team still has a vote active means the
+earlier vote never finished in the demo.
+"call_vote_failed" means the vote never started (for example calling a vote too often).
+*/
+pub fn collect_votes(events: &[GameEvent]) -> Vec<VoteRecord> {
+    let mut events: Vec<&GameEvent> = events.iter().filter(|x| VOTE_EVENTS.contains(&x.name.as_str())).collect();
+    events.sort_by_key(|x| x.tick);
+
+    let mut votes = vec![];
+    let mut active: Vec<VoteRecord> = vec![];
+    for event in events {
+        let team = extract_i32("team", &event.fields);
+        match event.name.as_str() {
+            "vote_start" => {
+                let (unfinished, still_active): (Vec<VoteRecord>, Vec<VoteRecord>) = active.into_iter().partition(|vote| teams_overlap(vote.team, team));
+                votes.extend(unfinished);
+                active = still_active;
+                active.push(VoteRecord {
+                    start_tick: event.tick,
+                    end_tick: None,
+                    vote_type: extract_string("vote_type", &event.fields),
+                    team,
+                    issuer_steamid: extract_steamid("user_steamid", &event.fields),
+                    issuer_name: extract_string("user_name", &event.fields),
+                    target_steamid: extract_steamid("target_steamid", &event.fields),
+                    target_name: extract_string("target_name", &event.fields),
+                    details: extract_string("details_str", &event.fields),
+                    outcome: "unfinished".to_string(),
+                    failed_reason: None,
+                    votes: vec![],
+                });
+            }
+            "vote_cast" => {
+                if let Some(idx) = find_active_vote(&active, team) {
+                    let vote_option = match extract_i32("vote_option", &event.fields) {
+                        Some(0) => "yes".to_string(),
+                        Some(1) => "no".to_string(),
+                        Some(option) => format!("option{}", option + 1),
+                        None => "unknown".to_string(),
+                    };
+                    active[idx].votes.push(PlayerVote {
+                        tick: event.tick,
+                        steamid: extract_steamid("user_steamid", &event.fields),
+                        name: extract_string("user_name", &event.fields),
+                        vote: vote_option,
+                    });
+                }
+            }
+            "vote_pass" => {
+                if let Some(idx) = find_active_vote(&active, team) {
+                    let mut vote = active.remove(idx);
+                    vote.end_tick = Some(event.tick);
+                    vote.outcome = "passed".to_string();
+                    votes.push(vote);
+                }
+            }
+            "vote_failed" => {
+                if let Some(idx) = find_active_vote(&active, team) {
+                    let mut vote = active.remove(idx);
+                    vote.end_tick = Some(event.tick);
+                    vote.outcome = "failed".to_string();
+                    vote.failed_reason = extract_string("reason", &event.fields);
+                    votes.push(vote);
+                }
+            }
+            "call_vote_failed" => {
+                votes.push(VoteRecord {
+                    start_tick: event.tick,
+                    end_tick: Some(event.tick),
+                    vote_type: None,
+                    team: None,
+                    issuer_steamid: None,
+                    issuer_name: None,
+                    target_steamid: None,
+                    target_name: None,
+                    details: None,
+                    outcome: "call_failed".to_string(),
+                    failed_reason: extract_string("reason", &event.fields),
+                    votes: vec![],
+                });
+            }
+            _ => {}
+        }
+    }
+    votes.extend(active);
+    votes.sort_by_key(|vote| vote.start_tick);
+    votes
+}
+
+// Active vote of the same team, otherwise one the event could belong to (vote for everyone or team unknown)
+fn find_active_vote(active: &[VoteRecord], team: Option<i32>) -> Option<usize> {
+    active
+        .iter()
+        .position(|vote| team.is_some() && vote.team == team)
+        .or_else(|| active.iter().position(|vote| teams_overlap(vote.team, team)))
+}
+fn teams_overlap(a: Option<i32>, b: Option<i32>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a == b || a == -1 || b == -1,
+        _ => true,
+    }
+}
+
+fn extract_field<'a>(name: &str, fields: &'a [EventField]) -> Option<&'a Variant> {
+    for field in fields {
+        if field.name == name {
+            return field.data.as_ref();
+        }
+    }
+    None
+}
+fn extract_string(name: &str, fields: &[EventField]) -> Option<String> {
+    match extract_field(name, fields) {
+        Some(Variant::String(s)) => Some(s.clone()),
+        _ => None,
+    }
+}
+fn extract_i32(name: &str, fields: &[EventField]) -> Option<i32> {
+    match extract_field(name, fields) {
+        Some(Variant::I32(i)) => Some(*i),
+        Some(Variant::U32(i)) => Some(*i as i32),
+        _ => None,
+    }
+}
+fn extract_steamid(name: &str, fields: &[EventField]) -> Option<u64> {
+    match extract_field(name, fields) {
+        Some(Variant::String(s)) => s.parse::<u64>().ok(),
+        Some(Variant::U64(steamid)) => Some(*steamid),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(name: &str, tick: i32, fields: Vec<(&str, Variant)>) -> GameEvent {
+        GameEvent {
+            name: name.to_string(),
+            tick,
+            fields: fields
+                .into_iter()
+                .map(|(name, data)| EventField {
+                    name: name.to_string(),
+                    data: Some(data),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_collect_votes() {
+        let events = vec![
+            event(
+                "vote_cast",
+                110,
+                vec![("vote_option", Variant::I32(1)), ("user_steamid", Variant::String("2".to_string()))],
+            ),
+            event(
+                "vote_start",
+                100,
+                vec![
+                    ("vote_type", Variant::String("kick".to_string())),
+                    ("user_steamid", Variant::String("1".to_string())),
+                ],
+            ),
+            event("vote_pass", 120, vec![]),
+            event("call_vote_failed", 200, vec![("reason", Variant::String("failed_recently".to_string()))]),
+        ];
+        let votes = collect_votes(&events);
+        assert_eq!(votes.len(), 2);
+        assert_eq!(votes[0].vote_type, Some("kick".to_string()));
+        assert_eq!(votes[0].issuer_steamid, Some(1));
+        assert_eq!(votes[0].outcome, "passed");
+        assert_eq!(votes[0].end_tick, Some(120));
+        assert_eq!(votes[0].votes[0].vote, "no");
+        assert_eq!(votes[0].votes[0].steamid, Some(2));
+        assert_eq!(votes[1].outcome, "call_failed");
+        assert_eq!(votes[1].failed_reason, Some("failed_recently".to_string()));
+    }
+
+    #[test]
+    fn test_collect_votes_overlapping_teams() {
+        let events = vec![
+            event("vote_start", 100, vec![("vote_type", Variant::String("surrender".to_string())), ("team", Variant::I32(2))]),
+            event("vote_start", 105, vec![("vote_type", Variant::String("start_timeout".to_string())), ("team", Variant::I32(3))]),
+            event("vote_cast", 110, vec![("vote_option", Variant::I32(0)), ("team", Variant::I32(2)), ("user_steamid", Variant::String("1".to_string()))]),
+            event("vote_cast", 111, vec![("vote_option", Variant::I32(1)), ("team", Variant::I32(3)), ("user_steamid", Variant::String("2".to_string()))]),
+            event("vote_cast", 112, vec![("vote_option", Variant::I32(0)), ("team", Variant::I32(3)), ("user_steamid", Variant::String("3".to_string()))]),
+            event("vote_pass", 120, vec![("team", Variant::I32(3))]),
+            event("vote_failed", 130, vec![("team", Variant::I32(2)), ("reason", Variant::String("not_enough_votes".to_string()))]),
+        ];
+        let votes = collect_votes(&events);
+        assert_eq!(votes.len(), 2);
+        assert_eq!((votes[0].vote_type.as_deref(), votes[0].outcome.as_str(), votes[0].end_tick), (Some("surrender"), "failed", Some(130)));
+        assert_eq!(votes[0].votes.iter().map(|v| v.steamid).collect::<Vec<_>>(), vec![Some(1)]);
+        assert_eq!((votes[1].vote_type.as_deref(), votes[1].outcome.as_str(), votes[1].end_tick), (Some("start_timeout"), "passed", Some(120)));
+        assert_eq!(votes[1].votes.iter().map(|v| v.steamid).collect::<Vec<_>>(), vec![Some(2), Some(3)]);
+    }
+
+    #[test]
+    fn test_collect_votes_after_unfinished_vote() {
+        let events = vec![
+            event("vote_start", 100, vec![("vote_type", Variant::String("kick".to_string())), ("team", Variant::I32(2))]),
+            event("vote_cast", 101, vec![("vote_option", Variant::I32(0)), ("team", Variant::I32(2))]),
+            // The first vote never ended in the demo
+            event("vote_start", 200, vec![("vote_type", Variant::String("surrender".to_string())), ("team", Variant::I32(2))]),
+            event("vote_cast", 201, vec![("vote_option", Variant::I32(1)), ("team", Variant::I32(2))]),
+            event("vote_cast", 202, vec![("vote_option", Variant::I32(1)), ("team", Variant::I32(2))]),
+            event("vote_failed", 210, vec![("team", Variant::I32(2))]),
+        ];
+        let votes = collect_votes(&events);
+        assert_eq!(votes.len(), 2);
+        assert_eq!((votes[0].vote_type.as_deref(), votes[0].outcome.as_str(), votes[0].end_tick), (Some("kick"), "unfinished", None));
+        assert_eq!(votes[0].votes.len(), 1);
+        assert_eq!((votes[1].vote_type.as_deref(), votes[1].outcome.as_str(), votes[1].end_tick), (Some("surrender"), "failed", Some(210)));
+        assert_eq!(votes[1].votes.len(), 2);
+    }
+}
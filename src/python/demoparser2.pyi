@@ -1,5 +1,7 @@
 import pandas as pd
+import polars as pl
 from typing import (
+    Callable,
     Dict,
     Sequence,
     Optional,
@@ -18,6 +20,84 @@ class VoiceData(TypedDict):
     steamid: int
     bytes: bytes
 
+@type_check_only
+class PlayerVote(TypedDict):
+    tick: int
+    steamid: Optional[int]
+    name: Optional[str]
+    vote: str
+
+@type_check_only
+class Vote(TypedDict):
+    start_tick: int
+    end_tick: Optional[int]
+    vote_type: Optional[str]
+    team: Optional[int]
+    issuer_steamid: Optional[int]
+    issuer_name: Optional[str]
+    target_steamid: Optional[int]
+    target_name: Optional[str]
+    details: Optional[str]
+    outcome: str
+    failed_reason: Optional[str]
+    votes: List[PlayerVote]
+
+@type_check_only
+class ValidationIssue(TypedDict):
+    byte_offset: int
+    tick: Optional[int]
+    message: str
+
+@type_check_only
+class TickGap(TypedDict):
+    byte_offset: int
+    from_tick: int
+    to_tick: int
+
+@type_check_only
+class ValidationReport(TypedDict):
+    is_valid: bool
+    issues: List[ValidationIssue]
+    file_length: int
+    n_frames: int
+    first_tick: Optional[int]
+    last_tick: Optional[int]
+    last_good_offset: int
+    tick_gaps: List[TickGap]
+    has_file_header: bool
+    has_sendtables: bool
+    has_class_info: bool
+    has_game_event_list: bool
+    has_stop: bool
+
+@type_check_only
+class RetypedProp(TypedDict):
+    path: str
+    var_type_before: Optional[str]
+    var_type_after: Optional[str]
+    decoder_before: str
+    decoder_after: str
+    variant_type_before: str
+    variant_type_after: str
+
+@type_check_only
+class ChangedGameEvent(TypedDict):
+    name: str
+    added_keys: List[str]
+    removed_keys: List[str]
+    retyped_keys: List[str]
+
+@type_check_only
+class SchemaDiff(TypedDict):
+    added_props: List[str]
+    removed_props: List[str]
+    retyped_props: List[RetypedProp]
+    added_serializers: List[str]
+    removed_serializers: List[str]
+    added_game_events: List[str]
+    removed_game_events: List[str]
+    changed_game_events: List[ChangedGameEvent]
+
 @type_check_only
 class WantedPropStateProtocol(Protocol):
     prop: str
@@ -27,11 +107,25 @@ class WantedPropStateProtocol(Protocol):
 class WantedPropState:
     def __new__(cls, prop: str, state: Union[bool, str, int, float]) -> WantedPropState: ...
 
+@final
+class ArrowTable:
+    """Parser output in Arrow format. Pass it to pyarrow.table, polars.DataFrame or duckdb."""
+    column_names: List[str]
+    def __arrow_c_stream__(self, requested_schema: Optional[object] = None) -> object: ...
+    def __len__(self) -> int: ...
+
 @final
 class DemoParser:
-    def __new__(cls, demo_path: str) -> DemoParser: ...
+    def __new__(
+        cls,
+        demo_path: str,
+        lenient: bool = False,
+        progress_callback: Optional[Callable[[int, int], None]] = None,
+        include_non_players: bool = False,
+    ) -> DemoParser: ...
     def parse_header(self) -> Dict[str, str]: ...
     def list_updated_fields(self) -> List[str]: ...
+    def describe_schema(self) -> pd.DataFrame: ...
     def list_game_events(self) -> List[str]: ...
     def parse_grenades(
         self, *, extra: Optional[Sequence[str]] = None, grenades: bool = True
@@ -53,7 +147,55 @@ class DemoParser:
         player: Optional[Sequence[str]] = None,
         other: Optional[Sequence[str]] = None,
     ) -> List[Tuple[str, pd.DataFrame]]: ...
+    async def parse_event_async(
+        self,
+        event_name: str,
+        *,
+        player: Optional[Sequence[str]] = None,
+        other: Optional[Sequence[str]] = None,
+    ) -> pd.DataFrame: ...
+    async def parse_events_async(
+        self,
+        event_name: Sequence[str],
+        *,
+        player: Optional[Sequence[str]] = None,
+        other: Optional[Sequence[str]] = None,
+    ) -> List[Tuple[str, pd.DataFrame]]: ...
+    def parse_user_messages(
+        self, message_names: Sequence[str]
+    ) -> List[Tuple[str, pd.DataFrame]]:
+        """Decode net/user messages by name, for example "CS_UM_RadioText".
+
+        Pass "all" to get every message. Nested message fields are flattened
+        with a dot, for example "pos.x".
+        """
+        ...
+    def parse_votes(self) -> List[Vote]:
+        """Votes with start/end tick, issuer, target, vote type, outcome and each player's vote."""
+        ...
     def parse_voice(self) -> List[VoiceData]: ...
+    def parse_player_identity(self) -> pd.DataFrame:
+        """Every change of the userinfo string table.
+
+        Returns:
+            pd.DataFrame: tick, slot, userid, steamid (0 for bots), name, fake_player and is_hltv.
+                A player renaming mid-match is a new row with the same steamid.
+        """
+    def parse_string_tables(
+        self, names: Optional[Sequence[str]] = None
+    ) -> Tuple[Dict[str, pd.DataFrame], pd.DataFrame]:
+        """Contents of the string tables at the end of the demo and every update to them.
+
+        Args:
+            names: tables to return, for example ["userinfo", "modelprecache"]. All tables if None.
+
+        Returns:
+            (tables, updates): a DataFrame with idx, key, value and decoded per table, and one row
+                per added or changed entry with tick, table, idx, key, value and decoded.
+        """
+    def validate_demo(self) -> ValidationReport:
+        """Checks the demo for truncation, missing tables and tick problems without parsing it."""
+        ...
     def parse_ticks(
         self,
         wanted_props: Sequence[str],
@@ -63,6 +205,8 @@ class DemoParser:
         prop_states: Optional[
             Sequence[WantedPropStateProtocol | WantedPropState]
         ] = None,
+        filter: Optional[str] = None,
+        derived_columns: Optional[Sequence[str]] = None,
     ) -> pd.DataFrame:
         """Parse the specified props.
 
@@ -72,9 +216,85 @@ class DemoParser:
                 `None` or an empty Sequence means all players. Defaults to `None`.
             ticks (Optional[Sequence[int]]): Sequence of ticks to parse.
                 `None` or an empty Sequence means all ticks. Defaults to `None`.
+            filter (Optional[str]): Only keep rows where this expression is true,
+                e.g. `"is_alive && health < 30 && team_num == 3"`. Props used in the
+                filter are added to the output. Defaults to `None`.
+            derived_columns (Optional[Sequence[str]]): Extra float columns computed from
+                other props while parsing, e.g. `"speed_2d = sqrt(velocity_X^2 + velocity_Y^2)"`
+                or `"hp_lost = prev(health) - health"`. Props they use are not added
+                to the output. Defaults to `None`.
 
         Returns:
             pd.DataFrame: Dataframe of all the parsed props for each player at each tick.
         """
+    async def parse_ticks_async(
+        self,
+        wanted_props: Sequence[str],
+        *,
+        players: Optional[Sequence[int]] = None,
+        ticks: Optional[Sequence[int]] = None,
+        prop_states: Optional[
+            Sequence[WantedPropStateProtocol | WantedPropState]
+        ] = None,
+        filter: Optional[str] = None,
+        derived_columns: Optional[Sequence[str]] = None,
+    ) -> pd.DataFrame:
+        """Same as parse_ticks but parses in the background. Must be awaited inside a running event loop."""
+    def parse_event_windows(
+        self,
+        event_name: str,
+        wanted_props: Sequence[str],
+        *,
+        start: int,
+        end: int = 0,
+        step: int = 1,
+    ) -> pd.DataFrame:
+        """Props of the players in each event at event tick + start, + start + step ... + end, in one pass.
+
+        Returns:
+            pd.DataFrame: event_index (index in parse_event(event_name)), event_tick, offset, role
+                ("attacker", "user" ...) and the parsed props.
+        """
+    def parse_prop_changes(
+        self,
+        wanted_props: Sequence[str],
+        *,
+        players: Optional[Sequence[int]] = None,
+        ticks: Optional[Sequence[int]] = None,
+        prop_states: Optional[
+            Sequence[WantedPropStateProtocol | WantedPropState]
+        ] = None,
+        filter: Optional[str] = None,
+        derived_columns: Optional[Sequence[str]] = None,
+        dense: bool = False,
+    ) -> pd.DataFrame:
+        """Only the changes of the props: one (tick, steamid, prop, value) row per change.
+
+        With dense=True the changes are forward-filled to the same DataFrame as parse_ticks.
+        """
+    def scan_ticks(self, wanted_props: Sequence[str]) -> pl.LazyFrame:
+        """Lazy parse_ticks. Selected columns and simple filters on columns are applied while parsing."""
+    def parse_ticks_arrow(
+        self,
+        wanted_props: Sequence[str],
+        *,
+        players: Optional[Sequence[int]] = None,
+        ticks: Optional[Sequence[int]] = None,
+        prop_states: Optional[
+            Sequence[WantedPropStateProtocol | WantedPropState]
+        ] = None,
+        filter: Optional[str] = None,
+        derived_columns: Optional[Sequence[str]] = None,
+    ) -> ArrowTable:
+        """Same as parse_ticks but returns an ArrowTable with typed struct/list columns."""
+    def parse_events_arrow(
+        self,
+        event_name: Sequence[str],
+        *,
+        player: Optional[Sequence[str]] = None,
+        other: Optional[Sequence[str]] = None,
+    ) -> List[Tuple[str, ArrowTable]]: ...
+
+def diff_schemas(demo_a: str, demo_b: str) -> SchemaDiff: ...
 
-__all__ = ["DemoParser", "WantedPropState"]
+__all__ = ["ArrowTable", "DemoParser", "WantedPropState", "diff_schemas"]
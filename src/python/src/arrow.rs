@@ -0,0 +1,320 @@
+use crate::Exception;
+use itertools::Itertools;
+use parser::parse_demo::DemoOutput;
+use parser::second_pass::game_events::GameEvent;
+use parser::second_pass::variants::PropColumn;
+use parser::second_pass::variants::VarVec;
+use polars::prelude::ArrowField;
+use polars_arrow::array::{
+    Array, BooleanArray, Float32Array, Int32Array, ListArray, NullArray, StructArray, UInt32Array,
+    UInt64Array, Utf8Array,
+};
+use polars_arrow::bitmap::Bitmap;
+use polars_arrow::datatypes::ArrowDataType;
+use polars_arrow::ffi;
+use polars_arrow::offset::Offsets;
+use pyo3::prelude::*;
+use pyo3::types::PyCapsule;
+use std::ffi::CString;
+
+/*
+Columns kept as arrow arrays so they can be handed out through the Arrow PyCapsule interface
+(__arrow_c_stream__) without going through python objects. pyarrow.table(t), polars.DataFrame(t)
+and duckdb all accept this directly.
+
+Vector props become structs ({x, y, z}), stickers and input history become lists of structs and
+StringVec/U32Vec/U64Vec (inventory etc.) become lists.
+*/
+#[pyclass]
+pub struct ArrowTable {
+    names: Vec<String>,
+    columns: Vec<Box<dyn Array>>,
+}
+
+// The stream is only touched by whoever consumes the capsule, the raw pointers inside are never shared.
+#[repr(transparent)]
+struct ArrowStreamCapsule(ffi::ArrowArrayStream);
+unsafe impl Send for ArrowStreamCapsule {}
+
+#[pymethods]
+impl ArrowTable {
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_stream__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyCapsule>> {
+        let fields = self
+            .names
+            .iter()
+            .zip(&self.columns)
+            .map(|(name, col)| ArrowField::new(name, col.data_type().clone(), true))
+            .collect_vec();
+        let data_type = ArrowDataType::Struct(fields);
+        // Casting is optional in the protocol. We don't cast, so a different schema is an error
+        // instead of the consumer silently getting other types than it asked for.
+        if let Some(requested_schema) = requested_schema {
+            let capsule = requested_schema.cast::<PyCapsule>()?;
+            let schema = capsule.pointer() as *const ffi::ArrowSchema;
+            let requested = unsafe { ffi::import_field_from_c(&*schema) }
+                .map_err(|e| Exception::new_err(format!("invalid requested_schema: {e}")))?;
+            if requested.data_type() != &data_type {
+                return Err(Exception::new_err(format!(
+                    "requested_schema {:?} differs from the schema of the table {:?}, cast after reading instead",
+                    requested.data_type(),
+                    data_type
+                )));
+            }
+        }
+        let batch = StructArray::new(data_type.clone(), self.columns.clone(), None).boxed();
+        let stream = ffi::export_iterator(
+            Box::new(std::iter::once(Ok(batch))),
+            ArrowField::new("", data_type, false),
+        );
+        let name = CString::new("arrow_array_stream").unwrap();
+        PyCapsule::new_with_destructor(py, ArrowStreamCapsule(stream), Some(name), |_, _| {})
+    }
+    #[getter]
+    fn column_names(&self) -> Vec<String> {
+        self.names.clone()
+    }
+    fn __len__(&self) -> usize {
+        self.columns.first().map_or(0, |col| col.len())
+    }
+}
+
+impl ArrowTable {
+    pub fn new(mut columns: Vec<(String, Box<dyn Array>)>) -> PyResult<Self> {
+        columns.sort_by(|a, b| a.0.cmp(&b.0));
+        if let Some((first_name, first)) = columns.first() {
+            if let Some((name, col)) = columns.iter().find(|(_, col)| col.len() != first.len()) {
+                return Err(Exception::new_err(format!(
+                    "column {name} has {} rows but {first_name} has {} rows",
+                    col.len(),
+                    first.len()
+                )));
+            }
+        }
+        let (names, columns) = columns.into_iter().unzip();
+        Ok(ArrowTable { names, columns })
+    }
+}
+
+pub fn ticks_to_arrow(output: &DemoOutput) -> PyResult<ArrowTable> {
+    let mut columns = vec![];
+    for prop_info in &output.prop_controller.prop_infos {
+        if let Some(col) = output.df.get(&prop_info.id) {
+            columns.push((
+                prop_info.prop_friendly_name.clone(),
+                prop_column_to_arrow(col)?,
+            ));
+        }
+    }
+    ArrowTable::new(columns)
+}
+
+// One table per event name, rows in the order the events happened.
+pub fn events_to_arrow(events: &[GameEvent]) -> PyResult<Vec<(String, ArrowTable)>> {
+    let mut tables = vec![];
+    for (event_name, events) in events
+        .iter()
+        .into_group_map_by(|x| x.name.clone())
+        .into_iter()
+        .sorted_by(|a, b| a.0.cmp(&b.0))
+    {
+        let field_names = events
+            .iter()
+            .flat_map(|e| e.fields.iter().map(|f| &f.name))
+            .unique()
+            .collect_vec();
+        let mut columns = vec![];
+        for field_name in field_names {
+            let mut col = PropColumn::new();
+            for event in &events {
+                col.push(
+                    event
+                        .fields
+                        .iter()
+                        .find(|f| &f.name == field_name)
+                        .and_then(|f| f.data.clone()),
+                );
+            }
+            // Events with mixed types for the same field would silently drop values
+            if col.len() != events.len() {
+                return Err(Exception::new_err(format!(
+                    "field {field_name} in {event_name} has values of different types"
+                )));
+            }
+            columns.push((field_name.clone(), prop_column_to_arrow(&col)?));
+        }
+        tables.push((event_name, ArrowTable::new(columns)?));
+    }
+    Ok(tables)
+}
+
+pub fn prop_column_to_arrow(col: &PropColumn) -> PyResult<Box<dyn Array>> {
+    match &col.data {
+        Some(data) => var_vec_to_arrow(data),
+        None => Ok(NullArray::new(ArrowDataType::Null, col.num_nones).boxed()),
+    }
+}
+
+pub fn var_vec_to_arrow(data: &VarVec) -> PyResult<Box<dyn Array>> {
+    let array = match data {
+        VarVec::F32(data) => Float32Array::from(data).boxed(),
+        VarVec::I32(data) => Int32Array::from(data).boxed(),
+        VarVec::U32(data) => UInt32Array::from(data).boxed(),
+        VarVec::U64(data) => UInt64Array::from(data).boxed(),
+        VarVec::Bool(data) => BooleanArray::from(data).boxed(),
+        VarVec::String(data) => Utf8Array::<i64>::from(data).boxed(),
+        VarVec::StringVec(data) => {
+            let values = Utf8Array::<i64>::from_slice(data.iter().flatten().collect_vec()).boxed();
+            list_array(data.iter().map(|v| v.len()), values)?
+        }
+        VarVec::U32Vec(data) => {
+            let values =
+                UInt32Array::from_vec(data.iter().flatten().copied().collect_vec()).boxed();
+            list_array(data.iter().map(|v| v.len()), values)?
+        }
+        VarVec::U64Vec(data) => {
+            let values =
+                UInt64Array::from_vec(data.iter().flatten().copied().collect_vec()).boxed();
+            list_array(data.iter().map(|v| v.len()), values)?
+        }
+        VarVec::XYVec(data) => {
+            let validity = Bitmap::from_iter(data.iter().map(|v| v.is_some()));
+            let xy = data.iter().map(|v| v.unwrap_or_default()).collect_vec();
+            struct_array(
+                vec![
+                    (
+                        "x",
+                        Float32Array::from_vec(xy.iter().map(|v| v[0]).collect()).boxed(),
+                    ),
+                    (
+                        "y",
+                        Float32Array::from_vec(xy.iter().map(|v| v[1]).collect()).boxed(),
+                    ),
+                ],
+                Some(validity),
+            )
+        }
+        VarVec::XYZVec(data) => {
+            let validity = Bitmap::from_iter(data.iter().map(|v| v.is_some()));
+            let xyz = data.iter().map(|v| v.unwrap_or_default()).collect_vec();
+            struct_array(
+                vec![
+                    (
+                        "x",
+                        Float32Array::from_vec(xyz.iter().map(|v| v[0]).collect()).boxed(),
+                    ),
+                    (
+                        "y",
+                        Float32Array::from_vec(xyz.iter().map(|v| v[1]).collect()).boxed(),
+                    ),
+                    (
+                        "z",
+                        Float32Array::from_vec(xyz.iter().map(|v| v[2]).collect()).boxed(),
+                    ),
+                ],
+                Some(validity),
+            )
+        }
+        VarVec::Stickers(data) => {
+            let stickers = data.iter().flatten().collect_vec();
+            let values = struct_array(
+                vec![
+                    (
+                        "id",
+                        UInt32Array::from_vec(stickers.iter().map(|s| s.id).collect()).boxed(),
+                    ),
+                    (
+                        "name",
+                        Utf8Array::<i64>::from_slice(
+                            stickers.iter().map(|s| &s.name).collect_vec(),
+                        )
+                        .boxed(),
+                    ),
+                    (
+                        "wear",
+                        Float32Array::from_vec(stickers.iter().map(|s| s.wear).collect()).boxed(),
+                    ),
+                    (
+                        "x",
+                        Float32Array::from_vec(stickers.iter().map(|s| s.x).collect()).boxed(),
+                    ),
+                    (
+                        "y",
+                        Float32Array::from_vec(stickers.iter().map(|s| s.y).collect()).boxed(),
+                    ),
+                ],
+                None,
+            );
+            list_array(data.iter().map(|v| v.len()), values)?
+        }
+        VarVec::InputHistory(data) => {
+            let inputs = data.iter().flatten().collect_vec();
+            let values = struct_array(
+                vec![
+                    (
+                        "x",
+                        Float32Array::from_vec(inputs.iter().map(|i| i.x).collect()).boxed(),
+                    ),
+                    (
+                        "y",
+                        Float32Array::from_vec(inputs.iter().map(|i| i.y).collect()).boxed(),
+                    ),
+                    (
+                        "z",
+                        Float32Array::from_vec(inputs.iter().map(|i| i.z).collect()).boxed(),
+                    ),
+                    (
+                        "render_tick_count",
+                        Int32Array::from_vec(inputs.iter().map(|i| i.render_tick_count).collect())
+                            .boxed(),
+                    ),
+                    (
+                        "render_tick_fraction",
+                        Float32Array::from_vec(
+                            inputs.iter().map(|i| i.render_tick_fraction).collect(),
+                        )
+                        .boxed(),
+                    ),
+                    (
+                        "player_tick_count",
+                        Int32Array::from_vec(inputs.iter().map(|i| i.player_tick_count).collect())
+                            .boxed(),
+                    ),
+                    (
+                        "player_tick_fraction",
+                        Float32Array::from_vec(
+                            inputs.iter().map(|i| i.player_tick_fraction).collect(),
+                        )
+                        .boxed(),
+                    ),
+                ],
+                None,
+            );
+            list_array(data.iter().map(|v| v.len()), values)?
+        }
+    };
+    Ok(array)
+}
+
+fn list_array(
+    lengths: impl Iterator<Item = usize>,
+    values: Box<dyn Array>,
+) -> PyResult<Box<dyn Array>> {
+    let offsets = Offsets::<i64>::try_from_lengths(lengths)
+        .map_err(|e| Exception::new_err(format!("list column is too long for arrow: {e}")))?;
+    let data_type = ListArray::<i64>::default_datatype(values.data_type().clone());
+    Ok(ListArray::<i64>::new(data_type, offsets.into(), values, None).boxed())
+}
+
+fn struct_array(children: Vec<(&str, Box<dyn Array>)>, validity: Option<Bitmap>) -> Box<dyn Array> {
+    let fields = children
+        .iter()
+        .map(|(name, arr)| ArrowField::new(*name, arr.data_type().clone(), false))
+        .collect_vec();
+    let values = children.into_iter().map(|(_, arr)| arr).collect_vec();
+    StructArray::new(ArrowDataType::Struct(fields), values, validity).boxed()
+}
@@ -1,4 +1,5 @@
 use ahash::{AHashMap, RandomState};
+use arrow::ArrowTable;
 use itertools::Itertools;
 use memmap2::Mmap;
 use parser::first_pass::parser_settings::create_mmap;
@@ -7,12 +8,25 @@ use parser::first_pass::parser_settings::rm_user_friendly_names;
 use parser::first_pass::parser_settings::FirstPassParser;
 use parser::first_pass::parser_settings::ParserInputs;
 use parser::first_pass::read_bits::DemoParserError;
+use parser::first_pass::read_bits::ParseWarning;
+use parser::first_pass::schema::DemoSchema;
+use parser::first_pass::stringtables::decode_string_table_value;
+use parser::first_pass::stringtables::StringTableValue;
+use parser::first_pass::validate::validate_demo;
+use parser::parse_control::ParseControl;
+use parser::parse_demo::DemoOutput;
 use parser::parse_demo::Parser;
+use parser::second_pass::derived_column::DerivedColumn;
+use parser::second_pass::event_windows::EventWindow;
 use parser::second_pass::game_events::EventField;
 use parser::second_pass::game_events::GameEvent;
 use parser::second_pass::parser_settings::create_huffman_lookup_table;
+use parser::second_pass::player_identity::player_identity_timeline;
+use parser::second_pass::row_filter::RowFilter;
 use parser::second_pass::variants::VarVec;
 use parser::second_pass::variants::Variant;
+use parser::second_pass::votes::collect_votes;
+use parser::second_pass::votes::VOTE_EVENTS;
 use polars::prelude::ArrayRef;
 use polars::prelude::ArrowField;
 use polars::prelude::NamedFrom;
@@ -21,23 +35,32 @@ use polars_arrow::array::{
     Array, BooleanArray, Float32Array, Int32Array, UInt32Array, UInt64Array,
 };
 use polars_arrow::ffi;
+use pyo3::exceptions::PyUserWarning;
 use pyo3::exceptions::PyValueError;
 use pyo3::ffi::Py_uintptr_t;
 use pyo3::impl_::frompyobject::extract_struct_field;
 use pyo3::prelude::*;
 use pyo3::types::IntoPyDict;
 use pyo3::types::PyBytes;
+use pyo3::types::PyCFunction;
 use pyo3::types::PyDict;
 use pyo3::types::PyList;
 use pyo3::IntoPyObjectExt;
 use pyo3::{intern, Python};
 use pyo3::{PyAny, PyResult};
 use std::collections::HashMap;
+use std::ffi::CString;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::sync::OnceLock;
+use std::time::Duration;
 
 use pyo3::create_exception;
 create_exception!(DemoParser, Exception, pyo3::exceptions::PyException);
 
+mod arrow;
+mod scan;
+
 #[derive(Clone)]
 struct PyVariant(Variant);
 
@@ -110,13 +133,24 @@ impl<'py> FromPyObject<'_, 'py> for WantedPropState {
 #[pymethods]
 impl DemoParser {
     #[new]
-    pub fn py_new(demo_path: String) -> PyResult<Self> {
+    #[pyo3(signature = (demo_path, lenient=false, progress_callback=None, include_non_players=false))]
+    pub fn py_new(
+        demo_path: String,
+        lenient: bool,
+        progress_callback: Option<Py<PyAny>>,
+        include_non_players: bool,
+    ) -> PyResult<Self> {
         let mmap = match create_mmap(demo_path.clone()) {
             Ok(mmap) => mmap,
             Err(e) => return Err(Exception::new_err(format!("{e}. File name: {demo_path}"))),
         };
-        let huf = create_huffman_lookup_table();
-        Ok(Self { mmap, huf })
+        Ok(Self {
+            mmap: Arc::new(mmap),
+            huf: huffman_lookup_table(),
+            lenient,
+            progress_callback,
+            include_non_players,
+        })
     }
 
     /// Parses header message (different from the first 16 bytes of the file)
@@ -141,8 +175,17 @@ impl DemoParser {
             only_header: true,
             list_props: false,
             only_convars: false,
-            huffman_lookup_table: &self.huf,
+            huffman_lookup_table: self.huf,
             order_by_steamid: false,
+            wanted_user_messages: vec![],
+            lenient: self.lenient,
+            parse_control: ParseControl::default(),
+            row_filters: vec![],
+            event_windows: vec![],
+            only_prop_changes: false,
+            wanted_string_tables: vec![],
+            include_non_players: self.include_non_players,
+            derived_columns: vec![],
             fallback_bytes: None,
         };
         let mut parser = FirstPassParser::new(&settings);
@@ -154,7 +197,7 @@ impl DemoParser {
     }
 
     /// Returns the names of game events present in the demo
-    pub fn list_updated_fields(&self, _py: Python<'_>) -> PyResult<Vec<String>> {
+    pub fn list_updated_fields(&self, py: Python<'_>) -> PyResult<Vec<String>> {
         let settings = ParserInputs {
             real_name_to_og_name: AHashMap::default(),
             wanted_players: vec![],
@@ -169,18 +212,114 @@ impl DemoParser {
             only_header: false,
             list_props: true,
             only_convars: false,
-            huffman_lookup_table: &self.huf,
+            huffman_lookup_table: self.huf,
             order_by_steamid: false,
+            wanted_user_messages: vec![],
+            lenient: self.lenient,
+            parse_control: ParseControl::default(),
+            row_filters: vec![],
+            event_windows: vec![],
+            only_prop_changes: false,
+            wanted_string_tables: vec![],
+            include_non_players: self.include_non_players,
+            derived_columns: vec![],
             fallback_bytes: None,
         };
-        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
-        let output = match parser.parse_demo(&self.mmap) {
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
             Ok(output) => output,
             Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
+        warn_parse_warnings(&output.warnings)?;
         Ok(output.uniq_prop_names)
     }
-    pub fn list_game_events(&self, _py: Python<'_>) -> PyResult<Vec<String>> {
+
+    /// Every prop path in the demo with its class, sendtable type and encoding, decoder,
+    /// the type it is parsed into, what kind of entity it is collected from and its friendly name.
+    pub fn describe_schema(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let settings = ParserInputs {
+            real_name_to_og_name: AHashMap::default(),
+            wanted_players: vec![],
+            wanted_player_props: vec![],
+            wanted_other_props: vec![],
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: vec![],
+            parse_ents: false,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            parse_grenades: false,
+            only_header: false,
+            list_props: false,
+            only_convars: false,
+            huffman_lookup_table: self.huf,
+            order_by_steamid: false,
+            wanted_user_messages: vec![],
+            lenient: self.lenient,
+            parse_control: ParseControl::default(),
+            row_filters: vec![],
+            event_windows: vec![],
+            only_prop_changes: false,
+            wanted_string_tables: vec![],
+            include_non_players: self.include_non_players,
+            derived_columns: vec![],
+            fallback_bytes: None,
+        };
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let mmap = self.mmap.clone();
+        let schema = match py.detach(|| parser.describe_schema(&mmap)) {
+            Ok(schema) => schema,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let columns = PyDict::new(py);
+        columns.set_item("path", schema.iter().map(|p| p.path.clone()).collect_vec())?;
+        columns.set_item(
+            "class",
+            schema.iter().map(|p| p.class.clone()).collect_vec(),
+        )?;
+        columns.set_item(
+            "prop_name",
+            schema.iter().map(|p| p.prop_name.clone()).collect_vec(),
+        )?;
+        columns.set_item(
+            "friendly_name",
+            schema.iter().map(|p| p.friendly_name.clone()).collect_vec(),
+        )?;
+        columns.set_item(
+            "var_type",
+            schema.iter().map(|p| p.var_type.clone()).collect_vec(),
+        )?;
+        columns.set_item(
+            "encoder",
+            schema.iter().map(|p| p.encoder.clone()).collect_vec(),
+        )?;
+        columns.set_item(
+            "encode_flags",
+            schema.iter().map(|p| p.encode_flags).collect_vec(),
+        )?;
+        columns.set_item("bitcount", schema.iter().map(|p| p.bitcount).collect_vec())?;
+        columns.set_item(
+            "low_value",
+            schema.iter().map(|p| p.low_value).collect_vec(),
+        )?;
+        columns.set_item(
+            "high_value",
+            schema.iter().map(|p| p.high_value).collect_vec(),
+        )?;
+        columns.set_item(
+            "decoder",
+            schema.iter().map(|p| p.decoder.clone()).collect_vec(),
+        )?;
+        columns.set_item(
+            "variant_type",
+            schema.iter().map(|p| p.variant_type).collect_vec(),
+        )?;
+        columns.set_item("kind", schema.iter().map(|p| p.kind).collect_vec())?;
+        let pandas = py.import("pandas")?;
+        pandas
+            .call_method1("DataFrame", (columns,))?
+            .into_py_any(py)
+    }
+    pub fn list_game_events(&self, py: Python<'_>) -> PyResult<Vec<String>> {
         let settings = ParserInputs {
             real_name_to_og_name: AHashMap::default(),
             wanted_players: vec![],
@@ -195,15 +334,25 @@ impl DemoParser {
             only_header: false,
             list_props: false,
             only_convars: false,
-            huffman_lookup_table: &self.huf,
+            huffman_lookup_table: self.huf,
             order_by_steamid: false,
+            wanted_user_messages: vec![],
+            lenient: self.lenient,
+            parse_control: ParseControl::default(),
+            row_filters: vec![],
+            event_windows: vec![],
+            only_prop_changes: false,
+            wanted_string_tables: vec![],
+            include_non_players: self.include_non_players,
+            derived_columns: vec![],
             fallback_bytes: None,
         };
-        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
-        let output = match parser.parse_demo(&self.mmap) {
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
             Ok(output) => output,
             Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
+        warn_parse_warnings(&output.warnings)?;
         Ok(output.game_events_counter.into_iter().collect_vec())
     }
 
@@ -246,16 +395,26 @@ impl DemoParser {
             only_header: false,
             list_props: false,
             only_convars: false,
-            huffman_lookup_table: &self.huf,
+            huffman_lookup_table: self.huf,
             order_by_steamid: false,
+            wanted_user_messages: vec![],
+            lenient: self.lenient,
+            parse_control: ParseControl::default(),
+            row_filters: vec![],
+            event_windows: vec![],
+            only_prop_changes: false,
+            wanted_string_tables: vec![],
+            include_non_players: self.include_non_players,
+            derived_columns: vec![],
             fallback_bytes: None,
         };
 
-        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
-        let output = match parser.parse_demo(&self.mmap) {
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
             Ok(output) => output,
             Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
+        warn_parse_warnings(&output.warnings)?;
         let mut all_series = vec![];
         let mut all_pyobjects = vec![];
         let prop_infos = output.prop_controller.prop_infos;
@@ -344,31 +503,45 @@ impl DemoParser {
             only_header: true,
             list_props: false,
             only_convars: false,
-            huffman_lookup_table: &self.huf,
+            huffman_lookup_table: self.huf,
             order_by_steamid: false,
+            wanted_user_messages: vec![],
+            lenient: self.lenient,
+            parse_control: ParseControl::default(),
+            row_filters: vec![],
+            event_windows: vec![],
+            only_prop_changes: false,
+            wanted_string_tables: vec![],
+            include_non_players: self.include_non_players,
+            derived_columns: vec![],
             fallback_bytes: None,
         };
-        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
-        let output = match parser.parse_demo(&self.mmap) {
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
             Ok(output) => output,
             Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
+        warn_parse_warnings(&output.warnings)?;
         let steamids: Vec<Option<u64>> = output.player_md.iter().map(|p| p.steamid).collect();
         let team_numbers: Vec<Option<i32>> =
             output.player_md.iter().map(|p| p.team_number).collect();
         let names: Vec<Option<String>> = output.player_md.iter().map(|p| p.name.clone()).collect();
+        let player_keys: Vec<u64> = output.player_md.iter().map(|p| p.player_key.0).collect();
+        let roles: Vec<&str> = output.player_md.iter().map(|p| p.role.as_str()).collect();
 
         // SoA form
         let steamid = rust_series_to_py_series(&Series::new("Steamid", steamids))?;
         let team_number = arr_to_py(Box::new(Int32Array::from(team_numbers)))?;
         let name = rust_series_to_py_series(&Series::new("param2", names))?;
+        let player_key = rust_series_to_py_series(&Series::new("player_key", player_keys))?;
+        let role = rust_series_to_py_series(&Series::new("role", roles))?;
 
         let polars = py.import("polars")?;
-        let all_series_py = [steamid, name, team_number].into_py_any(py)?;
+        let all_series_py = [steamid, name, team_number, player_key, role].into_py_any(py)?;
         Python::attach(|py| {
             let df = polars.call_method1("DataFrame", (all_series_py,))?;
             // Set column names
-            let column_names = ["steamid", "name", "team_number"];
+            let column_names = ["steamid", "name", "team_number", "player_key", "role"];
             df.setattr("columns", column_names)?;
             // Call to_pandas with use_pyarrow_extension_array = true
             let kwargs = vec![("use_pyarrow_extension_array", true)]
@@ -393,15 +566,25 @@ impl DemoParser {
             only_header: true,
             list_props: false,
             only_convars: false,
-            huffman_lookup_table: &self.huf,
+            huffman_lookup_table: self.huf,
             order_by_steamid: false,
+            wanted_user_messages: vec![],
+            lenient: self.lenient,
+            parse_control: ParseControl::default(),
+            row_filters: vec![],
+            event_windows: vec![],
+            only_prop_changes: false,
+            wanted_string_tables: vec![],
+            include_non_players: self.include_non_players,
+            derived_columns: vec![],
             fallback_bytes: None,
         };
-        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
-        let output = match parser.parse_demo(&self.mmap) {
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
             Ok(output) => output,
             Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
+        warn_parse_warnings(&output.warnings)?;
         let def_index: Vec<Option<u32>> = output.item_drops.iter().map(|x| x.def_index).collect();
         let account_id: Vec<Option<u32>> = output.item_drops.iter().map(|x| x.account_id).collect();
         let dropreason: Vec<Option<u32>> = output.item_drops.iter().map(|x| x.dropreason).collect();
@@ -478,15 +661,25 @@ impl DemoParser {
             only_header: true,
             list_props: false,
             only_convars: false,
-            huffman_lookup_table: &self.huf,
+            huffman_lookup_table: self.huf,
             order_by_steamid: false,
+            wanted_user_messages: vec![],
+            lenient: self.lenient,
+            parse_control: ParseControl::default(),
+            row_filters: vec![],
+            event_windows: vec![],
+            only_prop_changes: false,
+            wanted_string_tables: vec![],
+            include_non_players: self.include_non_players,
+            derived_columns: vec![],
             fallback_bytes: None,
         };
-        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
-        let output = match parser.parse_demo(&self.mmap) {
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
             Ok(output) => output,
             Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
+        warn_parse_warnings(&output.warnings)?;
 
         let def_idx_vec: Vec<Option<u32>> = output.skins.iter().map(|s| s.def_index).collect();
         let item_id: Vec<Option<u64>> = output.skins.iter().map(|s| s.item_id).collect();
@@ -546,96 +739,134 @@ impl DemoParser {
         player: Option<Vec<String>>,
         other: Option<Vec<String>>,
     ) -> PyResult<Py<PyAny>> {
-        let wanted_player_props = player.unwrap_or_default();
-        let wanted_other_props = other.unwrap_or_default();
+        let settings = self.event_settings(vec![event_name], player, other)?;
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        warn_parse_warnings(&output.warnings)?;
+        event_to_df(py, output)
+    }
 
-        let real_player_props = rm_user_friendly_names(&wanted_player_props);
-        let real_other_props = rm_user_friendly_names(&wanted_other_props);
+    #[pyo3(signature = (event_name, *, player=None, other=None))]
+    pub fn parse_event_async(
+        &self,
+        py: Python<'_>,
+        event_name: String,
+        player: Option<Vec<String>>,
+        other: Option<Vec<String>>,
+    ) -> PyResult<Py<PyAny>> {
+        let settings = self.event_settings(vec![event_name], player, other)?;
+        self.spawn_async(py, settings, event_to_df)
+    }
 
-        let real_player_props = match real_player_props {
-            Ok(real_props) => real_props,
-            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
-        };
-        let real_other_props = match real_other_props {
-            Ok(real_props) => real_props,
-            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+    #[pyo3(signature = (event_name, *, player=None, other=None))]
+    pub fn parse_events(
+        &self,
+        py: Python<'_>,
+        event_name: Vec<String>,
+        player: Option<Vec<String>>,
+        other: Option<Vec<String>>,
+    ) -> PyResult<Py<PyAny>> {
+        let settings = self.event_settings(event_name, player, other)?;
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
+        warn_parse_warnings(&output.warnings)?;
+        events_to_dfs(py, output)
+    }
 
-        let mut real_name_to_og_name = AHashMap::default();
-        for (real_name, user_friendly_name) in real_player_props.iter().zip(&wanted_player_props) {
-            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
-        }
-        for (real_name, user_friendly_name) in real_other_props.iter().zip(&wanted_other_props) {
-            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
-        }
+    #[pyo3(signature = (event_name, *, player=None, other=None))]
+    pub fn parse_events_async(
+        &self,
+        py: Python<'_>,
+        event_name: Vec<String>,
+        player: Option<Vec<String>>,
+        other: Option<Vec<String>>,
+    ) -> PyResult<Py<PyAny>> {
+        let settings = self.event_settings(event_name, player, other)?;
+        self.spawn_async(py, settings, events_to_dfs)
+    }
 
+    /// Same as parse_events but returns a list of (event_name, ArrowTable) tuples.
+    /// See parse_ticks_arrow.
+    #[pyo3(signature = (event_name, *, player=None, other=None))]
+    pub fn parse_events_arrow(
+        &self,
+        py: Python<'_>,
+        event_name: Vec<String>,
+        player: Option<Vec<String>>,
+        other: Option<Vec<String>>,
+    ) -> PyResult<Vec<(String, ArrowTable)>> {
+        let settings = self.event_settings(event_name, player, other)?;
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        warn_parse_warnings(&output.warnings)?;
+        arrow::events_to_arrow(&output.game_events)
+    }
+    /// Decodes net/user messages by their name, for example "CS_UM_RadioText" or "CS_UM_Damage".
+    /// Pass "all" to get every message. Returns a list of (message_name, dataframe) tuples
+    /// like parse_events. Nested fields are flattened with a dot, for example "pos.x".
+    pub fn parse_user_messages(
+        &self,
+        py: Python<'_>,
+        message_names: Vec<String>,
+    ) -> PyResult<Py<PyAny>> {
         let settings = ParserInputs {
-            real_name_to_og_name,
+            real_name_to_og_name: AHashMap::default(),
             wanted_players: vec![],
-            wanted_player_props: real_player_props,
-            wanted_other_props: real_other_props,
-            wanted_events: vec![event_name],
+            wanted_player_props: vec![],
+            wanted_other_props: vec![],
+            wanted_events: vec![],
             wanted_prop_states: AHashMap::default(),
-            parse_ents: true,
+            parse_ents: false,
             wanted_ticks: vec![],
             parse_projectiles: false,
             parse_grenades: false,
             only_header: true,
             list_props: false,
             only_convars: false,
-            huffman_lookup_table: &self.huf,
+            huffman_lookup_table: self.huf,
             order_by_steamid: false,
+            wanted_user_messages: message_names,
+            lenient: self.lenient,
+            parse_control: ParseControl::default(),
+            row_filters: vec![],
+            event_windows: vec![],
+            only_prop_changes: false,
+            wanted_string_tables: vec![],
+            include_non_players: self.include_non_players,
+            derived_columns: vec![],
             fallback_bytes: None,
         };
-        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
-        let output = match parser.parse_demo(&self.mmap) {
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
             Ok(output) => output,
             Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
-        let event_series = match series_from_event(&output.game_events, py) {
+        warn_parse_warnings(&output.warnings)?;
+        let message_series = match series_from_multiple_events(&output.user_messages, py) {
             Ok(ser) => ser,
-            Err(_e) => return Ok(PyList::empty(py).into()),
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
-        Ok(event_series)
+        Ok(message_series)
     }
-
-    #[pyo3(signature = (event_name, *, player=None, other=None))]
-    pub fn parse_events(
-        &self,
-        py: Python<'_>,
-        event_name: Vec<String>,
-        player: Option<Vec<String>>,
-        other: Option<Vec<String>>,
-    ) -> PyResult<Py<PyAny>> {
-        let wanted_player_props = player.unwrap_or_default();
-        let wanted_other_props = other.unwrap_or_default();
-
-        let real_player_props = rm_user_friendly_names(&wanted_player_props);
-        let real_other_props = rm_user_friendly_names(&wanted_other_props);
-
-        let real_player_props = match real_player_props {
-            Ok(real_props) => real_props,
-            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
-        };
-        let real_other_props = match real_other_props {
-            Ok(real_props) => real_props,
-            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
-        };
-
-        let mut real_name_to_og_name = AHashMap::default();
-        for (real_name, user_friendly_name) in real_player_props.iter().zip(&wanted_player_props) {
-            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
-        }
-        for (real_name, user_friendly_name) in real_other_props.iter().zip(&wanted_other_props) {
-            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
-        }
-
+    /// Returns votes (kick, timeout, surrender, pause etc.) as a list of dicts.
+    /// Each vote has the tick it started and ended, issuer, target, vote type, outcome
+    /// and every player's vote under "votes".
+    pub fn parse_votes(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
         let settings = ParserInputs {
-            real_name_to_og_name,
+            real_name_to_og_name: AHashMap::default(),
             wanted_players: vec![],
-            wanted_player_props: real_player_props,
-            wanted_other_props: real_other_props,
-            wanted_events: event_name,
+            wanted_player_props: vec![],
+            wanted_other_props: vec![],
+            wanted_events: VOTE_EVENTS.iter().map(|x| x.to_string()).collect(),
             wanted_prop_states: AHashMap::default(),
             parse_ents: true,
             wanted_ticks: vec![],
@@ -644,20 +875,86 @@ impl DemoParser {
             only_header: true,
             list_props: false,
             only_convars: false,
-            huffman_lookup_table: &self.huf,
+            huffman_lookup_table: self.huf,
             order_by_steamid: false,
+            wanted_user_messages: vec![],
+            lenient: self.lenient,
+            parse_control: ParseControl::default(),
+            row_filters: vec![],
+            event_windows: vec![],
+            only_prop_changes: false,
+            wanted_string_tables: vec![],
+            include_non_players: self.include_non_players,
+            derived_columns: vec![],
             fallback_bytes: None,
         };
-        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
-        let output = match parser.parse_demo(&self.mmap) {
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
             Ok(output) => output,
             Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
-        let event_series = match series_from_multiple_events(&output.game_events, py) {
-            Ok(ser) => ser,
-            Err(e) => return Err(Exception::new_err(format!("{e}"))),
-        };
-        Ok(event_series)
+        warn_parse_warnings(&output.warnings)?;
+        let mut votes: Vec<Bound<'_, PyDict>> = vec![];
+        for vote in collect_votes(&output.game_events) {
+            let d = PyDict::new(py);
+            d.set_item("start_tick", vote.start_tick)?;
+            d.set_item("end_tick", vote.end_tick)?;
+            d.set_item("vote_type", vote.vote_type)?;
+            d.set_item("team", vote.team)?;
+            d.set_item("issuer_steamid", vote.issuer_steamid)?;
+            d.set_item("issuer_name", vote.issuer_name)?;
+            d.set_item("target_steamid", vote.target_steamid)?;
+            d.set_item("target_name", vote.target_name)?;
+            d.set_item("details", vote.details)?;
+            d.set_item("outcome", vote.outcome)?;
+            d.set_item("failed_reason", vote.failed_reason)?;
+            let mut player_votes: Vec<Bound<'_, PyDict>> = vec![];
+            for player_vote in vote.votes {
+                let pv = PyDict::new(py);
+                pv.set_item("tick", player_vote.tick)?;
+                pv.set_item("steamid", player_vote.steamid)?;
+                pv.set_item("name", player_vote.name)?;
+                pv.set_item("vote", player_vote.vote)?;
+                player_votes.push(pv);
+            }
+            d.set_item("votes", player_votes)?;
+            votes.push(d);
+        }
+        votes.into_py_any(py)
+    }
+    pub fn validate_demo(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let report = py.detach(|| validate_demo(&self.mmap));
+        let d = PyDict::new(py);
+        d.set_item("is_valid", report.is_valid)?;
+        d.set_item("file_length", report.file_length)?;
+        d.set_item("n_frames", report.n_frames)?;
+        d.set_item("first_tick", report.first_tick)?;
+        d.set_item("last_tick", report.last_tick)?;
+        d.set_item("last_good_offset", report.last_good_offset)?;
+        d.set_item("has_file_header", report.has_file_header)?;
+        d.set_item("has_sendtables", report.has_sendtables)?;
+        d.set_item("has_class_info", report.has_class_info)?;
+        d.set_item("has_game_event_list", report.has_game_event_list)?;
+        d.set_item("has_stop", report.has_stop)?;
+        let mut issues: Vec<Bound<'_, PyDict>> = vec![];
+        for issue in report.issues {
+            let i = PyDict::new(py);
+            i.set_item("byte_offset", issue.byte_offset)?;
+            i.set_item("tick", issue.tick)?;
+            i.set_item("message", issue.message)?;
+            issues.push(i);
+        }
+        d.set_item("issues", issues)?;
+        let mut tick_gaps: Vec<Bound<'_, PyDict>> = vec![];
+        for gap in report.tick_gaps {
+            let g = PyDict::new(py);
+            g.set_item("byte_offset", gap.byte_offset)?;
+            g.set_item("from_tick", gap.from_tick)?;
+            g.set_item("to_tick", gap.to_tick)?;
+            tick_gaps.push(g);
+        }
+        d.set_item("tick_gaps", tick_gaps)?;
+        d.into_py_any(py)
     }
     pub fn parse_voice(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
         let settings = ParserInputs {
@@ -676,13 +973,23 @@ impl DemoParser {
             only_convars: false,
             huffman_lookup_table: &vec![],
             order_by_steamid: false,
+            wanted_user_messages: vec![],
+            lenient: self.lenient,
+            parse_control: ParseControl::default(),
+            row_filters: vec![],
+            event_windows: vec![],
+            only_prop_changes: false,
+            wanted_string_tables: vec![],
+            include_non_players: self.include_non_players,
+            derived_columns: vec![],
             fallback_bytes: None,
         };
-        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
-        let output = match parser.parse_demo(&self.mmap) {
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
             Ok(output) => output,
             Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
         };
+        warn_parse_warnings(&output.warnings)?;
         let mut hm: Vec<Bound<'_, PyDict>> = vec![];
 
         for (tick, packet) in output.voice_data {
@@ -698,187 +1005,629 @@ impl DemoParser {
         hm.into_py_any(py)
     }
 
-    #[pyo3(signature = (wanted_props, *, players=None, ticks=None, prop_states=None))]
-    pub fn parse_ticks(
-        &self,
-        py: Python,
-        wanted_props: Vec<String>,
-        players: Option<Vec<u64>>,
-        ticks: Option<Vec<i32>>,
-        prop_states: Option<Vec<WantedPropState>>,
-    ) -> PyResult<Py<PyAny>> {
-        let wanted_players = players.unwrap_or_default();
-        let wanted_ticks = ticks.unwrap_or_default();
-        let wanted_prop_states = prop_states
-            .unwrap_or_default()
-            .into_iter()
-            .map(|prop| (prop.prop, prop.state.0))
-            .collect();
-
-        let real_props = rm_user_friendly_names(&wanted_props);
-        let real_wanted_prop_states = rm_map_user_friendly_names(&wanted_prop_states);
-
-        let real_props = match real_props {
-            Ok(real_props) => real_props,
-            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+    /// Every change of the userinfo string table: name, steamid and bot/HLTV flags of each player
+    /// slot with the tick of the change. Name changes mid-match are new rows with the same steamid.
+    pub fn parse_player_identity(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let settings = ParserInputs {
+            wanted_players: vec![],
+            wanted_player_props: vec![],
+            wanted_other_props: vec![],
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: vec![],
+            wanted_ticks: vec![],
+            real_name_to_og_name: AHashMap::default(),
+            parse_ents: false,
+            parse_projectiles: false,
+            parse_grenades: false,
+            only_header: false,
+            list_props: false,
+            only_convars: false,
+            huffman_lookup_table: &vec![],
+            order_by_steamid: false,
+            wanted_user_messages: vec![],
+            lenient: self.lenient,
+            parse_control: ParseControl::default(),
+            row_filters: vec![],
+            event_windows: vec![],
+            only_prop_changes: false,
+            wanted_string_tables: vec!["userinfo".to_string()],
+            include_non_players: self.include_non_players,
+            derived_columns: vec![],
+            fallback_bytes: None,
         };
-        let real_wanted_prop_states = match real_wanted_prop_states {
-            Ok(real_wanted_prop_states) => real_wanted_prop_states,
-            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
+            Ok(output) => output,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
         };
+        warn_parse_warnings(&output.warnings)?;
+        let timeline = player_identity_timeline(&output.string_tables.updates);
+        let columns = PyDict::new(py);
+        columns.set_item("tick", timeline.iter().map(|p| p.tick).collect_vec())?;
+        columns.set_item("slot", timeline.iter().map(|p| p.slot).collect_vec())?;
+        columns.set_item("userid", timeline.iter().map(|p| p.userid).collect_vec())?;
+        columns.set_item("steamid", timeline.iter().map(|p| p.steamid).collect_vec())?;
+        columns.set_item(
+            "name",
+            timeline.iter().map(|p| p.name.clone()).collect_vec(),
+        )?;
+        columns.set_item(
+            "fake_player",
+            timeline.iter().map(|p| p.fake_player).collect_vec(),
+        )?;
+        columns.set_item("is_hltv", timeline.iter().map(|p| p.is_hltv).collect_vec())?;
+        let pandas = py.import("pandas")?;
+        pandas
+            .call_method1("DataFrame", (columns,))?
+            .into_py_any(py)
+    }
 
-        let arc_huf = Arc::new(&self.huf);
-        let mut real_name_to_og_name = AHashMap::default();
-        for (real_name, user_friendly_name) in real_props.iter().zip(&wanted_props) {
-            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
-        }
-        for (real_name, user_friendly_name) in real_wanted_prop_states
-            .keys()
-            .zip(wanted_prop_states.keys())
-        {
-            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
-        }
-
+    /// Contents of the string tables at the end of the demo and every update to them.
+    /// Returns (tables, updates): tables is a dict of DataFrames with idx, key, value (bytes)
+    /// and decoded (userinfo and lightstyles), updates has one row per changed entry.
+    #[pyo3(signature = (names=None))]
+    pub fn parse_string_tables(
+        &self,
+        py: Python<'_>,
+        names: Option<Vec<String>>,
+    ) -> PyResult<Py<PyAny>> {
         let settings = ParserInputs {
-            real_name_to_og_name,
-            wanted_players,
-            wanted_player_props: real_props,
+            wanted_players: vec![],
+            wanted_player_props: vec![],
             wanted_other_props: vec![],
+            wanted_prop_states: AHashMap::default(),
             wanted_events: vec![],
-            wanted_prop_states: real_wanted_prop_states,
-            parse_ents: true,
-            wanted_ticks,
+            wanted_ticks: vec![],
+            real_name_to_og_name: AHashMap::default(),
+            parse_ents: false,
             parse_projectiles: false,
             parse_grenades: false,
-            only_header: true,
+            only_header: false,
             list_props: false,
             only_convars: false,
-            huffman_lookup_table: &arc_huf,
+            huffman_lookup_table: &vec![],
             order_by_steamid: false,
+            wanted_user_messages: vec![],
+            lenient: self.lenient,
+            parse_control: ParseControl::default(),
+            row_filters: vec![],
+            event_windows: vec![],
+            only_prop_changes: false,
+            wanted_string_tables: names.unwrap_or_else(|| vec!["all".to_string()]),
+            include_non_players: self.include_non_players,
+            derived_columns: vec![],
             fallback_bytes: None,
         };
-        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
-        let output = match parser.parse_demo(&self.mmap) {
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
+            Ok(output) => output,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        warn_parse_warnings(&output.warnings)?;
+        let pandas = py.import("pandas")?;
+
+        let tables = PyDict::new(py);
+        for table in &output.string_tables.tables {
+            let columns = PyDict::new(py);
+            columns.set_item("idx", table.data.iter().map(|e| e.idx).collect_vec())?;
+            columns.set_item(
+                "key",
+                table.data.iter().map(|e| e.key.clone()).collect_vec(),
+            )?;
+            let mut values = vec![];
+            let mut decoded = vec![];
+            for entry in &table.data {
+                values.push(PyBytes::new(py, &entry.value));
+                decoded.push(string_table_value_to_py(py, &table.name, &entry.value)?);
+            }
+            columns.set_item("value", values)?;
+            columns.set_item("decoded", decoded)?;
+            tables.set_item(&table.name, pandas.call_method1("DataFrame", (columns,))?)?;
+        }
+
+        let updates = &output.string_tables.updates;
+        let columns = PyDict::new(py);
+        columns.set_item("tick", updates.iter().map(|u| u.tick).collect_vec())?;
+        columns.set_item(
+            "table",
+            updates.iter().map(|u| u.table.clone()).collect_vec(),
+        )?;
+        columns.set_item("idx", updates.iter().map(|u| u.idx).collect_vec())?;
+        columns.set_item("key", updates.iter().map(|u| u.key.clone()).collect_vec())?;
+        let mut values = vec![];
+        let mut decoded = vec![];
+        for update in updates {
+            values.push(PyBytes::new(py, &update.value));
+            decoded.push(string_table_value_to_py(py, &update.table, &update.value)?);
+        }
+        columns.set_item("value", values)?;
+        columns.set_item("decoded", decoded)?;
+        let updates_df = pandas.call_method1("DataFrame", (columns,))?;
+        (tables, updates_df).into_py_any(py)
+    }
+
+    #[pyo3(signature = (wanted_props, *, players=None, ticks=None, prop_states=None, filter=None, derived_columns=None))]
+    pub fn parse_ticks(
+        &self,
+        py: Python,
+        wanted_props: Vec<String>,
+        players: Option<Vec<u64>>,
+        ticks: Option<Vec<i32>>,
+        prop_states: Option<Vec<WantedPropState>>,
+        filter: Option<String>,
+        derived_columns: Option<Vec<String>>,
+    ) -> PyResult<Py<PyAny>> {
+        let settings = self.ticks_settings(
+            wanted_props,
+            players,
+            ticks,
+            prop_states,
+            filter,
+            derived_columns,
+        )?;
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
             Ok(output) => output,
             Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
-        let mut all_series = vec![];
-        let mut all_pyobjects = vec![];
-        let prop_infos = output.prop_controller.prop_infos;
-        let mut df_column_names_arrow = vec![];
-        let mut df_column_names_py = vec![];
+        warn_parse_warnings(&output.warnings)?;
+        ticks_to_df(py, output)
+    }
 
-        for prop_info in prop_infos {
-            if output.df.contains_key(&prop_info.id) {
-                match &output.df[&prop_info.id].data {
-                    Some(VarVec::F32(data)) => {
-                        df_column_names_arrow.push(prop_info.prop_friendly_name);
-                        all_series.push(arr_to_py(Box::new(Float32Array::from(data)))?);
-                    }
-                    Some(VarVec::I32(data)) => {
-                        df_column_names_arrow.push(prop_info.prop_friendly_name);
-                        all_series.push(arr_to_py(Box::new(Int32Array::from(data)))?);
-                    }
-                    Some(VarVec::U64(data)) => {
-                        df_column_names_arrow.push(prop_info.prop_friendly_name);
-                        all_series.push(arr_to_py(Box::new(UInt64Array::from(data)))?);
-                    }
-                    Some(VarVec::U32(data)) => {
-                        df_column_names_arrow.push(prop_info.prop_friendly_name);
-                        all_series.push(arr_to_py(Box::new(UInt32Array::from(data)))?);
-                    }
-                    Some(VarVec::Bool(data)) => {
-                        df_column_names_arrow.push(prop_info.prop_friendly_name);
-                        all_series.push(arr_to_py(Box::new(BooleanArray::from(data)))?);
-                    }
-                    Some(VarVec::String(data)) => {
-                        df_column_names_arrow.push(prop_info.prop_friendly_name.clone());
-                        let s = Series::new(&prop_info.prop_friendly_name.clone(), data);
-                        let py_series = rust_series_to_py_series(&s)?;
-                        all_series.push(py_series);
-                    }
-                    Some(VarVec::StringVec(data)) => {
-                        df_column_names_py.push(prop_info.prop_friendly_name);
-                        all_pyobjects.push(data.into_py_any(py)?);
-                    }
-                    Some(VarVec::U64Vec(data)) => {
-                        df_column_names_py.push(prop_info.prop_friendly_name);
-                        all_pyobjects.push(data.into_py_any(py)?);
-                    }
-                    Some(VarVec::XYZVec(data)) => {
-                        df_column_names_py.push(prop_info.prop_friendly_name);
-                        all_pyobjects.push(data.into_py_any(py)?);
-                    }
-                    Some(VarVec::U32Vec(data)) => {
-                        df_column_names_py.push(prop_info.prop_friendly_name);
-                        all_pyobjects.push(data.into_py_any(py)?);
-                    }
+    #[pyo3(signature = (wanted_props, *, players=None, ticks=None, prop_states=None, filter=None, derived_columns=None))]
+    pub fn parse_ticks_async(
+        &self,
+        py: Python,
+        wanted_props: Vec<String>,
+        players: Option<Vec<u64>>,
+        ticks: Option<Vec<i32>>,
+        prop_states: Option<Vec<WantedPropState>>,
+        filter: Option<String>,
+        derived_columns: Option<Vec<String>>,
+    ) -> PyResult<Py<PyAny>> {
+        let settings = self.ticks_settings(
+            wanted_props,
+            players,
+            ticks,
+            prop_states,
+            filter,
+            derived_columns,
+        )?;
+        self.spawn_async(py, settings, ticks_to_df)
+    }
+
+    /// Like parse_ticks but returns a polars LazyFrame and parses only when it is collected.
+    /// Only the selected columns are parsed and simple filters like pl.col("tick") > 1000 or
+    /// pl.col("steamid") == 76561198000000000 are checked while parsing, so filtered out rows
+    /// are never created.
+    pub fn scan_ticks(slf: &Bound<'_, Self>, wanted_props: Vec<String>) -> PyResult<Py<PyAny>> {
+        let py = slf.py();
+        let this = slf.borrow();
+        let settings = this.ticks_settings(wanted_props, None, None, None, None, None)?;
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let mmap = this.mmap.clone();
+        let prop_types = match py.detach(|| parser.prop_types(&mmap)) {
+            Ok(prop_types) => prop_types,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let polars = py.import("polars")?;
+        let schema = PyDict::new(py);
+        for (name, prop_type) in &prop_types {
+            schema.set_item(name, scan::polars_dtype(&polars, prop_type)?)?;
+        }
 
-                    Some(VarVec::Stickers(data)) => {
-                        let mut dicts = vec![];
-                        for weapon in data {
-                            let mut v = vec![];
-                            for sticker in weapon {
-                                let dict = PyDict::new(py);
-                                dict.set_item("id", sticker.id)?;
-                                dict.set_item("name", sticker.name.clone())?;
-                                dict.set_item("wear", sticker.wear)?;
-                                dict.set_item("x", sticker.x)?;
-                                dict.set_item("y", sticker.y)?;
-                                v.push(dict);
-                            }
-                            dicts.push(v);
+        let demo_parser = slf.clone().unbind();
+        let scan_schema = schema.clone().unbind();
+        // Called by polars as io_source(with_columns, predicate, n_rows, batch_size)
+        let io_source = PyCFunction::new_closure(
+            py,
+            None,
+            None,
+            move |args, _kwargs| -> PyResult<Py<PyAny>> {
+                let py = args.py();
+                demo_parser.borrow(py).scan_ticks_batch(
+                    py,
+                    scan_schema.bind(py),
+                    args.get_item(0)?.extract()?,
+                    &args.get_item(1)?,
+                    args.get_item(2)?.extract()?,
+                )
+            },
+        )?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("schema", schema)?;
+        let lazy_frame = py.import("polars.io.plugins")?.call_method(
+            "register_io_source",
+            (io_source,),
+            Some(&kwargs),
+        )?;
+        Ok(lazy_frame.unbind())
+    }
+
+    /// Props of the players in every event_name event at event tick + start, event tick + start + step
+    /// ... up to event tick + end, in a single pass over the demo. Events without players sample every
+    /// player. event_index is the index of the event in parse_event(event_name).
+    #[pyo3(signature = (event_name, wanted_props, *, start, end=0, step=1))]
+    pub fn parse_event_windows(
+        &self,
+        py: Python,
+        event_name: String,
+        wanted_props: Vec<String>,
+        start: i32,
+        end: i32,
+        step: i32,
+    ) -> PyResult<Py<PyAny>> {
+        if step < 1 || start > end {
+            return Err(PyValueError::new_err(
+                "step must be at least 1 and start can not be after end",
+            ));
+        }
+        let mut settings = self.ticks_settings(wanted_props, None, None, None, None, None)?;
+        settings.event_windows = vec![EventWindow::new(&event_name, start, end, step)];
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        warn_parse_warnings(&output.warnings)?;
+        event_windows_to_df(py, output)
+    }
+
+    /// Like parse_ticks but only returns a row (tick, steamid, prop, value) when the value
+    /// changes. Every prop is returned the first time a player is seen, and a row with prop None
+    /// when a player is no longer there (left or stopped matching the filter). With dense=True the
+    /// changes are forward-filled back into the same DataFrame parse_ticks would return.
+    #[pyo3(signature = (wanted_props, *, players=None, ticks=None, prop_states=None, filter=None, derived_columns=None, dense=false))]
+    pub fn parse_prop_changes(
+        &self,
+        py: Python,
+        wanted_props: Vec<String>,
+        players: Option<Vec<u64>>,
+        ticks: Option<Vec<i32>>,
+        prop_states: Option<Vec<WantedPropState>>,
+        filter: Option<String>,
+        derived_columns: Option<Vec<String>>,
+        dense: bool,
+    ) -> PyResult<Py<PyAny>> {
+        let mut settings = self.ticks_settings(
+            wanted_props,
+            players,
+            ticks,
+            prop_states,
+            filter,
+            derived_columns,
+        )?;
+        settings.only_prop_changes = true;
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let mut output = match self.run_parser(py, parser)? {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        warn_parse_warnings(&output.warnings)?;
+        if dense {
+            output.df = output
+                .prop_changes
+                .to_dense(&[], &output.prop_controller.prop_infos);
+            return ticks_to_df(py, output);
+        }
+        prop_changes_to_df(py, output)
+    }
+
+    /// Same as parse_ticks but returns an ArrowTable that implements __arrow_c_stream__.
+    /// Vector, sticker and inventory columns are kept as typed struct/list columns instead
+    /// of python objects: pyarrow.table(t), polars.DataFrame(t) or duckdb can read it directly.
+    #[pyo3(signature = (wanted_props, *, players=None, ticks=None, prop_states=None, filter=None, derived_columns=None))]
+    pub fn parse_ticks_arrow(
+        &self,
+        py: Python,
+        wanted_props: Vec<String>,
+        players: Option<Vec<u64>>,
+        ticks: Option<Vec<i32>>,
+        prop_states: Option<Vec<WantedPropState>>,
+        filter: Option<String>,
+        derived_columns: Option<Vec<String>>,
+    ) -> PyResult<ArrowTable> {
+        let settings = self.ticks_settings(
+            wanted_props,
+            players,
+            ticks,
+            prop_states,
+            filter,
+            derived_columns,
+        )?;
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        warn_parse_warnings(&output.warnings)?;
+        arrow::ticks_to_arrow(&output)
+    }
+}
+
+fn ticks_to_df(py: Python<'_>, output: DemoOutput) -> PyResult<Py<PyAny>> {
+    let mut all_series = vec![];
+    let mut all_pyobjects = vec![];
+    let prop_infos = output.prop_controller.prop_infos;
+    let mut df_column_names_arrow = vec![];
+    let mut df_column_names_py = vec![];
+
+    for prop_info in prop_infos {
+        if output.df.contains_key(&prop_info.id) {
+            match &output.df[&prop_info.id].data {
+                Some(VarVec::F32(data)) => {
+                    df_column_names_arrow.push(prop_info.prop_friendly_name);
+                    all_series.push(arr_to_py(Box::new(Float32Array::from(data)))?);
+                }
+                Some(VarVec::I32(data)) => {
+                    df_column_names_arrow.push(prop_info.prop_friendly_name);
+                    all_series.push(arr_to_py(Box::new(Int32Array::from(data)))?);
+                }
+                Some(VarVec::U64(data)) => {
+                    df_column_names_arrow.push(prop_info.prop_friendly_name);
+                    all_series.push(arr_to_py(Box::new(UInt64Array::from(data)))?);
+                }
+                Some(VarVec::U32(data)) => {
+                    df_column_names_arrow.push(prop_info.prop_friendly_name);
+                    all_series.push(arr_to_py(Box::new(UInt32Array::from(data)))?);
+                }
+                Some(VarVec::Bool(data)) => {
+                    df_column_names_arrow.push(prop_info.prop_friendly_name);
+                    all_series.push(arr_to_py(Box::new(BooleanArray::from(data)))?);
+                }
+                Some(VarVec::String(data)) => {
+                    df_column_names_arrow.push(prop_info.prop_friendly_name.clone());
+                    let s = Series::new(&prop_info.prop_friendly_name.clone(), data);
+                    let py_series = rust_series_to_py_series(&s)?;
+                    all_series.push(py_series);
+                }
+                Some(VarVec::StringVec(data)) => {
+                    df_column_names_py.push(prop_info.prop_friendly_name);
+                    all_pyobjects.push(data.into_py_any(py)?);
+                }
+                Some(VarVec::U64Vec(data)) => {
+                    df_column_names_py.push(prop_info.prop_friendly_name);
+                    all_pyobjects.push(data.into_py_any(py)?);
+                }
+                Some(VarVec::XYZVec(data)) => {
+                    df_column_names_py.push(prop_info.prop_friendly_name);
+                    all_pyobjects.push(data.into_py_any(py)?);
+                }
+                Some(VarVec::U32Vec(data)) => {
+                    df_column_names_py.push(prop_info.prop_friendly_name);
+                    all_pyobjects.push(data.into_py_any(py)?);
+                }
+
+                Some(VarVec::Stickers(data)) => {
+                    let mut dicts = vec![];
+                    for weapon in data {
+                        let mut v = vec![];
+                        for sticker in weapon {
+                            let dict = PyDict::new(py);
+                            dict.set_item("id", sticker.id)?;
+                            dict.set_item("name", sticker.name.clone())?;
+                            dict.set_item("wear", sticker.wear)?;
+                            dict.set_item("x", sticker.x)?;
+                            dict.set_item("y", sticker.y)?;
+                            v.push(dict);
                         }
-                        df_column_names_py.push(prop_info.prop_friendly_name);
-                        all_pyobjects.push(dicts.into_py_any(py)?);
+                        dicts.push(v);
                     }
+                    df_column_names_py.push(prop_info.prop_friendly_name);
+                    all_pyobjects.push(dicts.into_py_any(py)?);
+                }
 
-                    Some(VarVec::InputHistory(data)) => {
-                        let mut dicts = vec![];
-                        for input in data {
-                            let mut v = vec![];
-                            for sticker in input {
-                                let dict = PyDict::new(py);
-                                dict.set_item("x", sticker.x)?;
-                                dict.set_item("y", sticker.y)?;
-                                dict.set_item("z", sticker.z)?;
-                                dict.set_item("render_tick_count", sticker.render_tick_count)?;
-                                dict.set_item(
-                                    "render_tick_fraction",
-                                    sticker.render_tick_fraction,
-                                )?;
-                                dict.set_item("player_tick_count", sticker.player_tick_count)?;
-                                dict.set_item(
-                                    "player_tick_fraction",
-                                    sticker.player_tick_fraction,
-                                )?;
-                                v.push(dict);
-                            }
-                            dicts.push(v);
+                Some(VarVec::InputHistory(data)) => {
+                    let mut dicts = vec![];
+                    for input in data {
+                        let mut v = vec![];
+                        for sticker in input {
+                            let dict = PyDict::new(py);
+                            dict.set_item("x", sticker.x)?;
+                            dict.set_item("y", sticker.y)?;
+                            dict.set_item("z", sticker.z)?;
+                            dict.set_item("render_tick_count", sticker.render_tick_count)?;
+                            dict.set_item("render_tick_fraction", sticker.render_tick_fraction)?;
+                            dict.set_item("player_tick_count", sticker.player_tick_count)?;
+                            dict.set_item("player_tick_fraction", sticker.player_tick_fraction)?;
+                            v.push(dict);
                         }
-                        df_column_names_py.push(prop_info.prop_friendly_name);
-                        all_pyobjects.push(dicts.into_py_any(py)?);
+                        dicts.push(v);
                     }
-                    _ => {}
+                    df_column_names_py.push(prop_info.prop_friendly_name);
+                    all_pyobjects.push(dicts.into_py_any(py)?);
                 }
+                _ => {}
             }
         }
-        Python::attach(|py| {
-            let polars = py.import("polars")?;
-            let all_series_py = all_series.into_py_any(py)?;
-            let df = polars.call_method1("DataFrame", (all_series_py,))?;
-            df.setattr("columns", df_column_names_arrow.clone())?;
-            let pandas_df = df.call_method0("to_pandas")?;
-            for (pyobj, col_name) in all_pyobjects.iter().zip(&df_column_names_py) {
-                pandas_df.call_method1("insert", (0, col_name, pyobj))?;
-            }
-            df_column_names_arrow.extend(df_column_names_py);
-            df_column_names_arrow.sort();
-            let kwargs = vec![("axis", 1)].into_py_dict(py).unwrap();
-            let args = (df_column_names_arrow,);
-            pandas_df.call_method("reindex", args, Some(&kwargs))?;
-            pandas_df.into_py_any(py)
+    }
+    Python::attach(|py| {
+        let polars = py.import("polars")?;
+        let all_series_py = all_series.into_py_any(py)?;
+        let df = polars.call_method1("DataFrame", (all_series_py,))?;
+        df.setattr("columns", df_column_names_arrow.clone())?;
+        let pandas_df = df.call_method0("to_pandas")?;
+        for (pyobj, col_name) in all_pyobjects.iter().zip(&df_column_names_py) {
+            pandas_df.call_method1("insert", (0, col_name, pyobj))?;
+        }
+        df_column_names_arrow.extend(df_column_names_py);
+        df_column_names_arrow.sort();
+        let kwargs = vec![("axis", 1)].into_py_dict(py).unwrap();
+        let args = (df_column_names_arrow,);
+        pandas_df.call_method("reindex", args, Some(&kwargs))?;
+        pandas_df.into_py_any(py)
+    })
+}
+
+fn event_windows_to_df(py: Python<'_>, mut output: DemoOutput) -> PyResult<Py<PyAny>> {
+    let keys = std::mem::take(&mut output.event_windows.keys);
+    output.df = std::mem::take(&mut output.event_windows.df);
+    let df = ticks_to_df(py, output)?;
+    let df = df.bind(py);
+    let key_columns = [
+        (
+            "event_index",
+            keys.iter()
+                .map(|k| k.event_index)
+                .collect_vec()
+                .into_py_any(py)?,
+        ),
+        (
+            "event_tick",
+            keys.iter()
+                .map(|k| k.event_tick)
+                .collect_vec()
+                .into_py_any(py)?,
+        ),
+        (
+            "offset",
+            keys.iter()
+                .map(|k| k.offset)
+                .collect_vec()
+                .into_py_any(py)?,
+        ),
+        (
+            "role",
+            keys.iter()
+                .map(|k| k.role.clone())
+                .collect_vec()
+                .into_py_any(py)?,
+        ),
+    ];
+    // Inserted at 0 in reverse so they end up as the first columns in this order
+    for (name, values) in key_columns.into_iter().rev() {
+        df.call_method1("insert", (0, name, values))?;
+    }
+    Ok(df.clone().unbind())
+}
+
+fn prop_changes_to_df(py: Python<'_>, output: DemoOutput) -> PyResult<Py<PyAny>> {
+    let names: AHashMap<u32, &String> = output
+        .prop_controller
+        .prop_infos
+        .iter()
+        .map(|info| (info.id, &info.prop_friendly_name))
+        .collect();
+    let changes = &output.prop_changes;
+    // Players that left (or stopped matching the filter) get a row with prop and value None
+    let mut rows = (0..changes.len())
+        .map(|idx| {
+            (
+                changes.ticks[idx],
+                changes.player_keys[idx],
+                changes.steamids[idx],
+                Some(idx),
+            )
         })
+        .chain(
+            changes
+                .left
+                .iter()
+                .map(|left| (left.tick, left.player_key, left.steamid, None)),
+        )
+        .collect_vec();
+    rows.sort_by_key(|(tick, player_key, _, _)| (*tick, *player_key));
+
+    let mut props = Vec::with_capacity(rows.len());
+    let mut values = Vec::with_capacity(rows.len());
+    for (_, _, _, idx) in &rows {
+        match idx {
+            Some(idx) => {
+                props.push(names.get(&changes.prop_ids[*idx]).map(|name| name.as_str()));
+                values.push(variant_to_py(py, &changes.values[*idx])?);
+            }
+            None => {
+                props.push(None);
+                values.push(py.None());
+            }
+        }
+    }
+    let columns = PyDict::new(py);
+    columns.set_item("tick", rows.iter().map(|row| row.0).collect_vec())?;
+    columns.set_item("steamid", rows.iter().map(|row| row.2).collect_vec())?;
+    columns.set_item("player_key", rows.iter().map(|row| row.1).collect_vec())?;
+    columns.set_item("prop", props)?;
+    columns.set_item("value", values)?;
+    let pandas = py.import("pandas")?;
+    pandas
+        .call_method1("DataFrame", (columns,))?
+        .into_py_any(py)
+}
+
+// Values in the same column can have different types so each one is converted separately
+fn variant_to_py(py: Python<'_>, value: &Option<Variant>) -> PyResult<Py<PyAny>> {
+    let Some(value) = value else {
+        return Ok(py.None());
+    };
+    match value {
+        Variant::Bool(v) => v.into_py_any(py),
+        Variant::U32(v) => v.into_py_any(py),
+        Variant::I32(v) => v.into_py_any(py),
+        Variant::F32(v) => v.into_py_any(py),
+        Variant::U64(v) => v.into_py_any(py),
+        Variant::String(v) => v.into_py_any(py),
+        Variant::VecXY(v) => v.into_py_any(py),
+        Variant::VecXYZ(v) => v.into_py_any(py),
+        Variant::StringVec(v) => v.into_py_any(py),
+        Variant::U32Vec(v) => v.into_py_any(py),
+        Variant::U64Vec(v) => v.into_py_any(py),
+        Variant::Stickers(stickers) => {
+            let mut v = vec![];
+            for sticker in stickers {
+                let dict = PyDict::new(py);
+                dict.set_item("id", sticker.id)?;
+                dict.set_item("name", sticker.name.clone())?;
+                dict.set_item("wear", sticker.wear)?;
+                dict.set_item("x", sticker.x)?;
+                dict.set_item("y", sticker.y)?;
+                v.push(dict);
+            }
+            v.into_py_any(py)
+        }
+        Variant::InputHistory(inputs) => {
+            let mut v = vec![];
+            for input in inputs {
+                let dict = PyDict::new(py);
+                dict.set_item("x", input.x)?;
+                dict.set_item("y", input.y)?;
+                dict.set_item("z", input.z)?;
+                dict.set_item("render_tick_count", input.render_tick_count)?;
+                dict.set_item("render_tick_fraction", input.render_tick_fraction)?;
+                dict.set_item("player_tick_count", input.player_tick_count)?;
+                dict.set_item("player_tick_fraction", input.player_tick_fraction)?;
+                v.push(dict);
+            }
+            v.into_py_any(py)
+        }
+    }
+}
+
+fn string_table_value_to_py(py: Python<'_>, table: &str, value: &[u8]) -> PyResult<Py<PyAny>> {
+    match decode_string_table_value(table, value) {
+        Some(StringTableValue::UserInfo(player)) => {
+            let dict = PyDict::new(py);
+            dict.set_item("steamid", player.steamid)?;
+            dict.set_item("name", player.name)?;
+            dict.set_item("userid", player.userid)?;
+            dict.set_item("is_hltv", player.is_hltv)?;
+            dict.into_py_any(py)
+        }
+        Some(StringTableValue::String(s)) => s.into_py_any(py),
+        None => Ok(py.None()),
+    }
+}
+
+fn event_to_df(py: Python<'_>, output: DemoOutput) -> PyResult<Py<PyAny>> {
+    match series_from_event(&output.game_events, py) {
+        Ok(ser) => Ok(ser),
+        Err(_e) => Ok(PyList::empty(py).into()),
+    }
+}
+
+fn events_to_dfs(py: Python<'_>, output: DemoOutput) -> PyResult<Py<PyAny>> {
+    match series_from_multiple_events(&output.game_events, py) {
+        Ok(ser) => Ok(ser),
+        Err(e) => Err(Exception::new_err(format!("{e}"))),
     }
 }
 
@@ -940,8 +1689,392 @@ pub fn arr_to_py(array: Box<dyn Array>) -> PyResult<Py<PyAny>> {
 }
 #[pyclass]
 struct DemoParser {
-    mmap: Mmap,
-    huf: Vec<(u8, u8)>,
+    mmap: Arc<Mmap>,
+    huf: &'static Vec<(u8, u8)>,
+    lenient: bool,
+    // Called with (bytes_processed, total_bytes) while parsing
+    progress_callback: Option<Py<PyAny>>,
+    // Keep coaches, spectators and GOTV in every output
+    include_non_players: bool,
+}
+
+impl DemoParser {
+    fn ticks_settings(
+        &self,
+        wanted_props: Vec<String>,
+        players: Option<Vec<u64>>,
+        ticks: Option<Vec<i32>>,
+        prop_states: Option<Vec<WantedPropState>>,
+        filter: Option<String>,
+        derived_columns: Option<Vec<String>>,
+    ) -> PyResult<ParserInputs<'static>> {
+        let row_filters = match filter.map(|filter| RowFilter::parse(&filter)).transpose() {
+            Ok(row_filter) => row_filter.into_iter().collect_vec(),
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let derived_columns = match derived_columns
+            .unwrap_or_default()
+            .iter()
+            .map(|column| DerivedColumn::parse(column))
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(derived_columns) => derived_columns,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        // Props in the filter are only available to it if they are parsed
+        let mut wanted_props = wanted_props;
+        for filter in &row_filters {
+            for prop in filter.props() {
+                if !["tick", "steamid", "name"].contains(&prop)
+                    && !wanted_props.iter().any(|p| p == prop)
+                    && !derived_columns.iter().any(|c| c.name == prop)
+                {
+                    wanted_props.push(prop.to_string());
+                }
+            }
+        }
+        let wanted_players = players.unwrap_or_default();
+        let wanted_ticks = ticks.unwrap_or_default();
+        let wanted_prop_states = prop_states
+            .unwrap_or_default()
+            .into_iter()
+            .map(|prop| (prop.prop, prop.state.0))
+            .collect();
+
+        let real_props = rm_user_friendly_names(&wanted_props);
+        let real_wanted_prop_states = rm_map_user_friendly_names(&wanted_prop_states);
+
+        let real_props = match real_props {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let real_wanted_prop_states = match real_wanted_prop_states {
+            Ok(real_wanted_prop_states) => real_wanted_prop_states,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+
+        let mut real_name_to_og_name = AHashMap::default();
+        for (real_name, user_friendly_name) in real_props.iter().zip(&wanted_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        for (real_name, user_friendly_name) in real_wanted_prop_states
+            .keys()
+            .zip(wanted_prop_states.keys())
+        {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+
+        Ok(ParserInputs {
+            real_name_to_og_name,
+            wanted_players,
+            wanted_player_props: real_props,
+            wanted_other_props: vec![],
+            wanted_events: vec![],
+            wanted_prop_states: real_wanted_prop_states,
+            parse_ents: true,
+            wanted_ticks,
+            parse_projectiles: false,
+            parse_grenades: false,
+            only_header: true,
+            list_props: false,
+            only_convars: false,
+            huffman_lookup_table: self.huf,
+            order_by_steamid: false,
+            wanted_user_messages: vec![],
+            lenient: self.lenient,
+            parse_control: ParseControl::default(),
+            row_filters,
+            event_windows: vec![],
+            only_prop_changes: false,
+            wanted_string_tables: vec![],
+            include_non_players: self.include_non_players,
+            derived_columns,
+            fallback_bytes: None,
+        })
+    }
+
+    fn event_settings(
+        &self,
+        wanted_events: Vec<String>,
+        player: Option<Vec<String>>,
+        other: Option<Vec<String>>,
+    ) -> PyResult<ParserInputs<'static>> {
+        let wanted_player_props = player.unwrap_or_default();
+        let wanted_other_props = other.unwrap_or_default();
+
+        let real_player_props = rm_user_friendly_names(&wanted_player_props);
+        let real_other_props = rm_user_friendly_names(&wanted_other_props);
+
+        let real_player_props = match real_player_props {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let real_other_props = match real_other_props {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+
+        let mut real_name_to_og_name = AHashMap::default();
+        for (real_name, user_friendly_name) in real_player_props.iter().zip(&wanted_player_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        for (real_name, user_friendly_name) in real_other_props.iter().zip(&wanted_other_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+
+        Ok(ParserInputs {
+            real_name_to_og_name,
+            wanted_players: vec![],
+            wanted_player_props: real_player_props,
+            wanted_other_props: real_other_props,
+            wanted_events,
+            wanted_prop_states: AHashMap::default(),
+            parse_ents: true,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            parse_grenades: false,
+            only_header: true,
+            list_props: false,
+            only_convars: false,
+            huffman_lookup_table: self.huf,
+            order_by_steamid: false,
+            wanted_user_messages: vec![],
+            lenient: self.lenient,
+            parse_control: ParseControl::default(),
+            row_filters: vec![],
+            event_windows: vec![],
+            only_prop_changes: false,
+            wanted_string_tables: vec![],
+            include_non_players: self.include_non_players,
+            derived_columns: vec![],
+            fallback_bytes: None,
+        })
+    }
+
+    fn scan_ticks_batch(
+        &self,
+        py: Python<'_>,
+        schema: &Bound<'_, PyDict>,
+        with_columns: Option<Vec<String>>,
+        predicate: &Bound<'_, PyAny>,
+        n_rows: Option<usize>,
+    ) -> PyResult<Py<PyAny>> {
+        let columns: Vec<String> = match with_columns {
+            Some(columns) => columns,
+            None => schema.keys().extract()?,
+        };
+        let (row_filters, predicate_columns) = match predicate.is_none() {
+            true => (vec![], vec![]),
+            false => (
+                scan::predicate_to_row_filters(py, predicate),
+                predicate
+                    .getattr("meta")?
+                    .call_method0("root_names")?
+                    .extract::<Vec<String>>()?,
+            ),
+        };
+        // Columns used in the predicate must be parsed even if they are not selected.
+        // tick, steamid and name are always included by the parser
+        let wanted_props = columns
+            .iter()
+            .chain(&predicate_columns)
+            .filter(|name| !["tick", "steamid", "name"].contains(&name.as_str()))
+            .filter(|name| schema.contains(name).unwrap_or(false))
+            .unique()
+            .cloned()
+            .collect_vec();
+        let mut settings = self.ticks_settings(wanted_props, None, None, None, None, None)?;
+        settings.row_filters = row_filters;
+        let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match self.run_parser(py, parser)? {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        warn_parse_warnings(&output.warnings)?;
+
+        let polars = py.import("polars")?;
+        let dtypes = PyDict::new(py);
+        for column in &columns {
+            dtypes.set_item(column, schema.get_item(column)?)?;
+        }
+        let mut df = match output.df.is_empty() {
+            // No rows passed the row filters
+            true => polars.call_method1("DataFrame", (py.None(), dtypes.clone()))?,
+            false => {
+                let df = polars.call_method1("DataFrame", (arrow::ticks_to_arrow(&output)?,))?;
+                match predicate.is_none() {
+                    true => df,
+                    false => df.call_method1("filter", (predicate,))?,
+                }
+            }
+        };
+        df = df
+            .call_method1("select", (columns,))?
+            .call_method1("cast", (dtypes,))?;
+        if let Some(n_rows) = n_rows {
+            df = df.call_method1("head", (n_rows,))?;
+        }
+        let batches = py.import("builtins")?.call_method1("iter", (vec![df],))?;
+        Ok(batches.unbind())
+    }
+
+    /*
+    Parses on the rayon thread pool and returns an asyncio future that is resolved on the
+    running event loop. `to_python` converts the output once the parse is done.
+    Cancelling the future stops the parse.
+    */
+    fn spawn_async<F>(
+        &self,
+        py: Python<'_>,
+        mut settings: ParserInputs<'static>,
+        to_python: F,
+    ) -> PyResult<Py<PyAny>>
+    where
+        F: FnOnce(Python<'_>, DemoOutput) -> PyResult<Py<PyAny>> + Send + 'static,
+    {
+        let event_loop = py.import("asyncio")?.call_method0("get_running_loop")?;
+        let future = event_loop.call_method0("create_future")?;
+
+        let control = ParseControl::new();
+        let handle = control.clone();
+        let on_done =
+            PyCFunction::new_closure(py, None, None, move |args, _kwargs| -> PyResult<()> {
+                if args.get_item(0)?.call_method0("cancelled")?.is_truthy()? {
+                    handle.cancel();
+                }
+                Ok(())
+            })?;
+        future.call_method1("add_done_callback", (on_done,))?;
+        settings.parse_control = match &self.progress_callback {
+            Some(callback) => {
+                let callback = callback.clone_ref(py);
+                let handle = control.clone();
+                control.with_progress_callback(move |bytes_processed, total_bytes| {
+                    Python::attach(|py| {
+                        if let Err(e) = callback.call1(py, (bytes_processed, total_bytes)) {
+                            e.write_unraisable(py, None);
+                            handle.cancel();
+                        }
+                    })
+                })
+            }
+            None => control,
+        };
+
+        let mmap = self.mmap.clone();
+        let event_loop = event_loop.unbind();
+        let result_future = future.clone().unbind();
+        rayon::spawn(move || {
+            let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+            let output = parser.parse_demo(&mmap);
+            Python::attach(|py| {
+                let result = match output {
+                    Ok(output) => {
+                        warn_parse_warnings(&output.warnings).and_then(|_| to_python(py, output))
+                    }
+                    Err(e) => Err(Exception::new_err(format!("{e}"))),
+                };
+                if let Err(e) = resolve_future(py, &event_loop, result_future, result) {
+                    e.write_unraisable(py, None);
+                }
+            })
+        });
+        Ok(future.unbind())
+    }
+
+    /*
+    Runs the parser on another thread so that Ctrl+C (KeyboardInterrupt) and the progress
+    callback can be handled while waiting. Both cancel the parse if they raise.
+    The GIL is released while waiting so other Python threads can run meanwhile.
+    */
+    fn run_parser(
+        &self,
+        py: Python<'_>,
+        mut parser: Parser,
+    ) -> PyResult<Result<DemoOutput, DemoParserError>> {
+        let control = parser.parse_control().clone();
+        let mmap = &self.mmap;
+        let waiting_thread = std::thread::current();
+        let finished = AtomicBool::new(false);
+        std::thread::scope(|s| {
+            let handle = s.spawn(|| {
+                let output = parser.parse_demo(mmap);
+                finished.store(true, Ordering::Relaxed);
+                waiting_thread.unpark();
+                output
+            });
+            let mut last_progress = None;
+            while !finished.load(Ordering::Relaxed) {
+                py.detach(|| std::thread::park_timeout(Duration::from_millis(50)));
+                let progress = control.progress();
+                let result = py
+                    .check_signals()
+                    .and_then(|_| match &self.progress_callback {
+                        Some(callback) if last_progress != Some(progress) => {
+                            last_progress = Some(progress);
+                            callback.call1(py, progress).map(|_| ())
+                        }
+                        _ => Ok(()),
+                    });
+                if let Err(e) = result {
+                    control.cancel();
+                    return Err(e);
+                }
+            }
+            match handle.join() {
+                Ok(output) => Ok(output),
+                Err(_) => Err(Exception::new_err("parser thread panicked")),
+            }
+        })
+    }
+}
+
+// Sets the result from another thread. The future might have been cancelled while parsing.
+fn resolve_future(
+    py: Python<'_>,
+    event_loop: &Py<PyAny>,
+    future: Py<PyAny>,
+    result: PyResult<Py<PyAny>>,
+) -> PyResult<()> {
+    let (method, value) = match result {
+        Ok(value) => ("set_result", value),
+        Err(e) => ("set_exception", e.into_value(py).into_any()),
+    };
+    let callback =
+        PyCFunction::new_closure(py, None, None, move |args, _kwargs| -> PyResult<()> {
+            let py = args.py();
+            let future = future.bind(py);
+            if !future.call_method0("done")?.is_truthy()? {
+                future.call_method1(method, (value.clone_ref(py),))?;
+            }
+            Ok(())
+        })?;
+    event_loop.call_method1(py, "call_soon_threadsafe", (callback,))?;
+    Ok(())
+}
+
+// Same for every demo so only build it once
+fn huffman_lookup_table() -> &'static Vec<(u8, u8)> {
+    static HUFFMAN_LOOKUP_TABLE: OnceLock<Vec<(u8, u8)>> = OnceLock::new();
+    HUFFMAN_LOOKUP_TABLE.get_or_init(create_huffman_lookup_table)
+}
+
+// Lenient mode skips broken parts of the demo, let the user know what was skipped
+fn warn_parse_warnings(warnings: &[ParseWarning]) -> PyResult<()> {
+    if warnings.is_empty() {
+        return Ok(());
+    }
+    Python::attach(|py| {
+        let category = py.get_type::<PyUserWarning>();
+        for warning in warnings {
+            let message = CString::new(format!(
+                "Skipped broken part of demo at tick {} (byte offset {}): {}",
+                warning.tick, warning.byte_offset, warning.error
+            ))?;
+            PyErr::warn(py, &category, &message, 1)?;
+        }
+        Ok(())
+    })
 }
 
 pub fn series_from_multiple_events(
@@ -1269,9 +2402,102 @@ fn find_type_of_vals(pairs: &Vec<&EventField>) -> Result<Option<Variant>, DemoPa
     Ok(None)
 }
 
+fn demo_schema_from_path(py: Python<'_>, demo_path: String) -> PyResult<DemoSchema> {
+    let mmap = match create_mmap(demo_path.clone()) {
+        Ok(mmap) => mmap,
+        Err(e) => return Err(Exception::new_err(format!("{e}. File name: {demo_path}"))),
+    };
+    let settings = ParserInputs {
+        real_name_to_og_name: AHashMap::default(),
+        wanted_players: vec![],
+        wanted_player_props: vec![],
+        wanted_other_props: vec![],
+        wanted_prop_states: AHashMap::default(),
+        wanted_events: vec![],
+        parse_ents: false,
+        wanted_ticks: vec![],
+        parse_projectiles: false,
+        parse_grenades: false,
+        only_header: false,
+        list_props: false,
+        only_convars: false,
+        huffman_lookup_table: huffman_lookup_table(),
+        order_by_steamid: false,
+        wanted_user_messages: vec![],
+        lenient: false,
+        parse_control: ParseControl::default(),
+        row_filters: vec![],
+        event_windows: vec![],
+        only_prop_changes: false,
+        wanted_string_tables: vec![],
+        include_non_players: false,
+        derived_columns: vec![],
+        fallback_bytes: None,
+    };
+    let parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+    match py.detach(|| parser.demo_schema(&mmap)) {
+        Ok(schema) => Ok(schema),
+        Err(e) => Err(Exception::new_err(format!("{e}. File name: {demo_path}"))),
+    }
+}
+
+/// Props, serializers and game events that were added, removed or changed type from demo_a to
+/// demo_b, for example after a game update. Every list is empty if both are from the same build.
+#[pyfunction]
+fn diff_schemas(py: Python<'_>, demo_a: String, demo_b: String) -> PyResult<Py<PyAny>> {
+    let a = demo_schema_from_path(py, demo_a)?;
+    let b = demo_schema_from_path(py, demo_b)?;
+    let diff = parser::first_pass::schema::diff_schemas(&a, &b);
+    let retyped_props = PyList::empty(py);
+    for change in &diff.retyped_props {
+        let d = PyDict::new(py);
+        d.set_item("path", &change.after.path)?;
+        d.set_item("var_type_before", &change.before.var_type)?;
+        d.set_item("var_type_after", &change.after.var_type)?;
+        d.set_item("decoder_before", &change.before.decoder)?;
+        d.set_item("decoder_after", &change.after.decoder)?;
+        d.set_item("variant_type_before", change.before.variant_type)?;
+        d.set_item("variant_type_after", change.after.variant_type)?;
+        retyped_props.append(d)?;
+    }
+    let changed_game_events = PyList::empty(py);
+    for change in &diff.changed_game_events {
+        let d = PyDict::new(py);
+        d.set_item("name", &change.name)?;
+        d.set_item("added_keys", &change.added_keys)?;
+        d.set_item("removed_keys", &change.removed_keys)?;
+        d.set_item("retyped_keys", &change.retyped_keys)?;
+        changed_game_events.append(d)?;
+    }
+    let out = PyDict::new(py);
+    out.set_item(
+        "added_props",
+        diff.added_props
+            .iter()
+            .map(|p| p.path.clone())
+            .collect_vec(),
+    )?;
+    out.set_item(
+        "removed_props",
+        diff.removed_props
+            .iter()
+            .map(|p| p.path.clone())
+            .collect_vec(),
+    )?;
+    out.set_item("retyped_props", retyped_props)?;
+    out.set_item("added_serializers", &diff.added_serializers)?;
+    out.set_item("removed_serializers", &diff.removed_serializers)?;
+    out.set_item("added_game_events", &diff.added_game_events)?;
+    out.set_item("removed_game_events", &diff.removed_game_events)?;
+    out.set_item("changed_game_events", changed_game_events)?;
+    out.into_py_any(py)
+}
+
 #[pymodule]
 fn demoparser2(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<DemoParser>()?;
+    m.add_function(wrap_pyfunction!(diff_schemas, m)?)?;
     m.add_class::<WantedPropState>()?;
+    m.add_class::<ArrowTable>()?;
     Ok(())
 }
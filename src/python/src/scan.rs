@@ -0,0 +1,200 @@
+use parser::second_pass::row_filter::{CompareOp, RowFilter};
+use parser::second_pass::variants::Variant;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyString};
+
+/// polars dtype of a column with values of this type. Matches the arrow arrays in arrow.rs.
+/// None means the prop is never collected and the column is always null.
+pub fn polars_dtype<'py>(
+    polars: &Bound<'py, PyModule>,
+    prop_type: &Option<Variant>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let dtype = match prop_type {
+        None => polars.getattr("Null")?,
+        Some(Variant::Bool(_)) => polars.getattr("Boolean")?,
+        Some(Variant::U32(_)) => polars.getattr("UInt32")?,
+        Some(Variant::I32(_)) => polars.getattr("Int32")?,
+        Some(Variant::F32(_)) => polars.getattr("Float32")?,
+        Some(Variant::U64(_)) => polars.getattr("UInt64")?,
+        Some(Variant::String(_)) => polars.getattr("String")?,
+        Some(Variant::StringVec(_)) => list_dtype(polars, polars.getattr("String")?)?,
+        Some(Variant::U32Vec(_)) => list_dtype(polars, polars.getattr("UInt32")?)?,
+        Some(Variant::U64Vec(_)) => list_dtype(polars, polars.getattr("UInt64")?)?,
+        Some(Variant::VecXY(_)) => struct_dtype(polars, &[("x", "Float32"), ("y", "Float32")])?,
+        Some(Variant::VecXYZ(_)) => struct_dtype(
+            polars,
+            &[("x", "Float32"), ("y", "Float32"), ("z", "Float32")],
+        )?,
+        Some(Variant::Stickers(_)) => {
+            let sticker = struct_dtype(
+                polars,
+                &[
+                    ("id", "UInt32"),
+                    ("name", "String"),
+                    ("wear", "Float32"),
+                    ("x", "Float32"),
+                    ("y", "Float32"),
+                ],
+            )?;
+            list_dtype(polars, sticker)?
+        }
+        Some(Variant::InputHistory(_)) => {
+            let input = struct_dtype(
+                polars,
+                &[
+                    ("x", "Float32"),
+                    ("y", "Float32"),
+                    ("z", "Float32"),
+                    ("render_tick_count", "Int32"),
+                    ("render_tick_fraction", "Float32"),
+                    ("player_tick_count", "Int32"),
+                    ("player_tick_fraction", "Float32"),
+                ],
+            )?;
+            list_dtype(polars, input)?
+        }
+    };
+    Ok(dtype)
+}
+
+fn list_dtype<'py>(
+    polars: &Bound<'py, PyModule>,
+    inner: Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    polars.getattr("List")?.call1((inner,))
+}
+
+fn struct_dtype<'py>(
+    polars: &Bound<'py, PyModule>,
+    fields: &[(&str, &str)],
+) -> PyResult<Bound<'py, PyAny>> {
+    let dict = PyDict::new(polars.py());
+    for (name, dtype) in fields {
+        dict.set_item(name, polars.getattr(*dtype)?)?;
+    }
+    polars.getattr("Struct")?.call1((dict,))
+}
+
+/*
+Turns the simple parts of a polars predicate into RowFilters so they can be checked while
+parsing: comparisons between a column and a literal, combined with &. Everything else is
+left to polars. The full predicate is applied after parsing either way, so anything missed
+here only costs speed.
+*/
+pub fn predicate_to_row_filters(py: Python<'_>, predicate: &Bound<'_, PyAny>) -> Vec<RowFilter> {
+    let mut filters = vec![];
+    if let Ok(tree) = serialize_expr(py, predicate) {
+        collect_row_filters(&tree, &mut filters);
+    }
+    filters
+}
+
+fn serialize_expr<'py>(py: Python<'py>, expr: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("format", "json")?;
+    let json = expr
+        .getattr("meta")?
+        .call_method("serialize", (), Some(&kwargs))?;
+    py.import("json")?.call_method1("loads", (json,))
+}
+
+fn collect_row_filters(node: &Bound<'_, PyAny>, filters: &mut Vec<RowFilter>) {
+    let Some(expr) = dict_item(node, "BinaryExpr") else {
+        return;
+    };
+    let (Some(left), Some(right)) = (dict_item(&expr, "left"), dict_item(&expr, "right")) else {
+        return;
+    };
+    let Some(op) = dict_item(&expr, "op").and_then(|op| op.extract::<String>().ok()) else {
+        return;
+    };
+    match op.as_str() {
+        "And" | "LogicalAnd" => {
+            collect_row_filters(&left, filters);
+            collect_row_filters(&right, filters);
+        }
+        _ => {
+            if let Some(filter) = comparison_to_row_filter(&left, &op, &right) {
+                filters.push(filter);
+            }
+        }
+    }
+}
+
+fn comparison_to_row_filter(
+    left: &Bound<'_, PyAny>,
+    op: &str,
+    right: &Bound<'_, PyAny>,
+) -> Option<RowFilter> {
+    let op = match op {
+        "Eq" => CompareOp::Eq,
+        "NotEq" => CompareOp::NotEq,
+        "Lt" => CompareOp::Lt,
+        "LtEq" => CompareOp::LtEq,
+        "Gt" => CompareOp::Gt,
+        "GtEq" => CompareOp::GtEq,
+        _ => return None,
+    };
+    if let (Some(column), Some(value)) = (column_name(left), literal_value(right)) {
+        return Some(RowFilter::new(&column, op, value));
+    }
+    // lit(1000) < col("tick") is the same as col("tick") > lit(1000)
+    let flipped = match op {
+        CompareOp::Lt => CompareOp::Gt,
+        CompareOp::LtEq => CompareOp::GtEq,
+        CompareOp::Gt => CompareOp::Lt,
+        CompareOp::GtEq => CompareOp::LtEq,
+        other => other,
+    };
+    Some(RowFilter::new(
+        &column_name(right)?,
+        flipped,
+        literal_value(left)?,
+    ))
+}
+
+fn column_name(node: &Bound<'_, PyAny>) -> Option<String> {
+    dict_item(node, "Column")?.extract().ok()
+}
+
+// Float literals are not pushed down. The parser compares as f32, which can drop rows that
+// polars keeps (col("x") > 0.1 where x is exactly 0.1 as f32), and a missed row can't be recovered.
+fn literal_value(node: &Bound<'_, PyAny>) -> Option<Variant> {
+    let scalar = first_scalar(&dict_item(node, "Literal")?)?;
+    if scalar.is_instance_of::<PyBool>() {
+        return scalar.extract().ok().map(Variant::Bool);
+    }
+    if scalar.is_instance_of::<PyInt>() {
+        if let Ok(i) = scalar.extract::<i32>() {
+            return Some(Variant::I32(i));
+        }
+        return scalar.extract().ok().map(Variant::U64);
+    }
+    if scalar.is_instance_of::<PyString>() {
+        return scalar.extract().ok().map(Variant::String);
+    }
+    None
+}
+
+// The layout of literals differs between polars versions, for example {"Int": 5},
+// {"Dyn": {"Int": 5}} or {"Scalar": {"dtype": ..., "value": ...}}. Take the first scalar.
+fn first_scalar<'py>(node: &Bound<'py, PyAny>) -> Option<Bound<'py, PyAny>> {
+    if node.is_instance_of::<PyBool>()
+        || node.is_instance_of::<PyInt>()
+        || node.is_instance_of::<PyFloat>()
+        || node.is_instance_of::<PyString>()
+    {
+        return Some(node.clone());
+    }
+    let dict = node.cast::<PyDict>().ok()?;
+    dict.iter()
+        .filter(|(key, _)| {
+            key.extract::<String>()
+                .is_ok_and(|key| !matches!(key.as_str(), "dtype" | "Series" | "Range"))
+        })
+        .find_map(|(_, value)| first_scalar(&value))
+}
+
+fn dict_item<'py>(node: &Bound<'py, PyAny>, key: &str) -> Option<Bound<'py, PyAny>> {
+    node.cast::<PyDict>().ok()?.get_item(key).ok()?
+}
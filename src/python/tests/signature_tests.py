@@ -184,6 +184,65 @@ class SignatureTest(TestCase):
                 ["X", "Y"], prop_states=[{"prop": "is_alive", "state": True}]
             )
 
+    def test_parse_ticks_arrow_signature(self):
+        import pyarrow as pa
+
+        parser = DemoParser(demo_path)
+        table = parser.parse_ticks_arrow(["X", "inventory"], ticks=[10000, 10001])
+        self.assertIsInstance(table.column_names, list)
+        t = pa.table(table)
+        self.assertEqual(t.num_rows, len(table))
+        self.assertEqual(sorted(t.column_names), sorted(table.column_names))
+        self.assertTrue(pa.types.is_list(t.schema.field("inventory").type))
+
+        with self.assertRaises(Exception):
+            other_schema = pa.schema([("X", pa.string())])
+            table.__arrow_c_stream__(other_schema.__arrow_c_schema__())
+
+        with self.assertRaises(TypeError):
+            parser.parse_ticks_arrow(5)
+
+    def test_scan_ticks_signature(self):
+        import polars as pl
+
+        parser = DemoParser(demo_path)
+        props = ["X", "health", "is_alive", "inventory", "weapon_name"]
+        lf = parser.scan_ticks(props)
+        self.assertIsInstance(lf, pl.LazyFrame)
+
+        # Schema is known before parsing and matches what the parse gives
+        schema = lf.collect_schema()
+        for prop in props:
+            self.assertIn(prop, schema.names())
+        df = lf.filter((pl.col("tick") >= 10000) & (pl.col("tick") < 10002)).collect()
+        self.assertEqual(df.schema, schema)
+
+        expected = parser.parse_ticks(props, ticks=[10000, 10001])
+        self.assertEqual(len(df), len(expected))
+        self.assertEqual(sorted(df["steamid"].to_list()), sorted(expected["steamid"].to_list()))
+
+        only_x = lf.select("tick", "X").head(5).collect()
+        self.assertEqual(only_x.columns, ["tick", "X"])
+        self.assertLessEqual(len(only_x), 5)
+
+        with self.assertRaises(TypeError):
+            parser.scan_ticks(5)
+
+    def test_parse_events_arrow_signature(self):
+        import pyarrow as pa
+
+        parser = DemoParser(demo_path)
+        tables = parser.parse_events_arrow(["player_death"], player=["X"])
+        self.assertIsInstance(tables, list)
+        for event_name, table in tables:
+            self.assertEqual(event_name, "player_death")
+            t = pa.table(table)
+            self.assertIn("attacker_X", t.column_names)
+            self.assertEqual(t.num_rows, len(table))
+
+        with self.assertRaises(TypeError):
+            parser.parse_events_arrow(["player_death"], player=5)
+
     def test_list_updated_fields(self):
         parser = DemoParser(demo_path)
 
@@ -1,11 +1,16 @@
 use parser::first_pass::parser_settings::rm_user_friendly_names;
 use parser::first_pass::parser_settings::FirstPassParser;
 use parser::first_pass::parser_settings::ParserInputs;
+use parser::first_pass::validate::validate_demo;
+use parser::parse_control::ParseControl;
 use parser::parse_demo::Parser;
 use parser::parse_demo::ParsingMode::ForceSingleThreaded;
 use parser::second_pass::parser_settings::create_huffman_lookup_table;
+use parser::second_pass::row_filter::RowFilter;
 use parser::second_pass::variants::soa_to_aos;
 use parser::second_pass::variants::OutputSerdeHelperStruct;
+use parser::second_pass::votes::collect_votes;
+use parser::second_pass::votes::VOTE_EVENTS;
 use std::collections::HashMap;
 use std::iter::FromIterator;
 use std::result::Result;
@@ -18,6 +23,7 @@ pub fn parseEvent(
     event_name: Option<String>,
     wanted_player_props: Option<Vec<JsValue>>,
     wanted_other_props: Option<Vec<JsValue>>,
+    lenient: Option<bool>,
 ) -> Result<JsValue, JsError> {
     let player_props = match wanted_player_props {
         Some(p) => p.iter().map(|s| s.as_string().unwrap()).collect::<Vec<_>>(),
@@ -59,6 +65,15 @@ pub fn parseEvent(
         huffman_lookup_table: &arc_huf,
         order_by_steamid: false,
         wanted_prop_states: HashMap::default().into(),
+        wanted_user_messages: vec![],
+        lenient: lenient.unwrap_or(false),
+        parse_control: ParseControl::default(),
+        row_filters: vec![],
+        event_windows: vec![],
+        only_prop_changes: false,
+        wanted_string_tables: vec![],
+        include_non_players: false,
+        derived_columns: vec![],
         fallback_bytes: None,
         parse_grenades: false,
     };
@@ -79,6 +94,8 @@ pub fn parseEvents(
     event_names: Option<Vec<JsValue>>,
     wanted_player_props: Option<Vec<JsValue>>,
     wanted_other_props: Option<Vec<JsValue>>,
+    on_progress: Option<js_sys::Function>,
+    lenient: Option<bool>,
 ) -> Result<JsValue, JsError> {
     let event_names = match event_names {
         Some(p) => p.iter().map(|s| s.as_string().unwrap()).collect::<Vec<_>>(),
@@ -124,6 +141,15 @@ pub fn parseEvents(
         huffman_lookup_table: &arc_huf,
         order_by_steamid: false,
         wanted_prop_states: HashMap::default().into(),
+        wanted_user_messages: vec![],
+        lenient: lenient.unwrap_or(false),
+        parse_control: create_parse_control(on_progress),
+        row_filters: vec![],
+        event_windows: vec![],
+        only_prop_changes: false,
+        wanted_string_tables: vec![],
+        include_non_players: false,
+        derived_columns: vec![],
         fallback_bytes: None,
         parse_grenades: false,
     };
@@ -139,6 +165,109 @@ pub fn parseEvents(
     }
 }
 
+#[wasm_bindgen]
+pub fn parseUserMessages(
+    file: Vec<u8>,
+    message_names: Option<Vec<JsValue>>,
+) -> Result<JsValue, JsError> {
+    let message_names = match message_names {
+        Some(p) => match p.iter().map(|s| s.as_string()).collect::<Option<Vec<_>>>() {
+            Some(names) => names,
+            None => return Err(JsError::new("message names must be strings")),
+        },
+        None => vec![],
+    };
+    let arc_huf = Arc::new(create_huffman_lookup_table());
+    let settings = ParserInputs {
+        wanted_players: vec![],
+        wanted_player_props: vec![],
+        wanted_other_props: vec![],
+        real_name_to_og_name: HashMap::default().into(),
+        wanted_events: vec![],
+        parse_ents: false,
+        wanted_ticks: vec![],
+        parse_projectiles: false,
+        only_header: false,
+        list_props: false,
+        only_convars: false,
+        huffman_lookup_table: &arc_huf,
+        order_by_steamid: false,
+        wanted_prop_states: HashMap::default().into(),
+        wanted_user_messages: message_names,
+        lenient: false,
+        parse_control: ParseControl::default(),
+        row_filters: vec![],
+        event_windows: vec![],
+        only_prop_changes: false,
+        wanted_string_tables: vec![],
+        include_non_players: false,
+        derived_columns: vec![],
+        fallback_bytes: None,
+        parse_grenades: false,
+    };
+    let mut parser = Parser::new(settings, ForceSingleThreaded);
+
+    let output = match parser.parse_demo(&file) {
+        Ok(output) => output,
+        Err(e) => return Err(JsError::new(&format!("{}", e))),
+    };
+    match serde_wasm_bindgen::to_value(&output.user_messages) {
+        Ok(s) => Ok(s),
+        Err(e) => return Err(JsError::new(&format!("{}", e))),
+    }
+}
+
+#[wasm_bindgen]
+pub fn parseVotes(file: Vec<u8>) -> Result<JsValue, JsError> {
+    let arc_huf = Arc::new(create_huffman_lookup_table());
+    let settings = ParserInputs {
+        wanted_players: vec![],
+        wanted_player_props: vec![],
+        wanted_other_props: vec![],
+        real_name_to_og_name: HashMap::default().into(),
+        wanted_events: VOTE_EVENTS.iter().map(|x| x.to_string()).collect(),
+        parse_ents: true,
+        wanted_ticks: vec![],
+        parse_projectiles: false,
+        only_header: false,
+        list_props: false,
+        only_convars: false,
+        huffman_lookup_table: &arc_huf,
+        order_by_steamid: false,
+        wanted_prop_states: HashMap::default().into(),
+        wanted_user_messages: vec![],
+        lenient: false,
+        parse_control: ParseControl::default(),
+        row_filters: vec![],
+        event_windows: vec![],
+        only_prop_changes: false,
+        wanted_string_tables: vec![],
+        include_non_players: false,
+        derived_columns: vec![],
+        fallback_bytes: None,
+        parse_grenades: false,
+    };
+    let mut parser = Parser::new(settings, ForceSingleThreaded);
+
+    let output = match parser.parse_demo(&file) {
+        Ok(output) => output,
+        Err(e) => return Err(JsError::new(&format!("{}", e))),
+    };
+    match serde_wasm_bindgen::to_value(&collect_votes(&output.game_events)) {
+        Ok(s) => Ok(s),
+        Err(e) => return Err(JsError::new(&format!("{}", e))),
+    }
+}
+
+#[wasm_bindgen]
+pub fn validateDemo(file: Vec<u8>) -> Result<JsValue, JsError> {
+    let report = validate_demo(&file);
+    match serde_wasm_bindgen::to_value(&report) {
+        Ok(s) => Ok(s),
+        Err(e) => return Err(JsError::new(&format!("{}", e))),
+    }
+}
+
 #[wasm_bindgen]
 pub fn listGameEvents(fileBytes: Vec<u8>) -> Result<JsValue, JsError> {
     let arc_huf = Arc::new(create_huffman_lookup_table());
@@ -157,6 +286,15 @@ pub fn listGameEvents(fileBytes: Vec<u8>) -> Result<JsValue, JsError> {
         huffman_lookup_table: &arc_huf.clone(),
         order_by_steamid: false,
         wanted_prop_states: HashMap::default().into(),
+        wanted_user_messages: vec![],
+        lenient: false,
+        parse_control: ParseControl::default(),
+        row_filters: vec![],
+        event_windows: vec![],
+        only_prop_changes: false,
+        wanted_string_tables: vec![],
+        include_non_players: false,
+        derived_columns: vec![],
         fallback_bytes: None,
         parse_grenades: false,
     };
@@ -190,6 +328,15 @@ pub fn listUpdatedFields(fileBytes: Vec<u8>) -> Result<JsValue, JsError> {
         huffman_lookup_table: &arc_huf.clone(),
         order_by_steamid: false,
         wanted_prop_states: HashMap::default().into(),
+        wanted_user_messages: vec![],
+        lenient: false,
+        parse_control: ParseControl::default(),
+        row_filters: vec![],
+        event_windows: vec![],
+        only_prop_changes: false,
+        wanted_string_tables: vec![],
+        include_non_players: false,
+        derived_columns: vec![],
         fallback_bytes: None,
         parse_grenades: false,
     };
@@ -213,11 +360,29 @@ pub fn parseTicks(
     wanted_ticks: Option<Vec<i32>>,
     wanted_players: Option<Vec<JsValue>>,
     struct_of_arrays: Option<bool>,
+    on_progress: Option<js_sys::Function>,
+    filter: Option<String>,
+    lenient: Option<bool>,
 ) -> Result<JsValue, JsError> {
-    let wanted_props = match wanted_props {
+    let row_filters: Vec<RowFilter> =
+        match filter.map(|filter| RowFilter::parse(&filter)).transpose() {
+            Ok(row_filter) => row_filter.into_iter().collect(),
+            Err(e) => return Err(JsError::new(&format!("{}", e))),
+        };
+    let mut wanted_props = match wanted_props {
         Some(p) => p.iter().map(|s| s.as_string().unwrap()).collect::<Vec<_>>(),
         None => vec![],
     };
+    // Props in the filter are only available to it if they are parsed
+    for filter in &row_filters {
+        for prop in filter.props() {
+            if !["tick", "steamid", "name"].contains(&prop)
+                && !wanted_props.iter().any(|p| p == prop)
+            {
+                wanted_props.push(prop.to_string());
+            }
+        }
+    }
     let wanted_players_u64 = match wanted_players {
         Some(v) => v
             .iter()
@@ -253,6 +418,15 @@ pub fn parseTicks(
         huffman_lookup_table: &arc_huf.clone(),
         order_by_steamid: false,
         wanted_prop_states: HashMap::default().into(),
+        wanted_user_messages: vec![],
+        lenient: lenient.unwrap_or(false),
+        parse_control: create_parse_control(on_progress),
+        row_filters: row_filters,
+        event_windows: vec![],
+        only_prop_changes: false,
+        wanted_string_tables: vec![],
+        include_non_players: false,
+        derived_columns: vec![],
         fallback_bytes: None,
         parse_grenades: false,
     };
@@ -328,6 +502,15 @@ pub fn parseGrenades(
         huffman_lookup_table: &arc_huf.clone(),
         order_by_steamid: false,
         wanted_prop_states: HashMap::default().into(),
+        wanted_user_messages: vec![],
+        lenient: false,
+        parse_control: ParseControl::default(),
+        row_filters: vec![],
+        event_windows: vec![],
+        only_prop_changes: false,
+        wanted_string_tables: vec![],
+        include_non_players: false,
+        derived_columns: vec![],
         fallback_bytes: None,
         parse_grenades: grenades,
     };
@@ -370,6 +553,15 @@ pub fn parseHeader(file: Vec<u8>) -> Result<JsValue, JsError> {
         huffman_lookup_table: &arc_huf.clone(),
         order_by_steamid: false,
         wanted_prop_states: HashMap::default().into(),
+        wanted_user_messages: vec![],
+        lenient: false,
+        parse_control: ParseControl::default(),
+        row_filters: vec![],
+        event_windows: vec![],
+        only_prop_changes: false,
+        wanted_string_tables: vec![],
+        include_non_players: false,
+        derived_columns: vec![],
         fallback_bytes: None,
         parse_grenades: false,
     };
@@ -382,3 +574,37 @@ pub fn parseHeader(file: Vec<u8>) -> Result<JsValue, JsError> {
         Err(e) => return Err(JsError::new(&format!("{}", e))),
     }
 }
+
+// wasm is single threaded so the callback is only ever called from the thread that created it
+struct JsProgressCallback(js_sys::Function);
+unsafe impl Send for JsProgressCallback {}
+unsafe impl Sync for JsProgressCallback {}
+
+impl JsProgressCallback {
+    // Returns false if the callback returned false
+    fn call(&self, bytes_processed: usize, total_bytes: usize) -> bool {
+        let result = self.0.call2(
+            &JsValue::NULL,
+            &JsValue::from_f64(bytes_processed as f64),
+            &JsValue::from_f64(total_bytes as f64),
+        );
+        !matches!(result.map(|x| x.as_bool()), Ok(Some(false)))
+    }
+}
+
+// The callback gets (bytesProcessed, totalBytes). Returning false from it stops parsing.
+fn create_parse_control(on_progress: Option<js_sys::Function>) -> ParseControl {
+    let control = ParseControl::new();
+    match on_progress {
+        Some(callback) => {
+            let callback = JsProgressCallback(callback);
+            let handle = control.clone();
+            control.with_progress_callback(move |bytes_processed, total_bytes| {
+                if !callback.call(bytes_processed, total_bytes) {
+                    handle.cancel();
+                }
+            })
+        }
+        None => control,
+    }
+}